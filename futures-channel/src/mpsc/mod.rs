@@ -78,6 +78,7 @@
 // happens-before semantics required for the acquire / release semantics used
 // by the queue structure.
 
+use futures_core::future::Future;
 use futures_core::stream::{FusedStream, Stream};
 use futures_core::task::__internal::AtomicWaker;
 use futures_core::task::{Context, Poll, Waker};
@@ -987,6 +988,72 @@ impl<T> Receiver<T> {
         }
     }
 
+    /// Waits until at least one message is available, then moves up to `max`
+    /// of the messages which are available into `buf`, returning how many
+    /// messages were moved.
+    ///
+    /// This amortizes the cost of waiting over all the messages dequeued at
+    /// once. The returned future resolves to zero once the channel is closed
+    /// and all the messages have been received, or right away if `max` is
+    /// zero. Messages which are still buffered when the channel is closed are
+    /// received before that.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::channel::mpsc;
+    ///
+    /// let (mut tx, mut rx) = mpsc::channel(8);
+    /// for i in 0..5 {
+    ///     tx.try_send(i).unwrap();
+    /// }
+    /// drop(tx);
+    ///
+    /// let mut buf = Vec::new();
+    /// assert_eq!(rx.recv_many(&mut buf, 3).await, 3);
+    /// assert_eq!(rx.recv_many(&mut buf, 3).await, 2);
+    /// assert_eq!(rx.recv_many(&mut buf, 3).await, 0);
+    /// assert_eq!(buf, [0, 1, 2, 3, 4]);
+    /// # });
+    /// ```
+    pub fn recv_many<'a>(&'a mut self, buf: &'a mut Vec<T>, max: usize) -> RecvMany<'a, T> {
+        RecvMany { receiver: self, buf, max }
+    }
+
+    /// Polls for at least one message, then moves up to `max` of the messages
+    /// which are available into `buf`.
+    ///
+    /// This is the polling counterpart of
+    /// [`recv_many`](Receiver::recv_many), returning `Poll::Ready` with the
+    /// number of messages moved.
+    pub fn poll_recv_many(
+        &mut self,
+        cx: &mut Context<'_>,
+        buf: &mut Vec<T>,
+        max: usize,
+    ) -> Poll<usize> {
+        if max == 0 {
+            return Poll::Ready(0);
+        }
+        match Pin::new(&mut *self).poll_next(cx) {
+            Poll::Ready(Some(msg)) => buf.push(msg),
+            Poll::Ready(None) => return Poll::Ready(0),
+            Poll::Pending => return Poll::Pending,
+        }
+
+        let mut received = 1;
+        while received < max {
+            match self.next_message() {
+                Poll::Ready(Some(msg)) => buf.push(msg),
+                // The end of the channel is reported by the next call.
+                Poll::Ready(None) | Poll::Pending => break,
+            }
+            received += 1;
+        }
+        Poll::Ready(received)
+    }
+
     fn next_message(&mut self) -> Poll<Option<T>> {
         let inner = match self.inner.as_mut() {
             None => return Poll::Ready(None),
@@ -1077,6 +1144,26 @@ impl<T> Stream for Receiver<T> {
     }
 }
 
+/// Future for the [`recv_many`](Receiver::recv_many) method.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct RecvMany<'a, T> {
+    receiver: &'a mut Receiver<T>,
+    buf: &'a mut Vec<T>,
+    max: usize,
+}
+
+impl<T> Unpin for RecvMany<'_, T> {}
+
+impl<T> Future for RecvMany<'_, T> {
+    type Output = usize;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<usize> {
+        let this = &mut *self;
+        this.receiver.poll_recv_many(cx, this.buf, this.max)
+    }
+}
+
 impl<T> Drop for Receiver<T> {
     fn drop(&mut self) {
         // Drain the channel of all pending messages
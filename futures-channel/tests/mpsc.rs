@@ -1,6 +1,6 @@
 use futures::channel::{mpsc, oneshot};
 use futures::executor::{block_on, block_on_stream};
-use futures::future::{poll_fn, FutureExt};
+use futures::future::{poll_fn, Future, FutureExt};
 use futures::pin_mut;
 use futures::sink::{Sink, SinkExt};
 use futures::stream::{Stream, StreamExt};
@@ -131,6 +131,61 @@ fn tx_close_gets_none() {
     }));
 }
 
+#[test]
+fn recv_many() {
+    let (mut tx, mut rx) = mpsc::channel::<i32>(10);
+    let mut buf = Vec::new();
+    let (waker, counter) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+
+    {
+        let recv = rx.recv_many(&mut buf, 3);
+        pin_mut!(recv);
+        assert_eq!(recv.as_mut().poll(&mut cx), Poll::Pending);
+        for i in 0..5 {
+            tx.try_send(i).unwrap();
+        }
+        assert_eq!(counter, 1);
+        assert_eq!(recv.poll(&mut cx), Poll::Ready(3));
+    }
+    assert_eq!(buf, [0, 1, 2]);
+
+    // Closing the channel still lets the buffered messages be received first.
+    drop(tx);
+    assert_eq!(block_on(rx.recv_many(&mut buf, 10)), 2);
+    assert_eq!(block_on(rx.recv_many(&mut buf, 10)), 0);
+    assert_eq!(buf, [0, 1, 2, 3, 4]);
+}
+
+#[test]
+fn recv_many_zero_max() {
+    let (mut tx, mut rx) = mpsc::channel::<i32>(10);
+    tx.try_send(1).unwrap();
+
+    let mut buf = Vec::new();
+    assert_eq!(block_on(rx.recv_many(&mut buf, 0)), 0);
+    assert_eq!(block_on(rx.recv_many(&mut buf, 1)), 1);
+    assert_eq!(buf, [1]);
+}
+
+#[test]
+fn recv_many_threads() {
+    const AMT: usize = 1000;
+    let (mut tx, mut rx) = mpsc::channel::<usize>(16);
+
+    let t = thread::spawn(move || {
+        for i in 0..AMT {
+            block_on(tx.send(i)).unwrap();
+        }
+    });
+
+    let mut buf = Vec::new();
+    while block_on(rx.recv_many(&mut buf, 64)) > 0 {}
+    assert_eq!(buf, (0..AMT).collect::<Vec<_>>());
+
+    t.join().unwrap();
+}
+
 // #[test]
 // fn spawn_sends_items() {
 //     let core = local_executor::Core::new();
@@ -1,13 +1,11 @@
-use std::cell::Cell;
+use futures_task::__private::{self as private, Entered};
 use std::fmt;
 
-thread_local!(static ENTERED: Cell<bool> = Cell::new(false));
-
 /// Represents an executor context.
 ///
 /// For more details, see [`enter` documentation](enter()).
 pub struct Enter {
-    _priv: (),
+    _entered: Entered,
 }
 
 /// An error returned by `enter` if an execution scope has already been
@@ -53,15 +51,10 @@ impl std::error::Error for EnterError {}
 /// Returns an error if the current thread is already marked, in which case the
 /// caller should panic with a tailored error message.
 pub fn enter() -> Result<Enter, EnterError> {
-    ENTERED.with(|c| {
-        if c.get() {
-            Err(EnterError { _priv: () })
-        } else {
-            c.set(true);
-
-            Ok(Enter { _priv: () })
-        }
-    })
+    match private::enter() {
+        Some(entered) => Ok(Enter { _entered: entered }),
+        None => Err(EnterError { _priv: () }),
+    }
 }
 
 impl fmt::Debug for Enter {
@@ -69,12 +62,3 @@ impl fmt::Debug for Enter {
         f.debug_struct("Enter").finish()
     }
 }
-
-impl Drop for Enter {
-    fn drop(&mut self) {
-        ENTERED.with(|c| {
-            assert!(c.get());
-            c.set(false);
-        });
-    }
-}
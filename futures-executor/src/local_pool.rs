@@ -4,11 +4,17 @@ use futures_core::stream::Stream;
 use futures_core::task::{Context, Poll};
 use futures_task::{waker_ref, ArcWake};
 use futures_task::{FutureObj, LocalFutureObj, LocalSpawn, Spawn, SpawnError};
+use futures_util::future::{CatchUnwind, FutureExt};
 use futures_util::pin_mut;
 use futures_util::stream::FuturesUnordered;
 use futures_util::stream::StreamExt;
-use std::cell::RefCell;
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::mem;
 use std::ops::{Deref, DerefMut};
+use std::panic::{self, AssertUnwindSafe};
+use std::pin::Pin;
 use std::rc::{Rc, Weak};
 use std::sync::{
     atomic::{AtomicBool, Ordering},
@@ -27,10 +33,28 @@ use std::thread::{self, Thread};
 /// [`spawner()`](LocalPool::spawner) method. Because the executor is
 /// single-threaded, it supports a special form of task spawning for non-`Send`
 /// futures, via [`spawn_local_obj`](futures_task::LocalSpawn::spawn_local_obj).
-#[derive(Debug)]
+///
+/// By default, a panic in one of the pool's tasks unwinds out of the method
+/// running the pool. See [`set_panic_handler`](LocalPool::set_panic_handler)
+/// to handle such panics and keep running the other tasks instead.
 pub struct LocalPool {
-    pool: FuturesUnordered<LocalFutureObj<'static, ()>>,
+    pool: FuturesUnordered<CatchUnwind<AssertUnwindSafe<Task>>>,
     incoming: Rc<Incoming>,
+    panic_handler: Option<PanicHandler>,
+    // The number of times a task was polled, and the number of tasks which
+    // completed, over the lifetime of the pool.
+    polls: Rc<Cell<usize>>,
+    completed: usize,
+}
+
+impl fmt::Debug for LocalPool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LocalPool")
+            .field("pool", &self.pool)
+            .field("incoming", &self.incoming)
+            .field("has_panic_handler", &self.panic_handler.is_some())
+            .finish()
+    }
 }
 
 /// A handle to a [`LocalPool`](LocalPool) that implements
@@ -40,7 +64,57 @@ pub struct LocalSpawner {
     incoming: Weak<Incoming>,
 }
 
-type Incoming = RefCell<Vec<LocalFutureObj<'static, ()>>>;
+#[derive(Debug, Default)]
+struct Incoming {
+    tasks: RefCell<Vec<LocalFutureObj<'static, ()>>>,
+    // Set by `LocalSpawner::shutdown`, and shared with the spawned tasks so
+    // they aren't polled anymore.
+    shutdown: Rc<Cell<bool>>,
+}
+
+type PanicHandler = Box<dyn FnMut(Box<dyn Any + Send>)>;
+
+// A spawned task, counting how often the pool polls it.
+#[derive(Debug)]
+struct Task {
+    future: LocalFutureObj<'static, ()>,
+    polls: Rc<Cell<usize>>,
+    shutdown: Rc<Cell<bool>>,
+}
+
+impl Future for Task {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.shutdown.get() {
+            // The pool was shut down by a task polled before this one, and
+            // drops this task once `FuturesUnordered` returns.
+            return Poll::Pending;
+        }
+        self.polls.set(self.polls.get().wrapping_add(1));
+        self.future.poll_unpin(cx)
+    }
+}
+
+/// The progress made by [`LocalPool::run_until_stalled_report`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RunReport {
+    tasks_polled: usize,
+    completed: usize,
+}
+
+impl RunReport {
+    /// Returns the number of times a task was polled.
+    pub fn tasks_polled(&self) -> usize {
+        self.tasks_polled
+    }
+
+    /// Returns the number of tasks which completed, including tasks that
+    /// panicked if a [panic handler](LocalPool::set_panic_handler) is set.
+    pub fn completed(&self) -> usize {
+        self.completed
+    }
+}
 
 pub(crate) struct ThreadNotify {
     /// The (single) executor thread.
@@ -122,7 +196,44 @@ fn poll_executor<T, F: FnMut(&mut Context<'_>) -> T>(mut f: F) -> T {
 impl LocalPool {
     /// Create a new, empty pool of tasks.
     pub fn new() -> Self {
-        Self { pool: FuturesUnordered::new(), incoming: Default::default() }
+        Self {
+            pool: FuturesUnordered::new(),
+            incoming: Default::default(),
+            panic_handler: None,
+            polls: Default::default(),
+            completed: 0,
+        }
+    }
+
+    /// Sets a handler which is called with the panic payload whenever one of
+    /// the pool's tasks panics.
+    ///
+    /// The panicking task is removed from the pool, and the pool continues
+    /// running its other tasks, instead of the panic unwinding out of the
+    /// method running the pool. This replaces any previously set handler.
+    ///
+    /// ```
+    /// use futures::executor::LocalPool;
+    /// use futures::task::LocalSpawnExt;
+    /// use std::cell::Cell;
+    /// use std::rc::Rc;
+    ///
+    /// let mut pool = LocalPool::new();
+    /// let panics = Rc::new(Cell::new(0));
+    /// pool.set_panic_handler({
+    ///     let panics = panics.clone();
+    ///     Box::new(move |_payload| panics.set(panics.get() + 1))
+    /// });
+    ///
+    /// let spawner = pool.spawner();
+    /// spawner.spawn_local(async { panic!("task failed") }).unwrap();
+    /// spawner.spawn_local(async {}).unwrap();
+    ///
+    /// pool.run();
+    /// assert_eq!(panics.get(), 1);
+    /// ```
+    pub fn set_panic_handler(&mut self, handler: Box<dyn FnMut(Box<dyn Any + Send>)>) {
+        self.panic_handler = Some(handler);
     }
 
     /// Get a clonable handle to the pool as a [`Spawn`].
@@ -130,6 +241,28 @@ impl LocalPool {
         LocalSpawner { incoming: Rc::downgrade(&self.incoming) }
     }
 
+    /// Returns the number of tasks in the pool which haven't completed yet,
+    /// including tasks which were spawned but haven't been polled yet.
+    ///
+    /// ```
+    /// use futures::executor::LocalPool;
+    /// use futures::future::{pending, ready};
+    /// use futures::task::LocalSpawnExt;
+    ///
+    /// let mut pool = LocalPool::new();
+    /// let spawner = pool.spawner();
+    ///
+    /// spawner.spawn_local(ready(())).unwrap();
+    /// spawner.spawn_local(pending()).unwrap();
+    /// assert_eq!(pool.pending_tasks(), 2);
+    ///
+    /// pool.run_until_stalled();
+    /// assert_eq!(pool.pending_tasks(), 1);
+    /// ```
+    pub fn pending_tasks(&self) -> usize {
+        self.pool.len() + self.incoming.tasks.borrow().len()
+    }
+
     /// Run all tasks in the pool to completion.
     ///
     /// ```
@@ -166,6 +299,10 @@ impl LocalPool {
     /// be inert after the call completes, but can continue with further use of
     /// one of the pool's run or poll methods. While the function is running,
     /// however, all tasks in the pool will try to make progress.
+    ///
+    /// If the pool is [shut down](LocalSpawner::shutdown), the future is still
+    /// run to completion, even though the pool's tasks are not. It must not
+    /// depend on one of those tasks then, or this will block forever.
     pub fn run_until<F: Future>(&mut self, future: F) -> F::Output {
         pin_mut!(future);
 
@@ -224,7 +361,7 @@ impl LocalPool {
                 // if there are no new incoming futures
                 // then there is no feature that can make progress
                 // and we can return without having completed a single future
-                if self.incoming.borrow().is_empty() {
+                if self.incoming.tasks.borrow().is_empty() {
                     return false;
                 }
             }
@@ -256,10 +393,49 @@ impl LocalPool {
     /// remaining incomplete tasks in the pool can continue with further use of one
     /// of the pool's run or poll methods. While the function is running, all tasks
     /// in the pool will try to make progress.
+    ///
+    /// See [`run_until_stalled_report`](Self::run_until_stalled_report) to find
+    /// out how much progress was made.
     pub fn run_until_stalled(&mut self) {
+        let _ = self.run_until_stalled_report();
+    }
+
+    /// Runs all tasks in the pool like
+    /// [`run_until_stalled`](Self::run_until_stalled), returning a
+    /// [`RunReport`] of the progress made during this call.
+    ///
+    /// The report tells how many times tasks were polled and how many of them
+    /// completed, for example to decide whether to wait for a wakeup before
+    /// running the pool again.
+    ///
+    /// ```
+    /// use futures::executor::LocalPool;
+    /// use futures::task::LocalSpawnExt;
+    /// use futures::future::{ready, pending};
+    ///
+    /// let mut pool = LocalPool::new();
+    /// let spawner = pool.spawner();
+    ///
+    /// spawner.spawn_local(ready(())).unwrap();
+    /// spawner.spawn_local(ready(())).unwrap();
+    /// spawner.spawn_local(pending()).unwrap();
+    ///
+    /// let report = pool.run_until_stalled_report();
+    /// assert_eq!(report.tasks_polled(), 3);
+    /// assert_eq!(report.completed(), 2);
+    ///
+    /// // No task was woken up in the meantime, so nothing happens.
+    /// assert_eq!(pool.run_until_stalled_report().tasks_polled(), 0);
+    /// ```
+    pub fn run_until_stalled_report(&mut self) -> RunReport {
+        let (polls, completed) = (self.polls.get(), self.completed);
         poll_executor(|ctx| {
             let _ = self.poll_pool(ctx);
         });
+        RunReport {
+            tasks_polled: self.polls.get().wrapping_sub(polls),
+            completed: self.completed.wrapping_sub(completed),
+        }
     }
 
     // Make maximal progress on the entire pool of spawned task, returning `Ready`
@@ -270,7 +446,7 @@ impl LocalPool {
             let ret = self.poll_pool_once(cx);
 
             // we queued up some new tasks; add them and poll again
-            if !self.incoming.borrow().is_empty() {
+            if !self.incoming.tasks.borrow().is_empty() {
                 continue;
             }
 
@@ -283,18 +459,50 @@ impl LocalPool {
         }
     }
 
+    // Drop all tasks if the pool was shut down, returning whether it was.
+    fn drop_if_shutdown(&mut self) -> bool {
+        let shutdown = self.incoming.shutdown.get();
+        if shutdown {
+            self.pool.clear();
+        }
+        shutdown
+    }
+
     // Try make minimal progress on the pool of spawned tasks
     fn poll_pool_once(&mut self, cx: &mut Context<'_>) -> Poll<Option<()>> {
+        if self.drop_if_shutdown() {
+            return Poll::Ready(None);
+        }
+
         // empty the incoming queue of newly-spawned tasks
         {
-            let mut incoming = self.incoming.borrow_mut();
-            for task in incoming.drain(..) {
-                self.pool.push(task)
+            let mut incoming = self.incoming.tasks.borrow_mut();
+            for future in incoming.drain(..) {
+                let shutdown = self.incoming.shutdown.clone();
+                let task = Task { future, polls: self.polls.clone(), shutdown };
+                self.pool.push(AssertUnwindSafe(task).catch_unwind())
             }
         }
 
         // try to execute the next ready future
-        self.pool.poll_next_unpin(cx)
+        match self.pool.poll_next_unpin(cx) {
+            Poll::Ready(Some(Err(payload))) => {
+                self.completed = self.completed.wrapping_add(1);
+                match &mut self.panic_handler {
+                    Some(handler) => handler(payload),
+                    None => panic::resume_unwind(payload),
+                }
+                Poll::Ready(Some(()))
+            }
+            Poll::Ready(Some(Ok(()))) => {
+                self.completed = self.completed.wrapping_add(1);
+                Poll::Ready(Some(()))
+            }
+            Poll::Ready(None) => Poll::Ready(None),
+            // A task may have shut down the pool while it was polled.
+            Poll::Pending if self.drop_if_shutdown() => Poll::Ready(None),
+            Poll::Pending => Poll::Pending,
+        }
     }
 }
 
@@ -361,10 +569,57 @@ impl<S: Stream + Unpin> Iterator for BlockingStream<S> {
     }
 }
 
+impl LocalSpawner {
+    /// Shuts down the pool, cancelling all of its tasks.
+    ///
+    /// Tasks which were spawned but haven't been polled yet are dropped
+    /// immediately. The other tasks are dropped the next time the pool is
+    /// run, or right after the current task has been polled if this is
+    /// called from one of the pool's tasks; a task is never dropped while it
+    /// is being polled. Spawning on the pool fails with
+    /// [`SpawnError::shutdown`] afterwards, and running it returns right away,
+    /// except for [`run_until`](LocalPool::run_until): it no longer runs any
+    /// tasks, but still blocks until the given future completes.
+    ///
+    /// Does nothing if the pool was dropped or shut down already.
+    ///
+    /// ```
+    /// use futures::executor::LocalPool;
+    /// use futures::future::pending;
+    /// use futures::task::LocalSpawnExt;
+    ///
+    /// let mut pool = LocalPool::new();
+    /// let spawner = pool.spawner();
+    /// spawner.spawn_local(pending()).unwrap();
+    /// pool.run_until_stalled();
+    ///
+    /// spawner.shutdown();
+    /// assert!(spawner.spawn_local(async {}).unwrap_err().is_shutdown());
+    ///
+    /// // Would never return without the shutdown.
+    /// pool.run();
+    /// assert_eq!(pool.pending_tasks(), 0);
+    /// ```
+    pub fn shutdown(&self) {
+        if let Some(incoming) = self.incoming() {
+            incoming.shutdown.set(true);
+            // Drop the tasks outside of the borrow, in case their destructors
+            // try to spawn new tasks.
+            let tasks = mem::replace(&mut *incoming.tasks.borrow_mut(), Vec::new());
+            drop(tasks);
+        }
+    }
+
+    // The pool's queue of spawned tasks, unless it was dropped or shut down.
+    fn incoming(&self) -> Option<Rc<Incoming>> {
+        self.incoming.upgrade().filter(|incoming| !incoming.shutdown.get())
+    }
+}
+
 impl Spawn for LocalSpawner {
     fn spawn_obj(&self, future: FutureObj<'static, ()>) -> Result<(), SpawnError> {
-        if let Some(incoming) = self.incoming.upgrade() {
-            incoming.borrow_mut().push(future.into());
+        if let Some(incoming) = self.incoming() {
+            incoming.tasks.borrow_mut().push(future.into());
             Ok(())
         } else {
             Err(SpawnError::shutdown())
@@ -372,7 +627,7 @@ impl Spawn for LocalSpawner {
     }
 
     fn status(&self) -> Result<(), SpawnError> {
-        if self.incoming.upgrade().is_some() {
+        if self.incoming().is_some() {
             Ok(())
         } else {
             Err(SpawnError::shutdown())
@@ -382,8 +637,8 @@ impl Spawn for LocalSpawner {
 
 impl LocalSpawn for LocalSpawner {
     fn spawn_local_obj(&self, future: LocalFutureObj<'static, ()>) -> Result<(), SpawnError> {
-        if let Some(incoming) = self.incoming.upgrade() {
-            incoming.borrow_mut().push(future);
+        if let Some(incoming) = self.incoming() {
+            incoming.tasks.borrow_mut().push(future);
             Ok(())
         } else {
             Err(SpawnError::shutdown())
@@ -391,7 +646,7 @@ impl LocalSpawn for LocalSpawner {
     }
 
     fn status_local(&self) -> Result<(), SpawnError> {
-        if self.incoming.upgrade().is_some() {
+        if self.incoming().is_some() {
             Ok(())
         } else {
             Err(SpawnError::shutdown())
@@ -5,12 +5,15 @@ use futures_core::task::{Context, Poll};
 use futures_task::{waker_ref, ArcWake};
 use futures_task::{FutureObj, Spawn, SpawnError};
 use futures_util::future::FutureExt;
+use std::any::Any;
 use std::cmp;
 use std::fmt;
 use std::io;
-use std::sync::atomic::{AtomicUsize, Ordering};
+use std::mem;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::mpsc;
-use std::sync::{Arc, Mutex};
+use std::sync::{Arc, Condvar, Mutex};
 use std::thread;
 
 /// A general-purpose thread pool for scheduling tasks that poll futures to
@@ -40,8 +43,38 @@ pub struct ThreadPoolBuilder {
     name_prefix: Option<String>,
     after_start: Option<Arc<dyn Fn(usize) + Send + Sync>>,
     before_stop: Option<Arc<dyn Fn(usize) + Send + Sync>>,
+    panic_handler: Option<PanicHandler>,
+    panic_policy: PanicPolicy,
 }
 
+/// What a [`ThreadPool`]'s worker thread does when one of its tasks panics,
+/// set with [`ThreadPoolBuilder::panic_policy`].
+///
+/// This type is only available when the `thread-pool` feature of this
+/// library is activated.
+#[cfg_attr(docsrs, doc(cfg(feature = "thread-pool")))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PanicPolicy {
+    /// Drop the panicking task, pass the panic payload to the
+    /// [`panic_handler`](ThreadPoolBuilder::panic_handler), and keep running
+    /// other tasks on the worker thread.
+    CatchAndContinue,
+    /// Don't catch the panic, stopping the worker thread.
+    ///
+    /// The pool is shut down along with it: spawning fails with
+    /// [`SpawnError::shutdown`] afterwards, and the other tasks are cancelled
+    /// like with [`ShutdownHandle::shutdown_now`].
+    Propagate,
+}
+
+impl Default for PanicPolicy {
+    fn default() -> Self {
+        Self::CatchAndContinue
+    }
+}
+
+type PanicHandler = Arc<dyn Fn(Box<dyn Any + Send>) + Send + Sync>;
+
 trait AssertSendSync: Send + Sync {}
 impl AssertSendSync for ThreadPool {}
 
@@ -50,6 +83,31 @@ struct PoolState {
     rx: Mutex<mpsc::Receiver<Message>>,
     cnt: AtomicUsize,
     size: usize,
+    // The number of spawned tasks which haven't been dropped yet, along with
+    // the `SHUTDOWN` bit, after which no more tasks can be spawned.
+    tasks: AtomicUsize,
+    // Only used to wait for the last task to be dropped after a shutdown.
+    join_lock: Mutex<()>,
+    tasks_dropped: Condvar,
+    // Set by `ShutdownHandle::shutdown_now`, the workers drop the tasks
+    // instead of running them.
+    cancelled: AtomicBool,
+    workers: Mutex<Vec<thread::JoinHandle<()>>>,
+    panic_handler: Option<PanicHandler>,
+    panic_policy: PanicPolicy,
+}
+
+const SHUTDOWN: usize = !(usize::max_value() >> 1);
+
+/// A handle to wait for a [`ThreadPool`] to stop, returned by
+/// [`ThreadPool::shutdown`].
+///
+/// This type is only available when the `thread-pool` feature of this
+/// library is activated.
+#[cfg_attr(docsrs, doc(cfg(feature = "thread-pool")))]
+#[must_use = "the pool keeps running its tasks unless joined"]
+pub struct ShutdownHandle {
+    state: Arc<PoolState>,
 }
 
 impl fmt::Debug for ThreadPool {
@@ -58,11 +116,18 @@ impl fmt::Debug for ThreadPool {
     }
 }
 
+impl fmt::Debug for ShutdownHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ShutdownHandle").field("size", &self.state.size).finish()
+    }
+}
+
 impl fmt::Debug for ThreadPoolBuilder {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("ThreadPoolBuilder")
             .field("pool_size", &self.pool_size)
             .field("name_prefix", &self.name_prefix)
+            .field("panic_policy", &self.panic_policy)
             .finish()
     }
 }
@@ -94,14 +159,11 @@ impl ThreadPool {
     /// Spawns a future that will be run to completion.
     ///
     /// > **Note**: This method is similar to `Spawn::spawn_obj`, except that
-    /// >           it is guaranteed to always succeed.
+    /// >           it is guaranteed to always succeed, unless the pool was
+    /// >           [shut down](ThreadPool::shutdown), in which case the future
+    /// >           is dropped.
     pub fn spawn_obj_ok(&self, future: FutureObj<'static, ()>) {
-        let task = Task {
-            future,
-            wake_handle: Arc::new(WakeHandle { exec: self.clone(), mutex: UnparkMutex::new() }),
-            exec: self.clone(),
-        };
-        self.state.send(Message::Run(task));
+        let _ = self.spawn_obj(future);
     }
 
     /// Spawns a task that polls the given future with output `()` to
@@ -124,13 +186,126 @@ impl ThreadPool {
     {
         self.spawn_obj_ok(FutureObj::new(Box::new(future)))
     }
+
+    /// Shuts down the pool, returning a handle to wait for its threads to
+    /// stop.
+    ///
+    /// Spawning on any clone of the pool fails with
+    /// [`SpawnError::shutdown`] afterwards, while the tasks which were
+    /// spawned already keep running until the returned handle is used to
+    /// either [`join`](ShutdownHandle::join) them or to cancel them with
+    /// [`shutdown_now`](ShutdownHandle::shutdown_now). The `before_stop`
+    /// hook runs on each worker thread as it stops.
+    ///
+    /// ```
+    /// use futures::executor::ThreadPool;
+    /// use futures::task::SpawnExt;
+    ///
+    /// let pool = ThreadPool::new().unwrap();
+    /// let spawner = pool.clone();
+    /// pool.spawn_ok(async { /* ... */ });
+    ///
+    /// let handle = pool.shutdown();
+    /// assert!(spawner.spawn(async {}).unwrap_err().is_shutdown());
+    ///
+    /// // Returns once the spawned task has completed.
+    /// handle.join();
+    /// ```
+    pub fn shutdown(self) -> ShutdownHandle {
+        self.state.tasks.fetch_or(SHUTDOWN, Ordering::AcqRel);
+        ShutdownHandle { state: self.state.clone() }
+    }
 }
 
 impl Spawn for ThreadPool {
     fn spawn_obj(&self, future: FutureObj<'static, ()>) -> Result<(), SpawnError> {
-        self.spawn_obj_ok(future);
+        let mut tasks = self.state.tasks.load(Ordering::Relaxed);
+        loop {
+            if tasks & SHUTDOWN != 0 {
+                return Err(SpawnError::shutdown());
+            }
+            match self.state.tasks.compare_exchange_weak(
+                tasks,
+                tasks + 1,
+                Ordering::AcqRel,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => break,
+                Err(actual) => tasks = actual,
+            }
+        }
+        let task = Task {
+            future,
+            wake_handle: Arc::new(WakeHandle { exec: self.clone(), mutex: UnparkMutex::new() }),
+            exec: self.clone(),
+        };
+        self.state.send(Message::Run(task));
         Ok(())
     }
+
+    fn status(&self) -> Result<(), SpawnError> {
+        if self.state.tasks.load(Ordering::Acquire) & SHUTDOWN != 0 {
+            Err(SpawnError::shutdown())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl ShutdownHandle {
+    /// Blocks the current thread until all tasks spawned on the pool have
+    /// completed, and its worker threads have stopped.
+    ///
+    /// Tasks which never complete keep this from returning, much like
+    /// [`block_on`](crate::block_on) for a future which never completes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called from within an executor, including from one of the
+    /// pool's own tasks.
+    pub fn join(self) {
+        let _enter = enter().expect("cannot join a `ThreadPool` from within an executor");
+        let mut guard = self.state.join_lock.lock().unwrap();
+        while self.state.tasks.load(Ordering::Acquire) != SHUTDOWN {
+            guard = self.state.tasks_dropped.wait(guard).unwrap();
+        }
+        drop(guard);
+        self.stop_workers();
+    }
+
+    /// Cancels the tasks spawned on the pool, and blocks the current thread
+    /// until its worker threads have stopped.
+    ///
+    /// Tasks waiting to be polled are dropped without being polled, while
+    /// tasks which are being polled are dropped once their poll returns.
+    /// Tasks which aren't waiting to be polled, because they haven't been
+    /// woken up since they were last polled, are dropped when they're woken.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called from within an executor, including from one of the
+    /// pool's own tasks.
+    pub fn shutdown_now(self) {
+        let _enter = enter().expect("cannot shut down a `ThreadPool` from within an executor");
+        self.state.cancelled.store(true, Ordering::SeqCst);
+        self.stop_workers();
+    }
+
+    fn stop_workers(self) {
+        for _ in 0..self.state.size {
+            self.state.send(Message::Close);
+        }
+        // Only the first handle for the pool waits for its threads.
+        let workers = mem::replace(&mut *self.state.workers.lock().unwrap(), Vec::new());
+        for worker in workers {
+            // A worker thread only panics if one of the tasks did, which
+            // already stopped it.
+            let _ = worker.join();
+        }
+        // A task woken up just before it was cancelled may have been sent
+        // after the workers stopped, so drop it here.
+        self.state.drop_queued_tasks();
+    }
 }
 
 impl PoolState {
@@ -138,6 +313,42 @@ impl PoolState {
         self.tx.lock().unwrap().send(msg).unwrap();
     }
 
+    // Drops the tasks waiting to be run, keeping the `Close` messages for the
+    // workers.
+    fn drop_queued_tasks(&self) {
+        let mut closes = 0;
+        loop {
+            let msg = self.rx.lock().unwrap().try_recv();
+            match msg {
+                Ok(Message::Run(task)) => drop(task),
+                Ok(Message::Close) => closes += 1,
+                Err(_) => break,
+            }
+        }
+        for _ in 0..closes {
+            self.send(Message::Close);
+        }
+    }
+
+    // Called on the worker thread after a task panicked and was dropped.
+    fn task_panicked(&self, payload: Box<dyn Any + Send>) {
+        match self.panic_policy {
+            PanicPolicy::CatchAndContinue => {
+                if let Some(panic_handler) = &self.panic_handler {
+                    panic_handler(payload);
+                }
+            }
+            PanicPolicy::Propagate => {
+                // Rather than carrying on with one worker less, stop the pool
+                // as a whole.
+                self.tasks.fetch_or(SHUTDOWN, Ordering::AcqRel);
+                self.cancelled.store(true, Ordering::SeqCst);
+                self.drop_queued_tasks();
+                panic::resume_unwind(payload)
+            }
+        }
+    }
+
     fn work(
         &self,
         idx: usize,
@@ -151,6 +362,7 @@ impl PoolState {
         loop {
             let msg = self.rx.lock().unwrap().recv().unwrap();
             match msg {
+                Message::Run(task) if self.cancelled.load(Ordering::SeqCst) => drop(task),
                 Message::Run(task) => task.run(),
                 Message::Close => break,
             }
@@ -189,6 +401,8 @@ impl ThreadPoolBuilder {
             name_prefix: None,
             after_start: None,
             before_stop: None,
+            panic_handler: None,
+            panic_policy: PanicPolicy::default(),
         }
     }
 
@@ -258,6 +472,30 @@ impl ThreadPoolBuilder {
         self
     }
 
+    /// Execute closure `f` with the payload of each panic of a task in the
+    /// pool.
+    ///
+    /// The closure is called on the worker thread which ran the task, after
+    /// the task has been dropped, unless the [`panic_policy`] is
+    /// [`Propagate`](PanicPolicy::Propagate).
+    ///
+    /// [`panic_policy`]: ThreadPoolBuilder::panic_policy
+    pub fn panic_handler<F>(&mut self, f: F) -> &mut Self
+    where
+        F: Fn(Box<dyn Any + Send>) + Send + Sync + 'static,
+    {
+        self.panic_handler = Some(Arc::new(f));
+        self
+    }
+
+    /// Set what a worker thread does when one of its tasks panics.
+    ///
+    /// By default, this is [`PanicPolicy::CatchAndContinue`].
+    pub fn panic_policy(&mut self, policy: PanicPolicy) -> &mut Self {
+        self.panic_policy = policy;
+        self
+    }
+
     /// Create a [`ThreadPool`](ThreadPool) with the given configuration.
     pub fn create(&mut self) -> Result<ThreadPool, io::Error> {
         let (tx, rx) = mpsc::channel();
@@ -267,6 +505,13 @@ impl ThreadPoolBuilder {
                 rx: Mutex::new(rx),
                 cnt: AtomicUsize::new(1),
                 size: self.pool_size,
+                tasks: AtomicUsize::new(0),
+                join_lock: Mutex::new(()),
+                tasks_dropped: Condvar::new(),
+                cancelled: AtomicBool::new(false),
+                workers: Mutex::new(Vec::with_capacity(self.pool_size)),
+                panic_handler: self.panic_handler.clone(),
+                panic_policy: self.panic_policy,
             }),
         };
 
@@ -281,7 +526,9 @@ impl ThreadPoolBuilder {
             if self.stack_size > 0 {
                 thread_builder = thread_builder.stack_size(self.stack_size);
             }
-            thread_builder.spawn(move || state.work(counter, after_start, before_stop))?;
+            let worker =
+                thread_builder.spawn(move || state.work(counter, after_start, before_stop))?;
+            pool.state.workers.lock().unwrap().push(worker);
         }
         Ok(pool)
     }
@@ -309,9 +556,10 @@ impl Task {
     /// Actually run the task (invoking `poll` on the future) on the current
     /// thread.
     fn run(self) {
-        let Self { mut future, wake_handle, mut exec } = self;
+        let wake_handle = self.wake_handle.clone();
         let waker = waker_ref(&wake_handle);
         let mut cx = Context::from_waker(&waker);
+        let mut task = self;
 
         // Safety: The ownership of this `Task` object is evidence that
         // we are in the `POLLING`/`REPOLL` state for the mutex.
@@ -319,25 +567,44 @@ impl Task {
             wake_handle.mutex.start_poll();
 
             loop {
-                let res = future.poll_unpin(&mut cx);
+                let res = panic::catch_unwind(AssertUnwindSafe(|| task.future.poll_unpin(&mut cx)));
                 match res {
-                    Poll::Pending => {}
-                    Poll::Ready(()) => return wake_handle.mutex.complete(),
-                }
-                let task = Self { future, wake_handle: wake_handle.clone(), exec };
-                match wake_handle.mutex.wait(task) {
-                    Ok(()) => return, // we've waited
-                    Err(task) => {
-                        // someone's notified us
-                        future = task.future;
-                        exec = task.exec;
+                    Ok(Poll::Pending) if task.exec.state.cancelled.load(Ordering::SeqCst) => {}
+                    Ok(Poll::Pending) => match wake_handle.mutex.wait(task) {
+                        Ok(()) => return, // we've waited
+                        Err(t) => {
+                            // someone's notified us
+                            task = t;
+                            continue;
+                        }
+                    },
+                    Ok(Poll::Ready(())) => {}
+                    Err(payload) => {
+                        wake_handle.mutex.complete();
+                        let state = task.exec.state.clone();
+                        drop(task);
+                        return state.task_panicked(payload);
                     }
                 }
+                // The task completed or the pool was shut down, so `task` is
+                // dropped.
+                return wake_handle.mutex.complete();
             }
         }
     }
 }
 
+impl Drop for Task {
+    fn drop(&mut self) {
+        let state = &self.exec.state;
+        // Only a `join` waiting for the last task needs to be notified.
+        if state.tasks.fetch_sub(1, Ordering::AcqRel) == SHUTDOWN | 1 {
+            let _guard = state.join_lock.lock().unwrap();
+            state.tasks_dropped.notify_all();
+        }
+    }
+}
+
 impl fmt::Debug for Task {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Task").field("contents", &"...").finish()
@@ -347,7 +614,13 @@ impl fmt::Debug for Task {
 impl ArcWake for WakeHandle {
     fn wake_by_ref(arc_self: &Arc<Self>) {
         if let Ok(task) = arc_self.mutex.notify() {
-            arc_self.exec.state.send(Message::Run(task))
+            if arc_self.exec.state.cancelled.load(Ordering::SeqCst) {
+                // Safety: `notify` moved the mutex to the `POLLING` state.
+                unsafe { arc_self.mutex.complete() };
+                drop(task);
+            } else {
+                arc_self.exec.state.send(Message::Run(task))
+            }
         }
     }
 }
@@ -356,6 +629,7 @@ impl ArcWake for WakeHandle {
 mod tests {
     use super::*;
     use std::sync::mpsc;
+    use std::time::Duration;
 
     #[test]
     fn test_drop_after_start() {
@@ -371,4 +645,178 @@ mod tests {
         let count = rx.into_iter().count();
         assert_eq!(count, 2);
     }
+
+    struct DropCounter(Arc<AtomicUsize>);
+
+    impl Drop for DropCounter {
+        fn drop(&mut self) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn test_shutdown_join() {
+        let stopped = Arc::new(AtomicUsize::new(0));
+        let pool = ThreadPoolBuilder::new()
+            .pool_size(2)
+            .before_stop({
+                let stopped = stopped.clone();
+                move |_| {
+                    stopped.fetch_add(1, Ordering::SeqCst);
+                }
+            })
+            .create()
+            .unwrap();
+
+        let completed = Arc::new(AtomicUsize::new(0));
+        let (tx, rx) = futures::channel::oneshot::channel::<()>();
+        pool.spawn_ok({
+            let completed = completed.clone();
+            async move {
+                rx.await.unwrap();
+                completed.fetch_add(1, Ordering::SeqCst);
+            }
+        });
+        let spawner = pool.clone();
+        let handle = pool.shutdown();
+        assert!(spawner.spawn_obj(FutureObj::new(Box::new(async {}))).unwrap_err().is_shutdown());
+        assert!(spawner.status().unwrap_err().is_shutdown());
+
+        // The task spawned before the shutdown still runs to completion.
+        let sender = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(10));
+            tx.send(()).unwrap();
+        });
+        handle.join();
+        assert_eq!(completed.load(Ordering::SeqCst), 1);
+        assert_eq!(stopped.load(Ordering::SeqCst), 2);
+        sender.join().unwrap();
+    }
+
+    #[test]
+    fn test_shutdown_now() {
+        let stopped = Arc::new(AtomicUsize::new(0));
+        let pool = ThreadPoolBuilder::new()
+            .pool_size(1)
+            .before_stop({
+                let stopped = stopped.clone();
+                move |_| {
+                    stopped.fetch_add(1, Ordering::SeqCst);
+                }
+            })
+            .create()
+            .unwrap();
+
+        // Keep the only worker thread busy polling, so the other tasks stay
+        // queued.
+        let (started_tx, started_rx) = mpsc::channel();
+        let (release_tx, release_rx) = mpsc::channel::<()>();
+        let in_flight = Arc::new(AtomicUsize::new(0));
+        pool.spawn_ok({
+            let in_flight = DropCounter(in_flight.clone());
+            futures::future::lazy(move |_| {
+                started_tx.send(()).unwrap();
+                release_rx.recv().unwrap();
+                // The task isn't dropped while it's being polled.
+                assert_eq!(in_flight.0.load(Ordering::SeqCst), 0);
+            })
+        });
+        let polled = Arc::new(AtomicUsize::new(0));
+        let dropped = Arc::new(AtomicUsize::new(0));
+        for _ in 0..3 {
+            let polled = polled.clone();
+            let dropped = DropCounter(dropped.clone());
+            pool.spawn_ok(async move {
+                let _dropped = dropped;
+                polled.fetch_add(1, Ordering::SeqCst);
+            });
+        }
+        started_rx.recv().unwrap();
+
+        // Only let the first task return once the pool was cancelled.
+        let state = pool.state.clone();
+        let stopper = thread::spawn(move || pool.shutdown().shutdown_now());
+        while !state.cancelled.load(Ordering::SeqCst) {
+            thread::yield_now();
+        }
+        release_tx.send(()).unwrap();
+        stopper.join().unwrap();
+        assert_eq!(in_flight.load(Ordering::SeqCst), 1);
+        assert_eq!(polled.load(Ordering::SeqCst), 0);
+        assert_eq!(dropped.load(Ordering::SeqCst), 3);
+        assert_eq!(stopped.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_panic_handler() {
+        let (tx, rx) = mpsc::channel();
+        let pool = ThreadPoolBuilder::new()
+            .pool_size(1)
+            .panic_handler(move |payload| {
+                let thread = thread::current().name().map(str::to_owned);
+                tx.send((*payload.downcast::<&'static str>().unwrap(), thread)).unwrap();
+            })
+            .name_prefix("worker-")
+            .create()
+            .unwrap();
+
+        pool.spawn_ok(async { panic!("task panicked") });
+        assert_eq!(rx.recv().unwrap(), ("task panicked", Some("worker-0".to_owned())));
+
+        // The worker thread keeps running tasks.
+        let (done_tx, done_rx) = mpsc::channel();
+        pool.spawn_ok(async move { done_tx.send(()).unwrap() });
+        done_rx.recv().unwrap();
+        pool.shutdown().join();
+    }
+
+    #[test]
+    fn test_panic_policy_propagate() {
+        let handled = Arc::new(AtomicUsize::new(0));
+        let stopped = Arc::new(AtomicUsize::new(0));
+        let pool = ThreadPoolBuilder::new()
+            .pool_size(1)
+            .panic_policy(PanicPolicy::Propagate)
+            .panic_handler({
+                let handled = handled.clone();
+                move |_| {
+                    handled.fetch_add(1, Ordering::SeqCst);
+                }
+            })
+            .before_stop({
+                let stopped = stopped.clone();
+                move |_| {
+                    stopped.fetch_add(1, Ordering::SeqCst);
+                }
+            })
+            .create()
+            .unwrap();
+
+        let (release_tx, release_rx) = mpsc::channel::<()>();
+        pool.spawn_ok(futures::future::lazy(move |_| {
+            release_rx.recv().unwrap();
+            panic!("task panicked")
+        }));
+        let polled = Arc::new(AtomicUsize::new(0));
+        let dropped = Arc::new(AtomicUsize::new(0));
+        pool.spawn_ok({
+            let polled = polled.clone();
+            let dropped = DropCounter(dropped.clone());
+            async move {
+                let _dropped = dropped;
+                polled.fetch_add(1, Ordering::SeqCst);
+            }
+        });
+        release_tx.send(()).unwrap();
+
+        // The panic stopped the only worker thread, and shut down the pool,
+        // dropping the queued task.
+        let spawner = pool.clone();
+        pool.shutdown().join();
+        assert!(spawner.status().unwrap_err().is_shutdown());
+        assert_eq!(polled.load(Ordering::SeqCst), 0);
+        assert_eq!(dropped.load(Ordering::SeqCst), 1);
+        assert_eq!(handled.load(Ordering::SeqCst), 0);
+        assert_eq!(stopped.load(Ordering::SeqCst), 0);
+    }
 }
@@ -1,7 +1,7 @@
 use futures::channel::oneshot;
 use futures::executor::LocalPool;
 use futures::future::{self, lazy, poll_fn, Future};
-use futures::task::{Context, LocalSpawn, Poll, Spawn, Waker};
+use futures::task::{Context, LocalSpawn, LocalSpawnExt, Poll, Spawn, Waker};
 use std::cell::{Cell, RefCell};
 use std::pin::Pin;
 use std::rc::Rc;
@@ -319,6 +319,41 @@ fn run_until_stalled_executes_all_ready() {
     }
 }
 
+#[test]
+fn run_until_stalled_report_counts_progress() {
+    let mut pool = LocalPool::new();
+    let spawn = pool.spawner();
+    let wakers = Rc::new(RefCell::new(Vec::<Waker>::new()));
+
+    // Each task returns `Pending` the given number of times, waiting for the
+    // test to wake it up before every further poll.
+    for &times in &[1, 2] {
+        let wakers = wakers.clone();
+        let mut remaining = times;
+        spawn
+            .spawn_local(poll_fn(move |cx| {
+                if remaining == 0 {
+                    return Poll::Ready(());
+                }
+                remaining -= 1;
+                wakers.borrow_mut().push(cx.waker().clone());
+                Poll::Pending
+            }))
+            .unwrap();
+    }
+    assert_eq!(pool.pending_tasks(), 2);
+
+    let mut tick = || {
+        wakers.borrow_mut().drain(..).for_each(Waker::wake);
+        let report = pool.run_until_stalled_report();
+        (report.tasks_polled(), report.completed(), pool.pending_tasks())
+    };
+    assert_eq!(tick(), (2, 0, 2));
+    assert_eq!(tick(), (2, 1, 1));
+    assert_eq!(tick(), (1, 1, 0));
+    assert_eq!(tick(), (0, 0, 0));
+}
+
 #[test]
 #[should_panic]
 fn nesting_run() {
@@ -432,3 +467,150 @@ fn park_unpark_independence() {
 
     futures::executor::block_on(future)
 }
+
+#[test]
+fn spawn_local_with_handle_returns_output() {
+    let mut pool = LocalPool::new();
+    let spawner = pool.spawner();
+
+    // `Rc` makes the future `!Send`.
+    let value = Rc::new(Cell::new(1));
+    let handle = spawner
+        .spawn_local_with_handle({
+            let value = value.clone();
+            async move {
+                value.set(value.get() + 1);
+                value
+            }
+        })
+        .unwrap();
+
+    let output = pool.run_until(handle);
+    assert_eq!(output.get(), 2);
+    assert!(Rc::ptr_eq(&output, &value));
+}
+
+#[test]
+fn panic_handler_removes_panicking_task() {
+    let mut pool = LocalPool::new();
+    let spawner = pool.spawner();
+
+    let messages = Rc::new(RefCell::new(Vec::new()));
+    pool.set_panic_handler({
+        let messages = messages.clone();
+        Box::new(move |payload| {
+            messages.borrow_mut().push(*payload.downcast::<&'static str>().unwrap());
+        })
+    });
+
+    let completed = Rc::new(Cell::new(0));
+    for i in 0..4 {
+        let completed = completed.clone();
+        spawner
+            .spawn_local(async move {
+                future::ready(()).await;
+                if i % 2 == 0 {
+                    panic!("task panicked");
+                }
+                completed.set(completed.get() + 1);
+            })
+            .unwrap();
+    }
+
+    pool.run();
+    assert_eq!(completed.get(), 2);
+    assert_eq!(*messages.borrow(), ["task panicked", "task panicked"]);
+}
+
+#[test]
+#[should_panic(expected = "task panicked")]
+fn panic_without_handler_unwinds() {
+    let mut pool = LocalPool::new();
+    pool.spawner().spawn_local(async { panic!("task panicked") }).unwrap();
+    pool.run();
+}
+
+#[test]
+fn shutdown_drops_tasks() {
+    let mut pool = LocalPool::new();
+    let spawner = pool.spawner();
+
+    let polled = Rc::new(());
+    spawner.spawn_local_obj(Box::pin(Pending(polled.clone())).into()).unwrap();
+    assert_eq!(pool.run_until_stalled_report().tasks_polled(), 1);
+    let queued = Rc::new(());
+    spawner.spawn_local_obj(Box::pin(Pending(queued.clone())).into()).unwrap();
+
+    spawner.shutdown();
+    assert_eq!(Rc::strong_count(&queued), 1);
+    assert_eq!(Rc::strong_count(&polled), 2);
+    assert!(spawner.spawn_local(async {}).unwrap_err().is_shutdown());
+    assert!(spawner.status_local().unwrap_err().is_shutdown());
+
+    assert_eq!(pool.run_until_stalled_report().tasks_polled(), 0);
+    assert_eq!(Rc::strong_count(&polled), 1);
+    assert_eq!(pool.pending_tasks(), 0);
+    pool.run();
+}
+
+#[test]
+fn run_until_after_shutdown_runs_future() {
+    let mut pool = LocalPool::new();
+    let spawner = pool.spawner();
+    let task = Rc::new(());
+    spawner.spawn_local_obj(Box::pin(Pending(task.clone())).into()).unwrap();
+    spawner.shutdown();
+
+    let mut polls = 0;
+    let output = pool.run_until(poll_fn(|cx| {
+        polls += 1;
+        if polls == 3 {
+            Poll::Ready(polls)
+        } else {
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }));
+    assert_eq!(output, 3);
+    assert_eq!(Rc::strong_count(&task), 1);
+}
+
+#[test]
+fn shutdown_from_task_waits_for_poll() {
+    struct ShutdownOnPoll {
+        spawner: futures::executor::LocalSpawner,
+        polling: Rc<Cell<bool>>,
+    }
+
+    impl Future for ShutdownOnPoll {
+        type Output = ();
+
+        fn poll(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<()> {
+            self.polling.set(true);
+            self.spawner.shutdown();
+            self.polling.set(false);
+            Poll::Pending
+        }
+    }
+
+    impl Drop for ShutdownOnPoll {
+        fn drop(&mut self) {
+            assert!(!self.polling.get(), "task dropped while being polled");
+        }
+    }
+
+    let mut pool = LocalPool::new();
+    let spawner = pool.spawner();
+    let other = Rc::new(());
+    spawner.spawn_local_obj(Box::pin(Pending(other.clone())).into()).unwrap();
+    let polling = Rc::new(Cell::new(false));
+    spawner
+        .spawn_local_obj(
+            Box::pin(ShutdownOnPoll { spawner: spawner.clone(), polling: polling.clone() }).into(),
+        )
+        .unwrap();
+
+    pool.run();
+    assert_eq!(Rc::strong_count(&other), 1);
+    assert_eq!(Rc::strong_count(&polling), 1);
+}
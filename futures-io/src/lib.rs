@@ -21,8 +21,12 @@
 ))]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
+#[cfg(feature = "std")]
+mod read_buf;
+
 #[cfg(feature = "std")]
 mod if_std {
+    use std::cmp;
     use std::io;
     use std::ops::DerefMut;
     use std::pin::Pin;
@@ -34,6 +38,8 @@ mod if_std {
     #[doc(no_inline)]
     pub use io::{Error, ErrorKind, IoSlice, IoSliceMut, Result, SeekFrom};
 
+    pub use crate::read_buf::ReadBuf;
+
     /// Read bytes asynchronously.
     ///
     /// This trait is analogous to the `std::io::Read` trait, but integrates
@@ -98,6 +104,52 @@ mod if_std {
 
             self.poll_read(cx, &mut [])
         }
+
+        /// Attempt to read from the `AsyncRead` into `read_buf`, which may be
+        /// partially uninitialized.
+        ///
+        /// On success, returns `Poll::Ready(Ok(()))` and advances the filled
+        /// part of `read_buf` by the number of bytes read. Not advancing it
+        /// means that EOF has been reached, unless `read_buf` had no capacity
+        /// remaining.
+        ///
+        /// If no data is available for reading, the method returns
+        /// `Poll::Pending` and arranges for the current task (via
+        /// `cx.waker().wake_by_ref()`) to receive a notification when the object becomes
+        /// readable or is closed.
+        ///
+        /// By default, this method initializes the unfilled part of `read_buf`
+        /// and delegates to `poll_read`. Objects which never read from the
+        /// buffer they are given should override this method, so that callers
+        /// can avoid zeroing buffers before reading into them.
+        ///
+        /// # Implementation
+        ///
+        /// This function may not return errors of kind `WouldBlock` or
+        /// `Interrupted`.  Implementations must convert `WouldBlock` into
+        /// `Poll::Pending` and either internally retry or convert
+        /// `Interrupted` into another error kind.
+        ///
+        /// Implementations must not replace `read_buf` with a different
+        /// `ReadBuf`; callers may panic if they do.
+        fn poll_read_buf(
+            self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            read_buf: &mut ReadBuf<'_>,
+        ) -> Poll<Result<()>> {
+            let buf = read_buf.initialize_unfilled();
+            match self.poll_read(cx, buf) {
+                Poll::Ready(Ok(n)) => {
+                    // We can't allow bogus values from read, as they would be
+                    // taken to have been read into the buffer.
+                    assert!(n <= buf.len());
+                    read_buf.advance(n);
+                    Poll::Ready(Ok(()))
+                }
+                Poll::Ready(Err(e)) => Poll::Ready(Err(e)),
+                Poll::Pending => Poll::Pending,
+            }
+        }
     }
 
     /// Write bytes asynchronously.
@@ -169,6 +221,20 @@ mod if_std {
             self.poll_write(cx, &[])
         }
 
+        /// Determines if this writer has an efficient
+        /// [`poll_write_vectored`](AsyncWrite::poll_write_vectored)
+        /// implementation.
+        ///
+        /// If a writer does not override the default `poll_write_vectored`
+        /// implementation, code using it may want to avoid the method all
+        /// together and coalesce writes into a single buffer for higher
+        /// performance.
+        ///
+        /// The default implementation returns `false`.
+        fn is_write_vectored(&self) -> bool {
+            false
+        }
+
         /// Attempt to flush the object, ensuring that any buffered data reach
         /// their destination.
         ///
@@ -314,6 +380,14 @@ mod if_std {
             ) -> Poll<Result<usize>> {
                 Pin::new(&mut **self).poll_read_vectored(cx, bufs)
             }
+
+            fn poll_read_buf(
+                mut self: Pin<&mut Self>,
+                cx: &mut Context<'_>,
+                buf: &mut ReadBuf<'_>,
+            ) -> Poll<Result<()>> {
+                Pin::new(&mut **self).poll_read_buf(cx, buf)
+            }
         };
     }
 
@@ -345,6 +419,14 @@ mod if_std {
         ) -> Poll<Result<usize>> {
             self.get_mut().as_mut().poll_read_vectored(cx, bufs)
         }
+
+        fn poll_read_buf(
+            self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &mut ReadBuf<'_>,
+        ) -> Poll<Result<()>> {
+            self.get_mut().as_mut().poll_read_buf(cx, buf)
+        }
     }
 
     macro_rules! delegate_async_read_to_stdio {
@@ -369,6 +451,18 @@ mod if_std {
 
     impl AsyncRead for &[u8] {
         delegate_async_read_to_stdio!();
+
+        fn poll_read_buf(
+            mut self: Pin<&mut Self>,
+            _: &mut Context<'_>,
+            buf: &mut ReadBuf<'_>,
+        ) -> Poll<Result<()>> {
+            let n = cmp::min(buf.remaining(), self.len());
+            let (data, rest) = self.split_at(n);
+            buf.put_slice(data);
+            *self = rest;
+            Poll::Ready(Ok(()))
+        }
     }
 
     macro_rules! deref_async_write {
@@ -389,6 +483,10 @@ mod if_std {
                 Pin::new(&mut **self).poll_write_vectored(cx, bufs)
             }
 
+            fn is_write_vectored(&self) -> bool {
+                (**self).is_write_vectored()
+            }
+
             fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<()>> {
                 Pin::new(&mut **self).poll_flush(cx)
             }
@@ -428,6 +526,10 @@ mod if_std {
             self.get_mut().as_mut().poll_write_vectored(cx, bufs)
         }
 
+        fn is_write_vectored(&self) -> bool {
+            (**self).is_write_vectored()
+        }
+
         fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<()>> {
             self.get_mut().as_mut().poll_flush(cx)
         }
@@ -455,6 +557,10 @@ mod if_std {
                 Poll::Ready(io::Write::write_vectored(&mut *self, bufs))
             }
 
+            fn is_write_vectored(&self) -> bool {
+                true
+            }
+
             fn poll_flush(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<()>> {
                 Poll::Ready(io::Write::flush(&mut *self))
             }
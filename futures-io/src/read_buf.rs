@@ -0,0 +1,154 @@
+use std::fmt;
+use std::mem::MaybeUninit;
+use std::ptr;
+
+/// A buffer to read into, which may be partially uninitialized.
+///
+/// `ReadBuf` wraps a byte buffer and keeps track of how much of it has been
+/// filled with data and how much of it is known to be initialized:
+///
+/// ```text
+/// [             capacity              ]
+/// [ filled |         unfilled         ]
+/// [    initialized    | uninitialized ]
+/// ```
+///
+/// This allows [`AsyncRead::poll_read_buf`](crate::AsyncRead::poll_read_buf)
+/// to read into memory which hasn't been initialized yet, which saves
+/// zeroing it first if the reader never looks at the buffer's contents before
+/// writing to it. It tracks the same state as `BorrowedBuf` in the standard
+/// library, so the two can be converted to each other once that is stable.
+pub struct ReadBuf<'a> {
+    buf: &'a mut [MaybeUninit<u8>],
+    filled: usize,
+    initialized: usize,
+}
+
+impl<'a> ReadBuf<'a> {
+    /// Creates a new `ReadBuf` from a fully initialized buffer.
+    #[inline]
+    pub fn new(buf: &'a mut [u8]) -> Self {
+        let initialized = buf.len();
+        let buf = unsafe { &mut *(buf as *mut [u8] as *mut [MaybeUninit<u8>]) };
+        Self { buf, filled: 0, initialized }
+    }
+
+    /// Creates a new `ReadBuf` from a buffer which may be uninitialized.
+    #[inline]
+    pub fn uninit(buf: &'a mut [MaybeUninit<u8>]) -> Self {
+        Self { buf, filled: 0, initialized: 0 }
+    }
+
+    /// Returns the total size of the buffer.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.buf.len()
+    }
+
+    /// Returns the number of bytes at the end of the buffer which haven't
+    /// been filled yet.
+    #[inline]
+    pub fn remaining(&self) -> usize {
+        self.capacity() - self.filled
+    }
+
+    /// Returns the part of the buffer which has been filled.
+    #[inline]
+    pub fn filled(&self) -> &[u8] {
+        unsafe { &*(&self.buf[..self.filled] as *const [MaybeUninit<u8>] as *const [u8]) }
+    }
+
+    /// Returns the part of the buffer which has been filled, mutably.
+    #[inline]
+    pub fn filled_mut(&mut self) -> &mut [u8] {
+        unsafe { &mut *(&mut self.buf[..self.filled] as *mut [MaybeUninit<u8>] as *mut [u8]) }
+    }
+
+    /// Returns the part of the buffer which is known to be initialized.
+    #[inline]
+    pub fn initialized(&self) -> &[u8] {
+        unsafe { &*(&self.buf[..self.initialized] as *const [MaybeUninit<u8>] as *const [u8]) }
+    }
+
+    /// Initializes the unfilled part of the buffer, if it isn't already, and
+    /// returns it.
+    ///
+    /// Only the bytes which aren't known to be initialized yet are zeroed.
+    #[inline]
+    pub fn initialize_unfilled(&mut self) -> &mut [u8] {
+        let uninit = &mut self.buf[self.initialized..];
+        unsafe { ptr::write_bytes(uninit.as_mut_ptr(), 0, uninit.len()) };
+        self.initialized = self.buf.len();
+        unsafe { &mut *(&mut self.buf[self.filled..] as *mut [MaybeUninit<u8>] as *mut [u8]) }
+    }
+
+    /// Returns the unfilled part of the buffer, which may be uninitialized.
+    ///
+    /// # Safety
+    ///
+    /// The caller must not write uninitialized bytes to the returned buffer,
+    /// as that would de-initialize memory which this `ReadBuf` may have
+    /// reported as initialized.
+    #[inline]
+    pub unsafe fn unfilled_mut(&mut self) -> &mut [MaybeUninit<u8>] {
+        &mut self.buf[self.filled..]
+    }
+
+    /// Marks the first `n` unfilled bytes of the buffer as initialized.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that those bytes have been initialized.
+    #[inline]
+    pub unsafe fn assume_init(&mut self, n: usize) {
+        let end = self.filled + n;
+        if end > self.initialized {
+            self.initialized = end;
+        }
+    }
+
+    /// Marks the next `n` bytes of the buffer as filled.
+    ///
+    /// # Panics
+    ///
+    /// Panics if those bytes aren't known to be initialized.
+    #[inline]
+    pub fn advance(&mut self, n: usize) {
+        let filled = self.filled.checked_add(n).expect("filled overflow");
+        assert!(filled <= self.initialized, "filled must not become larger than initialized");
+        self.filled = filled;
+    }
+
+    /// Appends `data` to the filled part of the buffer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `data` is larger than the remaining capacity.
+    #[inline]
+    pub fn put_slice(&mut self, data: &[u8]) {
+        assert!(data.len() <= self.remaining(), "data must not be larger than remaining capacity");
+        unsafe {
+            let dst = self.buf.as_mut_ptr().add(self.filled) as *mut u8;
+            ptr::copy_nonoverlapping(data.as_ptr(), dst, data.len());
+            self.assume_init(data.len());
+        }
+        self.filled += data.len();
+    }
+
+    /// Marks the whole buffer as unfilled, while keeping track of how much of
+    /// it is initialized.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.filled = 0;
+    }
+}
+
+impl fmt::Debug for ReadBuf<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReadBuf")
+            .field("filled", &self.filled)
+            .field("initialized", &self.initialized)
+            .field("capacity", &self.capacity())
+            .finish()
+    }
+}
@@ -0,0 +1,34 @@
+use std::cell::Cell;
+
+thread_local!(static ENTERED: Cell<bool> = Cell::new(false));
+
+/// Marks the current thread as being within the dynamic extent of an executor
+/// until it is dropped.
+///
+/// This backs `futures_executor::enter`, and lives here so that crates which
+/// can't depend on `futures-executor` can check it as well.
+#[derive(Debug)]
+pub struct Entered {
+    _priv: (),
+}
+
+/// Marks the current thread, returning `None` if it is already marked.
+pub fn enter() -> Option<Entered> {
+    ENTERED.with(|c| {
+        if c.get() {
+            None
+        } else {
+            c.set(true);
+            Some(Entered { _priv: () })
+        }
+    })
+}
+
+impl Drop for Entered {
+    fn drop(&mut self) {
+        ENTERED.with(|c| {
+            assert!(c.get());
+            c.set(false);
+        });
+    }
+}
@@ -46,5 +46,15 @@ mod noop_waker;
 pub use crate::noop_waker::noop_waker;
 pub use crate::noop_waker::noop_waker_ref;
 
+#[cfg(feature = "std")]
+mod enter;
+
+// Not public API.
+#[cfg(feature = "std")]
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::enter::{enter, Entered};
+}
+
 #[doc(no_inline)]
 pub use core::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
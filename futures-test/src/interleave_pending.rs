@@ -161,6 +161,10 @@ impl<W: AsyncWrite> AsyncWrite for InterleavePending<W> {
         self.poll_with(cx, |w, cx| w.poll_write_vectored(cx, bufs))
     }
 
+    fn is_write_vectored(&self) -> bool {
+        self.inner.is_write_vectored()
+    }
+
     fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
         self.poll_with(cx, W::poll_flush)
     }
@@ -109,6 +109,10 @@ impl<T: AsyncWrite> AsyncWrite for TrackClosed<T> {
         }
         self.project().inner.poll_write_vectored(cx, bufs)
     }
+
+    fn is_write_vectored(&self) -> bool {
+        self.inner.is_write_vectored()
+    }
 }
 
 impl<Item, T: Sink<Item>> Sink<Item> for TrackClosed<T> {
@@ -0,0 +1,48 @@
+#![feature(test)]
+
+extern crate test;
+use crate::test::Bencher;
+
+use futures::executor::block_on;
+use futures::io::{AsyncRead, AsyncReadExt, Cursor};
+use futures::task::{Context, Poll};
+use std::io;
+use std::pin::Pin;
+
+const LEN: usize = 64 << 20;
+
+// Reader which only implements `poll_read`, so the buffers it reads into have
+// to be zeroed first.
+struct ZeroingReader(Cursor<Vec<u8>>);
+
+impl AsyncRead for ZeroingReader {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.0).poll_read(cx, buf)
+    }
+}
+
+#[bench]
+fn read_to_end_initialized(b: &mut Bencher) {
+    let mut reader = ZeroingReader(Cursor::new(vec![1; LEN]));
+    b.iter(|| {
+        reader.0.set_position(0);
+        let mut buf = Vec::new();
+        block_on(reader.read_to_end(&mut buf)).unwrap();
+        buf
+    });
+}
+
+#[bench]
+fn read_to_end_uninitialized(b: &mut Bencher) {
+    let mut reader = Cursor::new(vec![1; LEN]);
+    b.iter(|| {
+        reader.set_position(0);
+        let mut buf = Vec::new();
+        block_on(reader.read_to_end(&mut buf)).unwrap();
+        buf
+    });
+}
@@ -0,0 +1,52 @@
+#![feature(test)]
+
+extern crate test;
+use crate::test::Bencher;
+
+use futures::channel::oneshot;
+use futures::executor::block_on;
+use futures::future::{self, FutureExt};
+use futures::task::Poll;
+use std::cell::Cell;
+use std::rc::Rc;
+
+#[bench]
+fn many_pending_one_completer(b: &mut Bencher) {
+    const NUM: usize = 10_000;
+
+    b.iter(|| {
+        let polls = Rc::new(Cell::new(0));
+        let (tx, rx) = oneshot::channel::<()>();
+        let mut txs = Vec::with_capacity(NUM);
+        let mut futures = Vec::with_capacity(NUM);
+        futures.push(rx.map(drop).boxed_local());
+        for _ in 1..NUM {
+            let (tx, mut rx) = oneshot::channel::<()>();
+            txs.push(tx);
+            let polls = polls.clone();
+            futures.push(
+                future::poll_fn(move |cx| {
+                    polls.set(polls.get() + 1);
+                    rx.poll_unpin(cx).map(drop)
+                })
+                .boxed_local(),
+            );
+        }
+
+        let mut tx = Some(tx);
+        let mut select = future::select_all(futures);
+        block_on(future::poll_fn(move |cx| {
+            // Wake a single future after the first poll, so that only it
+            // needs to be polled again.
+            if let Poll::Ready(_) = select.poll_unpin(cx) {
+                return Poll::Ready(());
+            }
+            tx.take().unwrap().send(()).unwrap();
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }));
+        // Each of the futures which never completes was only polled once.
+        assert_eq!(polls.get(), NUM - 1);
+        drop(txs);
+    });
+}
@@ -1,6 +1,5 @@
 #![feature(test)]
 
-#[cfg(feature = "bilock")]
 mod bench {
     use futures::executor::LocalPool;
     use futures::task::{Context, Waker};
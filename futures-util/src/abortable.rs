@@ -1,5 +1,8 @@
 use crate::task::AtomicWaker;
 use alloc::sync::Arc;
+#[cfg(feature = "std")]
+use alloc::vec::Vec;
+use core::cell::UnsafeCell;
 use core::fmt;
 use core::pin::Pin;
 use core::sync::atomic::{AtomicBool, Ordering};
@@ -7,6 +10,8 @@ use futures_core::future::Future;
 use futures_core::task::{Context, Poll};
 use futures_core::Stream;
 use pin_project_lite::pin_project;
+#[cfg(feature = "std")]
+use std::sync::Mutex;
 
 pin_project! {
     /// A future/stream which can be remotely short-circuited using an `AbortHandle`.
@@ -15,7 +20,7 @@ pin_project! {
     pub struct Abortable<T> {
         #[pin]
         task: T,
-        inner: Arc<AbortInner>,
+        inner: Arc<AbortInner<()>>,
     }
 }
 
@@ -66,7 +71,62 @@ impl<T> Abortable<T> {
     /// * `abort` was called while the task was being polled - the task may still be running and
     /// will not be stopped until `poll` returns.
     pub fn is_aborted(&self) -> bool {
-        self.inner.aborted.load(Ordering::Relaxed)
+        self.inner.is_aborted()
+    }
+}
+
+pin_project! {
+    /// A future/stream which can be remotely short-circuited using an
+    /// `AbortHandle`, finding out why it was aborted.
+    #[derive(Debug, Clone)]
+    #[must_use = "futures/streams do nothing unless you poll them"]
+    pub struct AbortableWith<T, R> {
+        #[pin]
+        task: T,
+        inner: Arc<AbortInner<R>>,
+    }
+}
+
+impl<T, R> AbortableWith<T, R> {
+    /// Creates a new `AbortableWith` future/stream using an existing
+    /// `AbortRegistration`, which can be acquired through
+    /// [`AbortHandle::new_pair_with`].
+    ///
+    /// This works like [`Abortable::new`], except that the future resolves to
+    /// [`AbortedWith`], holding the reason passed to
+    /// [`AbortHandle::abort_with`], if it is aborted. The stream ends, and the
+    /// reason is available from [`reason`](AbortableWith::reason).
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::future::{AbortHandle, AbortableWith};
+    ///
+    /// let (abort_handle, abort_registration) = AbortHandle::new_pair_with();
+    /// let future = AbortableWith::new(async { 2 }, abort_registration);
+    /// abort_handle.abort_with("shutting down");
+    /// abort_handle.abort_with("timed out");
+    /// assert_eq!(future.await.unwrap_err().into_reason(), "shutting down");
+    /// # });
+    /// ```
+    pub fn new(task: T, reg: AbortRegistration<R>) -> Self {
+        Self { task, inner: reg.inner }
+    }
+
+    /// Checks whether the task has been aborted. See
+    /// [`Abortable::is_aborted`] for what this does and doesn't indicate.
+    pub fn is_aborted(&self) -> bool {
+        self.inner.is_aborted()
+    }
+
+    /// Returns the reason the task has been aborted with, if it has been
+    /// aborted.
+    pub fn reason(&self) -> Option<R>
+    where
+        R: Clone,
+    {
+        self.inner.reason()
     }
 }
 
@@ -74,14 +134,20 @@ impl<T> Abortable<T> {
 /// Values of this type can be acquired from `AbortHandle::new` and are used
 /// in calls to `Abortable::new`.
 #[derive(Debug)]
-pub struct AbortRegistration {
-    inner: Arc<AbortInner>,
+pub struct AbortRegistration<R = ()> {
+    inner: Arc<AbortInner<R>>,
 }
 
 /// A handle to an `Abortable` task.
-#[derive(Debug, Clone)]
-pub struct AbortHandle {
-    inner: Arc<AbortInner>,
+#[derive(Debug)]
+pub struct AbortHandle<R = ()> {
+    inner: Arc<AbortInner<R>>,
+}
+
+impl<R> Clone for AbortHandle<R> {
+    fn clone(&self) -> Self {
+        Self { inner: self.inner.clone() }
+    }
 }
 
 impl AbortHandle {
@@ -90,65 +156,136 @@ impl AbortHandle {
     ///
     /// This function is usually paired with a call to [`Abortable::new`].
     pub fn new_pair() -> (Self, AbortRegistration) {
-        let inner =
-            Arc::new(AbortInner { waker: AtomicWaker::new(), aborted: AtomicBool::new(false) });
+        Self::new_pair_with()
+    }
+}
+
+impl<R> AbortHandle<R> {
+    /// Creates an (`AbortHandle`, `AbortRegistration`) pair which can be used
+    /// to abort a running future or stream with a reason of type `R`.
+    ///
+    /// This function is usually paired with a call to [`AbortableWith::new`].
+    pub fn new_pair_with() -> (Self, AbortRegistration<R>) {
+        let inner = Arc::new(AbortInner {
+            waker: AtomicWaker::new(),
+            claimed: AtomicBool::new(false),
+            aborted: AtomicBool::new(false),
+            reason: UnsafeCell::new(None),
+        });
 
         (Self { inner: inner.clone() }, AbortRegistration { inner })
     }
 }
 
-// Inner type storing the waker to awaken and a bool indicating that it
-// should be aborted.
+// Inner type storing the waker to awaken, a bool indicating that it should be
+// aborted and the reason given for that.
 #[derive(Debug)]
-struct AbortInner {
+struct AbortInner<R> {
     waker: AtomicWaker,
+    // Set by the first call to `abort_with`, which gets to store its reason.
+    claimed: AtomicBool,
+    // Set once `reason` has been stored, which is never changed afterwards.
     aborted: AtomicBool,
+    reason: UnsafeCell<Option<R>>,
 }
 
-/// Indicator that the `Abortable` task was aborted.
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
-pub struct Aborted;
+// `reason` is only written before `aborted` is set, and only read after.
+unsafe impl<R: Send + Sync> Sync for AbortInner<R> {}
 
-impl fmt::Display for Aborted {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "`Abortable` future has been aborted")
+impl<R> AbortInner<R> {
+    fn is_aborted(&self) -> bool {
+        self.aborted.load(Ordering::Relaxed)
     }
-}
 
-#[cfg(feature = "std")]
-impl std::error::Error for Aborted {}
+    fn reason(&self) -> Option<R>
+    where
+        R: Clone,
+    {
+        if self.aborted.load(Ordering::Acquire) {
+            unsafe { (*self.reason.get()).clone() }
+        } else {
+            None
+        }
+    }
 
-impl<T> Abortable<T> {
-    fn try_poll<I>(
-        mut self: Pin<&mut Self>,
+    fn abort_with(&self, reason: R) {
+        if !self.claimed.swap(true, Ordering::Acquire) {
+            unsafe { *self.reason.get() = Some(reason) };
+            self.aborted.store(true, Ordering::Release);
+        }
+        self.waker.wake();
+    }
+
+    fn try_poll<T, I>(
+        &self,
+        task: Pin<&mut T>,
         cx: &mut Context<'_>,
         poll: impl Fn(Pin<&mut T>, &mut Context<'_>) -> Poll<I>,
-    ) -> Poll<Result<I, Aborted>> {
+    ) -> Poll<Option<I>> {
         // Check if the task has been aborted
         if self.is_aborted() {
-            return Poll::Ready(Err(Aborted));
+            return Poll::Ready(None);
         }
 
         // attempt to complete the task
-        if let Poll::Ready(x) = poll(self.as_mut().project().task, cx) {
-            return Poll::Ready(Ok(x));
+        if let Poll::Ready(x) = poll(task, cx) {
+            return Poll::Ready(Some(x));
         }
 
         // Register to receive a wakeup if the task is aborted in the future
-        self.inner.waker.register(cx.waker());
+        self.waker.register(cx.waker());
 
         // Check to see if the task was aborted between the first check and
         // registration.
         // Checking with `is_aborted` which uses `Relaxed` is sufficient because
         // `register` introduces an `AcqRel` barrier.
         if self.is_aborted() {
-            return Poll::Ready(Err(Aborted));
+            return Poll::Ready(None);
         }
 
         Poll::Pending
     }
 }
 
+/// Indicator that the `Abortable` task was aborted.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Aborted;
+
+impl fmt::Display for Aborted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "`Abortable` future has been aborted")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Aborted {}
+
+/// Indicator that the `AbortableWith` task was aborted, holding the reason it
+/// was aborted with.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct AbortedWith<R>(R);
+
+impl<R> AbortedWith<R> {
+    /// Returns a reference to the reason the task was aborted with.
+    pub fn reason(&self) -> &R {
+        &self.0
+    }
+
+    /// Consumes this error, returning the reason the task was aborted with.
+    pub fn into_reason(self) -> R {
+        self.0
+    }
+}
+
+impl<R: fmt::Debug> fmt::Display for AbortedWith<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "`Abortable` future has been aborted: {:?}", self.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: fmt::Debug> std::error::Error for AbortedWith<R> {}
+
 impl<Fut> Future for Abortable<Fut>
 where
     Fut: Future,
@@ -156,7 +293,8 @@ where
     type Output = Result<Fut::Output, Aborted>;
 
     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        self.try_poll(cx, |fut, cx| fut.poll(cx))
+        let this = self.project();
+        this.inner.try_poll(this.task, cx, |fut, cx| fut.poll(cx)).map(|x| x.ok_or(Aborted))
     }
 }
 
@@ -167,7 +305,36 @@ where
     type Item = St::Item;
 
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
-        self.try_poll(cx, |stream, cx| stream.poll_next(cx)).map(Result::ok).map(Option::flatten)
+        let this = self.project();
+        this.inner.try_poll(this.task, cx, |stream, cx| stream.poll_next(cx)).map(Option::flatten)
+    }
+}
+
+impl<Fut, R> Future for AbortableWith<Fut, R>
+where
+    Fut: Future,
+    R: Clone,
+{
+    type Output = Result<Fut::Output, AbortedWith<R>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let inner = &**this.inner;
+        inner.try_poll(this.task, cx, |fut, cx| fut.poll(cx)).map(|x| {
+            x.ok_or_else(|| AbortedWith(inner.reason().expect("aborted without a reason")))
+        })
+    }
+}
+
+impl<St, R> Stream for AbortableWith<St, R>
+where
+    St: Stream,
+{
+    type Item = St::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        this.inner.try_poll(this.task, cx, |stream, cx| stream.poll_next(cx)).map(Option::flatten)
     }
 }
 
@@ -179,7 +346,169 @@ impl AbortHandle {
     /// another thread, it will not immediately stop running. Instead, it will
     /// continue to run until its poll method returns.
     pub fn abort(&self) {
-        self.inner.aborted.store(true, Ordering::Relaxed);
-        self.inner.waker.wake();
+        self.abort_with(());
+    }
+
+    /// Turns this handle into one which aborts the associated task when it
+    /// is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::future::{self, AbortHandle, Abortable, Aborted};
+    ///
+    /// let (abort_handle, abort_registration) = AbortHandle::new_pair();
+    /// let future = Abortable::new(future::pending::<()>(), abort_registration);
+    ///
+    /// let guard = abort_handle.on_drop();
+    /// drop(guard);
+    /// assert_eq!(future.await, Err(Aborted));
+    /// # });
+    /// ```
+    pub fn on_drop(self) -> AbortOnDropHandle {
+        AbortOnDropHandle { handle: self }
+    }
+}
+
+/// A handle to an `Abortable` task, which aborts the task when dropped.
+///
+/// Values of this type can be acquired from [`AbortHandle::on_drop`].
+#[derive(Debug)]
+#[must_use = "the task is aborted as soon as this handle is dropped"]
+pub struct AbortOnDropHandle {
+    handle: AbortHandle,
+}
+
+impl AbortOnDropHandle {
+    /// Abort the `Abortable` stream/future associated with this handle right
+    /// away, see [`AbortHandle::abort`].
+    pub fn abort(&self) {
+        self.handle.abort();
+    }
+
+    /// Returns a new [`AbortHandle`] for the task associated with this
+    /// handle, which doesn't abort it on drop.
+    pub fn handle(&self) -> AbortHandle {
+        self.handle.clone()
+    }
+}
+
+impl Drop for AbortOnDropHandle {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
+
+/// A group of `Abortable` tasks which can be aborted at once.
+///
+/// Each call to [`register`](AbortSet::register) returns an
+/// [`AbortRegistration`] to create an [`Abortable`] with, which is aborted
+/// by [`abort_all`](AbortSet::abort_all). Registrations made after that are
+/// aborted right away. Tasks can be registered from multiple threads at the
+/// same time.
+///
+/// By default, the tasks keep running when the set is dropped. Use
+/// [`abort_on_drop`](AbortSet::abort_on_drop) to abort them then instead.
+///
+/// This type is only available when the `std` feature of this
+/// library is activated, and it is activated by default.
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::future::{self, AbortSet, Abortable, Aborted};
+///
+/// let set = AbortSet::new();
+/// let first = Abortable::new(future::pending::<()>(), set.register());
+/// let second = Abortable::new(future::pending::<()>(), set.register());
+///
+/// set.abort_all();
+/// assert_eq!(first.await, Err(Aborted));
+/// assert_eq!(second.await, Err(Aborted));
+/// # });
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug, Default)]
+pub struct AbortSet {
+    state: Mutex<AbortSetState>,
+    abort_on_drop: bool,
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Default)]
+struct AbortSetState {
+    aborted: bool,
+    handles: Vec<AbortHandle>,
+}
+
+#[cfg(feature = "std")]
+impl AbortSet {
+    /// Creates a new, empty `AbortSet`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether the tasks in this set are aborted when it's dropped.
+    pub fn abort_on_drop(&mut self, abort_on_drop: bool) {
+        self.abort_on_drop = abort_on_drop;
+    }
+
+    /// Adds a new task to this set, returning the registration to pass to
+    /// [`Abortable::new`] for it.
+    ///
+    /// If [`abort_all`](AbortSet::abort_all) has already been called, the
+    /// task is aborted right away.
+    pub fn register(&self) -> AbortRegistration {
+        let (handle, reg) = AbortHandle::new_pair();
+        let mut state = self.state.lock().unwrap();
+        if state.aborted {
+            handle.abort();
+        } else {
+            // Forget about the tasks which are gone already.
+            state.handles.retain(|handle| Arc::strong_count(&handle.inner) > 1);
+            state.handles.push(handle);
+        }
+        reg
+    }
+
+    /// Aborts all the tasks in this set, including the ones registered later.
+    pub fn abort_all(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.aborted = true;
+        for handle in state.handles.drain(..) {
+            handle.abort();
+        }
+    }
+
+    /// Returns whether [`abort_all`](AbortSet::abort_all) has been called.
+    pub fn is_aborted(&self) -> bool {
+        self.state.lock().unwrap().aborted
+    }
+}
+
+#[cfg(feature = "std")]
+impl Drop for AbortSet {
+    fn drop(&mut self) {
+        if self.abort_on_drop {
+            if let Ok(state) = self.state.get_mut() {
+                for handle in state.handles.drain(..) {
+                    handle.abort();
+                }
+            }
+        }
+    }
+}
+
+impl<R> AbortHandle<R> {
+    /// Abort the `AbortableWith` stream/future associated with this handle,
+    /// giving the reason for it.
+    ///
+    /// This works like [`abort`](AbortHandle::abort). Only the reason given
+    /// by the first call to `abort` or `abort_with` on any of the clones of
+    /// this handle is kept; later ones are dropped.
+    pub fn abort_with(&self, reason: R) {
+        self.inner.abort_with(reason);
     }
 }
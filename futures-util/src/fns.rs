@@ -370,3 +370,61 @@ where
 }
 
 trivial_fn_impls!(into_fn <T> IntoFn<T> = "Into::into");
+
+#[derive(Default)]
+pub struct ClonedFn;
+
+impl<'a, T> FnOnce1<&'a T> for ClonedFn
+where
+    T: Clone + 'a,
+{
+    type Output = T;
+    fn call_once(self, arg: &'a T) -> Self::Output {
+        arg.clone()
+    }
+}
+
+trivial_fn_impls!(cloned_fn <> ClonedFn = "Clone::clone");
+
+#[derive(Default)]
+pub struct CopiedFn;
+
+impl<'a, T> FnOnce1<&'a T> for CopiedFn
+where
+    T: Copy + 'a,
+{
+    type Output = T;
+    fn call_once(self, arg: &'a T) -> Self::Output {
+        *arg
+    }
+}
+
+trivial_fn_impls!(copied_fn <> CopiedFn = "Copy");
+
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "alloc")]
+pub struct CollectFn<C>(PhantomData<fn() -> C>);
+
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "alloc")]
+impl<C> Default for CollectFn<C> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "alloc")]
+impl<St, C> FnOnce1<St> for CollectFn<C>
+where
+    St: crate::stream::Stream,
+    C: Default + Extend<St::Item>,
+{
+    type Output = crate::stream::Collect<St, C>;
+    fn call_once(self, arg: St) -> Self::Output {
+        crate::stream::StreamExt::collect(arg)
+    }
+}
+
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "alloc")]
+trivial_fn_impls!(collect_fn <C> CollectFn<C> = "collect");
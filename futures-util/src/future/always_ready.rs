@@ -0,0 +1,62 @@
+use super::assert_future;
+use core::fmt;
+use core::pin::Pin;
+use futures_core::future::{FusedFuture, Future};
+use futures_core::task::{Context, Poll};
+
+/// Future for the [`always_ready`](always_ready()) function.
+#[derive(Clone, Copy)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct AlwaysReady<F>(F);
+
+impl<F> fmt::Debug for AlwaysReady<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("AlwaysReady").finish()
+    }
+}
+
+impl<F> Unpin for AlwaysReady<F> {}
+
+// Unlike `Ready`, this future can be polled again after completing, so it
+// never reports being terminated. This allows it to be used as a fallback in
+// `select!` loops, which skip terminated futures.
+impl<T, F: Fn() -> T> FusedFuture for AlwaysReady<F> {
+    fn is_terminated(&self) -> bool {
+        false
+    }
+}
+
+impl<T, F: Fn() -> T> Future for AlwaysReady<F> {
+    type Output = T;
+
+    #[inline]
+    fn poll(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<T> {
+        Poll::Ready((self.0)())
+    }
+}
+
+/// Creates a future that is always immediately ready with a value produced
+/// by the given closure.
+///
+/// This is similar to [`ready`](super::ready()), but rather than storing the
+/// value, the future calls `f` each time it is polled. It is as large as the
+/// closure, so a closure which doesn't capture anything makes it zero-sized,
+/// and it is [`Copy`] if the closure is.
+///
+/// The future can be polled any number of times, returning a new value on
+/// each poll. Its [`FusedFuture`] implementation therefore always reports it
+/// as not terminated.
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::future;
+///
+/// let a = future::always_ready(|| 1);
+/// assert_eq!(a.await, 1);
+/// # });
+/// ```
+pub fn always_ready<T, F: Fn() -> T>(f: F) -> AlwaysReady<F> {
+    assert_future::<T, _>(AlwaysReady(f))
+}
@@ -185,7 +185,8 @@ mod if_std {
     use core::pin::Pin;
     use core::task::{Context, Poll};
     use futures_io::{
-        AsyncBufRead, AsyncRead, AsyncSeek, AsyncWrite, IoSlice, IoSliceMut, Result, SeekFrom,
+        AsyncBufRead, AsyncRead, AsyncSeek, AsyncWrite, IoSlice, IoSliceMut, ReadBuf, Result,
+        SeekFrom,
     };
 
     impl<A, B> AsyncRead for Either<A, B>
@@ -214,6 +215,17 @@ mod if_std {
                 Either::Right(x) => x.poll_read_vectored(cx, bufs),
             }
         }
+
+        fn poll_read_buf(
+            self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &mut ReadBuf<'_>,
+        ) -> Poll<Result<()>> {
+            match self.project() {
+                Either::Left(x) => x.poll_read_buf(cx, buf),
+                Either::Right(x) => x.poll_read_buf(cx, buf),
+            }
+        }
     }
 
     impl<A, B> AsyncWrite for Either<A, B>
@@ -243,6 +255,13 @@ mod if_std {
             }
         }
 
+        fn is_write_vectored(&self) -> bool {
+            match self {
+                Either::Left(x) => x.is_write_vectored(),
+                Either::Right(x) => x.is_write_vectored(),
+            }
+        }
+
         fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<()>> {
             match self.project() {
                 Either::Left(x) => x.poll_flush(cx),
@@ -1,6 +1,9 @@
 use core::any::Any;
+use core::fmt;
 use core::pin::Pin;
+use std::error::Error;
 use std::panic::{catch_unwind, AssertUnwindSafe, UnwindSafe};
+use std::string::{String, ToString};
 
 use futures_core::future::Future;
 use futures_core::task::{Context, Poll};
@@ -36,3 +39,131 @@ where
         catch_unwind(AssertUnwindSafe(|| f.poll(cx)))?.map(Ok)
     }
 }
+
+pin_project! {
+    /// Future for the [`catch_unwind_message`](super::FutureExt::catch_unwind_message) method.
+    #[derive(Debug)]
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct CatchUnwindMessage<Fut> {
+        #[pin]
+        inner: CatchUnwind<Fut>,
+    }
+}
+
+impl<Fut> CatchUnwindMessage<Fut>
+where
+    Fut: Future + UnwindSafe,
+{
+    pub(super) fn new(future: Fut) -> Self {
+        Self { inner: CatchUnwind::new(future) }
+    }
+}
+
+impl<Fut> Future for CatchUnwindMessage<Fut>
+where
+    Fut: Future + UnwindSafe,
+{
+    type Output = Result<Fut::Output, String>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.project().inner.poll(cx).map(|res| res.map_err(panic_message))
+    }
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => match payload.downcast_ref::<&str>() {
+            Some(message) => message.to_string(),
+            None => "Box<dyn Any>".to_string(),
+        },
+    }
+}
+
+/// The payload of a panic caught by
+/// [`FutureExt::catch_unwind`](super::FutureExt::catch_unwind) or
+/// [`StreamExt::catch_unwind`](crate::stream::StreamExt::catch_unwind).
+///
+/// Those return the payload as a `Box<dyn Any + Send>`, which can be
+/// converted into a `Panicked` with [`From`], so `?` converts it
+/// automatically in functions returning `Result<_, Panicked>`. It implements
+/// [`Error`], so it can be converted into other error types in turn.
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::future::{FutureExt, Panicked};
+/// use std::panic::AssertUnwindSafe;
+///
+/// async fn run() -> Result<i32, Panicked> {
+///     let value = AssertUnwindSafe(async { panic!("task {} failed", 7) }).catch_unwind().await?;
+///     Ok(value)
+/// }
+///
+/// let panicked = run().await.unwrap_err();
+/// assert_eq!(panicked.message(), Some("task 7 failed"));
+/// # });
+/// ```
+pub struct Panicked {
+    payload: Box<dyn Any + Send>,
+}
+
+// Safety: the payload is only accessed through a shared reference in
+// `message`, which only looks at it as a `String` or a `&str`, both of
+// which are `Sync`.
+unsafe impl Sync for Panicked {}
+
+impl Panicked {
+    /// Wraps the payload of a panic.
+    pub fn new(payload: Box<dyn Any + Send>) -> Self {
+        Self { payload }
+    }
+
+    /// Returns the panic's message, if the payload is a `String` or a `&str`,
+    /// as is the case for panics caused by [`panic!`].
+    pub fn message(&self) -> Option<&str> {
+        match self.payload.downcast_ref::<String>() {
+            Some(message) => Some(message),
+            None => self.payload.downcast_ref::<&str>().copied(),
+        }
+    }
+
+    /// Attempts to downcast the payload to a concrete type, returning `self`
+    /// again if it's of a different type.
+    pub fn downcast<T: Any>(self) -> Result<Box<T>, Self> {
+        self.payload.downcast().map_err(Self::new)
+    }
+
+    /// Returns the payload, as passed to
+    /// [`resume_unwind`](std::panic::resume_unwind).
+    pub fn into_any(self) -> Box<dyn Any + Send> {
+        self.payload
+    }
+}
+
+impl From<Box<dyn Any + Send>> for Panicked {
+    fn from(payload: Box<dyn Any + Send>) -> Self {
+        Self::new(payload)
+    }
+}
+
+impl From<Panicked> for Box<dyn Any + Send> {
+    fn from(panicked: Panicked) -> Self {
+        panicked.into_any()
+    }
+}
+
+impl fmt::Debug for Panicked {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Panicked").field("message", &self.message()).finish()
+    }
+}
+
+impl fmt::Display for Panicked {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.message().unwrap_or("Box<dyn Any>"))
+    }
+}
+
+impl Error for Panicked {}
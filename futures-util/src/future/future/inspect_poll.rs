@@ -0,0 +1,117 @@
+use core::fmt;
+use core::pin::Pin;
+use futures_core::future::{FusedFuture, Future};
+use futures_core::task::{Context, Poll};
+use pin_project_lite::pin_project;
+#[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+use std::time::{Duration, Instant};
+
+/// Information about a single poll, passed to the closure given to
+/// [`FutureExt::inspect_poll`](super::FutureExt::inspect_poll) and
+/// [`StreamExt::inspect_poll`](crate::stream::StreamExt::inspect_poll).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PollEvent {
+    index: usize,
+    ready: bool,
+    #[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+    elapsed: Duration,
+}
+
+impl PollEvent {
+    /// Returns how many times the future or stream had been polled before
+    /// this poll.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns whether this poll returned `Poll::Ready`.
+    pub fn is_ready(&self) -> bool {
+        self.ready
+    }
+
+    /// Returns how long this poll took.
+    ///
+    /// This method is only available when the `std` feature of this
+    /// library is activated, and it is activated by default. It is not
+    /// available on `wasm32` targets, where reading the clock may panic.
+    #[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+}
+
+// Polls with `poll` and calls `f` with the event describing it.
+pub(crate) fn poll_inspected<T, F>(
+    index: &mut usize,
+    f: &mut F,
+    poll: impl FnOnce() -> Poll<T>,
+) -> Poll<T>
+where
+    F: FnMut(PollEvent),
+{
+    #[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+    let start = Instant::now();
+    let res = poll();
+    let event = PollEvent {
+        index: *index,
+        ready: res.is_ready(),
+        #[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+        elapsed: start.elapsed(),
+    };
+    *index += 1;
+    f(event);
+    res
+}
+
+pin_project! {
+    /// Future for the [`inspect_poll`](super::FutureExt::inspect_poll) method.
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct InspectPoll<Fut, F> {
+        #[pin]
+        future: Fut,
+        f: F,
+        polls: usize,
+    }
+}
+
+impl<Fut, F> InspectPoll<Fut, F> {
+    pub(super) fn new(future: Fut, f: F) -> Self {
+        Self { future, f, polls: 0 }
+    }
+}
+
+impl<Fut, F> fmt::Debug for InspectPoll<Fut, F>
+where
+    Fut: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InspectPoll")
+            .field("future", &self.future)
+            .field("polls", &self.polls)
+            .finish()
+    }
+}
+
+impl<Fut, F> FusedFuture for InspectPoll<Fut, F>
+where
+    Fut: FusedFuture,
+    F: FnMut(PollEvent),
+{
+    fn is_terminated(&self) -> bool {
+        self.future.is_terminated()
+    }
+}
+
+impl<Fut, F> Future for InspectPoll<Fut, F>
+where
+    Fut: Future,
+    F: FnMut(PollEvent),
+{
+    type Output = Fut::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let future = this.future;
+        poll_inspected(this.polls, this.f, || future.poll(cx))
+    }
+}
@@ -94,11 +94,16 @@ delegate_all!(
     ): Debug + Future + FusedFuture + New[|x: Fut| Map::new(x, ok_fn())]
 );
 
+mod inspect_poll;
+pub(crate) use self::inspect_poll::poll_inspected;
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::inspect_poll::{InspectPoll, PollEvent};
+
 #[cfg(feature = "std")]
 mod catch_unwind;
 #[cfg(feature = "std")]
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
-pub use self::catch_unwind::CatchUnwind;
+pub use self::catch_unwind::{CatchUnwind, CatchUnwindMessage, Panicked};
 
 #[cfg(feature = "channel")]
 #[cfg_attr(docsrs, doc(cfg(feature = "channel")))]
@@ -108,7 +113,7 @@ mod remote_handle;
 #[cfg_attr(docsrs, doc(cfg(feature = "channel")))]
 #[cfg(feature = "std")]
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
-pub use self::remote_handle::{Remote, RemoteHandle};
+pub use self::remote_handle::{CompletionWatcher, Remote, RemoteHandle};
 
 #[cfg(feature = "std")]
 mod shared;
@@ -116,6 +121,12 @@ mod shared;
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
 pub use self::shared::{Shared, WeakShared};
 
+#[cfg(feature = "std")]
+mod shared_arc;
+#[cfg(feature = "std")]
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::shared_arc::{SharedArc, WeakSharedArc};
+
 impl<T: ?Sized> FutureExt for T where T: Future {}
 
 /// An extension trait for `Future`s that provides a variety of convenient
@@ -396,6 +407,36 @@ pub trait FutureExt: Future {
         assert_future::<Self::Output, _>(Inspect::new(self, f))
     }
 
+    /// Calls `f` after each poll of this future with information about that
+    /// poll.
+    ///
+    /// The [`PollEvent`] passed to `f` holds the index of the poll, starting at
+    /// zero, and whether it returned `Poll::Ready`. When the `std` feature of
+    /// this library is activated, it also holds how long the poll took. `f`
+    /// doesn't get to see the output, so this is useful for finding out how
+    /// often, and for how long, a future gets polled without changing it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::future::{self, FutureExt};
+    /// use futures_test::future::FutureTestExt;
+    ///
+    /// let mut polls = Vec::new();
+    /// let future = future::ready(1).pending_once().inspect_poll(|event| polls.push(event.is_ready()));
+    /// assert_eq!(future.await, 1);
+    /// assert_eq!(polls, [false, true]);
+    /// # });
+    /// ```
+    fn inspect_poll<F>(self, f: F) -> InspectPoll<Self, F>
+    where
+        F: FnMut(PollEvent),
+        Self: Sized,
+    {
+        assert_future::<Self::Output, _>(InspectPoll::new(self, f))
+    }
+
     /// Catches unwinding panics while polling the future.
     ///
     /// In general, panics within a future can propagate all the way out to the
@@ -437,6 +478,36 @@ pub trait FutureExt: Future {
         ))
     }
 
+    /// Catches unwinding panics while polling the future, turning them into
+    /// their messages.
+    ///
+    /// This works like [`catch_unwind`](FutureExt::catch_unwind), but
+    /// instead of the raw panic payload, the error is the message the future
+    /// panicked with. If the payload is neither a `&str` nor a `String`, as
+    /// with a panic started by [`std::panic::panic_any`], the error is
+    /// `"Box<dyn Any>"`.
+    ///
+    /// This method is only available when the `std` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::future::{self, FutureExt};
+    ///
+    /// let future = future::lazy(|_| -> i32 { panic!("task {} failed", 7) });
+    /// assert_eq!(future.catch_unwind_message().await, Err("task 7 failed".to_string()));
+    /// # });
+    /// ```
+    #[cfg(feature = "std")]
+    fn catch_unwind_message(self) -> CatchUnwindMessage<Self>
+    where
+        Self: Sized + ::std::panic::UnwindSafe,
+    {
+        assert_future::<Result<Self::Output, std::string::String>, _>(CatchUnwindMessage::new(self))
+    }
+
     /// Create a cloneable handle to this future where all handles will resolve
     /// to the same result.
     ///
@@ -491,6 +562,46 @@ pub trait FutureExt: Future {
         assert_future::<Self::Output, _>(Shared::new(self))
     }
 
+    /// Create a cloneable handle to this future where all handles will resolve
+    /// to an [`Arc`](std::sync::Arc) of the same output.
+    ///
+    /// This works like [`shared`](FutureExt::shared), but stores the output in
+    /// an `Arc` and hands out clones of that, so the output itself doesn't
+    /// need to implement [`Clone`]. For the handles to be sent to other
+    /// threads, the output needs to be `Send + Sync`.
+    ///
+    /// This method is only available when the `std` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::future::FutureExt;
+    /// use std::sync::Arc;
+    ///
+    /// struct Config {
+    ///     name: String,
+    /// }
+    ///
+    /// let future = async { Config { name: "prod".to_string() } };
+    /// let shared1 = future.shared_arc();
+    /// let shared2 = shared1.clone();
+    ///
+    /// let config1 = shared1.await;
+    /// let config2 = shared2.await;
+    /// assert_eq!(config1.name, "prod");
+    /// assert!(Arc::ptr_eq(&config1, &config2));
+    /// # });
+    /// ```
+    #[cfg(feature = "std")]
+    fn shared_arc(self) -> SharedArc<Self>
+    where
+        Self: Sized,
+    {
+        assert_future::<std::sync::Arc<Self::Output>, _>(SharedArc::new(self))
+    }
+
     /// Turn this future into a future that yields `()` on completion and sends
     /// its output to another future on a separate task.
     ///
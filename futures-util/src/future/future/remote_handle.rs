@@ -1,6 +1,6 @@
 use {
     crate::future::{CatchUnwind, FutureExt},
-    futures_channel::oneshot::{self, Receiver, Sender},
+    futures_channel::oneshot::{self, Canceled, Receiver, Sender},
     futures_core::{
         future::Future,
         ready,
@@ -14,7 +14,7 @@ use {
         pin::Pin,
         sync::{
             atomic::{AtomicBool, Ordering},
-            Arc,
+            Arc, Mutex,
         },
         thread,
     },
@@ -41,7 +41,19 @@ use {
 #[cfg_attr(docsrs, doc(cfg(feature = "channel")))]
 pub struct RemoteHandle<T> {
     rx: Receiver<thread::Result<T>>,
-    keep_running: Arc<AtomicBool>,
+    // The message received by `poll_finished`, until it's taken. This is
+    // only accessed through `&mut self`, the `Mutex` just keeps this `Sync`.
+    received: Mutex<Option<Result<thread::Result<T>, Canceled>>>,
+    state: Arc<State>,
+}
+
+impl<T> Unpin for RemoteHandle<T> {}
+
+// State shared between a `Remote` and its `RemoteHandle`.
+#[derive(Debug, Default)]
+struct State {
+    keep_running: AtomicBool,
+    finished: AtomicBool,
 }
 
 impl<T> RemoteHandle<T> {
@@ -50,7 +62,72 @@ impl<T> RemoteHandle<T> {
     /// This method can be used if you want to drop the handle, but let the
     /// execution continue.
     pub fn forget(self) {
-        self.keep_running.store(true, Ordering::SeqCst);
+        self.state.keep_running.store(true, Ordering::SeqCst);
+    }
+
+    /// Drops this handle *without* canceling the underlying future, returning
+    /// a future which resolves once the remote future has finished.
+    ///
+    /// Unlike [`forget`](RemoteHandle::forget), this still lets you find out
+    /// when the remote future is done, and whether it panicked, but not its
+    /// output, which is dropped when it's received.
+    pub fn detach(self) -> CompletionWatcher<T> {
+        self.state.keep_running.store(true, Ordering::SeqCst);
+        CompletionWatcher { handle: self }
+    }
+
+    /// Returns whether the remote future has finished and its output is
+    /// available.
+    ///
+    /// This includes the remote future having panicked, in which case the
+    /// panic is resumed when taking the output. Once this returns `true`,
+    /// [`try_take`](RemoteHandle::try_take) returns the output unless it was
+    /// already taken, and polling this handle resolves immediately.
+    pub fn is_finished(&self) -> bool {
+        self.state.finished.load(Ordering::SeqCst)
+    }
+
+    /// Polls for the remote future to finish, without taking its output.
+    ///
+    /// This returns `Poll::Ready` once the remote future has finished,
+    /// including by panicking, or has been dropped without finishing. The
+    /// output, if any, stays in this handle until it's taken by polling it or
+    /// with [`try_take`](RemoteHandle::try_take), which returns the output
+    /// unless it was already taken.
+    pub fn poll_finished(&mut self, cx: &mut Context<'_>) -> Poll<()> {
+        let received = self.received.get_mut().unwrap();
+        if received.is_none() {
+            *received = Some(ready!(self.rx.poll_unpin(cx)));
+        }
+        Poll::Ready(())
+    }
+
+    fn poll_output(&mut self, cx: &mut Context<'_>) -> Poll<Result<thread::Result<T>, Canceled>> {
+        ready!(self.poll_finished(cx));
+        Poll::Ready(self.received.get_mut().unwrap().take().unwrap())
+    }
+
+    /// Attempts to take the output of the remote future without waiting for it.
+    ///
+    /// Returns `None` if the remote future hasn't finished yet, if it was
+    /// dropped before finishing, or if its output has already been taken.
+    /// Polling this handle after its output has been taken panics.
+    ///
+    /// # Panics
+    ///
+    /// If the remote future panicked, the panic is resumed here, just like it
+    /// would be when polling this handle.
+    pub fn try_take(&mut self) -> Option<T> {
+        let received = match self.received.get_mut().unwrap().take() {
+            Some(received) => received.map(Some),
+            None => self.rx.try_recv(),
+        };
+        match received {
+            Ok(Some(Ok(output))) => Some(output),
+            // the remote future panicked.
+            Ok(Some(Err(e))) => panic::resume_unwind(e),
+            Ok(None) | Err(_) => None,
+        }
     }
 }
 
@@ -58,7 +135,7 @@ impl<T: 'static> Future for RemoteHandle<T> {
     type Output = T;
 
     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
-        match ready!(self.rx.poll_unpin(cx)) {
+        match ready!(self.poll_output(cx)) {
             Ok(Ok(output)) => Poll::Ready(output),
             // the remote future panicked.
             Ok(Err(e)) => panic::resume_unwind(e),
@@ -68,6 +145,38 @@ impl<T: 'static> Future for RemoteHandle<T> {
     }
 }
 
+/// Future for the [`detach`](RemoteHandle::detach) method.
+///
+/// This resolves to `Ok(())` once the remote future has finished, or to the
+/// panic payload if it panicked. If the remote future was dropped without
+/// finishing, it resolves to an error holding [`Canceled`].
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+#[derive(Debug)]
+#[cfg_attr(docsrs, doc(cfg(feature = "channel")))]
+pub struct CompletionWatcher<T> {
+    handle: RemoteHandle<T>,
+}
+
+impl<T> CompletionWatcher<T> {
+    /// Returns whether the remote future has finished, see
+    /// [`RemoteHandle::is_finished`].
+    pub fn is_finished(&self) -> bool {
+        self.handle.is_finished()
+    }
+}
+
+impl<T> Future for CompletionWatcher<T> {
+    type Output = thread::Result<()>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Poll::Ready(match ready!(self.handle.poll_output(cx)) {
+            Ok(Ok(_)) => Ok(()),
+            Ok(Err(e)) => Err(e),
+            Err(e) => Err(Box::new(e)),
+        })
+    }
+}
+
 type SendMsg<Fut> = Result<<Fut as Future>::Output, Box<(dyn Any + Send + 'static)>>;
 
 pin_project! {
@@ -77,7 +186,7 @@ pin_project! {
     #[cfg_attr(docsrs, doc(cfg(feature = "channel")))]
     pub struct Remote<Fut: Future> {
         tx: Option<Sender<SendMsg<Fut>>>,
-        keep_running: Arc<AtomicBool>,
+        state: Arc<State>,
         #[pin]
         future: CatchUnwind<AssertUnwindSafe<Fut>>,
     }
@@ -96,7 +205,7 @@ impl<Fut: Future> Future for Remote<Fut> {
         let this = self.project();
 
         if this.tx.as_mut().unwrap().poll_canceled(cx).is_ready()
-            && !this.keep_running.load(Ordering::SeqCst)
+            && !this.state.keep_running.load(Ordering::SeqCst)
         {
             // Cancelled, bail out
             return Poll::Ready(());
@@ -107,20 +216,21 @@ impl<Fut: Future> Future for Remote<Fut> {
         // if the receiving end has gone away then that's ok, we just ignore the
         // send error here.
         drop(this.tx.take().unwrap().send(output));
+        this.state.finished.store(true, Ordering::SeqCst);
         Poll::Ready(())
     }
 }
 
 pub(super) fn remote_handle<Fut: Future>(future: Fut) -> (Remote<Fut>, RemoteHandle<Fut::Output>) {
     let (tx, rx) = oneshot::channel();
-    let keep_running = Arc::new(AtomicBool::new(false));
+    let state = Arc::new(State::default());
 
     // Unwind Safety: See the docs for RemoteHandle.
     let wrapped = Remote {
         future: AssertUnwindSafe(future).catch_unwind(),
         tx: Some(tx),
-        keep_running: keep_running.clone(),
+        state: state.clone(),
     };
 
-    (wrapped, RemoteHandle { rx, keep_running })
+    (wrapped, RemoteHandle { rx, received: Mutex::new(None), state })
 }
@@ -27,6 +27,10 @@ struct Notifier {
 }
 
 /// A weak reference to a [`Shared`] that can be upgraded much like an `Arc`.
+///
+/// It doesn't keep the future alive: once all [`Shared`]s for it have been
+/// dropped or polled to completion, the future or its output is dropped, even
+/// if the future hasn't completed yet, and upgrading fails.
 pub struct WeakShared<Fut: Future>(Weak<Inner<Fut>>);
 
 impl<Fut: Future> Clone for WeakShared<Fut> {
@@ -98,6 +102,60 @@ impl<Fut: Future> Shared<Fut> {
 
         Self { inner: Some(Arc::new(inner)), waker_key: NULL_WAKER_KEY }
     }
+
+    /// Attempts to recover the wrapped future.
+    ///
+    /// This succeeds if this is the only [`Shared`] for the future, and the
+    /// future hasn't completed. It may have been polled already. Any
+    /// [`WeakShared`] for it can't be upgraded afterwards.
+    ///
+    /// Otherwise, this [`Shared`] is returned unchanged in the error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::future::{self, FutureExt};
+    ///
+    /// let shared = future::ready(1).shared();
+    /// let clone = shared.clone();
+    /// let shared = shared.try_into_inner().unwrap_err();
+    ///
+    /// drop(clone);
+    /// assert_eq!(shared.try_into_inner().unwrap().into_inner(), 1);
+    /// ```
+    pub fn try_into_inner(mut self) -> Result<Fut, Self> {
+        let inner = match self.inner.take() {
+            Some(inner) => inner,
+            None => return Err(self),
+        };
+        if inner.notifier.state.load(Acquire) != IDLE {
+            self.inner = Some(inner);
+            return Err(self);
+        }
+
+        // This fails if there is another `Shared`, including one which is
+        // being upgraded from a `WeakShared` concurrently. Once it succeeds,
+        // no `WeakShared` can be upgraded anymore.
+        let inner = match Arc::try_unwrap(inner) {
+            Ok(inner) => inner,
+            Err(inner) => {
+                self.inner = Some(inner);
+                return Err(self);
+            }
+        };
+
+        // A `Shared` upgraded in the meantime might have completed the future
+        // before being dropped.
+        if inner.notifier.state.load(Acquire) == IDLE {
+            match inner.future_or_output.into_inner() {
+                FutureOrOutput::Future(future) => Ok(future),
+                FutureOrOutput::Output(_) => unreachable!(),
+            }
+        } else {
+            self.inner = Some(Arc::new(inner));
+            Err(self)
+        }
+    }
 }
 
 impl<Fut> Shared<Fut>
@@ -155,6 +213,18 @@ where
     pub fn weak_count(&self) -> Option<usize> {
         self.inner.as_ref().map(Arc::weak_count)
     }
+
+    /// Returns `true` if the two [`Shared`]s refer to the same future, i.e.
+    /// one of them was created by cloning the other.
+    ///
+    /// Returns `false` if either of them has already been polled to
+    /// completion.
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        match (self.inner.as_ref(), other.inner.as_ref()) {
+            (Some(a), Some(b)) => Arc::ptr_eq(a, b),
+            _ => false,
+        }
+    }
 }
 
 impl<Fut> Inner<Fut>
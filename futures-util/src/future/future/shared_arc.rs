@@ -0,0 +1,114 @@
+use super::map::Map;
+use super::shared::{Shared, WeakShared};
+use futures_core::future::{FusedFuture, Future};
+use futures_core::task::{Context, Poll};
+use std::fmt;
+use std::pin::Pin;
+use std::sync::Arc;
+
+type Inner<Fut> = Map<Fut, fn(<Fut as Future>::Output) -> Arc<<Fut as Future>::Output>>;
+
+/// Future for the [`shared_arc`](super::FutureExt::shared_arc) method.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct SharedArc<Fut: Future> {
+    inner: Shared<Inner<Fut>>,
+}
+
+/// A weak reference to a [`SharedArc`] that can be upgraded much like an `Arc`.
+pub struct WeakSharedArc<Fut: Future>(WeakShared<Inner<Fut>>);
+
+impl<Fut: Future> Clone for WeakSharedArc<Fut> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<Fut: Future> fmt::Debug for SharedArc<Fut> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SharedArc").field("inner", &self.inner).finish()
+    }
+}
+
+impl<Fut: Future> fmt::Debug for WeakSharedArc<Fut> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WeakSharedArc").finish()
+    }
+}
+
+impl<Fut: Future> SharedArc<Fut> {
+    pub(super) fn new(future: Fut) -> Self {
+        Self { inner: Shared::new(Map::new(future, Arc::new as fn(_) -> _)) }
+    }
+
+    /// Returns [`Some`] containing a reference to this [`SharedArc`]'s output
+    /// if it has already been computed by a clone or [`None`] if it hasn't
+    /// been computed yet or this [`SharedArc`] already returned its output
+    /// from [`poll`](Future::poll).
+    pub fn peek(&self) -> Option<&Arc<Fut::Output>> {
+        self.inner.peek()
+    }
+
+    /// Creates a new [`WeakSharedArc`] for this [`SharedArc`].
+    ///
+    /// Returns [`None`] if it has already been polled to completion.
+    pub fn downgrade(&self) -> Option<WeakSharedArc<Fut>> {
+        self.inner.downgrade().map(WeakSharedArc)
+    }
+
+    /// Gets the number of strong pointers to this allocation.
+    ///
+    /// Returns [`None`] if it has already been polled to completion.
+    ///
+    /// See [`Shared::strong_count`] for the caveats of using this.
+    pub fn strong_count(&self) -> Option<usize> {
+        self.inner.strong_count()
+    }
+
+    /// Gets the number of weak pointers to this allocation.
+    ///
+    /// Returns [`None`] if it has already been polled to completion.
+    ///
+    /// See [`Shared::weak_count`] for the caveats of using this.
+    pub fn weak_count(&self) -> Option<usize> {
+        self.inner.weak_count()
+    }
+
+    /// Returns `true` if the two [`SharedArc`]s refer to the same future, i.e.
+    /// one of them was created by cloning the other.
+    ///
+    /// Returns `false` if either of them has already been polled to
+    /// completion.
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        self.inner.ptr_eq(&other.inner)
+    }
+}
+
+impl<Fut: Future> FusedFuture for SharedArc<Fut> {
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}
+
+impl<Fut: Future> Future for SharedArc<Fut> {
+    type Output = Arc<Fut::Output>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.inner).poll(cx)
+    }
+}
+
+impl<Fut: Future> Clone for SharedArc<Fut> {
+    fn clone(&self) -> Self {
+        Self { inner: self.inner.clone() }
+    }
+}
+
+impl<Fut: Future> WeakSharedArc<Fut> {
+    /// Attempts to upgrade this [`WeakSharedArc`] into a [`SharedArc`].
+    ///
+    /// Returns [`None`] if all clones of the [`SharedArc`] have been dropped
+    /// or polled to completion.
+    pub fn upgrade(&self) -> Option<SharedArc<Fut>> {
+        Some(SharedArc { inner: self.0.upgrade()? })
+    }
+}
@@ -0,0 +1,34 @@
+use futures_core::future::Future;
+
+/// The futures accepted by functions such as [`join`](super::join()).
+///
+/// This is any [`Future`], or with the unstable `into-future` feature,
+/// anything implementing [`IntoFuture`](core::future::IntoFuture), which is
+/// converted with `into_future` first.
+#[allow(unreachable_pub)] // only used in bounds, it can't be named outside of this crate
+pub trait IntoFutureArg {
+    /// The future this is converted into.
+    type Future: Future;
+
+    /// Converts this into a future.
+    fn into_future_arg(self) -> Self::Future;
+}
+
+#[cfg(feature = "into-future")]
+#[allow(clippy::incompatible_msrv)] // the `into-future` feature requires Rust 1.64
+impl<T: core::future::IntoFuture> IntoFutureArg for T {
+    type Future = T::IntoFuture;
+
+    fn into_future_arg(self) -> Self::Future {
+        self.into_future()
+    }
+}
+
+#[cfg(not(feature = "into-future"))]
+impl<T: Future> IntoFutureArg for T {
+    type Future = T;
+
+    fn into_future_arg(self) -> Self::Future {
+        self
+    }
+}
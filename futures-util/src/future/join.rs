@@ -1,3 +1,4 @@
+use super::into_future_arg::IntoFutureArg;
 use crate::future::{assert_future, maybe_done, MaybeDone};
 use core::fmt;
 use core::pin::Pin;
@@ -64,6 +65,10 @@ impl<Fut1: FusedFuture, Fut2: FusedFuture> FusedFuture for Join<Fut1, Fut2> {
 /// Note that this function consumes the passed futures and returns a
 /// wrapped version of it.
 ///
+/// With the unstable `into-future` feature, this accepts anything implementing
+/// [`IntoFuture`](core::future::IntoFuture), such as builders which can be
+/// `.await`ed directly, converting them with `into_future` first.
+///
 /// # Examples
 ///
 /// ```
@@ -77,10 +82,12 @@ impl<Fut1: FusedFuture, Fut2: FusedFuture> FusedFuture for Join<Fut1, Fut2> {
 /// assert_eq!(pair.await, (1, 2));
 /// # });
 /// ```
-pub fn join<Fut1, Fut2>(future1: Fut1, future2: Fut2) -> Join<Fut1, Fut2>
+pub fn join<Fut1, Fut2>(future1: Fut1, future2: Fut2) -> Join<Fut1::Future, Fut2::Future>
 where
-    Fut1: Future,
-    Fut2: Future,
+    Fut1: IntoFutureArg,
+    Fut2: IntoFutureArg,
 {
-    assert_future::<(Fut1::Output, Fut2::Output), _>(Join::new(future1, future2))
+    assert_future::<(<Fut1::Future as Future>::Output, <Fut2::Future as Future>::Output), _>(
+        Join::new(future1.into_future_arg(), future2.into_future_arg()),
+    )
 }
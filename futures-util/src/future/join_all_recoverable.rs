@@ -0,0 +1,117 @@
+//! Definition of the `JoinAllRecoverable` combinator, waiting for all of a
+//! list of futures to finish, while keeping the outputs which are available
+//! if it is cancelled.
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::fmt;
+use core::future::Future;
+use core::iter::FromIterator;
+use core::mem;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use super::{assert_future, iter_pin_mut, MaybeDone};
+
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+/// Future for the [`join_all_recoverable`] function.
+pub struct JoinAllRecoverable<F>
+where
+    F: Future,
+{
+    elems: Pin<Box<[MaybeDone<F>]>>,
+}
+
+impl<F> fmt::Debug for JoinAllRecoverable<F>
+where
+    F: Future + fmt::Debug,
+    F::Output: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JoinAllRecoverable").field("elems", &self.elems).finish()
+    }
+}
+
+/// Creates a future which represents a collection of the outputs of the futures
+/// given, whose completed outputs can be recovered if it is cancelled.
+///
+/// This works like [`join_all`](super::join_all), except that instead of
+/// dropping the returned future to cancel it, it can be turned into the
+/// outputs of the futures which have completed so far using
+/// [`into_partial`](JoinAllRecoverable::into_partial). The remaining futures
+/// are dropped at that point.
+///
+/// This function is only available when the `std` or `alloc` feature of this
+/// library is activated, and it is activated by default.
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::future::{self, FutureExt};
+///
+/// let mut all = future::join_all_recoverable(vec![
+///     future::ready(1).boxed(),
+///     future::pending().boxed(),
+///     future::ready(3).boxed(),
+/// ]);
+///
+/// // Give the futures a chance to make progress, then give up.
+/// assert_eq!(future::poll_immediate(&mut all).await, None);
+/// assert_eq!(all.into_partial(), [Some(1), None, Some(3)]);
+/// # });
+/// ```
+pub fn join_all_recoverable<I>(iter: I) -> JoinAllRecoverable<I::Item>
+where
+    I: IntoIterator,
+    I::Item: Future,
+{
+    let elems = iter.into_iter().map(MaybeDone::Future).collect::<Box<[_]>>().into();
+    assert_future::<Vec<<I::Item as Future>::Output>, _>(JoinAllRecoverable { elems })
+}
+
+impl<F> JoinAllRecoverable<F>
+where
+    F: Future,
+{
+    /// Consumes this future, returning the outputs of the futures in the
+    /// order they were provided, with [`None`] for those which haven't
+    /// completed yet.
+    ///
+    /// If this future has already completed, the outputs have been returned
+    /// from it, and the returned `Vec` is empty.
+    pub fn into_partial(mut self) -> Vec<Option<F::Output>> {
+        iter_pin_mut(self.elems.as_mut()).map(|e| e.take_output()).collect()
+    }
+}
+
+impl<F> Future for JoinAllRecoverable<F>
+where
+    F: Future,
+{
+    type Output = Vec<F::Output>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut all_done = true;
+
+        for elem in iter_pin_mut(self.elems.as_mut()) {
+            if elem.poll(cx).is_pending() {
+                all_done = false;
+            }
+        }
+
+        if all_done {
+            let mut elems = mem::replace(&mut self.elems, Box::pin([]));
+            let result = iter_pin_mut(elems.as_mut()).map(|e| e.take_output().unwrap()).collect();
+            Poll::Ready(result)
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl<F: Future> FromIterator<F> for JoinAllRecoverable<F> {
+    fn from_iter<T: IntoIterator<Item = F>>(iter: T) -> Self {
+        join_all_recoverable(iter)
+    }
+}
@@ -0,0 +1,108 @@
+//! Definition of the `JoinAllResults` combinator, waiting for all of a list of
+//! futures to finish, and collecting all of their errors.
+
+use alloc::vec::Vec;
+use core::fmt;
+use core::future::Future;
+use core::iter::FromIterator;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use futures_core::ready;
+
+use super::{assert_future, join_all, IntoFuture, JoinAll, TryFuture};
+
+/// Future for the [`join_all_results`] function.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct JoinAllResults<F>
+where
+    F: TryFuture,
+{
+    inner: JoinAll<IntoFuture<F>>,
+}
+
+impl<F> fmt::Debug for JoinAllResults<F>
+where
+    F: TryFuture + fmt::Debug,
+    F::Ok: fmt::Debug,
+    F::Error: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JoinAllResults").field("inner", &self.inner).finish()
+    }
+}
+
+/// Creates a future which represents either a collection of the results of
+/// the futures given or a collection of all of their errors.
+///
+/// Unlike [`try_join_all`](super::try_join_all), which completes as soon as
+/// one of the futures fails, the returned future runs all of the futures to
+/// completion. It then resolves to the outputs of all of them, in the same
+/// order as they were provided, if they all succeeded, and otherwise to all
+/// of the errors, along with the position of the future which failed, in the
+/// same order.
+///
+/// Like [`join_all`], this switches to
+/// [`FuturesOrdered`](crate::stream::FuturesOrdered) if the number of futures
+/// is large.
+///
+/// This function is only available when the `std` or `alloc` feature of this
+/// library is activated, and it is activated by default.
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::future::{self, join_all_results};
+///
+/// let futures = vec![
+///     future::ok::<u32, &str>(1),
+///     future::err("too small"),
+///     future::ok(3),
+///     future::err("too large"),
+/// ];
+/// assert_eq!(join_all_results(futures).await, Err(vec![(1, "too small"), (3, "too large")]));
+///
+/// let futures = vec![future::ok::<u32, &str>(1), future::ok(2)];
+/// assert_eq!(join_all_results(futures).await, Ok(vec![1, 2]));
+/// # });
+/// ```
+pub fn join_all_results<I>(iter: I) -> JoinAllResults<I::Item>
+where
+    I: IntoIterator,
+    I::Item: TryFuture,
+{
+    let inner = join_all(iter.into_iter().map(IntoFuture::new));
+    assert_future::<
+        Result<Vec<<I::Item as TryFuture>::Ok>, Vec<(usize, <I::Item as TryFuture>::Error)>>,
+        _,
+    >(JoinAllResults { inner })
+}
+
+impl<F> Future for JoinAllResults<F>
+where
+    F: TryFuture,
+{
+    type Output = Result<Vec<F::Ok>, Vec<(usize, F::Error)>>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let results = ready!(Pin::new(&mut self.inner).poll(cx));
+
+        let mut outputs = Vec::with_capacity(results.len());
+        let mut errors = Vec::new();
+        for (i, result) in results.into_iter().enumerate() {
+            match result {
+                Ok(output) if errors.is_empty() => outputs.push(output),
+                Ok(_) => {}
+                Err(e) => errors.push((i, e)),
+            }
+        }
+
+        Poll::Ready(if errors.is_empty() { Ok(outputs) } else { Err(errors) })
+    }
+}
+
+impl<F: TryFuture> FromIterator<F> for JoinAllResults<F> {
+    fn from_iter<T: IntoIterator<Item = F>>(iter: T) -> Self {
+        join_all_results(iter)
+    }
+}
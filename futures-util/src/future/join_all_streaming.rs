@@ -0,0 +1,306 @@
+//! Definition of the `JoinAllStreaming` and `TryJoinAllStreaming` combinators,
+//! yielding the outputs of a list of futures as they finish.
+
+use alloc::boxed::Box;
+use core::fmt;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use futures_core::ready;
+use futures_core::stream::{FusedStream, Stream};
+#[cfg(not(futures_no_atomic_cas))]
+use pin_project_lite::pin_project;
+
+use super::{iter_pin_mut, IntoFuture, MaybeDone, TryFuture};
+use crate::stream::assert_stream;
+#[cfg(not(futures_no_atomic_cas))]
+use crate::stream::{FuturesUnordered, StreamExt};
+
+#[cfg(not(futures_no_atomic_cas))]
+const SMALL: usize = 30;
+
+#[cfg(not(futures_no_atomic_cas))]
+pin_project! {
+    // A future along with its position in the list passed to
+    // `join_all_streaming`.
+    #[derive(Debug)]
+    struct Indexed<F> {
+        index: usize,
+        #[pin]
+        future: F,
+    }
+}
+
+#[cfg(not(futures_no_atomic_cas))]
+impl<F: Future> Future for Indexed<F> {
+    type Output = (usize, F::Output);
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let index = *this.index;
+        this.future.poll(cx).map(|output| (index, output))
+    }
+}
+
+/// Stream for the [`join_all_streaming`] function.
+#[must_use = "streams do nothing unless polled"]
+pub struct JoinAllStreaming<F>
+where
+    F: Future,
+{
+    kind: JoinAllStreamingKind<F>,
+}
+
+enum JoinAllStreamingKind<F>
+where
+    F: Future,
+{
+    Small {
+        elems: Pin<Box<[MaybeDone<F>]>>,
+        remaining: usize,
+    },
+    #[cfg(not(futures_no_atomic_cas))]
+    Big {
+        futures: FuturesUnordered<Indexed<F>>,
+    },
+}
+
+impl<F> fmt::Debug for JoinAllStreaming<F>
+where
+    F: Future + fmt::Debug,
+    F::Output: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            JoinAllStreamingKind::Small { ref elems, .. } => {
+                f.debug_struct("JoinAllStreaming").field("elems", elems).finish()
+            }
+            #[cfg(not(futures_no_atomic_cas))]
+            JoinAllStreamingKind::Big { ref futures } => {
+                f.debug_struct("JoinAllStreaming").field("futures", futures).finish()
+            }
+        }
+    }
+}
+
+/// Creates a stream which yields the output of each of the futures given as
+/// soon as it completes, along with the future's position in `iter`.
+///
+/// Unlike [`join_all`](super::join_all), which waits for all of the futures
+/// before resolving, this makes each output available as early as possible,
+/// while still keeping track of which future it came from. The stream ends
+/// once all of the futures have completed.
+///
+/// This function is only available when the `std` or `alloc` feature of this
+/// library is activated, and it is activated by default.
+///
+/// # See Also
+///
+/// Like `join_all`, this switches to
+/// [`FuturesUnordered`](crate::stream::FuturesUnordered) if the number of
+/// futures is large, so that only the futures which have been woken are
+/// polled again.
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::channel::oneshot;
+/// use futures::future::join_all_streaming;
+/// use futures::stream::StreamExt;
+///
+/// let (tx1, rx1) = oneshot::channel();
+/// let (tx2, rx2) = oneshot::channel();
+/// let mut stream = join_all_streaming(vec![rx1, rx2]);
+///
+/// tx2.send("second").unwrap();
+/// assert_eq!(stream.next().await, Some((1, Ok("second"))));
+/// tx1.send("first").unwrap();
+/// assert_eq!(stream.next().await, Some((0, Ok("first"))));
+/// assert_eq!(stream.next().await, None);
+/// # });
+/// ```
+pub fn join_all_streaming<I>(iter: I) -> JoinAllStreaming<I::Item>
+where
+    I: IntoIterator,
+    I::Item: Future,
+{
+    #[cfg(futures_no_atomic_cas)]
+    {
+        let elems = iter.into_iter().map(MaybeDone::Future).collect::<Box<[_]>>();
+        let kind = JoinAllStreamingKind::Small { remaining: elems.len(), elems: elems.into() };
+        assert_stream::<(usize, <I::Item as Future>::Output), _>(JoinAllStreaming { kind })
+    }
+    #[cfg(not(futures_no_atomic_cas))]
+    {
+        let iter = iter.into_iter();
+        let kind = match iter.size_hint().1 {
+            Some(max) if max <= SMALL => {
+                let elems = iter.map(MaybeDone::Future).collect::<Box<[_]>>();
+                JoinAllStreamingKind::Small { remaining: elems.len(), elems: elems.into() }
+            }
+            _ => JoinAllStreamingKind::Big {
+                futures: iter
+                    .enumerate()
+                    .map(|(index, future)| Indexed { index, future })
+                    .collect(),
+            },
+        };
+        assert_stream::<(usize, <I::Item as Future>::Output), _>(JoinAllStreaming { kind })
+    }
+}
+
+impl<F> JoinAllStreaming<F>
+where
+    F: Future,
+{
+    fn len(&self) -> usize {
+        match &self.kind {
+            JoinAllStreamingKind::Small { remaining, .. } => *remaining,
+            #[cfg(not(futures_no_atomic_cas))]
+            JoinAllStreamingKind::Big { futures } => futures.len(),
+        }
+    }
+}
+
+impl<F> Stream for JoinAllStreaming<F>
+where
+    F: Future,
+{
+    type Item = (usize, F::Output);
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        match &mut self.kind {
+            JoinAllStreamingKind::Small { elems, remaining } => {
+                if *remaining == 0 {
+                    return Poll::Ready(None);
+                }
+
+                for (index, mut elem) in iter_pin_mut(elems.as_mut()).enumerate() {
+                    if let MaybeDone::Gone = &*elem {
+                        continue;
+                    }
+                    if elem.as_mut().poll(cx).is_ready() {
+                        *remaining -= 1;
+                        return Poll::Ready(Some((index, elem.take_output().unwrap())));
+                    }
+                }
+
+                Poll::Pending
+            }
+            #[cfg(not(futures_no_atomic_cas))]
+            JoinAllStreamingKind::Big { futures } => futures.poll_next_unpin(cx),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<F> FusedStream for JoinAllStreaming<F>
+where
+    F: Future,
+{
+    fn is_terminated(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Stream for the [`try_join_all_streaming`] function.
+#[must_use = "streams do nothing unless polled"]
+pub struct TryJoinAllStreaming<F>
+where
+    F: TryFuture,
+{
+    inner: Option<JoinAllStreaming<IntoFuture<F>>>,
+}
+
+impl<F> fmt::Debug for TryJoinAllStreaming<F>
+where
+    F: TryFuture + fmt::Debug,
+    F::Ok: fmt::Debug,
+    F::Error: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TryJoinAllStreaming").field("inner", &self.inner).finish()
+    }
+}
+
+/// Creates a stream which yields the output of each of the futures given as
+/// soon as it succeeds, along with the future's position in `iter`.
+///
+/// This works like [`join_all_streaming`], except that if any of the futures
+/// fails, its error is yielded, the other futures are dropped, and the stream
+/// ends.
+///
+/// This function is only available when the `std` or `alloc` feature of this
+/// library is activated, and it is activated by default.
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::future::{self, try_join_all_streaming};
+/// use futures::stream::StreamExt;
+///
+/// let futures = vec![future::ok::<u32, &str>(1), future::err("failed"), future::ok(3)];
+/// let mut stream = try_join_all_streaming(futures);
+///
+/// assert_eq!(stream.next().await, Some(Ok((0, 1))));
+/// assert_eq!(stream.next().await, Some(Err("failed")));
+/// assert_eq!(stream.next().await, None);
+/// # });
+/// ```
+pub fn try_join_all_streaming<I>(iter: I) -> TryJoinAllStreaming<I::Item>
+where
+    I: IntoIterator,
+    I::Item: TryFuture,
+{
+    let inner = join_all_streaming(iter.into_iter().map(IntoFuture::new));
+    assert_stream::<Result<(usize, <I::Item as TryFuture>::Ok), <I::Item as TryFuture>::Error>, _>(
+        TryJoinAllStreaming { inner: Some(inner) },
+    )
+}
+
+impl<F> Stream for TryJoinAllStreaming<F>
+where
+    F: TryFuture,
+{
+    type Item = Result<(usize, F::Ok), F::Error>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let inner = match self.inner.as_mut() {
+            Some(inner) => inner,
+            None => return Poll::Ready(None),
+        };
+        match ready!(Pin::new(inner).poll_next(cx)) {
+            Some((index, Ok(output))) => Poll::Ready(Some(Ok((index, output)))),
+            Some((_, Err(e))) => {
+                self.inner = None;
+                Poll::Ready(Some(Err(e)))
+            }
+            None => {
+                self.inner = None;
+                Poll::Ready(None)
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.inner {
+            Some(inner) => (0, Some(inner.len())),
+            None => (0, Some(0)),
+        }
+    }
+}
+
+impl<F> FusedStream for TryJoinAllStreaming<F>
+where
+    F: TryFuture,
+{
+    fn is_terminated(&self) -> bool {
+        self.inner.is_none()
+    }
+}
@@ -0,0 +1,97 @@
+//! Definition of the `JoinArray` combinator, waiting for all of an array of
+//! futures to complete.
+
+// The `join-array` feature requires a newer Rust version than the rest of the
+// crate.
+#![allow(clippy::incompatible_msrv)]
+
+use super::{assert_future, maybe_done, MaybeDone};
+use core::fmt;
+use core::pin::Pin;
+use futures_core::future::{FusedFuture, Future};
+use futures_core::task::{Context, Poll};
+
+/// Future for the [`join_array`] function.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct JoinArray<Fut: Future, const N: usize> {
+    elems: [MaybeDone<Fut>; N],
+    terminated: bool,
+}
+
+impl<Fut, const N: usize> fmt::Debug for JoinArray<Fut, N>
+where
+    Fut: Future + fmt::Debug,
+    Fut::Output: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JoinArray").field("elems", &self.elems).finish()
+    }
+}
+
+/// Creates a future which represents a collection of the outputs of the
+/// futures given.
+///
+/// The returned future will drive execution for all of its underlying
+/// futures, collecting the results into an array of the same length and in
+/// the same order as they were provided.
+///
+/// Unlike [`join_all`](super::join_all()), this doesn't allocate: the futures
+/// are polled in place. As arrays can't be partially moved out of, the
+/// outputs are kept next to the futures until all of them have completed.
+///
+/// This function is only available when the `join-array` feature of this
+/// library is activated, which requires the `unstable` feature and Rust 1.55
+/// or later.
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::future::{self, join_array};
+///
+/// let futures = [future::ready(1), future::ready(2), future::ready(3)];
+///
+/// assert_eq!(join_array(futures).await, [1, 2, 3]);
+/// # });
+/// ```
+pub fn join_array<Fut, const N: usize>(futures: [Fut; N]) -> JoinArray<Fut, N>
+where
+    Fut: Future,
+{
+    assert_future::<[Fut::Output; N], _>(JoinArray {
+        elems: futures.map(maybe_done),
+        terminated: false,
+    })
+}
+
+impl<Fut: Future, const N: usize> Future for JoinArray<Fut, N> {
+    type Output = [Fut::Output; N];
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // Safety: the elements are never moved, as they are only accessed
+        // through pinned references to them.
+        let this = unsafe { self.get_unchecked_mut() };
+        let mut all_done = true;
+        for elem in this.elems.iter_mut() {
+            all_done &= unsafe { Pin::new_unchecked(elem) }.poll(cx).is_ready();
+        }
+
+        if all_done {
+            let mut elems = this.elems.iter_mut();
+            let output = [(); N].map(|()| {
+                let elem = unsafe { Pin::new_unchecked(elems.next().unwrap()) };
+                elem.take_output().unwrap()
+            });
+            this.terminated = true;
+            Poll::Ready(output)
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl<Fut: Future, const N: usize> FusedFuture for JoinArray<Fut, N> {
+    fn is_terminated(&self) -> bool {
+        self.terminated
+    }
+}
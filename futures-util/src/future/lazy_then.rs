@@ -0,0 +1,82 @@
+use super::assert_future;
+use core::pin::Pin;
+use futures_core::future::{FusedFuture, Future};
+use futures_core::ready;
+use futures_core::task::{Context, Poll};
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Future for the [`lazy_then`] function.
+    #[derive(Debug)]
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct LazyThen<F, Fut> {
+        f: Option<F>,
+        #[pin]
+        future: Option<Fut>,
+    }
+}
+
+/// Creates a new future which defers creating another future until it is
+/// first polled, and then drives that future to completion.
+///
+/// This is like [`lazy`](super::lazy()), but rather than producing a value,
+/// the closure produces a future, which is then polled by the returned
+/// future. Creating the future may have side effects, such as opening a
+/// connection; if the returned future is dropped without being polled, the
+/// closure is never run, so those never happen.
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::future;
+///
+/// let a = future::lazy_then(|| async { 1 });
+/// assert_eq!(a.await, 1);
+///
+/// let b = future::lazy_then(|| -> future::Ready<i32> {
+///     panic!("oh no!")
+/// });
+/// drop(b); // closure is never run
+/// # });
+/// ```
+pub fn lazy_then<F, Fut>(f: F) -> LazyThen<F, Fut>
+where
+    F: FnOnce() -> Fut,
+    Fut: Future,
+{
+    assert_future::<Fut::Output, _>(LazyThen { f: Some(f), future: None })
+}
+
+impl<F, Fut> FusedFuture for LazyThen<F, Fut>
+where
+    F: FnOnce() -> Fut,
+    Fut: Future,
+{
+    fn is_terminated(&self) -> bool {
+        self.f.is_none() && self.future.is_none()
+    }
+}
+
+impl<F, Fut> Future for LazyThen<F, Fut>
+where
+    F: FnOnce() -> Fut,
+    Fut: Future,
+{
+    type Output = Fut::Output;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Fut::Output> {
+        let mut this = self.as_mut().project();
+        if let Some(f) = this.f.take() {
+            this.future.set(Some(f()));
+        }
+        let output = ready!(this
+            .future
+            .as_mut()
+            .as_pin_mut()
+            .expect("LazyThen polled after completion")
+            .poll(cx));
+        this.future.set(None);
+        Poll::Ready(output)
+    }
+}
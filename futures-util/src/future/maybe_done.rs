@@ -74,10 +74,13 @@ impl<Fut: Future> MaybeDone<Fut> {
             }
         }
     }
-}
 
-impl<Fut: Future> FusedFuture for MaybeDone<Fut> {
-    fn is_terminated(&self) -> bool {
+    /// Returns whether the inner future has completed, whether or not its
+    /// output has been taken since.
+    ///
+    /// Unlike most methods of `MaybeDone`, this doesn't require pinning.
+    #[inline]
+    pub fn is_done(&self) -> bool {
         match self {
             Self::Future(_) => false,
             Self::Done(_) | Self::Gone => true,
@@ -85,6 +88,28 @@ impl<Fut: Future> FusedFuture for MaybeDone<Fut> {
     }
 }
 
+impl<Fut: Future + Unpin> MaybeDone<Fut> {
+    /// A convenience for calling [`MaybeDone::output_mut`] on [`Unpin`] futures,
+    /// which doesn't require pinning.
+    #[inline]
+    pub fn output_mut_unpin(&mut self) -> Option<&mut Fut::Output> {
+        Pin::new(self).output_mut()
+    }
+
+    /// A convenience for calling [`MaybeDone::take_output`] on [`Unpin`] futures,
+    /// which doesn't require pinning.
+    #[inline]
+    pub fn take_output_unpin(&mut self) -> Option<Fut::Output> {
+        Pin::new(self).take_output()
+    }
+}
+
+impl<Fut: Future> FusedFuture for MaybeDone<Fut> {
+    fn is_terminated(&self) -> bool {
+        self.is_done()
+    }
+}
+
 impl<Fut: Future> Future for MaybeDone<Fut> {
     type Output = ();
 
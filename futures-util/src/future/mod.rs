@@ -9,6 +9,8 @@
 //!   from a closure that defines its return value, and [`ready`](ready()),
 //!   which constructs a future with an immediate defined value.
 
+use core::pin::Pin;
+
 #[doc(no_inline)]
 pub use core::future::Future;
 
@@ -20,28 +22,30 @@ pub use futures_task::{FutureObj, LocalFutureObj, UnsafeFutureObj};
 // Extension traits and combinators
 #[allow(clippy::module_inception)]
 mod future;
+pub(crate) use self::future::poll_inspected;
 pub use self::future::{
-    Flatten, Fuse, FutureExt, Inspect, IntoStream, Map, MapInto, NeverError, Then, UnitError,
+    Flatten, Fuse, FutureExt, Inspect, InspectPoll, IntoStream, Map, MapInto, NeverError,
+    PollEvent, Then, UnitError,
 };
 
 #[deprecated(note = "This is now an alias for [Flatten](Flatten)")]
 pub use self::future::FlattenStream;
 
 #[cfg(feature = "std")]
-pub use self::future::CatchUnwind;
+pub use self::future::{CatchUnwind, CatchUnwindMessage, Panicked};
 
 #[cfg(feature = "channel")]
 #[cfg_attr(docsrs, doc(cfg(feature = "channel")))]
 #[cfg(feature = "std")]
-pub use self::future::{Remote, RemoteHandle};
+pub use self::future::{CompletionWatcher, Remote, RemoteHandle};
 
 #[cfg(feature = "std")]
-pub use self::future::{Shared, WeakShared};
+pub use self::future::{Shared, SharedArc, WeakShared, WeakSharedArc};
 
 mod try_future;
 pub use self::try_future::{
     AndThen, ErrInto, InspectErr, InspectOk, IntoFuture, MapErr, MapOk, MapOkOrElse, OkInto,
-    OrElse, TryFlatten, TryFlattenStream, TryFutureExt, UnwrapOrElse,
+    OrElse, Retry, TryFlatten, TryFlattenStream, TryFutureExt, UnwrapOrElse,
 };
 
 #[cfg(feature = "sink")]
@@ -50,9 +54,15 @@ pub use self::try_future::FlattenSink;
 
 // Primitive futures
 
+// The bound of functions like `join`, depending on the `into-future` feature.
+mod into_future_arg;
+
 mod lazy;
 pub use self::lazy::{lazy, Lazy};
 
+mod lazy_then;
+pub use self::lazy_then::{lazy_then, LazyThen};
+
 mod pending;
 pub use self::pending::{pending, Pending};
 
@@ -65,6 +75,9 @@ pub use self::try_maybe_done::{try_maybe_done, TryMaybeDone};
 mod option;
 pub use self::option::OptionFuture;
 
+mod always_ready;
+pub use self::always_ready::{always_ready, AlwaysReady};
+
 mod poll_fn;
 pub use self::poll_fn::{poll_fn, PollFn};
 
@@ -77,13 +90,37 @@ pub use self::ready::{err, ok, ready, Ready};
 mod join;
 pub use self::join::{join, Join};
 
+#[cfg(feature = "join-array")]
+#[cfg_attr(docsrs, doc(cfg(feature = "join-array")))]
+mod join_array;
+#[cfg(feature = "join-array")]
+#[cfg_attr(docsrs, doc(cfg(feature = "join-array")))]
+pub use self::join_array::{join_array, JoinArray};
+
 #[cfg(feature = "alloc")]
 mod join_all;
 #[cfg(feature = "alloc")]
 pub use self::join_all::{join_all, JoinAll};
 
+#[cfg(feature = "alloc")]
+mod join_all_recoverable;
+#[cfg(feature = "alloc")]
+pub use self::join_all_recoverable::{join_all_recoverable, JoinAllRecoverable};
+
+#[cfg(feature = "alloc")]
+mod join_all_results;
+#[cfg(feature = "alloc")]
+pub use self::join_all_results::{join_all_results, JoinAllResults};
+
+#[cfg(feature = "alloc")]
+mod join_all_streaming;
+#[cfg(feature = "alloc")]
+pub use self::join_all_streaming::{
+    join_all_streaming, try_join_all_streaming, JoinAllStreaming, TryJoinAllStreaming,
+};
+
 mod select;
-pub use self::select::{select, Select};
+pub use self::select::{select, select_named, Select, SelectNamed, SelectResult};
 
 #[cfg(feature = "alloc")]
 mod select_all;
@@ -98,13 +135,30 @@ mod try_join_all;
 #[cfg(feature = "alloc")]
 pub use self::try_join_all::{try_join_all, TryJoinAll};
 
+#[cfg(feature = "join-array")]
+#[cfg_attr(docsrs, doc(cfg(feature = "join-array")))]
+mod try_join_array;
+#[cfg(feature = "join-array")]
+#[cfg_attr(docsrs, doc(cfg(feature = "join-array")))]
+pub use self::try_join_array::{try_join_array, TryJoinArray};
+
+#[cfg(feature = "alloc")]
+mod try_join_all_partial;
+#[cfg(feature = "alloc")]
+pub use self::try_join_all_partial::{try_join_all_partial, TryJoinAllPartial};
+
+#[cfg(feature = "alloc")]
+mod try_join_with_cleanup;
+#[cfg(feature = "alloc")]
+pub use self::try_join_with_cleanup::{try_join_with_cleanup, TryJoinWithCleanup};
+
 mod try_select;
 pub use self::try_select::{try_select, TrySelect};
 
 #[cfg(feature = "alloc")]
 mod select_ok;
 #[cfg(feature = "alloc")]
-pub use self::select_ok::{select_ok, SelectOk};
+pub use self::select_ok::{select_ok, select_ok_all_errors, SelectOk, SelectOkAllErrors};
 
 mod either;
 pub use self::either::Either;
@@ -113,8 +167,14 @@ pub use self::either::Either;
 #[cfg(feature = "alloc")]
 mod abortable;
 #[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "std")]
+pub use crate::abortable::AbortSet;
+#[cfg(not(futures_no_atomic_cas))]
 #[cfg(feature = "alloc")]
-pub use crate::abortable::{AbortHandle, AbortRegistration, Abortable, Aborted};
+pub use crate::abortable::{
+    AbortHandle, AbortOnDropHandle, AbortRegistration, Abortable, AbortableWith, Aborted,
+    AbortedWith,
+};
 #[cfg(not(futures_no_atomic_cas))]
 #[cfg(feature = "alloc")]
 pub use abortable::abortable;
@@ -127,3 +187,12 @@ where
 {
     future
 }
+
+// Projects a pinned slice to pinned references to its elements.
+#[cfg(feature = "alloc")]
+pub(crate) fn iter_pin_mut<T>(slice: Pin<&mut [T]>) -> impl Iterator<Item = Pin<&mut T>> {
+    // Safety: `std` _could_ make this unsound if it were to decide Pin's
+    // invariants aren't required to transmit through slices. Otherwise this has
+    // the same safety as a normal field pin projection.
+    unsafe { slice.get_unchecked_mut() }.iter_mut().map(|t| unsafe { Pin::new_unchecked(t) })
+}
@@ -8,6 +8,11 @@ pin_project! {
     /// Future for the [`poll_immediate`](poll_immediate()) function.
     ///
     /// It will never return [Poll::Pending](core::task::Poll::Pending)
+    ///
+    /// When the inner future is pending, this resolves to `None` but keeps the
+    /// inner future, which is polled again the next time this is polled, until
+    /// it resolves to `Some`. Use it as a [`Stream`] instead to get the result
+    /// of each poll as a [`Poll`].
     #[derive(Debug, Clone)]
     #[must_use = "futures do nothing unless you `.await` or poll them"]
     pub struct PollImmediate<T> {
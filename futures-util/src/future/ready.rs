@@ -51,32 +51,44 @@ pub fn ready<T>(t: T) -> Ready<T> {
 
 /// Create a future that is immediately ready with a success value.
 ///
+/// This is a shorthand for `ready(Ok(t))`, and the returned future can be
+/// used with [`TryFutureExt`](super::TryFutureExt) directly.
+///
 /// # Examples
 ///
 /// ```
 /// # futures::executor::block_on(async {
-/// use futures::future;
+/// use futures::future::{self, TryFutureExt};
 ///
 /// let a = future::ok::<i32, i32>(1);
 /// assert_eq!(a.await, Ok(1));
+///
+/// let b = future::ok::<i32, i32>(1).and_then(|x| future::ok(x + 1));
+/// assert_eq!(b.await, Ok(2));
 /// # });
 /// ```
 pub fn ok<T, E>(t: T) -> Ready<Result<T, E>> {
-    Ready(Some(Ok(t)))
+    assert_future::<Result<T, E>, _>(Ready(Some(Ok(t))))
 }
 
 /// Create a future that is immediately ready with an error value.
 ///
+/// This is a shorthand for `ready(Err(err))`, and the returned future can be
+/// used with [`TryFutureExt`](super::TryFutureExt) directly.
+///
 /// # Examples
 ///
 /// ```
 /// # futures::executor::block_on(async {
-/// use futures::future;
+/// use futures::future::{self, TryFutureExt};
 ///
 /// let a = future::err::<i32, i32>(1);
 /// assert_eq!(a.await, Err(1));
+///
+/// let b = future::err::<i32, i32>(1).or_else(|e| future::ok::<i32, i32>(e * 10));
+/// assert_eq!(b.await, Ok(10));
 /// # });
 /// ```
 pub fn err<T, E>(err: E) -> Ready<Result<T, E>> {
-    Ready(Some(Err(err)))
+    assert_future::<Result<T, E>, _>(Ready(Some(Err(err))))
 }
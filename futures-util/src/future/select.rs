@@ -1,4 +1,5 @@
 use super::assert_future;
+use super::into_future_arg::IntoFutureArg;
 use crate::future::{Either, FutureExt};
 use core::pin::Pin;
 use futures_core::future::{FusedFuture, Future};
@@ -26,6 +27,10 @@ impl<A: Unpin, B: Unpin> Unpin for Select<A, B> {}
 /// output type you can use the `Either::factor_first` method to
 /// conveniently extract out the value at the end.
 ///
+/// With the unstable `into-future` feature, this accepts anything implementing
+/// [`IntoFuture`](core::future::IntoFuture), such as builders which can be
+/// `.await`ed directly, converting them with `into_future` first.
+///
 /// # Examples
 ///
 /// A simple example
@@ -81,14 +86,20 @@ impl<A: Unpin, B: Unpin> Unpin for Select<A, B> {}
 ///     })
 /// }
 /// ```
-pub fn select<A, B>(future1: A, future2: B) -> Select<A, B>
+pub fn select<A, B>(future1: A, future2: B) -> Select<A::Future, B::Future>
 where
-    A: Future + Unpin,
-    B: Future + Unpin,
+    A: IntoFutureArg,
+    A::Future: Unpin,
+    B: IntoFutureArg,
+    B::Future: Unpin,
 {
-    assert_future::<Either<(A::Output, B), (B::Output, A)>, _>(Select {
-        inner: Some((future1, future2)),
-    })
+    assert_future::<
+        Either<
+            (<A::Future as Future>::Output, B::Future),
+            (<B::Future as Future>::Output, A::Future),
+        >,
+        _,
+    >(Select { inner: Some((future1.into_future_arg(), future2.into_future_arg())) })
 }
 
 impl<A, B> Future for Select<A, B>
@@ -122,3 +133,74 @@ where
         self.inner.is_none()
     }
 }
+
+/// The output of the [`select_named`] function, telling which of the two
+/// futures completed first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SelectResult<A: Future, B: Future> {
+    /// The first future completed with this output. The second future is
+    /// returned along with it.
+    First(A::Output, B),
+    /// The second future completed with this output. The first future is
+    /// returned along with it.
+    Second(B::Output, A),
+}
+
+/// Future for the [`select_named`] function.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+#[derive(Debug)]
+pub struct SelectNamed<A, B> {
+    inner: Select<A, B>,
+}
+
+/// Waits for either one of two differently-typed futures to complete, like
+/// [`select()`], but resolves to a [`SelectResult`] rather than an
+/// [`Either`] of tuples.
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::future::{self, SelectResult};
+///
+/// let slow = future::pending::<&str>();
+/// let fast = future::ready(2);
+///
+/// match future::select_named(slow, fast).await {
+///     SelectResult::First(_, _) => unreachable!(),
+///     SelectResult::Second(value, _slow) => assert_eq!(value, 2),
+/// }
+/// # });
+/// ```
+pub fn select_named<A, B>(future1: A, future2: B) -> SelectNamed<A, B>
+where
+    A: Future + Unpin,
+    B: Future + Unpin,
+{
+    assert_future::<SelectResult<A, B>, _>(SelectNamed { inner: select(future1, future2) })
+}
+
+impl<A, B> Future for SelectNamed<A, B>
+where
+    A: Future + Unpin,
+    B: Future + Unpin,
+{
+    type Output = SelectResult<A, B>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.inner.poll_unpin(cx).map(|either| match either {
+            Either::Left((x, b)) => SelectResult::First(x, b),
+            Either::Right((x, a)) => SelectResult::Second(x, a),
+        })
+    }
+}
+
+impl<A, B> FusedFuture for SelectNamed<A, B>
+where
+    A: Future + Unpin,
+    B: Future + Unpin,
+{
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}
@@ -1,5 +1,7 @@
 use super::assert_future;
 use crate::future::FutureExt;
+#[cfg(not(futures_no_atomic_cas))]
+use crate::stream::{FuturesUnordered, StreamExt};
 use alloc::vec::Vec;
 use core::iter::FromIterator;
 use core::mem;
@@ -7,11 +9,51 @@ use core::pin::Pin;
 use futures_core::future::Future;
 use futures_core::task::{Context, Poll};
 
+#[cfg(not(futures_no_atomic_cas))]
+const SMALL: usize = 30;
+
 /// Future for the [`select_all`] function.
 #[derive(Debug)]
 #[must_use = "futures do nothing unless you `.await` or poll them"]
 pub struct SelectAll<Fut> {
-    inner: Vec<Fut>,
+    kind: SelectAllKind<Fut>,
+}
+
+#[derive(Debug)]
+enum SelectAllKind<Fut> {
+    Small {
+        inner: Vec<Fut>,
+    },
+    #[cfg(not(futures_no_atomic_cas))]
+    Big {
+        inner: FuturesUnordered<Indexed<Fut>>,
+    },
+}
+
+// A future along with its position in the list passed to `select_all`, so
+// that the remaining futures can be returned in their original order.
+#[cfg(not(futures_no_atomic_cas))]
+#[derive(Debug)]
+struct Indexed<Fut> {
+    index: usize,
+    future: Fut,
+}
+
+#[cfg(not(futures_no_atomic_cas))]
+impl<Fut: Future + Unpin> Future for Indexed<Fut> {
+    type Output = (Fut::Output, usize);
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let index = self.index;
+        self.future.poll_unpin(cx).map(|output| (output, index))
+    }
+}
+
+#[cfg(not(futures_no_atomic_cas))]
+fn into_ordered<Fut: Unpin>(futures: FuturesUnordered<Indexed<Fut>>) -> Vec<Fut> {
+    let mut futures: Vec<_> = futures.into_iter().collect();
+    futures.sort_unstable_by_key(|indexed| indexed.index);
+    futures.into_iter().map(|indexed| indexed.future).collect()
 }
 
 impl<Fut: Unpin> Unpin for SelectAll<Fut> {}
@@ -20,14 +62,19 @@ impl<Fut: Unpin> Unpin for SelectAll<Fut> {}
 ///
 /// The returned future will wait for any future within `iter` to be ready. Upon
 /// completion the item resolved will be returned, along with the index of the
-/// future that was ready and the list of all the remaining futures.
-///
-/// There are no guarantees provided on the order of the list with the remaining
-/// futures. They might be swapped around, reversed, or completely random.
+/// future that was ready and the list of all the remaining futures, in the
+/// order they were given in.
 ///
 /// This function is only available when the `std` or `alloc` feature of this
 /// library is activated, and it is activated by default.
 ///
+/// # See Also
+///
+/// If the number of futures is large, `select_all` switches to using
+/// [`FuturesUnordered`](crate::stream::FuturesUnordered) internally, so that
+/// only the futures which have been woken are polled again, rather than every
+/// future each time.
+///
 /// # Panics
 ///
 /// This function will panic if the iterator specified contains no items.
@@ -36,15 +83,34 @@ where
     I: IntoIterator,
     I::Item: Future + Unpin,
 {
-    let ret = SelectAll { inner: iter.into_iter().collect() };
-    assert!(!ret.inner.is_empty());
-    assert_future::<(<I::Item as Future>::Output, usize, Vec<I::Item>), _>(ret)
+    let inner: Vec<_> = iter.into_iter().collect();
+    assert!(!inner.is_empty());
+
+    #[cfg(futures_no_atomic_cas)]
+    let kind = SelectAllKind::Small { inner };
+    #[cfg(not(futures_no_atomic_cas))]
+    let kind = if inner.len() <= SMALL {
+        SelectAllKind::Small { inner }
+    } else {
+        SelectAllKind::Big {
+            inner: inner
+                .into_iter()
+                .enumerate()
+                .map(|(index, future)| Indexed { index, future })
+                .collect(),
+        }
+    };
+    assert_future::<(<I::Item as Future>::Output, usize, Vec<I::Item>), _>(SelectAll { kind })
 }
 
-impl<Fut> SelectAll<Fut> {
+impl<Fut: Unpin> SelectAll<Fut> {
     /// Consumes this combinator, returning the underlying futures.
     pub fn into_inner(self) -> Vec<Fut> {
-        self.inner
+        match self.kind {
+            SelectAllKind::Small { inner } => inner,
+            #[cfg(not(futures_no_atomic_cas))]
+            SelectAllKind::Big { inner } => into_ordered(inner),
+        }
     }
 }
 
@@ -52,17 +118,29 @@ impl<Fut: Future + Unpin> Future for SelectAll<Fut> {
     type Output = (Fut::Output, usize, Vec<Fut>);
 
     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        let item = self.inner.iter_mut().enumerate().find_map(|(i, f)| match f.poll_unpin(cx) {
-            Poll::Pending => None,
-            Poll::Ready(e) => Some((i, e)),
-        });
-        match item {
-            Some((idx, res)) => {
-                let _ = self.inner.swap_remove(idx);
-                let rest = mem::replace(&mut self.inner, Vec::new());
-                Poll::Ready((res, idx, rest))
+        match &mut self.kind {
+            SelectAllKind::Small { inner } => {
+                let item = inner.iter_mut().enumerate().find_map(|(i, f)| match f.poll_unpin(cx) {
+                    Poll::Pending => None,
+                    Poll::Ready(e) => Some((i, e)),
+                });
+                match item {
+                    Some((idx, res)) => {
+                        drop(inner.remove(idx));
+                        let rest = mem::replace(inner, Vec::new());
+                        Poll::Ready((res, idx, rest))
+                    }
+                    None => Poll::Pending,
+                }
             }
-            None => Poll::Pending,
+            #[cfg(not(futures_no_atomic_cas))]
+            SelectAllKind::Big { inner } => match inner.poll_next_unpin(cx) {
+                Poll::Ready(Some((res, idx))) => {
+                    let rest = into_ordered(mem::replace(inner, FuturesUnordered::new()));
+                    Poll::Ready((res, idx, rest))
+                }
+                Poll::Ready(None) | Poll::Pending => Poll::Pending,
+            },
         }
     }
 }
@@ -1,6 +1,7 @@
 use super::assert_future;
 use crate::future::TryFutureExt;
 use alloc::vec::Vec;
+use core::fmt;
 use core::iter::FromIterator;
 use core::mem;
 use core::pin::Pin;
@@ -21,7 +22,8 @@ impl<Fut: Unpin> Unpin for SelectOk<Fut> {}
 /// The returned future will wait for any future within `iter` to be ready and Ok. Unlike
 /// `select_all`, this will only return the first successful completion, or the last
 /// failure. This is useful in contexts where any success is desired and failures
-/// are ignored, unless all the futures fail.
+/// are ignored, unless all the futures fail. See [`select_ok_all_errors`] for
+/// getting the errors of all the futures in that case.
 ///
 ///  This function is only available when the `std` or `alloc` feature of this
 /// library is activated, and it is activated by default.
@@ -83,3 +85,115 @@ impl<Fut: TryFuture + Unpin> FromIterator<Fut> for SelectOk<Fut> {
         select_ok(iter)
     }
 }
+
+/// Future for the [`select_ok_all_errors`] function.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct SelectOkAllErrors<Fut: TryFuture> {
+    inner: Vec<(usize, Fut)>,
+    errors: Vec<Option<Fut::Error>>,
+}
+
+impl<Fut: TryFuture + Unpin> Unpin for SelectOkAllErrors<Fut> {}
+
+impl<Fut> fmt::Debug for SelectOkAllErrors<Fut>
+where
+    Fut: TryFuture + fmt::Debug,
+    Fut::Error: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SelectOkAllErrors")
+            .field("inner", &self.inner)
+            .field("errors", &self.errors)
+            .finish()
+    }
+}
+
+/// Creates a new future which will select the first successful future over a
+/// list of futures, or return the errors of all of them.
+///
+/// This works like [`select_ok`], except that if all the futures fail, the
+/// error of each of them is returned, in the order of `iter`, instead of only
+/// the last one.
+///
+/// This function is only available when the `std` or `alloc` feature of this
+/// library is activated, and it is activated by default.
+///
+/// # Panics
+///
+/// This function will panic if the iterator specified contains no items.
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::future::{self, select_ok_all_errors};
+///
+/// let mirrors = vec![
+///     future::err::<(), _>("timed out"),
+///     future::err("not found"),
+///     future::err("connection refused"),
+/// ];
+/// let errors = select_ok_all_errors(mirrors).await.unwrap_err();
+/// assert_eq!(errors, ["timed out", "not found", "connection refused"]);
+/// # });
+/// ```
+pub fn select_ok_all_errors<I>(iter: I) -> SelectOkAllErrors<I::Item>
+where
+    I: IntoIterator,
+    I::Item: TryFuture + Unpin,
+{
+    let inner: Vec<_> = iter.into_iter().enumerate().collect();
+    assert!(!inner.is_empty(), "iterator provided to select_ok_all_errors was empty");
+    let errors = inner.iter().map(|_| None).collect();
+    assert_future::<
+        Result<(<I::Item as TryFuture>::Ok, Vec<I::Item>), Vec<<I::Item as TryFuture>::Error>>,
+        _,
+    >(SelectOkAllErrors { inner, errors })
+}
+
+impl<Fut: TryFuture + Unpin> Future for SelectOkAllErrors<Fut> {
+    type Output = Result<(Fut::Ok, Vec<Fut>), Vec<Fut::Error>>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        loop {
+            let item = this.inner.iter_mut().enumerate().find_map(|(i, (_, f))| {
+                match f.try_poll_unpin(cx) {
+                    Poll::Pending => None,
+                    Poll::Ready(e) => Some((i, e)),
+                }
+            });
+            match item {
+                Some((idx, res)) => {
+                    let (pos, _) = this.inner.remove(idx);
+                    match res {
+                        Ok(e) => {
+                            let rest = mem::replace(&mut this.inner, Vec::new());
+                            return Poll::Ready(Ok((
+                                e,
+                                rest.into_iter().map(|(_, f)| f).collect(),
+                            )));
+                        }
+                        Err(e) => {
+                            this.errors[pos] = Some(e);
+                            if this.inner.is_empty() {
+                                let errors = mem::replace(&mut this.errors, Vec::new());
+                                return Poll::Ready(Err(errors
+                                    .into_iter()
+                                    .map(|e| e.expect("every future has failed"))
+                                    .collect()));
+                            }
+                        }
+                    }
+                }
+                None => return Poll::Pending,
+            }
+        }
+    }
+}
+
+impl<Fut: TryFuture + Unpin> FromIterator<Fut> for SelectOkAllErrors<Fut> {
+    fn from_iter<T: IntoIterator<Item = Fut>>(iter: T) -> Self {
+        select_ok_all_errors(iter)
+    }
+}
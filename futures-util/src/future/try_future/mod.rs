@@ -7,7 +7,7 @@
 use crate::compat::Compat;
 use core::pin::Pin;
 use futures_core::{
-    future::TryFuture,
+    future::{Future, TryFuture},
     stream::TryStream,
     task::{Context, Poll},
 };
@@ -24,6 +24,7 @@ use crate::stream::assert_stream;
 
 // Combinators
 mod into_future;
+mod retry;
 mod try_flatten;
 mod try_flatten_err;
 
@@ -102,6 +103,7 @@ delegate_all!(
 
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
 pub use self::into_future::IntoFuture;
+pub use self::retry::Retry;
 
 delegate_all!(
     /// Future for the [`map_ok`](TryFutureExt::map_ok) method.
@@ -569,6 +571,52 @@ pub trait TryFutureExt: TryFuture {
         assert_future::<Self::Ok, _>(UnwrapOrElse::new(self, f))
     }
 
+    /// Retries this future with the futures created by `factory` until one of
+    /// them succeeds, or `policy` gives up.
+    ///
+    /// Whenever the current attempt resolves to an error, `policy` is called
+    /// with the error and the number of attempts which have failed so far,
+    /// starting at 1. If it returns `Some` future, that future is awaited,
+    /// then `factory` is called to create the next attempt. If it returns
+    /// `None`, the returned future resolves to that error.
+    ///
+    /// As waiting between attempts is left to the future returned by
+    /// `policy`, this works with any timer. Return a
+    /// [`ready`](crate::future::ready) future to retry immediately.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::future::{self, TryFutureExt};
+    ///
+    /// # futures::executor::block_on(async {
+    /// let mut attempt = 0;
+    /// let mut connect = move || {
+    ///     attempt += 1;
+    ///     future::ready(if attempt < 3 { Err(attempt) } else { Ok(attempt) })
+    /// };
+    ///
+    /// let future = connect().retry(connect, |_, attempts| {
+    ///     // This could wait for 100 ms, 200 ms, 400 ms, ...
+    ///     if attempts < 5 {
+    ///         Some(future::ready(()))
+    ///     } else {
+    ///         None
+    ///     }
+    /// });
+    /// assert_eq!(future.await, Ok(3));
+    /// # });
+    /// ```
+    fn retry<F, P, D>(self, factory: F, policy: P) -> Retry<Self, F, P, D>
+    where
+        F: FnMut() -> Self,
+        P: FnMut(&Self::Error, usize) -> Option<D>,
+        D: Future<Output = ()>,
+        Self: Sized,
+    {
+        assert_future::<Result<Self::Ok, Self::Error>, _>(Retry::new(self, factory, policy))
+    }
+
     /// Wraps a [`TryFuture`] into a future compatible with libraries using
     /// futures 0.1 future definitions. Requires the `compat` feature to enable.
     #[cfg(feature = "compat")]
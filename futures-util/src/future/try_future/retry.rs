@@ -0,0 +1,90 @@
+use core::fmt;
+use core::pin::Pin;
+use futures_core::future::{FusedFuture, Future, TryFuture};
+use futures_core::ready;
+use futures_core::task::{Context, Poll};
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Future for the [`retry`](super::TryFutureExt::retry) method.
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct Retry<Fut, F, P, D> {
+        #[pin]
+        future: Option<Fut>,
+        #[pin]
+        delay: Option<D>,
+        factory: F,
+        policy: P,
+        attempts: usize,
+    }
+}
+
+impl<Fut, F, P, D> Retry<Fut, F, P, D> {
+    pub(super) fn new(future: Fut, factory: F, policy: P) -> Self {
+        Self { future: Some(future), delay: None, factory, policy, attempts: 0 }
+    }
+}
+
+impl<Fut, F, P, D> fmt::Debug for Retry<Fut, F, P, D>
+where
+    Fut: fmt::Debug,
+    D: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Retry")
+            .field("future", &self.future)
+            .field("delay", &self.delay)
+            .field("attempts", &self.attempts)
+            .finish()
+    }
+}
+
+impl<Fut, F, P, D> FusedFuture for Retry<Fut, F, P, D>
+where
+    Fut: TryFuture,
+    F: FnMut() -> Fut,
+    P: FnMut(&Fut::Error, usize) -> Option<D>,
+    D: Future<Output = ()>,
+{
+    fn is_terminated(&self) -> bool {
+        self.future.is_none() && self.delay.is_none()
+    }
+}
+
+impl<Fut, F, P, D> Future for Retry<Fut, F, P, D>
+where
+    Fut: TryFuture,
+    F: FnMut() -> Fut,
+    P: FnMut(&Fut::Error, usize) -> Option<D>,
+    D: Future<Output = ()>,
+{
+    type Output = Result<Fut::Ok, Fut::Error>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+
+        loop {
+            if let Some(delay) = this.delay.as_mut().as_pin_mut() {
+                ready!(delay.poll(cx));
+                this.delay.set(None);
+                this.future.set(Some((this.factory)()));
+            }
+
+            let future = this.future.as_mut().as_pin_mut().expect("Retry polled after completion");
+            let error = match ready!(future.try_poll(cx)) {
+                Ok(output) => {
+                    this.future.set(None);
+                    return Poll::Ready(Ok(output));
+                }
+                Err(error) => error,
+            };
+            this.future.set(None);
+
+            *this.attempts += 1;
+            match (this.policy)(&error, *this.attempts) {
+                Some(delay) => this.delay.set(Some(delay)),
+                None => return Poll::Ready(Err(error)),
+            }
+        }
+    }
+}
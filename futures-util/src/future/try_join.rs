@@ -1,3 +1,4 @@
+use super::into_future_arg::IntoFutureArg;
 use crate::future::{assert_future, try_maybe_done, TryMaybeDone};
 use core::fmt;
 use core::pin::Pin;
@@ -70,6 +71,10 @@ where
 /// Note that this function consumes the passed futures and returns a
 /// wrapped version of it.
 ///
+/// With the unstable `into-future` feature, this accepts anything implementing
+/// [`IntoFuture`](core::future::IntoFuture), such as builders which can be
+/// `.await`ed directly, converting them with `into_future` first.
+///
 /// # Examples
 ///
 /// When used on multiple futures that return [`Ok`], `try_join` will return
@@ -101,10 +106,18 @@ where
 /// assert_eq!(pair.await, Err(2));
 /// # });
 /// ```
-pub fn try_join<Fut1, Fut2>(future1: Fut1, future2: Fut2) -> TryJoin<Fut1, Fut2>
+pub fn try_join<Fut1, Fut2>(future1: Fut1, future2: Fut2) -> TryJoin<Fut1::Future, Fut2::Future>
 where
-    Fut1: TryFuture,
-    Fut2: TryFuture<Error = Fut1::Error>,
+    Fut1: IntoFutureArg,
+    Fut1::Future: TryFuture,
+    Fut2: IntoFutureArg,
+    Fut2::Future: TryFuture<Error = <Fut1::Future as TryFuture>::Error>,
 {
-    assert_future::<Result<(Fut1::Ok, Fut2::Ok), Fut1::Error>, _>(TryJoin::new(future1, future2))
+    assert_future::<
+        Result<
+            (<Fut1::Future as TryFuture>::Ok, <Fut2::Future as TryFuture>::Ok),
+            <Fut1::Future as TryFuture>::Error,
+        >,
+        _,
+    >(TryJoin::new(future1.into_future_arg(), future2.into_future_arg()))
 }
@@ -0,0 +1,118 @@
+//! Definition of the `TryJoinAllPartial` combinator, waiting for all of a list
+//! of futures to finish with either success or error, keeping the successful
+//! outputs in the error case.
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::fmt;
+use core::future::Future;
+use core::iter::FromIterator;
+use core::mem;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use super::{assert_future, iter_pin_mut, TryFuture, TryMaybeDone};
+
+/// Future for the [`try_join_all_partial`] function.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct TryJoinAllPartial<F>
+where
+    F: TryFuture,
+{
+    elems: Pin<Box<[TryMaybeDone<F>]>>,
+}
+
+impl<F> fmt::Debug for TryJoinAllPartial<F>
+where
+    F: TryFuture + fmt::Debug,
+    F::Ok: fmt::Debug,
+    F::Error: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TryJoinAllPartial").field("elems", &self.elems).finish()
+    }
+}
+
+/// Creates a future which represents either a collection of the results of the
+/// futures given or an error, along with the results which were available.
+///
+/// This works like [`try_join_all`](super::try_join_all), except that if any
+/// future returns an error, the outputs of the futures which have completed
+/// successfully by then are returned along with the error, in the same order as
+/// the futures were provided. The slots of the future which failed and of
+/// those which were canceled are [`None`]. This allows releasing whatever
+/// those futures acquired.
+///
+/// This function is only available when the `std` or `alloc` feature of this
+/// library is activated, and it is activated by default.
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::future::{self, try_join_all_partial, FutureExt};
+///
+/// let futures = vec![
+///     future::ok::<u32, u32>(1).boxed(),
+///     future::pending().boxed(),
+///     future::err::<u32, u32>(3).boxed(),
+///     future::ok::<u32, u32>(4).boxed(),
+/// ];
+///
+/// assert_eq!(try_join_all_partial(futures).await, Err((3, vec![Some(1), None, None, None])));
+/// # });
+/// ```
+pub fn try_join_all_partial<I>(i: I) -> TryJoinAllPartial<I::Item>
+where
+    I: IntoIterator,
+    I::Item: TryFuture,
+{
+    let elems: Box<[_]> = i.into_iter().map(TryMaybeDone::Future).collect();
+    assert_future::<
+        Result<
+            Vec<<I::Item as TryFuture>::Ok>,
+            (<I::Item as TryFuture>::Error, Vec<Option<<I::Item as TryFuture>::Ok>>),
+        >,
+        _,
+    >(TryJoinAllPartial { elems: elems.into() })
+}
+
+impl<F> Future for TryJoinAllPartial<F>
+where
+    F: TryFuture,
+{
+    type Output = Result<Vec<F::Ok>, (F::Error, Vec<Option<F::Ok>>)>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut pending = false;
+        let mut error = None;
+
+        for elem in iter_pin_mut(self.elems.as_mut()) {
+            match elem.try_poll(cx) {
+                Poll::Pending => pending = true,
+                Poll::Ready(Ok(())) => {}
+                Poll::Ready(Err(e)) => {
+                    error = Some(e);
+                    break;
+                }
+            }
+        }
+
+        let mut elems = match (error.is_some(), pending) {
+            (false, true) => return Poll::Pending,
+            _ => mem::replace(&mut self.elems, Box::pin([])),
+        };
+        let outputs = iter_pin_mut(elems.as_mut()).map(|e| e.take_output());
+        Poll::Ready(match error {
+            // The remaining futures are canceled when `elems` is dropped.
+            Some(e) => Err((e, outputs.collect())),
+            None => Ok(outputs.map(Option::unwrap).collect()),
+        })
+    }
+}
+
+impl<F: TryFuture> FromIterator<F> for TryJoinAllPartial<F> {
+    fn from_iter<T: IntoIterator<Item = F>>(iter: T) -> Self {
+        try_join_all_partial(iter)
+    }
+}
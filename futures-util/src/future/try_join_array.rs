@@ -0,0 +1,120 @@
+//! Definition of the `TryJoinArray` combinator, waiting for all of an array
+//! of futures to complete or for one of them to error.
+
+// The `join-array` feature requires a newer Rust version than the rest of the
+// crate.
+#![allow(clippy::incompatible_msrv)]
+
+use super::{assert_future, try_maybe_done, TryMaybeDone};
+use core::fmt;
+use core::pin::Pin;
+use futures_core::future::{FusedFuture, Future, TryFuture};
+use futures_core::task::{Context, Poll};
+
+/// Future for the [`try_join_array`] function.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct TryJoinArray<Fut: TryFuture, const N: usize> {
+    elems: [TryMaybeDone<Fut>; N],
+    terminated: bool,
+}
+
+impl<Fut, const N: usize> fmt::Debug for TryJoinArray<Fut, N>
+where
+    Fut: TryFuture + fmt::Debug,
+    Fut::Ok: fmt::Debug,
+    Fut::Error: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TryJoinArray").field("elems", &self.elems).finish()
+    }
+}
+
+/// Creates a future which represents either an array of the results of the
+/// futures given or an error.
+///
+/// The returned future will drive execution for all of its underlying
+/// futures, collecting the results into an array of the same length and in
+/// the same order as they were provided.
+///
+/// If any future returns an error then all other futures will be dropped
+/// and the error will be returned immediately. If all futures complete
+/// successfully, however, then the returned future will succeed with the
+/// array of all the successful results.
+///
+/// Like [`join_array`](super::join_array()), this doesn't allocate.
+///
+/// This function is only available when the `join-array` feature of this
+/// library is activated, which requires the `unstable` feature and Rust 1.55
+/// or later.
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::future::{self, try_join_array};
+///
+/// let futures = [future::ok::<u32, u32>(1), future::ok(2), future::ok(3)];
+/// assert_eq!(try_join_array(futures).await, Ok([1, 2, 3]));
+///
+/// let futures = [future::ok::<u32, u32>(1), future::err(2), future::ok(3)];
+/// assert_eq!(try_join_array(futures).await, Err(2));
+/// # });
+/// ```
+pub fn try_join_array<Fut, const N: usize>(futures: [Fut; N]) -> TryJoinArray<Fut, N>
+where
+    Fut: TryFuture,
+{
+    assert_future::<Result<[Fut::Ok; N], Fut::Error>, _>(TryJoinArray {
+        elems: futures.map(try_maybe_done),
+        terminated: false,
+    })
+}
+
+impl<Fut: TryFuture, const N: usize> Future for TryJoinArray<Fut, N> {
+    type Output = Result<[Fut::Ok; N], Fut::Error>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // Safety: the elements are never moved, as they are only accessed
+        // through pinned references to them.
+        let this = unsafe { self.get_unchecked_mut() };
+        let mut all_done = true;
+        let mut error = None;
+        for elem in this.elems.iter_mut() {
+            match unsafe { Pin::new_unchecked(elem) }.poll(cx) {
+                Poll::Pending => all_done = false,
+                Poll::Ready(Ok(())) => {}
+                Poll::Ready(Err(e)) => {
+                    error = Some(e);
+                    break;
+                }
+            }
+        }
+
+        if let Some(e) = error {
+            // Drop the other futures right away.
+            for elem in this.elems.iter_mut() {
+                unsafe { Pin::new_unchecked(elem) }.set(TryMaybeDone::Gone);
+            }
+            this.terminated = true;
+            return Poll::Ready(Err(e));
+        }
+
+        if all_done {
+            let mut elems = this.elems.iter_mut();
+            let output = [(); N].map(|()| {
+                let elem = unsafe { Pin::new_unchecked(elems.next().unwrap()) };
+                elem.take_output().unwrap()
+            });
+            this.terminated = true;
+            Poll::Ready(Ok(output))
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl<Fut: TryFuture, const N: usize> FusedFuture for TryJoinArray<Fut, N> {
+    fn is_terminated(&self) -> bool {
+        self.terminated
+    }
+}
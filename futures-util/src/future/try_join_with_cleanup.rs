@@ -0,0 +1,191 @@
+//! Definition of the `TryJoinWithCleanup` combinator, waiting for all of a
+//! list of futures to finish with either success or error, and giving the
+//! unfinished ones a chance to clean up on error.
+
+use alloc::vec::Vec;
+use core::fmt;
+use core::future::Future;
+use core::mem;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use futures_core::future::{FusedFuture, TryFuture};
+use futures_core::ready;
+
+use super::{assert_future, join_all, JoinAll, TryMaybeDone};
+
+/// Future for the [`try_join_with_cleanup`] function.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct TryJoinWithCleanup<Fut, F, C>
+where
+    Fut: TryFuture,
+    C: Future,
+{
+    state: State<Fut, F, C>,
+}
+
+enum State<Fut, F, C>
+where
+    Fut: TryFuture,
+    C: Future,
+{
+    Joining { elems: Vec<TryMaybeDone<Fut>>, cleanup: F },
+    CleaningUp { cleanups: JoinAll<C>, error: Option<Fut::Error> },
+    Done,
+}
+
+impl<Fut, F, C> Unpin for TryJoinWithCleanup<Fut, F, C>
+where
+    Fut: TryFuture + Unpin,
+    C: Future,
+{
+}
+
+impl<Fut, F, C> fmt::Debug for TryJoinWithCleanup<Fut, F, C>
+where
+    Fut: TryFuture + fmt::Debug,
+    Fut::Ok: fmt::Debug,
+    Fut::Error: fmt::Debug,
+    C: Future + fmt::Debug,
+    C::Output: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.state {
+            State::Joining { elems, .. } => {
+                f.debug_struct("TryJoinWithCleanup").field("elems", elems).finish()
+            }
+            State::CleaningUp { cleanups, error } => f
+                .debug_struct("TryJoinWithCleanup")
+                .field("cleanups", cleanups)
+                .field("error", error)
+                .finish(),
+            State::Done => f.debug_struct("TryJoinWithCleanup").finish(),
+        }
+    }
+}
+
+/// Creates a future which represents either a collection of the results of the
+/// futures given or an error, running `cleanup` over the unfinished futures
+/// before reporting the error.
+///
+/// This behaves like [`try_join_all`](super::try_join_all) until one of the
+/// futures returns an error. At that point, instead of dropping the futures
+/// that are still pending, each of them is passed to `cleanup`, in the order
+/// they were provided, and the resulting cleanup futures are driven to
+/// completion concurrently. Only once all of them have finished does the
+/// returned future resolve to the error. Outputs of futures which already
+/// completed successfully are dropped.
+///
+/// The futures have to be [`Unpin`] so they can be handed to `cleanup` by
+/// value; use [`Box::pin`] for futures that aren't.
+///
+/// This function is only available when the `std` or `alloc` feature of this
+/// library is activated, and it is activated by default.
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::future::{self, try_join_with_cleanup, FutureExt};
+///
+/// let futures = vec![
+///     future::ok::<u32, u32>(1).boxed(),
+///     future::err::<u32, u32>(2).boxed(),
+///     future::pending::<Result<u32, u32>>().boxed(),
+/// ];
+///
+/// let mut cleaned_up = 0;
+/// let result = try_join_with_cleanup(futures, |_fut| {
+///     cleaned_up += 1;
+///     async {}
+/// })
+/// .await;
+///
+/// assert_eq!(result, Err(2));
+/// assert_eq!(cleaned_up, 1);
+/// # });
+/// ```
+pub fn try_join_with_cleanup<I, F, C>(i: I, cleanup: F) -> TryJoinWithCleanup<I::Item, F, C>
+where
+    I: IntoIterator,
+    I::Item: TryFuture + Unpin,
+    F: FnMut(I::Item) -> C,
+    C: Future<Output = ()>,
+{
+    let elems = i.into_iter().map(TryMaybeDone::Future).collect();
+    assert_future::<Result<Vec<<I::Item as TryFuture>::Ok>, <I::Item as TryFuture>::Error>, _>(
+        TryJoinWithCleanup { state: State::Joining { elems, cleanup } },
+    )
+}
+
+impl<Fut, F, C> Future for TryJoinWithCleanup<Fut, F, C>
+where
+    Fut: TryFuture + Unpin,
+    F: FnMut(Fut) -> C,
+    C: Future<Output = ()>,
+{
+    type Output = Result<Vec<Fut::Ok>, Fut::Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        loop {
+            match &mut this.state {
+                State::Joining { elems, cleanup } => {
+                    let mut pending = false;
+                    let mut error = None;
+
+                    for elem in elems.iter_mut() {
+                        match Pin::new(elem).poll(cx) {
+                            Poll::Pending => pending = true,
+                            Poll::Ready(Ok(())) => {}
+                            Poll::Ready(Err(e)) => {
+                                error = Some(e);
+                                break;
+                            }
+                        }
+                    }
+
+                    if let Some(e) = error {
+                        let cleanups = join_all(elems.drain(..).filter_map(|elem| match elem {
+                            TryMaybeDone::Future(fut) => Some(cleanup(fut)),
+                            TryMaybeDone::Done(_) | TryMaybeDone::Gone => None,
+                        }));
+                        this.state = State::CleaningUp { cleanups, error: Some(e) };
+                    } else if pending {
+                        return Poll::Pending;
+                    } else {
+                        let results = mem::replace(elems, Vec::new())
+                            .into_iter()
+                            .map(|elem| match elem {
+                                TryMaybeDone::Done(output) => output,
+                                _ => unreachable!(),
+                            })
+                            .collect();
+                        this.state = State::Done;
+                        return Poll::Ready(Ok(results));
+                    }
+                }
+                State::CleaningUp { cleanups, error } => {
+                    ready!(Pin::new(cleanups).poll(cx));
+                    let e = error.take().unwrap();
+                    this.state = State::Done;
+                    return Poll::Ready(Err(e));
+                }
+                State::Done => panic!("TryJoinWithCleanup polled after completion"),
+            }
+        }
+    }
+}
+
+impl<Fut, F, C> FusedFuture for TryJoinWithCleanup<Fut, F, C>
+where
+    Fut: TryFuture + Unpin,
+    F: FnMut(Fut) -> C,
+    C: Future<Output = ()>,
+{
+    fn is_terminated(&self) -> bool {
+        match self.state {
+            State::Done => true,
+            State::Joining { .. } | State::CleaningUp { .. } => false,
+        }
+    }
+}
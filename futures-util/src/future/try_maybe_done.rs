@@ -59,10 +59,13 @@ impl<Fut: TryFuture> TryMaybeDone<Fut> {
             }
         }
     }
-}
 
-impl<Fut: TryFuture> FusedFuture for TryMaybeDone<Fut> {
-    fn is_terminated(&self) -> bool {
+    /// Returns whether the inner future has completed or returned an error,
+    /// whether or not its output has been taken since.
+    ///
+    /// Unlike most methods of `TryMaybeDone`, this doesn't require pinning.
+    #[inline]
+    pub fn is_done(&self) -> bool {
         match self {
             Self::Future(_) => false,
             Self::Done(_) | Self::Gone => true,
@@ -70,6 +73,28 @@ impl<Fut: TryFuture> FusedFuture for TryMaybeDone<Fut> {
     }
 }
 
+impl<Fut: TryFuture + Unpin> TryMaybeDone<Fut> {
+    /// A convenience for calling [`TryMaybeDone::output_mut`] on [`Unpin`] futures,
+    /// which doesn't require pinning.
+    #[inline]
+    pub fn output_mut_unpin(&mut self) -> Option<&mut Fut::Ok> {
+        Pin::new(self).output_mut()
+    }
+
+    /// A convenience for calling [`TryMaybeDone::take_output`] on [`Unpin`] futures,
+    /// which doesn't require pinning.
+    #[inline]
+    pub fn take_output_unpin(&mut self) -> Option<Fut::Ok> {
+        Pin::new(self).take_output()
+    }
+}
+
+impl<Fut: TryFuture> FusedFuture for TryMaybeDone<Fut> {
+    fn is_terminated(&self) -> bool {
+        self.is_done()
+    }
+}
+
 impl<Fut: TryFuture> Future for TryMaybeDone<Fut> {
     type Output = Result<(), Fut::Error>;
 
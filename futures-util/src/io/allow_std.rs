@@ -99,6 +99,9 @@ where
         Poll::Ready(Ok(try_with_interrupt!(self.0.write_vectored(bufs))))
     }
 
+    // `io::Write::is_write_vectored` is unstable, so whether `T` has efficient
+    // vectored writes can't be queried, and the default of `false` is kept.
+
     fn poll_flush(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<io::Result<()>> {
         try_with_interrupt!(self.0.flush());
         Poll::Ready(Ok(()))
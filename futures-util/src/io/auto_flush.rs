@@ -0,0 +1,153 @@
+use core::time::Duration;
+use futures_core::future::Future;
+use futures_core::ready;
+use futures_core::task::{Context, Poll};
+use futures_io::{AsyncWrite, IoSlice};
+use pin_project_lite::pin_project;
+use std::fmt;
+use std::io;
+use std::pin::Pin;
+
+pin_project! {
+    /// Writer for the [`auto_flush`](super::AsyncWriteExt::auto_flush) method.
+    #[must_use = "writers do nothing unless polled"]
+    pub struct AutoFlush<W, S, Fut> {
+        #[pin]
+        writer: W,
+        // Started once bytes are written after a flush, dropped by the next flush.
+        #[pin]
+        timer: Option<Fut>,
+        // Whether `timer` completed since the last flush.
+        expired: bool,
+        // The number of bytes written since the last flush.
+        unflushed: usize,
+        every_bytes: usize,
+        every: Duration,
+        sleep: S,
+    }
+}
+
+impl<W, S, Fut> fmt::Debug for AutoFlush<W, S, Fut>
+where
+    W: fmt::Debug,
+    Fut: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AutoFlush")
+            .field("writer", &self.writer)
+            .field("timer", &self.timer)
+            .field("unflushed", &self.unflushed)
+            .field("every_bytes", &self.every_bytes)
+            .field("every", &self.every)
+            .finish()
+    }
+}
+
+impl<W, S, Fut> AutoFlush<W, S, Fut>
+where
+    W: AsyncWrite,
+    S: FnMut(Duration) -> Fut,
+    Fut: Future<Output = ()>,
+{
+    pub(super) fn new(writer: W, every_bytes: usize, every: Duration, sleep: S) -> Self {
+        Self { writer, timer: None, expired: false, unflushed: 0, every_bytes, every, sleep }
+    }
+
+    // Returns whether either of the thresholds for a flush has been reached.
+    fn poll_due(self: Pin<&mut Self>, cx: &mut Context<'_>) -> bool {
+        let mut this = self.project();
+        if let Some(timer) = this.timer.as_mut().as_pin_mut() {
+            if timer.poll(cx).is_ready() {
+                this.timer.set(None);
+                *this.expired = true;
+            }
+        }
+        *this.expired || (*this.unflushed != 0 && *this.unflushed >= *this.every_bytes)
+    }
+
+    fn poll_flush_writer(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let mut this = self.project();
+        ready!(this.writer.poll_flush(cx))?;
+        this.timer.set(None);
+        *this.expired = false;
+        *this.unflushed = 0;
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_write_with(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        write: impl FnOnce(Pin<&mut W>, &mut Context<'_>) -> Poll<io::Result<usize>>,
+    ) -> Poll<io::Result<usize>> {
+        // Finish a flush which couldn't complete while writing before.
+        if self.as_mut().poll_due(cx) {
+            ready!(self.as_mut().poll_flush_writer(cx))?;
+        }
+
+        let mut this = self.as_mut().project();
+        let n = ready!(write(this.writer, cx))?;
+        *this.unflushed += n;
+        if n != 0 && this.timer.is_none() && !*this.expired {
+            this.timer.set(Some((this.sleep)(*this.every)));
+        }
+
+        if self.as_mut().poll_due(cx) {
+            // The bytes have been written already, so a flush which is pending
+            // or fails is left to the next call, which reports its error.
+            let _ = self.poll_flush_writer(cx);
+        }
+        Poll::Ready(Ok(n))
+    }
+
+    /// Flushes the writer once `every` has elapsed since the first of the
+    /// bytes which haven't been flushed yet was written.
+    ///
+    /// Writes only check the deadline when they happen, so this can be polled
+    /// alongside whatever produces the data to write, to flush any remaining
+    /// bytes in time while the writer is idle. It only registers for a wakeup
+    /// while some bytes haven't been flushed, so poll it again after writing.
+    pub fn poll_flush_due(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        if !self.as_mut().poll_due(cx) {
+            return Poll::Pending;
+        }
+        self.poll_flush_writer(cx)
+    }
+
+    delegate_access_inner!(writer, W, ());
+}
+
+impl<W, S, Fut> AsyncWrite for AutoFlush<W, S, Fut>
+where
+    W: AsyncWrite,
+    S: FnMut(Duration) -> Fut,
+    Fut: Future<Output = ()>,
+{
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        self.poll_write_with(cx, |writer, cx| writer.poll_write(cx, buf))
+    }
+
+    fn poll_write_vectored(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &[IoSlice<'_>],
+    ) -> Poll<io::Result<usize>> {
+        self.poll_write_with(cx, |writer, cx| writer.poll_write_vectored(cx, bufs))
+    }
+
+    fn is_write_vectored(&self) -> bool {
+        self.writer.is_write_vectored()
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.poll_flush_writer(cx)
+    }
+
+    fn poll_close(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        ready!(self.as_mut().poll_flush_writer(cx))?;
+        self.project().writer.poll_close(cx)
+    }
+}
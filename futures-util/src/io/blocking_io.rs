@@ -0,0 +1,311 @@
+use crate::future;
+use crate::park::park_on;
+use crate::stream::StreamExt;
+use crate::task::SpawnExt;
+use futures_channel::{mpsc, oneshot};
+use futures_core::future::Future;
+use futures_core::ready;
+use futures_core::task::{Context, Poll};
+use futures_io::{AsyncRead, AsyncSeek, AsyncWrite, SeekFrom};
+use futures_task::{Spawn, SpawnError};
+use std::io::{self, Read, Seek, Write};
+use std::pin::Pin;
+use std::{cmp, fmt, thread};
+
+// The maximum number of bytes handed to the thread in a single operation.
+const MAX_BUF: usize = 64 * 1024;
+
+/// A wrapper which allows blocking `std::io` types to be used as
+/// [`AsyncRead`], [`AsyncWrite`] and [`AsyncSeek`] without blocking the task
+/// using them.
+///
+/// Unlike [`AllowStdIo`](super::AllowStdIo), which performs each operation
+/// directly, `BlockingIo` moves the wrapped object to a dedicated thread and
+/// performs each operation there. The task is notified once an operation has
+/// completed, so it doesn't stall the executor while waiting for it.
+///
+/// Data is passed to and from the thread in a buffer which is reused for
+/// every operation. Writes complete as soon as the data has been handed to
+/// the thread; errors they encounter are returned by the next operation.
+/// Any written data is only guaranteed to have reached the wrapped object
+/// once [`poll_flush`](AsyncWrite::poll_flush) or
+/// [`poll_close`](AsyncWrite::poll_close) has completed.
+///
+/// Reads may read ahead into the buffer if the caller's buffer shrinks
+/// between polls. That data is accounted for when seeking, but discarded
+/// when writing, so seek before switching from reading to writing.
+///
+/// Dropping a `BlockingIo` doesn't wait for an operation that is still in
+/// progress. The thread finishes it in the background, then drops the
+/// wrapped object and exits.
+///
+/// Instead of a dedicated thread, the operations can also be run by a task
+/// spawned on an executor meant for blocking work, see
+/// [`with_spawner`](BlockingIo::with_spawner).
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt, BlockingIo, SeekFrom};
+///
+/// let mut file = BlockingIo::new(std::io::Cursor::new(Vec::new()));
+/// file.write_all(b"hello world").await?;
+/// file.seek(SeekFrom::Start(6)).await?;
+///
+/// let mut word = String::new();
+/// file.read_to_string(&mut word).await?;
+/// assert_eq!(word, "world");
+/// # Ok::<(), Box<dyn std::error::Error>>(()) }).unwrap();
+/// ```
+pub struct BlockingIo<T> {
+    jobs: mpsc::UnboundedSender<Job<T>>,
+    // `None` while an operation is in progress.
+    buf: Option<Vec<u8>>,
+    // Position of the data in `buf` which has been read ahead.
+    pos: usize,
+    in_flight: Option<(Op, oneshot::Receiver<Reply>)>,
+}
+
+impl<T> Unpin for BlockingIo<T> {}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum Op {
+    Read,
+    Write,
+    Flush,
+    // `requested` is the position the caller asked for, and `target` the one
+    // passed on to the wrapped object after accounting for read-ahead data.
+    Seek { requested: SeekFrom, target: SeekFrom },
+}
+
+type Reply = (Vec<u8>, io::Result<u64>);
+
+struct Job<T> {
+    op: Op,
+    run: fn(&mut T, &mut Vec<u8>, Op) -> io::Result<u64>,
+    buf: Vec<u8>,
+    reply: oneshot::Sender<Reply>,
+}
+
+impl<T: Send + 'static> BlockingIo<T> {
+    /// Creates a new `BlockingIo`, spawning the thread which `io` is moved to.
+    pub fn new(io: T) -> Self {
+        let (jobs, rx) = mpsc::unbounded();
+        thread::spawn(move || park_on(run_jobs(io, rx)));
+        Self::from_jobs(jobs)
+    }
+
+    /// Creates a new `BlockingIo` which moves `io` to a task spawned on
+    /// `spawner`, rather than to a dedicated thread.
+    ///
+    /// The operations block the thread polling that task, so `spawner`
+    /// should be dedicated to blocking work, like a separate thread pool.
+    pub fn with_spawner<S>(io: T, spawner: &S) -> Result<Self, SpawnError>
+    where
+        S: Spawn + ?Sized,
+    {
+        let (jobs, rx) = mpsc::unbounded();
+        spawner.spawn(run_jobs(io, rx))?;
+        Ok(Self::from_jobs(jobs))
+    }
+
+    fn from_jobs(jobs: mpsc::UnboundedSender<Job<T>>) -> Self {
+        Self { jobs, buf: Some(Vec::new()), pos: 0, in_flight: None }
+    }
+}
+
+// Runs every job sent to `jobs` on `io`, until the `BlockingIo` is dropped.
+fn run_jobs<T: Send + 'static>(
+    mut io: T,
+    jobs: mpsc::UnboundedReceiver<Job<T>>,
+) -> impl Future<Output = ()> + Send + Unpin {
+    jobs.for_each(move |mut job| {
+        let res = (job.run)(&mut io, &mut job.buf, job.op);
+        // The `BlockingIo` might be gone already.
+        drop(job.reply.send((job.buf, res)));
+        future::ready(())
+    })
+}
+
+impl<T> BlockingIo<T> {
+    fn submit(&mut self, op: Op, run: fn(&mut T, &mut Vec<u8>, Op) -> io::Result<u64>) {
+        let buf =
+            self.buf.take().expect("BlockingIo submitted an operation while one is in progress");
+        let (reply, rx) = oneshot::channel();
+        // If the thread is gone, the reply is canceled, which is reported
+        // when polling for it.
+        drop(self.jobs.unbounded_send(Job { op, run, buf, reply }));
+        self.in_flight = Some((op, rx));
+    }
+
+    // Waits for the operation in progress, if any, and returns it along with
+    // its result.
+    fn poll_complete(&mut self, cx: &mut Context<'_>) -> Poll<Option<(Op, io::Result<u64>)>> {
+        let (op, rx) = match &mut self.in_flight {
+            Some((op, rx)) => (*op, rx),
+            None => return Poll::Ready(None),
+        };
+        let (buf, res) = match ready!(Pin::new(rx).poll(cx)) {
+            Ok(reply) => reply,
+            Err(oneshot::Canceled) => (
+                Vec::new(),
+                Err(io::Error::new(io::ErrorKind::Other, "blocking I/O thread panicked")),
+            ),
+        };
+        self.in_flight = None;
+        self.buf = Some(buf);
+        self.pos = 0;
+        Poll::Ready(Some((op, res)))
+    }
+
+    // Waits for the operation in progress, if any, and then discards any data
+    // which has been read ahead.
+    fn poll_idle(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        if let Some((_, res)) = ready!(self.poll_complete(cx)) {
+            res?;
+        }
+        self.buf.as_mut().unwrap().clear();
+        self.pos = 0;
+        Poll::Ready(Ok(()))
+    }
+}
+
+fn run_read<T: Read>(io: &mut T, buf: &mut Vec<u8>, _: Op) -> io::Result<u64> {
+    loop {
+        match io.read(buf) {
+            Ok(n) => {
+                buf.truncate(n);
+                return Ok(n as u64);
+            }
+            Err(ref e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(e) => {
+                buf.clear();
+                return Err(e);
+            }
+        }
+    }
+}
+
+fn run_write<T: Write>(io: &mut T, buf: &mut Vec<u8>, _: Op) -> io::Result<u64> {
+    let res = io.write_all(buf).map(|()| buf.len() as u64);
+    buf.clear();
+    res
+}
+
+fn run_flush<T: Write>(io: &mut T, _: &mut Vec<u8>, _: Op) -> io::Result<u64> {
+    io.flush().map(|()| 0)
+}
+
+fn run_seek<T: Seek>(io: &mut T, _: &mut Vec<u8>, op: Op) -> io::Result<u64> {
+    match op {
+        Op::Seek { target, .. } => io.seek(target),
+        _ => unreachable!(),
+    }
+}
+
+impl<T: Read> AsyncRead for BlockingIo<T> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = &mut *self;
+        loop {
+            match ready!(this.poll_complete(cx)) {
+                Some((Op::Read, Ok(0))) => return Poll::Ready(Ok(0)),
+                Some((_, Err(e))) => return Poll::Ready(Err(e)),
+                Some(_) | None => {}
+            }
+
+            let data = this.buf.as_mut().unwrap();
+            if this.pos < data.len() {
+                let n = cmp::min(buf.len(), data.len() - this.pos);
+                buf[..n].copy_from_slice(&data[this.pos..this.pos + n]);
+                this.pos += n;
+                return Poll::Ready(Ok(n));
+            }
+            if buf.is_empty() {
+                return Poll::Ready(Ok(0));
+            }
+
+            data.clear();
+            data.resize(cmp::min(buf.len(), MAX_BUF), 0);
+            this.submit(Op::Read, run_read::<T>);
+        }
+    }
+}
+
+impl<T: Write> AsyncWrite for BlockingIo<T> {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = &mut *self;
+        ready!(this.poll_idle(cx))?;
+
+        let n = cmp::min(buf.len(), MAX_BUF);
+        this.buf.as_mut().unwrap().extend_from_slice(&buf[..n]);
+        this.submit(Op::Write, run_write::<T>);
+        Poll::Ready(Ok(n))
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this = &mut *self;
+        loop {
+            match ready!(this.poll_complete(cx)) {
+                // Writes wait for a flush in progress first, so one that was
+                // abandoned still covers everything written before this call.
+                Some((Op::Flush, res)) => return Poll::Ready(res.map(drop)),
+                Some((_, Err(e))) => return Poll::Ready(Err(e)),
+                Some(_) | None => this.submit(Op::Flush, run_flush::<T>),
+            }
+        }
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.poll_flush(cx)
+    }
+}
+
+impl<T: Seek> AsyncSeek for BlockingIo<T> {
+    fn poll_seek(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        pos: SeekFrom,
+    ) -> Poll<io::Result<u64>> {
+        let this = &mut *self;
+        loop {
+            match ready!(this.poll_complete(cx)) {
+                Some((Op::Seek { requested, .. }, res)) if requested == pos => {
+                    return Poll::Ready(res)
+                }
+                // The result of a seek which was abandoned before it
+                // completed, which this one overrides.
+                Some((Op::Seek { .. }, _)) => {}
+                Some((_, Err(e))) => return Poll::Ready(Err(e)),
+                Some(_) | None => {}
+            }
+
+            // The wrapped object is ahead of this one by the data which has
+            // been read ahead.
+            let data = this.buf.as_mut().unwrap();
+            let target = match pos {
+                SeekFrom::Current(n) => SeekFrom::Current(n - (data.len() - this.pos) as i64),
+                pos => pos,
+            };
+            data.clear();
+            this.pos = 0;
+            this.submit(Op::Seek { requested: pos, target }, run_seek::<T>);
+        }
+    }
+}
+
+impl<T> fmt::Debug for BlockingIo<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BlockingIo")
+            .field("in_flight", &self.in_flight.as_ref().map(|(op, _)| op))
+            .finish()
+    }
+}
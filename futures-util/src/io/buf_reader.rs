@@ -2,7 +2,7 @@ use super::DEFAULT_BUF_SIZE;
 use futures_core::future::Future;
 use futures_core::ready;
 use futures_core::task::{Context, Poll};
-use futures_io::{AsyncBufRead, AsyncRead, AsyncSeek, AsyncWrite, IoSliceMut, SeekFrom};
+use futures_io::{AsyncBufRead, AsyncRead, AsyncSeek, AsyncWrite, IoSliceMut, ReadBuf, SeekFrom};
 use pin_project_lite::pin_project;
 use std::io::{self, Read};
 use std::pin::Pin;
@@ -60,6 +60,81 @@ impl<R: AsyncRead> BufReader<R> {
         &self.buffer[self.pos..self.cap]
     }
 
+    /// Creates a future which fills the internal buffer until at least `n`
+    /// bytes are buffered or EOF is reached, and resolves to the first `n`
+    /// buffered bytes, or fewer at EOF, without consuming them.
+    ///
+    /// Subsequent reads return the peeked bytes again. This is useful to look
+    /// at the start of a stream before deciding how to handle it.
+    ///
+    /// The future resolves to an error of kind
+    /// [`InvalidInput`](io::ErrorKind::InvalidInput) if `n` is larger than
+    /// the capacity of the internal buffer, which is never grown.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::io::{AsyncReadExt, BufReader, Cursor};
+    /// use std::pin::Pin;
+    ///
+    /// let mut reader = BufReader::new(Cursor::new(b"\x16\x03\x01 hello"));
+    /// let is_tls = Pin::new(&mut reader).peek(2).await? == b"\x16\x03";
+    /// assert!(is_tls);
+    ///
+    /// let mut contents = Vec::new();
+    /// reader.read_to_end(&mut contents).await?;
+    /// assert_eq!(contents, b"\x16\x03\x01 hello");
+    /// # Ok::<(), Box<dyn std::error::Error>>(()) }).unwrap();
+    /// ```
+    pub fn peek(self: Pin<&mut Self>, n: usize) -> Peek<'_, R> {
+        Peek { inner: Some(self), n }
+    }
+
+    /// Attempts to fill the internal buffer until at least `n` bytes are
+    /// buffered or EOF is reached, and returns the first `n` buffered bytes,
+    /// or fewer at EOF, without consuming them.
+    ///
+    /// See [`peek`](BufReader::peek) for details.
+    pub fn poll_peek(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        n: usize,
+    ) -> Poll<io::Result<&[u8]>> {
+        ready!(self.as_mut().poll_fill_to(cx, n))?;
+        Poll::Ready(Ok(self.into_ref().get_ref().peeked(n)))
+    }
+
+    fn poll_fill_to(self: Pin<&mut Self>, cx: &mut Context<'_>, n: usize) -> Poll<io::Result<()>> {
+        if n > self.buffer.len() {
+            return Poll::Ready(Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "cannot peek more bytes than the capacity of the buffer",
+            )));
+        }
+
+        let mut this = self.project();
+        if *this.cap - *this.pos >= n {
+            return Poll::Ready(Ok(()));
+        }
+        // Move the buffered data to the front to make room for the rest.
+        this.buffer[..*this.cap].rotate_left(*this.pos);
+        *this.cap -= *this.pos;
+        *this.pos = 0;
+        while *this.cap < n {
+            let mut buf = ReadBuf::new(&mut this.buffer[*this.cap..]);
+            match ready!(super::poll_read_buf(this.inner.as_mut(), cx, &mut buf))? {
+                0 => break,
+                read => *this.cap += read,
+            }
+        }
+        Poll::Ready(Ok(()))
+    }
+
+    fn peeked(&self, n: usize) -> &[u8] {
+        &self.buffer[self.pos..cmp::min(self.cap, self.pos + n)]
+    }
+
     /// Invalidates all data in the internal buffer.
     #[inline]
     fn discard_buffer(self: Pin<&mut Self>) {
@@ -139,6 +214,23 @@ impl<R: AsyncRead> AsyncRead for BufReader<R> {
         self.consume(nread);
         Poll::Ready(Ok(nread))
     }
+
+    fn poll_read_buf(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        if self.pos == self.cap && buf.remaining() >= self.buffer.len() {
+            let res = ready!(self.as_mut().project().inner.poll_read_buf(cx, buf));
+            self.discard_buffer();
+            return Poll::Ready(res);
+        }
+        let rem = ready!(self.as_mut().poll_fill_buf(cx))?;
+        let nread = cmp::min(rem.len(), buf.remaining());
+        buf.put_slice(&rem[..nread]);
+        self.consume(nread);
+        Poll::Ready(Ok(()))
+    }
 }
 
 impl<R: AsyncRead> AsyncBufRead for BufReader<R> {
@@ -151,7 +243,8 @@ impl<R: AsyncRead> AsyncBufRead for BufReader<R> {
         // to tell the compiler that the pos..cap slice is always valid.
         if *this.pos >= *this.cap {
             debug_assert!(*this.pos == *this.cap);
-            *this.cap = ready!(this.inner.poll_read(cx, this.buffer))?;
+            let mut buf = ReadBuf::new(this.buffer);
+            *this.cap = ready!(super::poll_read_buf(this.inner, cx, &mut buf))?;
             *this.pos = 0;
         }
         Poll::Ready(Ok(&this.buffer[*this.pos..*this.cap]))
@@ -229,6 +322,31 @@ impl<R: AsyncRead + AsyncSeek> AsyncSeek for BufReader<R> {
     }
 }
 
+/// Future for the [`BufReader::peek`](self::BufReader::peek) method.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Peek<'a, R> {
+    inner: Option<Pin<&'a mut BufReader<R>>>,
+    n: usize,
+}
+
+impl<'a, R: AsyncRead> Future for Peek<'a, R> {
+    type Output = io::Result<&'a [u8]>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let n = self.n;
+        let mut inner = self.inner.take().expect("Polled Peek after completion");
+        match inner.as_mut().poll_fill_to(cx, n) {
+            Poll::Ready(Ok(())) => Poll::Ready(Ok(inner.into_ref().get_ref().peeked(n))),
+            Poll::Ready(Err(e)) => Poll::Ready(Err(e)),
+            Poll::Pending => {
+                self.inner = Some(inner);
+                Poll::Pending
+            }
+        }
+    }
+}
+
 /// Future for the [`BufReader::seek_relative`](self::BufReader::seek_relative) method.
 #[derive(Debug)]
 #[must_use = "futures do nothing unless polled"]
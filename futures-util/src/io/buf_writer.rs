@@ -1,13 +1,20 @@
 use super::DEFAULT_BUF_SIZE;
+use futures_core::future::Future;
 use futures_core::ready;
 use futures_core::task::{Context, Poll};
 use futures_io::{AsyncBufRead, AsyncRead, AsyncSeek, AsyncWrite, IoSlice, SeekFrom};
 use pin_project_lite::pin_project;
+use std::cmp;
+use std::error;
 use std::fmt;
 use std::io::{self, Write};
 use std::pin::Pin;
 use std::ptr;
 
+// The maximum number of slices passed to a single vectored write which
+// includes the buffered data.
+const MAX_WRITE_THROUGH_SLICES: usize = 16;
+
 pin_project! {
     /// Wraps a writer and buffers its output.
     ///
@@ -21,6 +28,11 @@ pin_project! {
     /// times. It also provides no advantage when writing to a destination that is
     /// in memory, like a `Vec<u8>`.
     ///
+    /// If the underlying writer has an efficient vectored write implementation,
+    /// as reported by [`AsyncWrite::is_write_vectored`], writes which are too
+    /// large for the buffer are not copied into it, but written together with
+    /// the buffered data using a single vectored write.
+    ///
     /// When the `BufWriter` is dropped, the contents of its buffer will be
     /// discarded. Creating multiple instances of a `BufWriter` on the same
     /// stream can cause data loss. If you need to write out the contents of its
@@ -85,6 +97,47 @@ impl<W: AsyncWrite> BufWriter<W> {
         &self.buf
     }
 
+    /// Consumes this `BufWriter`, returning the underlying writer and the
+    /// buffered data that has not been written to it yet.
+    ///
+    /// Unlike [`flush_into_inner`](BufWriter::flush_into_inner) this does
+    /// not attempt to write out the buffer, which allows recovering the
+    /// unwritten data, for example after a failed flush.
+    pub fn into_parts(self) -> (W, Vec<u8>) {
+        let mut buf = self.buf;
+        buf.drain(..self.written);
+        (self.inner, buf)
+    }
+
+    /// Consumes this `BufWriter`, returning a future which writes out the
+    /// buffered data and then resolves to the underlying writer.
+    ///
+    /// Only the internal buffer is written out; the underlying writer is not
+    /// flushed itself. If writing out the buffer fails, the future resolves to
+    /// an [`IntoInnerError`] holding both the error and this `BufWriter`, so
+    /// that no buffered data is lost.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::io::{AsyncWriteExt, BufWriter};
+    ///
+    /// let mut writer = BufWriter::new(Vec::new());
+    /// writer.write_all(b"hello").await?;
+    /// assert_eq!(writer.get_ref(), b"");
+    ///
+    /// let inner = writer.flush_into_inner().await?;
+    /// assert_eq!(inner, b"hello");
+    /// # Ok::<(), Box<dyn std::error::Error>>(()) }).unwrap();
+    /// ```
+    pub fn flush_into_inner(self) -> FlushIntoInner<W>
+    where
+        W: Unpin,
+    {
+        FlushIntoInner { writer: Some(self) }
+    }
+
     /// Capacity of `buf`. how many chars can be held in buffer
     pub(super) fn capacity(&self) -> usize {
         self.buf.capacity()
@@ -146,6 +199,50 @@ impl<W: AsyncWrite> BufWriter<W> {
     ) -> Poll<io::Result<usize>> {
         self.project().inner.poll_write_vectored(cx, bufs)
     }
+
+    /// Write `bufs` directly using `inner`, preceded by whatever is still
+    /// buffered, in as few vectored writes as possible.
+    ///
+    /// Only used if `inner` has an efficient vectored write implementation,
+    /// to avoid copying large writes into the buffer or flushing it with a
+    /// separate write first. Returns the number of bytes written from `bufs`.
+    fn write_through_vectored(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &[IoSlice<'_>],
+    ) -> Poll<io::Result<usize>> {
+        let mut this = self.project();
+        loop {
+            let buffered = this.buf.len() - *this.written;
+            if buffered == 0 {
+                this.buf.clear();
+                *this.written = 0;
+                return this.inner.poll_write_vectored(cx, bufs);
+            }
+
+            // A vectored write may write fewer slices than it's given anyway,
+            // so only the first few of `bufs` are passed along.
+            let mut slices = [IoSlice::new(&[]); MAX_WRITE_THROUGH_SLICES];
+            let n = cmp::min(bufs.len(), MAX_WRITE_THROUGH_SLICES - 1);
+            slices[0] = IoSlice::new(&this.buf[*this.written..]);
+            slices[1..=n].copy_from_slice(&bufs[..n]);
+            match ready!(this.inner.as_mut().poll_write_vectored(cx, &slices[..=n])) {
+                Ok(0) => {
+                    return Poll::Ready(Err(io::Error::new(
+                        io::ErrorKind::WriteZero,
+                        "failed to write the buffered data",
+                    )));
+                }
+                Ok(n) if n > buffered => {
+                    this.buf.clear();
+                    *this.written = 0;
+                    return Poll::Ready(Ok(n - buffered));
+                }
+                Ok(n) => *this.written += n,
+                Err(e) => return Poll::Ready(Err(e)),
+            }
+        }
+    }
 }
 
 impl<W: AsyncWrite> AsyncWrite for BufWriter<W> {
@@ -155,6 +252,9 @@ impl<W: AsyncWrite> AsyncWrite for BufWriter<W> {
         buf: &[u8],
     ) -> Poll<io::Result<usize>> {
         if self.buf.len() + buf.len() > self.buf.capacity() {
+            if buf.len() >= self.buf.capacity() && self.inner.is_write_vectored() {
+                return self.write_through_vectored(cx, &[IoSlice::new(buf)]);
+            }
             ready!(self.as_mut().flush_buf(cx))?;
         }
         if buf.len() >= self.buf.capacity() {
@@ -171,6 +271,9 @@ impl<W: AsyncWrite> AsyncWrite for BufWriter<W> {
     ) -> Poll<io::Result<usize>> {
         let total_len = bufs.iter().map(|b| b.len()).sum::<usize>();
         if self.buf.len() + total_len > self.buf.capacity() {
+            if total_len >= self.buf.capacity() && self.inner.is_write_vectored() {
+                return self.write_through_vectored(cx, bufs);
+            }
             ready!(self.as_mut().flush_buf(cx))?;
         }
         if total_len >= self.buf.capacity() {
@@ -180,6 +283,10 @@ impl<W: AsyncWrite> AsyncWrite for BufWriter<W> {
         }
     }
 
+    fn is_write_vectored(&self) -> bool {
+        true
+    }
+
     fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
         ready!(self.as_mut().flush_buf(cx))?;
         self.project().inner.poll_flush(cx)
@@ -222,3 +329,74 @@ impl<W: AsyncWrite + AsyncSeek> AsyncSeek for BufWriter<W> {
         self.project().inner.poll_seek(cx, pos)
     }
 }
+
+/// Future for the [`BufWriter::flush_into_inner`](self::BufWriter::flush_into_inner) method.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct FlushIntoInner<W> {
+    writer: Option<BufWriter<W>>,
+}
+
+impl<W: AsyncWrite + Unpin> Future for FlushIntoInner<W> {
+    type Output = Result<W, IntoInnerError<BufWriter<W>>>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let writer = self.writer.as_mut().expect("FlushIntoInner polled after completion");
+        let res = ready!(Pin::new(writer).flush_buf(cx));
+        let writer = self.writer.take().unwrap();
+        Poll::Ready(match res {
+            Ok(()) => Ok(writer.inner),
+            Err(e) => Err(IntoInnerError(writer, e)),
+        })
+    }
+}
+
+/// Error returned by [`BufWriter::flush_into_inner`] when writing out the
+/// buffered data fails.
+///
+/// It contains the error together with the writer, so that the buffered data
+/// that could not be written is not lost.
+pub struct IntoInnerError<W>(W, io::Error);
+
+impl<W> IntoInnerError<W> {
+    /// Returns the error which caused the call to `flush_into_inner` to fail.
+    pub fn error(&self) -> &io::Error {
+        &self.1
+    }
+
+    /// Returns the writer, which still holds the data that could not be
+    /// written out.
+    pub fn into_inner(self) -> W {
+        self.0
+    }
+
+    /// Consumes this error, returning the underlying error.
+    pub fn into_error(self) -> io::Error {
+        self.1
+    }
+
+    /// Consumes this error, returning both the writer and the error.
+    pub fn into_parts(self) -> (W, io::Error) {
+        (self.0, self.1)
+    }
+}
+
+impl<W> From<IntoInnerError<W>> for io::Error {
+    fn from(e: IntoInnerError<W>) -> Self {
+        e.1
+    }
+}
+
+impl<W> fmt::Debug for IntoInnerError<W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.1.fmt(f)
+    }
+}
+
+impl<W> fmt::Display for IntoInnerError<W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.1.fmt(f)
+    }
+}
+
+impl<W> error::Error for IntoInnerError<W> {}
@@ -0,0 +1,66 @@
+use super::DEFAULT_BUF_SIZE;
+use futures_core::ready;
+use futures_core::stream::Stream;
+use futures_core::task::{Context, Poll};
+use futures_io::AsyncRead;
+use pin_project_lite::pin_project;
+use std::io;
+use std::pin::Pin;
+
+pin_project! {
+    /// Stream for the [`bytes`](super::AsyncReadExt::bytes) method.
+    #[derive(Debug)]
+    #[must_use = "streams do nothing unless polled"]
+    pub struct Bytes<R> {
+        #[pin]
+        reader: R,
+        buf: Box<[u8]>,
+        pos: usize,
+        cap: usize,
+    }
+}
+
+impl<R: AsyncRead> Bytes<R> {
+    pub(super) fn new(reader: R) -> Self {
+        Self { reader, buf: vec![0; DEFAULT_BUF_SIZE].into_boxed_slice(), pos: 0, cap: 0 }
+    }
+
+    delegate_access_inner!(reader, R, ());
+
+    /// Returns a reference to the bytes which have been read from the reader,
+    /// but not yielded yet.
+    ///
+    /// These bytes are lost if the reader is taken out with `into_inner`.
+    pub fn buffer(&self) -> &[u8] {
+        &self.buf[self.pos..self.cap]
+    }
+}
+
+impl<R: AsyncRead> Stream for Bytes<R> {
+    type Item = io::Result<u8>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        if *this.pos == *this.cap {
+            let n = loop {
+                match ready!(this.reader.as_mut().poll_read(cx, this.buf)) {
+                    Ok(n) => break n,
+                    Err(ref e) if e.kind() == io::ErrorKind::Interrupted => {}
+                    Err(e) => return Poll::Ready(Some(Err(e))),
+                }
+            };
+            if n == 0 {
+                return Poll::Ready(None);
+            }
+            *this.pos = 0;
+            *this.cap = n;
+        }
+        let byte = this.buf[*this.pos];
+        *this.pos += 1;
+        Poll::Ready(Some(Ok(byte)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.cap - self.pos, None)
+    }
+}
@@ -0,0 +1,132 @@
+use futures_core::ready;
+use futures_core::task::{Context, Poll};
+use futures_io::{AsyncBufRead, AsyncRead, IoSliceMut};
+use std::collections::VecDeque;
+use std::io;
+use std::pin::Pin;
+
+/// Reader for the [`chain_all()`] function.
+#[derive(Debug)]
+#[must_use = "readers do nothing unless polled"]
+pub struct ChainAll<R> {
+    readers: VecDeque<R>,
+}
+
+/// Creates a reader which reads from each of the given readers in turn.
+///
+/// The returned reader will read all data from the first reader until it
+/// reaches EOF, then from the second one, and so on. It only reports EOF once
+/// the last reader has. Each reader is dropped as soon as it has reached EOF.
+///
+/// Unlike nesting [`chain`](super::AsyncReadExt::chain) calls, this works
+/// for any number of readers of the same type, and more readers can be
+/// appended while reading using [`ChainAll::push`].
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::io::{self, AsyncReadExt, Cursor};
+///
+/// let chunks = vec![Cursor::new(&b"hello "[..]), Cursor::new(&b"world"[..]), Cursor::new(&b"!"[..])];
+/// let mut reader = io::chain_all(chunks);
+///
+/// let mut buffer = String::new();
+/// reader.read_to_string(&mut buffer).await?;
+/// assert_eq!(buffer, "hello world!");
+/// # Ok::<(), Box<dyn std::error::Error>>(()) }).unwrap();
+/// ```
+pub fn chain_all<I>(readers: I) -> ChainAll<I::Item>
+where
+    I: IntoIterator,
+    I::Item: AsyncRead + Unpin,
+{
+    ChainAll { readers: readers.into_iter().collect() }
+}
+
+impl<R: AsyncRead + Unpin> ChainAll<R> {
+    /// Appends a reader, which will be read from after all the readers which
+    /// are already part of this `ChainAll`.
+    ///
+    /// If all previous readers have already reached EOF, the next read will
+    /// be from `reader`.
+    pub fn push(&mut self, reader: R) {
+        self.readers.push_back(reader);
+    }
+
+    /// Returns the number of readers which haven't reached EOF yet.
+    pub fn len(&self) -> usize {
+        self.readers.len()
+    }
+
+    /// Returns `true` if all readers have reached EOF.
+    pub fn is_empty(&self) -> bool {
+        self.readers.is_empty()
+    }
+
+    /// Consumes the `ChainAll`, returning the readers which haven't reached
+    /// EOF yet.
+    pub fn into_inner(self) -> VecDeque<R> {
+        self.readers
+    }
+}
+
+impl<R: AsyncRead + Unpin> AsyncRead for ChainAll<R> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        while let Some(reader) = self.readers.front_mut() {
+            match ready!(Pin::new(reader).poll_read(cx, buf)?) {
+                0 if !buf.is_empty() => {
+                    self.readers.pop_front();
+                }
+                n => return Poll::Ready(Ok(n)),
+            }
+        }
+        Poll::Ready(Ok(0))
+    }
+
+    fn poll_read_vectored(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &mut [IoSliceMut<'_>],
+    ) -> Poll<io::Result<usize>> {
+        while let Some(reader) = self.readers.front_mut() {
+            let n = ready!(Pin::new(reader).poll_read_vectored(cx, bufs)?);
+            if n == 0 && bufs.iter().any(|b| !b.is_empty()) {
+                self.readers.pop_front();
+            } else {
+                return Poll::Ready(Ok(n));
+            }
+        }
+        Poll::Ready(Ok(0))
+    }
+}
+
+impl<R: AsyncBufRead + Unpin> AsyncBufRead for ChainAll<R> {
+    fn poll_fill_buf(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<&[u8]>> {
+        let this = self.get_mut();
+
+        // Drop readers which have reached EOF first, as the buffer can't be
+        // returned from within the loop.
+        while let Some(reader) = this.readers.front_mut() {
+            if ready!(Pin::new(reader).poll_fill_buf(cx)?).is_empty() {
+                this.readers.pop_front();
+            } else {
+                break;
+            }
+        }
+        match this.readers.front_mut() {
+            Some(reader) => Pin::new(reader).poll_fill_buf(cx),
+            None => Poll::Ready(Ok(&[])),
+        }
+    }
+
+    fn consume(mut self: Pin<&mut Self>, amt: usize) {
+        if let Some(reader) = self.readers.front_mut() {
+            Pin::new(reader).consume(amt)
+        }
+    }
+}
@@ -49,6 +49,16 @@ pin_project! {
     }
 }
 
+impl<R, W: ?Sized> Copy<'_, R, W> {
+    /// Makes the copy yield back to the task's executor once it has copied at
+    /// least `bytes` bytes within a single poll.
+    ///
+    /// See [`CopyBuf::with_yield_threshold`] for details.
+    pub fn with_yield_threshold(self, bytes: u64) -> Self {
+        Self { inner: self.inner.with_yield_threshold(bytes) }
+    }
+}
+
 impl<R: AsyncRead, W: AsyncWrite + Unpin + ?Sized> Future for Copy<'_, R, W> {
     type Output = io::Result<u64>;
 
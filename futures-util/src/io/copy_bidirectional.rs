@@ -0,0 +1,267 @@
+use super::DEFAULT_BUF_SIZE;
+use futures_core::future::Future;
+use futures_core::ready;
+use futures_core::task::{Context, Poll};
+use futures_io::{AsyncRead, AsyncWrite};
+use std::io;
+use std::pin::Pin;
+use std::{error, fmt};
+
+/// Creates a future which copies data in both directions between `a` and `b`.
+///
+/// Data read from `a` is written to `b`, and data read from `b` is written to
+/// `a`, concurrently. Once one direction reaches EOF, the writer it copies
+/// to is flushed and closed, while the other direction keeps going. The
+/// returned future completes once both directions have finished, with the
+/// number of bytes copied from `a` to `b` and from `b` to `a`.
+///
+/// If an error occurs in either direction, both are stopped and the future
+/// resolves to an error of the same [`kind`](io::Error::kind), which wraps a
+/// [`CopyBidirectionalError`] with the original error and the number of bytes
+/// copied so far.
+///
+/// # Examples
+///
+/// ```
+/// use futures::io::{self, AsyncRead, AsyncWrite};
+///
+/// async fn proxy<C, S>(client: &mut C, server: &mut S) -> io::Result<()>
+/// where
+///     C: AsyncRead + AsyncWrite + Unpin,
+///     S: AsyncRead + AsyncWrite + Unpin,
+/// {
+///     let (to_server, to_client) = io::copy_bidirectional(client, server).await?;
+///     println!("sent {} bytes and received {} bytes", to_server, to_client);
+///     Ok(())
+/// }
+/// ```
+pub fn copy_bidirectional<'a, A, B>(a: &'a mut A, b: &'a mut B) -> CopyBidirectional<'a, A, B>
+where
+    A: AsyncRead + AsyncWrite + Unpin + ?Sized,
+    B: AsyncRead + AsyncWrite + Unpin + ?Sized,
+{
+    CopyBidirectional {
+        a,
+        b,
+        a_to_b: TransferState::Running(CopyBuffer::new()),
+        b_to_a: TransferState::Running(CopyBuffer::new()),
+    }
+}
+
+/// Future for the [`copy_bidirectional()`] function.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct CopyBidirectional<'a, A: ?Sized, B: ?Sized> {
+    a: &'a mut A,
+    b: &'a mut B,
+    a_to_b: TransferState,
+    b_to_a: TransferState,
+}
+
+/// Error wrapped in the [`io::Error`] returned by [`copy_bidirectional`] if
+/// copying fails in either direction.
+///
+/// The `io::Error` has the same kind as the original error, and this error
+/// can be retrieved from it using [`io::Error::get_ref`] or
+/// [`io::Error::into_inner`].
+#[derive(Debug)]
+pub struct CopyBidirectionalError {
+    error: io::Error,
+    a_to_b: u64,
+    b_to_a: u64,
+}
+
+impl CopyBidirectionalError {
+    /// Returns the error which stopped the copy.
+    pub fn error(&self) -> &io::Error {
+        &self.error
+    }
+
+    /// Consumes this error, returning the error which stopped the copy.
+    pub fn into_error(self) -> io::Error {
+        self.error
+    }
+
+    /// Returns the number of bytes which were copied from `a` to `b` before
+    /// the copy stopped.
+    pub fn a_to_b(&self) -> u64 {
+        self.a_to_b
+    }
+
+    /// Returns the number of bytes which were copied from `b` to `a` before
+    /// the copy stopped.
+    pub fn b_to_a(&self) -> u64 {
+        self.b_to_a
+    }
+}
+
+impl fmt::Display for CopyBidirectionalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.error.fmt(f)
+    }
+}
+
+impl error::Error for CopyBidirectionalError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+#[derive(Debug)]
+enum TransferState {
+    Running(CopyBuffer),
+    ShuttingDown(u64),
+    Done(u64),
+}
+
+impl TransferState {
+    fn amt(&self) -> u64 {
+        match self {
+            TransferState::Running(buf) => buf.amt,
+            TransferState::ShuttingDown(amt) | TransferState::Done(amt) => *amt,
+        }
+    }
+
+    fn poll_transfer<R, W>(
+        &mut self,
+        cx: &mut Context<'_>,
+        mut reader: Pin<&mut R>,
+        mut writer: Pin<&mut W>,
+    ) -> Poll<io::Result<u64>>
+    where
+        R: AsyncRead + ?Sized,
+        W: AsyncWrite + ?Sized,
+    {
+        loop {
+            match self {
+                TransferState::Running(buf) => {
+                    let amt = ready!(buf.poll_copy(cx, reader.as_mut(), writer.as_mut()))?;
+                    *self = TransferState::ShuttingDown(amt);
+                }
+                TransferState::ShuttingDown(amt) => {
+                    ready!(writer.as_mut().poll_close(cx))?;
+                    *self = TransferState::Done(*amt);
+                }
+                TransferState::Done(amt) => return Poll::Ready(Ok(*amt)),
+            }
+        }
+    }
+}
+
+struct CopyBuffer {
+    buf: Box<[u8]>,
+    pos: usize,
+    cap: usize,
+    amt: u64,
+    read_done: bool,
+    need_flush: bool,
+}
+
+impl fmt::Debug for CopyBuffer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CopyBuffer")
+            .field("buffer", &format_args!("{}/{}", self.cap - self.pos, self.buf.len()))
+            .field("amt", &self.amt)
+            .field("read_done", &self.read_done)
+            .finish()
+    }
+}
+
+impl CopyBuffer {
+    fn new() -> Self {
+        Self {
+            buf: vec![0; DEFAULT_BUF_SIZE].into_boxed_slice(),
+            pos: 0,
+            cap: 0,
+            amt: 0,
+            read_done: false,
+            need_flush: false,
+        }
+    }
+
+    fn poll_copy<R, W>(
+        &mut self,
+        cx: &mut Context<'_>,
+        mut reader: Pin<&mut R>,
+        mut writer: Pin<&mut W>,
+    ) -> Poll<io::Result<u64>>
+    where
+        R: AsyncRead + ?Sized,
+        W: AsyncWrite + ?Sized,
+    {
+        loop {
+            if self.pos == self.cap && !self.read_done {
+                match reader.as_mut().poll_read(cx, &mut self.buf) {
+                    Poll::Ready(Ok(0)) => self.read_done = true,
+                    Poll::Ready(Ok(n)) => {
+                        self.pos = 0;
+                        self.cap = n;
+                    }
+                    Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+                    Poll::Pending => {
+                        // Make sure the data written so far reaches the other
+                        // side while waiting for more.
+                        if self.need_flush {
+                            ready!(writer.as_mut().poll_flush(cx))?;
+                            self.need_flush = false;
+                        }
+                        return Poll::Pending;
+                    }
+                }
+            }
+
+            while self.pos < self.cap {
+                let n = ready!(writer.as_mut().poll_write(cx, &self.buf[self.pos..self.cap]))?;
+                if n == 0 {
+                    return Poll::Ready(Err(io::Error::new(
+                        io::ErrorKind::WriteZero,
+                        "write zero byte into writer",
+                    )));
+                }
+                self.pos += n;
+                self.amt += n as u64;
+                self.need_flush = true;
+            }
+
+            if self.read_done {
+                ready!(writer.as_mut().poll_flush(cx))?;
+                return Poll::Ready(Ok(self.amt));
+            }
+        }
+    }
+}
+
+impl<A, B> Future for CopyBidirectional<'_, A, B>
+where
+    A: AsyncRead + AsyncWrite + Unpin + ?Sized,
+    B: AsyncRead + AsyncWrite + Unpin + ?Sized,
+{
+    type Output = io::Result<(u64, u64)>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+
+        let a_to_b = this.a_to_b.poll_transfer(cx, Pin::new(&mut *this.a), Pin::new(&mut *this.b));
+        if let Poll::Ready(Err(e)) = a_to_b {
+            return Poll::Ready(Err(this.error(e)));
+        }
+        let b_to_a = this.b_to_a.poll_transfer(cx, Pin::new(&mut *this.b), Pin::new(&mut *this.a));
+        if let Poll::Ready(Err(e)) = b_to_a {
+            return Poll::Ready(Err(this.error(e)));
+        }
+
+        match (a_to_b, b_to_a) {
+            (Poll::Ready(Ok(a_to_b)), Poll::Ready(Ok(b_to_a))) => Poll::Ready(Ok((a_to_b, b_to_a))),
+            _ => Poll::Pending,
+        }
+    }
+}
+
+impl<A: ?Sized, B: ?Sized> CopyBidirectional<'_, A, B> {
+    fn error(&self, error: io::Error) -> io::Error {
+        let kind = error.kind();
+        let error =
+            CopyBidirectionalError { error, a_to_b: self.a_to_b.amt(), b_to_a: self.b_to_a.amt() };
+        io::Error::new(kind, error)
+    }
+}
@@ -36,7 +36,7 @@ where
     R: AsyncBufRead,
     W: AsyncWrite + Unpin + ?Sized,
 {
-    CopyBuf { reader, writer, amt: 0 }
+    CopyBuf { reader, writer, amt: 0, yield_threshold: None }
 }
 
 pin_project! {
@@ -48,6 +48,37 @@ pin_project! {
         reader: R,
         writer: &'a mut W,
         amt: u64,
+        yield_threshold: Option<u64>,
+    }
+}
+
+impl<R, W: ?Sized> CopyBuf<'_, R, W> {
+    /// Makes the copy yield back to the task's executor once it has copied at
+    /// least `bytes` bytes within a single poll.
+    ///
+    /// By default, the copy keeps going for as long as both the reader and the
+    /// writer are ready, which can starve other tasks sharing the executor when
+    /// copying large amounts of data between endpoints which are always ready,
+    /// like in-memory buffers. With a yield threshold, the future instead wakes
+    /// its task and returns [`Poll::Pending`] after copying that many bytes,
+    /// giving other tasks a chance to run before it continues.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::io::{self, Cursor};
+    ///
+    /// let reader = Cursor::new(vec![0u8; 1 << 20]);
+    /// let mut writer = Vec::new();
+    ///
+    /// let bytes = io::copy_buf(reader, &mut writer).with_yield_threshold(64 * 1024).await?;
+    /// assert_eq!(bytes, 1 << 20);
+    /// # Ok::<(), Box<dyn std::error::Error>>(()) }).unwrap();
+    /// ```
+    pub fn with_yield_threshold(mut self, bytes: u64) -> Self {
+        self.yield_threshold = Some(bytes);
+        self
     }
 }
 
@@ -59,20 +90,49 @@ where
     type Output = io::Result<u64>;
 
     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        let mut this = self.project();
-        loop {
-            let buffer = ready!(this.reader.as_mut().poll_fill_buf(cx))?;
-            if buffer.is_empty() {
-                ready!(Pin::new(&mut this.writer).poll_flush(cx))?;
-                return Poll::Ready(Ok(*this.amt));
-            }
+        let this = self.project();
+        poll_copy_buf(this.reader, this.writer, this.amt, *this.yield_threshold, cx, |_| {})
+    }
+}
+
+// Copies bytes from `reader` to `writer` until `reader` hits EOF, adding the
+// number of bytes written to `amt` and calling `progress` with the updated
+// total after each successful write. If `yield_threshold` is set, this
+// returns `Poll::Pending` after immediately waking the task once that many bytes
+// have been copied within this call.
+pub(super) fn poll_copy_buf<R, W, F>(
+    mut reader: Pin<&mut R>,
+    writer: &mut W,
+    amt: &mut u64,
+    yield_threshold: Option<u64>,
+    cx: &mut Context<'_>,
+    mut progress: F,
+) -> Poll<io::Result<u64>>
+where
+    R: AsyncBufRead + ?Sized,
+    W: AsyncWrite + Unpin + ?Sized,
+    F: FnMut(u64),
+{
+    let mut copied = 0;
+    loop {
+        let buffer = ready!(reader.as_mut().poll_fill_buf(cx))?;
+        if buffer.is_empty() {
+            ready!(Pin::new(&mut *writer).poll_flush(cx))?;
+            return Poll::Ready(Ok(*amt));
+        }
+
+        let i = ready!(Pin::new(&mut *writer).poll_write(cx, buffer))?;
+        if i == 0 {
+            return Poll::Ready(Err(io::ErrorKind::WriteZero.into()));
+        }
+        *amt += i as u64;
+        reader.as_mut().consume(i);
+        progress(*amt);
 
-            let i = ready!(Pin::new(&mut this.writer).poll_write(cx, buffer))?;
-            if i == 0 {
-                return Poll::Ready(Err(io::ErrorKind::WriteZero.into()));
-            }
-            *this.amt += i as u64;
-            this.reader.as_mut().consume(i);
+        copied += i as u64;
+        if yield_threshold.map_or(false, |threshold| copied >= threshold) {
+            cx.waker().wake_by_ref();
+            return Poll::Pending;
         }
     }
 }
@@ -0,0 +1,68 @@
+use super::{copy_buf, AsyncReadExt, BufReader, CopyBuf, Take};
+use futures_core::future::Future;
+use futures_core::ready;
+use futures_core::task::{Context, Poll};
+use futures_io::{AsyncRead, AsyncWrite};
+use pin_project_lite::pin_project;
+use std::io;
+use std::pin::Pin;
+
+/// Creates a future which copies at most `max` bytes from one object to
+/// another.
+///
+/// This behaves like [`copy()`](super::copy()), but stops once `max` bytes
+/// have been copied. No more than `max` bytes are ever read from `reader`, so
+/// any data after them is left in the reader.
+///
+/// On success the number of bytes copied is returned, together with whether
+/// the copy stopped because the limit was reached. In that case `reader` may
+/// or may not have more data available; this is not checked, to avoid waiting
+/// for more data that would not be copied anyway. The flag is `false` if
+/// `reader` hit EOF before the limit was reached.
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::io::{self, AsyncReadExt, Cursor};
+///
+/// let mut reader = Cursor::new([1, 2, 3, 4, 5]);
+/// let mut writer = Vec::new();
+///
+/// let (bytes, limit_reached) = io::copy_until_limit(&mut reader, &mut writer, 3).await?;
+/// assert_eq!((bytes, limit_reached), (3, true));
+/// assert_eq!(writer, [1, 2, 3]);
+///
+/// let mut rest = Vec::new();
+/// reader.read_to_end(&mut rest).await?;
+/// assert_eq!(rest, [4, 5]);
+/// # Ok::<(), Box<dyn std::error::Error>>(()) }).unwrap();
+/// ```
+pub fn copy_until_limit<R, W>(reader: R, writer: &mut W, max: u64) -> CopyUntilLimit<'_, R, W>
+where
+    R: AsyncRead,
+    W: AsyncWrite + Unpin + ?Sized,
+{
+    CopyUntilLimit { inner: copy_buf(BufReader::new(reader.take(max)), writer), max }
+}
+
+pin_project! {
+    /// Future for the [`copy_until_limit()`] function.
+    #[derive(Debug)]
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct CopyUntilLimit<'a, R, W: ?Sized> {
+        #[pin]
+        inner: CopyBuf<'a, BufReader<Take<R>>, W>,
+        max: u64,
+    }
+}
+
+impl<R: AsyncRead, W: AsyncWrite + Unpin + ?Sized> Future for CopyUntilLimit<'_, R, W> {
+    type Output = io::Result<(u64, bool)>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let amt = ready!(this.inner.poll(cx))?;
+        Poll::Ready(Ok((amt, amt == *this.max)))
+    }
+}
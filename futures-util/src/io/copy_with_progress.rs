@@ -0,0 +1,76 @@
+use super::copy_buf::poll_copy_buf;
+use super::BufReader;
+use futures_core::future::Future;
+use futures_core::task::{Context, Poll};
+use futures_io::{AsyncRead, AsyncWrite};
+use pin_project_lite::pin_project;
+use std::io;
+use std::pin::Pin;
+
+/// Creates a future which copies all the bytes from one object to another,
+/// reporting progress along the way.
+///
+/// This behaves like [`copy()`](super::copy()), but calls `progress` with the
+/// total number of bytes copied so far after each successful write to
+/// `writer`. This can be used to update a progress indicator, or to keep
+/// track of how much was transferred even if the copy fails part-way.
+///
+/// On success the number of bytes is returned.
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::io::{self, AsyncWriteExt, Cursor};
+///
+/// let reader = Cursor::new([1, 2, 3, 4]);
+/// let mut writer = Cursor::new(vec![0u8; 5]);
+///
+/// let mut reported = Vec::new();
+/// let bytes = io::copy_with_progress(reader, &mut writer, |n| reported.push(n)).await?;
+/// writer.close().await?;
+///
+/// assert_eq!(bytes, 4);
+/// assert_eq!(reported, [4]);
+/// assert_eq!(writer.into_inner(), [1, 2, 3, 4, 0]);
+/// # Ok::<(), Box<dyn std::error::Error>>(()) }).unwrap();
+/// ```
+pub fn copy_with_progress<R, W, F>(
+    reader: R,
+    writer: &mut W,
+    progress: F,
+) -> CopyWithProgress<'_, R, W, F>
+where
+    R: AsyncRead,
+    W: AsyncWrite + Unpin + ?Sized,
+    F: FnMut(u64),
+{
+    CopyWithProgress { reader: BufReader::new(reader), writer, amt: 0, progress }
+}
+
+pin_project! {
+    /// Future for the [`copy_with_progress()`] function.
+    #[derive(Debug)]
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct CopyWithProgress<'a, R, W: ?Sized, F> {
+        #[pin]
+        reader: BufReader<R>,
+        writer: &'a mut W,
+        amt: u64,
+        progress: F,
+    }
+}
+
+impl<R, W, F> Future for CopyWithProgress<'_, R, W, F>
+where
+    R: AsyncRead,
+    W: AsyncWrite + Unpin + ?Sized,
+    F: FnMut(u64),
+{
+    type Output = io::Result<u64>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        poll_copy_buf(this.reader, this.writer, this.amt, None, cx, this.progress)
+    }
+}
@@ -1,7 +1,11 @@
+use super::cursor_split::{self, ReadCursor, WriteCursor};
 use futures_core::task::{Context, Poll};
 #[cfg(feature = "read_initializer")]
 use futures_io::Initializer;
-use futures_io::{AsyncBufRead, AsyncRead, AsyncSeek, AsyncWrite, IoSlice, IoSliceMut, SeekFrom};
+use futures_io::{
+    AsyncBufRead, AsyncRead, AsyncSeek, AsyncWrite, IoSlice, IoSliceMut, ReadBuf, SeekFrom,
+};
+use std::cmp;
 use std::io;
 use std::pin::Pin;
 
@@ -145,6 +149,43 @@ impl<T> Cursor<T> {
     }
 }
 
+impl Cursor<Vec<u8>> {
+    /// Splits this cursor into a reading and a writing cursor which share the
+    /// underlying buffer, but each have their own position.
+    ///
+    /// The [`ReadCursor`] starts reading at the current position of this
+    /// cursor, and the [`WriteCursor`] starts writing at the end of the
+    /// buffer, so that data written to one can be read from the other, for
+    /// example to test protocol code against an in-memory loopback. Reading
+    /// waits for data to be written, and reports EOF once the [`WriteCursor`]
+    /// has been closed or dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::io::{AsyncReadExt, AsyncWriteExt, Cursor};
+    ///
+    /// let (mut reader, mut writer) = Cursor::new(b"hello".to_vec()).split_rw();
+    /// writer.write_all(b" world").await?;
+    ///
+    /// let mut buffer = [0; 8];
+    /// reader.read_exact(&mut buffer).await?;
+    /// assert_eq!(&buffer, b"hello wo");
+    ///
+    /// writer.write_all(b"!").await?;
+    /// writer.close().await?;
+    /// let mut buffer = Vec::new();
+    /// reader.read_to_end(&mut buffer).await?;
+    /// assert_eq!(buffer, b"rld!");
+    /// # Ok::<(), Box<dyn std::error::Error>>(()) }).unwrap();
+    /// ```
+    pub fn split_rw(self) -> (ReadCursor, WriteCursor) {
+        let pos = self.inner.position();
+        cursor_split::split_rw(self.inner.into_inner(), pos)
+    }
+}
+
 impl<T> AsyncSeek for Cursor<T>
 where
     T: AsRef<[u8]> + Unpin,
@@ -180,6 +221,19 @@ impl<T: AsRef<[u8]> + Unpin> AsyncRead for Cursor<T> {
     ) -> Poll<io::Result<usize>> {
         Poll::Ready(io::Read::read_vectored(&mut self.inner, bufs))
     }
+
+    fn poll_read_buf(
+        mut self: Pin<&mut Self>,
+        _: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let data = self.inner.get_ref().as_ref();
+        let pos = cmp::min(self.inner.position(), data.len() as u64) as usize;
+        let n = cmp::min(buf.remaining(), data.len() - pos);
+        buf.put_slice(&data[pos..pos + n]);
+        self.inner.set_position((pos + n) as u64);
+        Poll::Ready(Ok(()))
+    }
 }
 
 impl<T> AsyncBufRead for Cursor<T>
@@ -213,6 +267,10 @@ macro_rules! delegate_async_write_to_stdio {
             Poll::Ready(io::Write::write_vectored(&mut self.inner, bufs))
         }
 
+        fn is_write_vectored(&self) -> bool {
+            true
+        }
+
         fn poll_flush(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<io::Result<()>> {
             Poll::Ready(io::Write::flush(&mut self.inner))
         }
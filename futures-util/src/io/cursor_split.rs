@@ -0,0 +1,172 @@
+use crate::lock::BiLock;
+use crate::task::AtomicWaker;
+use futures_core::ready;
+use futures_core::task::{Context, Poll};
+use futures_io::{AsyncRead, AsyncWrite, IoSlice, IoSliceMut};
+use std::io::{self, Read, Write};
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// The reading cursor returned from [`Cursor::split_rw`](super::Cursor::split_rw).
+///
+/// It reads from the buffer shared with its [`WriteCursor`], from a position
+/// that is independent of the one that is written to. Once it has caught up
+/// with the end of the buffer, reading waits for more data to be written, and
+/// only reports EOF after the [`WriteCursor`] has been closed or dropped.
+#[derive(Debug)]
+pub struct ReadCursor {
+    buf: BiLock<Vec<u8>>,
+    pos: u64,
+    writer: Arc<WriterState>,
+}
+
+/// The writing cursor returned from [`Cursor::split_rw`](super::Cursor::split_rw).
+///
+/// It writes to the buffer shared with its [`ReadCursor`], at a position that
+/// is independent of the one that is read from.
+#[derive(Debug)]
+pub struct WriteCursor {
+    buf: BiLock<Vec<u8>>,
+    pos: u64,
+    writer: Arc<WriterState>,
+}
+
+// Kept outside of the `BiLock`, as dropping the `WriteCursor` can't wait for
+// the lock.
+#[derive(Debug)]
+struct WriterState {
+    closed: AtomicBool,
+    // Woken when data is written or the writer is closed.
+    reader: AtomicWaker,
+}
+
+pub(super) fn split_rw(buf: Vec<u8>, read_pos: u64) -> (ReadCursor, WriteCursor) {
+    let write_pos = buf.len() as u64;
+    let (a, b) = BiLock::new(buf);
+    let writer =
+        Arc::new(WriterState { closed: AtomicBool::new(false), reader: AtomicWaker::new() });
+    (
+        ReadCursor { buf: a, pos: read_pos, writer: writer.clone() },
+        WriteCursor { buf: b, pos: write_pos, writer },
+    )
+}
+
+impl WriterState {
+    fn close(&self) {
+        self.closed.store(true, Ordering::Release);
+        self.reader.wake();
+    }
+}
+
+impl ReadCursor {
+    /// Returns the position that the next read starts at.
+    pub fn position(&self) -> u64 {
+        self.pos
+    }
+}
+
+impl WriteCursor {
+    /// Returns the position that the next write starts at.
+    pub fn position(&self) -> u64 {
+        self.pos
+    }
+}
+
+impl ReadCursor {
+    fn poll_read_with<F>(
+        &mut self,
+        cx: &mut Context<'_>,
+        is_empty: bool,
+        f: F,
+    ) -> Poll<io::Result<usize>>
+    where
+        F: FnOnce(&mut io::Cursor<&[u8]>) -> io::Result<usize>,
+    {
+        let guard = ready!(self.buf.poll_lock(cx));
+        if !is_empty && self.pos >= guard.len() as u64 {
+            // Registered while holding the lock, so a write can't slip in
+            // between the check and the registration.
+            self.writer.reader.register(cx.waker());
+            if !self.writer.closed.load(Ordering::Acquire) {
+                return Poll::Pending;
+            }
+        }
+        let mut cursor = io::Cursor::new(&guard[..]);
+        cursor.set_position(self.pos);
+        let n = f(&mut cursor)?;
+        self.pos = cursor.position();
+        Poll::Ready(Ok(n))
+    }
+}
+
+impl AsyncRead for ReadCursor {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let is_empty = buf.is_empty();
+        self.poll_read_with(cx, is_empty, |cursor| cursor.read(buf))
+    }
+
+    fn poll_read_vectored(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &mut [IoSliceMut<'_>],
+    ) -> Poll<io::Result<usize>> {
+        let is_empty = bufs.iter().all(|b| b.is_empty());
+        self.poll_read_with(cx, is_empty, |cursor| cursor.read_vectored(bufs))
+    }
+}
+
+impl AsyncWrite for WriteCursor {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = &mut *self;
+        let mut guard = ready!(this.buf.poll_lock(cx));
+        let mut cursor = io::Cursor::new(&mut *guard);
+        cursor.set_position(this.pos);
+        let n = cursor.write(buf)?;
+        this.pos = cursor.position();
+        this.writer.reader.wake();
+        Poll::Ready(Ok(n))
+    }
+
+    fn poll_write_vectored(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &[IoSlice<'_>],
+    ) -> Poll<io::Result<usize>> {
+        let this = &mut *self;
+        let mut guard = ready!(this.buf.poll_lock(cx));
+        let mut cursor = io::Cursor::new(&mut *guard);
+        cursor.set_position(this.pos);
+        let n = cursor.write_vectored(bufs)?;
+        this.pos = cursor.position();
+        this.writer.reader.wake();
+        Poll::Ready(Ok(n))
+    }
+
+    fn is_write_vectored(&self) -> bool {
+        true
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.writer.close();
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl Drop for WriteCursor {
+    fn drop(&mut self) {
+        self.writer.close();
+    }
+}
@@ -0,0 +1,274 @@
+use crate::io::{AsyncRead, AsyncWrite, IoSlice, ReadBuf};
+use futures_core::future::Future;
+use futures_core::ready;
+use futures_core::task::{Context, Poll};
+use std::io;
+use std::mem;
+use std::pin::Pin;
+
+const PREFIX_LEN: usize = 4;
+
+/// Creates a future which reads a length-delimited frame from `reader`.
+///
+/// A frame consists of its length as a big-endian `u32`, followed by that many
+/// bytes. The returned future resolves to those bytes, once all of them have
+/// been read, or to `None` if EOF is reached before the first byte of the
+/// length, i.e. between frames.
+///
+/// See [`read_frame_into`] for reusing a buffer for the frames, and
+/// [`write_frame`] for writing them.
+///
+/// # Errors
+///
+/// If the length of the frame is larger than `max_len`, the returned future
+/// resolves to an error of kind [`InvalidData`](io::ErrorKind::InvalidData)
+/// without reading the rest of the frame, and without allocating for it.
+///
+/// If EOF is reached after the first byte of the length, but before the whole
+/// frame has been read, the returned future resolves to an error of kind
+/// [`UnexpectedEof`](io::ErrorKind::UnexpectedEof).
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::io::{self, Cursor};
+///
+/// let mut reader = Cursor::new(b"\x00\x00\x00\x05hello\x00\x00\x00\x00");
+///
+/// assert_eq!(io::read_frame(&mut reader, 1024).await?, Some(b"hello".to_vec()));
+/// assert_eq!(io::read_frame(&mut reader, 1024).await?, Some(Vec::new()));
+/// assert_eq!(io::read_frame(&mut reader, 1024).await?, None);
+/// # Ok::<(), Box<dyn std::error::Error>>(()) }).unwrap();
+/// ```
+pub fn read_frame<R>(reader: &mut R, max_len: usize) -> ReadFrame<'_, R>
+where
+    R: AsyncRead + Unpin + ?Sized,
+{
+    ReadFrame { reader, buf: Vec::new(), state: FrameState::new(max_len) }
+}
+
+/// Creates a future which reads a length-delimited frame from `reader` into
+/// `buf`.
+///
+/// This works like [`read_frame`], except that the bytes of the frame replace
+/// the contents of `buf`, reusing its allocation. The returned future resolves
+/// to the length of the frame, or to `None` at EOF between frames. If an error
+/// occurs, the contents of `buf` are unspecified.
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::io::{self, Cursor};
+///
+/// let mut reader = Cursor::new(b"\x00\x00\x00\x02hi\x00\x00\x00\x03you");
+/// let mut buf = Vec::new();
+///
+/// assert_eq!(io::read_frame_into(&mut reader, &mut buf, 1024).await?, Some(2));
+/// assert_eq!(buf, b"hi");
+/// assert_eq!(io::read_frame_into(&mut reader, &mut buf, 1024).await?, Some(3));
+/// assert_eq!(buf, b"you");
+/// assert_eq!(io::read_frame_into(&mut reader, &mut buf, 1024).await?, None);
+/// # Ok::<(), Box<dyn std::error::Error>>(()) }).unwrap();
+/// ```
+pub fn read_frame_into<'a, R>(
+    reader: &'a mut R,
+    buf: &'a mut Vec<u8>,
+    max_len: usize,
+) -> ReadFrameInto<'a, R>
+where
+    R: AsyncRead + Unpin + ?Sized,
+{
+    ReadFrameInto { reader, buf, state: FrameState::new(max_len) }
+}
+
+/// Creates a future which writes `data` to `writer` as a length-delimited
+/// frame.
+///
+/// This writes the length of `data` as a big-endian `u32`, followed by `data`
+/// itself, in the format read by [`read_frame`]. Like
+/// [`write_all`](super::AsyncWriteExt::write_all), this doesn't flush
+/// `writer`.
+///
+/// # Errors
+///
+/// If `data` is longer than `u32::MAX` bytes, the returned future resolves to
+/// an error of kind [`InvalidInput`](io::ErrorKind::InvalidInput) without
+/// writing anything.
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::io::{self, Cursor};
+///
+/// let mut writer = Cursor::new(Vec::new());
+/// io::write_frame(&mut writer, b"hello").await?;
+///
+/// assert_eq!(writer.into_inner(), b"\x00\x00\x00\x05hello");
+/// # Ok::<(), Box<dyn std::error::Error>>(()) }).unwrap();
+/// ```
+pub fn write_frame<'a, W>(writer: &'a mut W, data: &'a [u8]) -> WriteFrame<'a, W>
+where
+    W: AsyncWrite + Unpin + ?Sized,
+{
+    let prefix = if data.len() as u64 > u64::from(u32::max_value()) {
+        None
+    } else {
+        Some((data.len() as u32).to_be_bytes())
+    };
+    WriteFrame { writer, prefix, data, written: 0 }
+}
+
+#[derive(Debug)]
+struct FrameState {
+    prefix: [u8; PREFIX_LEN],
+    // Number of bytes read of the prefix, and then of the frame.
+    read: usize,
+    // Length of the frame, once the prefix has been read.
+    len: Option<usize>,
+    max_len: usize,
+}
+
+impl FrameState {
+    fn new(max_len: usize) -> Self {
+        Self { prefix: [0; PREFIX_LEN], read: 0, len: None, max_len }
+    }
+
+    fn poll_read<R: AsyncRead + ?Sized>(
+        &mut self,
+        mut reader: Pin<&mut R>,
+        cx: &mut Context<'_>,
+        buf: &mut Vec<u8>,
+    ) -> Poll<io::Result<Option<usize>>> {
+        let len = match self.len {
+            Some(len) => len,
+            None => {
+                while self.read < PREFIX_LEN {
+                    let mut read_buf = ReadBuf::new(&mut self.prefix[self.read..]);
+                    let n = ready!(super::poll_read_buf(reader.as_mut(), cx, &mut read_buf))?;
+                    if n == 0 && self.read == 0 {
+                        return Poll::Ready(Ok(None));
+                    } else if n == 0 {
+                        return Poll::Ready(Err(io::ErrorKind::UnexpectedEof.into()));
+                    }
+                    self.read += n;
+                }
+
+                let len = u32::from_be_bytes(self.prefix);
+                if u64::from(len) > self.max_len as u64 {
+                    return Poll::Ready(Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("frame length {} exceeds the maximum of {}", len, self.max_len),
+                    )));
+                }
+                let len = len as usize;
+                buf.clear();
+                buf.resize(len, 0);
+                self.len = Some(len);
+                self.read = 0;
+                len
+            }
+        };
+
+        while self.read < len {
+            let mut read_buf = ReadBuf::new(&mut buf[self.read..]);
+            let n = ready!(super::poll_read_buf(reader.as_mut(), cx, &mut read_buf))?;
+            if n == 0 {
+                return Poll::Ready(Err(io::ErrorKind::UnexpectedEof.into()));
+            }
+            self.read += n;
+        }
+        Poll::Ready(Ok(Some(len)))
+    }
+}
+
+/// Future for the [`read_frame`] function.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct ReadFrame<'a, R: ?Sized> {
+    reader: &'a mut R,
+    buf: Vec<u8>,
+    state: FrameState,
+}
+
+impl<R: ?Sized + Unpin> Unpin for ReadFrame<'_, R> {}
+
+impl<R: AsyncRead + ?Sized + Unpin> Future for ReadFrame<'_, R> {
+    type Output = io::Result<Option<Vec<u8>>>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        let len = ready!(this.state.poll_read(Pin::new(&mut this.reader), cx, &mut this.buf))?;
+        Poll::Ready(Ok(len.map(|_| mem::replace(&mut this.buf, Vec::new()))))
+    }
+}
+
+/// Future for the [`read_frame_into`] function.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct ReadFrameInto<'a, R: ?Sized> {
+    reader: &'a mut R,
+    buf: &'a mut Vec<u8>,
+    state: FrameState,
+}
+
+impl<R: ?Sized + Unpin> Unpin for ReadFrameInto<'_, R> {}
+
+impl<R: AsyncRead + ?Sized + Unpin> Future for ReadFrameInto<'_, R> {
+    type Output = io::Result<Option<usize>>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        this.state.poll_read(Pin::new(&mut this.reader), cx, this.buf)
+    }
+}
+
+/// Future for the [`write_frame`] function.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct WriteFrame<'a, W: ?Sized> {
+    writer: &'a mut W,
+    // `None` if `data` is too long for a frame.
+    prefix: Option<[u8; PREFIX_LEN]>,
+    data: &'a [u8],
+    // Number of bytes written of the prefix and `data`.
+    written: usize,
+}
+
+impl<W: ?Sized + Unpin> Unpin for WriteFrame<'_, W> {}
+
+impl<W: AsyncWrite + ?Sized + Unpin> Future for WriteFrame<'_, W> {
+    type Output = io::Result<()>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        let prefix = match &this.prefix {
+            Some(prefix) => prefix,
+            None => {
+                return Poll::Ready(Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "frame is longer than u32::MAX bytes",
+                )))
+            }
+        };
+
+        while this.written < PREFIX_LEN + this.data.len() {
+            let writer = Pin::new(&mut this.writer);
+            let n = if this.written < PREFIX_LEN {
+                // Write the prefix along with the data, to avoid a separate write
+                // for it if possible.
+                let bufs = [IoSlice::new(&prefix[this.written..]), IoSlice::new(this.data)];
+                ready!(writer.poll_write_vectored(cx, &bufs))?
+            } else {
+                ready!(writer.poll_write(cx, &this.data[this.written - PREFIX_LEN..]))?
+            };
+            if n == 0 {
+                return Poll::Ready(Err(io::ErrorKind::WriteZero.into()));
+            }
+            this.written += n;
+        }
+        Poll::Ready(Ok(()))
+    }
+}
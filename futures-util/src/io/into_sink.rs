@@ -13,30 +13,57 @@ struct Block<Item> {
 }
 
 pin_project! {
-    /// Sink for the [`into_sink`](super::AsyncWriteExt::into_sink) method.
+    /// Sink for the [`into_sink`](super::AsyncWriteExt::into_sink) and
+    /// [`into_sink_buffered`](super::AsyncWriteExt::into_sink_buffered) methods.
     #[must_use = "sinks do nothing unless polled"]
     #[derive(Debug)]
     #[cfg_attr(docsrs, doc(cfg(feature = "sink")))]
     pub struct IntoSink<W, Item> {
         #[pin]
         writer: W,
+        // Small items coalesced into a single buffer, along with how much of it has been written
+        // already. Only used if `capacity` isn't zero.
+        buf: Vec<u8>,
+        written: usize,
+        capacity: usize,
         // An outstanding block for us to push into the underlying writer, along with an offset of how
-        // far into this block we have written already.
+        // far into this block we have written already. It is written after `buf`.
         buffer: Option<Block<Item>>,
     }
 }
 
 impl<W: AsyncWrite, Item: AsRef<[u8]>> IntoSink<W, Item> {
     pub(super) fn new(writer: W) -> Self {
-        Self { writer, buffer: None }
+        Self::with_capacity(writer, 0)
     }
 
-    /// If we have an outstanding block in `buffer` attempt to push it into the writer, does _not_
-    /// flush the writer after it succeeds in pushing the block into it.
+    pub(super) fn with_capacity(writer: W, capacity: usize) -> Self {
+        Self { writer, buf: Vec::with_capacity(capacity), written: 0, capacity, buffer: None }
+    }
+
+    /// Push the coalesced bytes in `buf` into the writer.
+    fn poll_write_buf(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), io::Error>> {
+        let mut this = self.project();
+
+        while *this.written < this.buf.len() {
+            let written = ready!(this.writer.as_mut().poll_write(cx, &this.buf[*this.written..]))?;
+            if written == 0 {
+                return Poll::Ready(Err(io::ErrorKind::WriteZero.into()));
+            }
+            *this.written += written;
+        }
+        this.buf.clear();
+        *this.written = 0;
+        Poll::Ready(Ok(()))
+    }
+
+    /// Push the coalesced bytes in `buf` and then the outstanding block in `buffer`, if any, into
+    /// the writer, does _not_ flush the writer after it succeeds in pushing them into it.
     fn poll_flush_buffer(
-        self: Pin<&mut Self>,
+        mut self: Pin<&mut Self>,
         cx: &mut Context<'_>,
     ) -> Poll<Result<(), io::Error>> {
+        ready!(self.as_mut().poll_write_buf(cx))?;
         let mut this = self.project();
 
         if let Some(buffer) = this.buffer {
@@ -57,14 +84,37 @@ impl<W: AsyncWrite, Item: AsRef<[u8]>> IntoSink<W, Item> {
 impl<W: AsyncWrite, Item: AsRef<[u8]>> Sink<Item> for IntoSink<W, Item> {
     type Error = io::Error;
 
-    fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
-        ready!(self.poll_flush_buffer(cx))?;
+    fn poll_ready(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        // Items which fit are added to `buf`, so it only needs to be written once it is full.
+        if self.buffer.is_some() || self.buf.len() >= self.capacity {
+            ready!(self.as_mut().poll_write_buf(cx))?;
+        }
+
+        // An item which didn't fit into the rest of `buf` can be added to it now that it has been
+        // written, as long as it isn't larger than the whole buffer.
+        let this = self.as_mut().project();
+        if let Some(buffer) = this.buffer.take() {
+            let bytes = buffer.bytes.as_ref();
+            if buffer.offset == 0 && bytes.len() <= *this.capacity {
+                this.buf.extend_from_slice(bytes);
+            } else {
+                *this.buffer = Some(buffer);
+                ready!(self.poll_flush_buffer(cx))?;
+            }
+        }
         Poll::Ready(Ok(()))
     }
 
     fn start_send(self: Pin<&mut Self>, item: Item) -> Result<(), Self::Error> {
         debug_assert!(self.buffer.is_none());
-        *self.project().buffer = Some(Block { offset: 0, bytes: item });
+        let this = self.project();
+        let bytes = item.as_ref();
+        if bytes.len() <= *this.capacity - this.buf.len() {
+            this.buf.extend_from_slice(bytes);
+        } else {
+            // Items which don't fit are held on to until `buf` has been written.
+            *this.buffer = Some(Block { offset: 0, bytes: item });
+        }
         Ok(())
     }
 
@@ -70,7 +70,7 @@ impl<W: AsyncWrite> AsyncWrite for LineWriter<W> {
             Some(newline_index) => newline_index + 1,
         };
 
-        ready!(this.buf_writer.as_mut().poll_flush(cx)?);
+        ready!(this.buf_writer.as_mut().flush_buf(cx)?);
 
         let lines = &buf[..newline_index];
 
@@ -102,10 +102,16 @@ impl<W: AsyncWrite> AsyncWrite for LineWriter<W> {
         cx: &mut Context<'_>,
         bufs: &[IoSlice<'_>],
     ) -> Poll<io::Result<usize>> {
-        let mut this = self.as_mut().project();
-        // `is_write_vectored()` is handled in original code, but not in this crate
-        // see https://github.com/rust-lang/rust/issues/70436
+        // Without efficient vectored writes, handle the first non-empty buffer
+        // like `poll_write` does, as a vectored write would only write that.
+        if !self.is_write_vectored() {
+            return match bufs.iter().find(|buf| !buf.is_empty()) {
+                Some(buf) => self.poll_write(cx, buf),
+                None => Poll::Ready(Ok(0)),
+            };
+        }
 
+        let mut this = self.as_mut().project();
         let last_newline_buf_idx = bufs
             .iter()
             .enumerate()
@@ -119,7 +125,7 @@ impl<W: AsyncWrite> AsyncWrite for LineWriter<W> {
             Some(i) => i,
         };
 
-        ready!(this.buf_writer.as_mut().poll_flush(cx)?);
+        ready!(this.buf_writer.as_mut().flush_buf(cx)?);
 
         let (lines, tail) = bufs.split_at(last_newline_buf_idx + 1);
 
@@ -143,6 +149,10 @@ impl<W: AsyncWrite> AsyncWrite for LineWriter<W> {
         Poll::Ready(Ok(flushed + buffered))
     }
 
+    fn is_write_vectored(&self) -> bool {
+        self.buf_writer.get_ref().is_write_vectored()
+    }
+
     /// Forward to `buf_writer` 's `BufWriter::poll_flush()`
     fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
         self.as_mut().project().buf_writer.poll_flush(cx)
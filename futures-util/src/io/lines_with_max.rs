@@ -0,0 +1,150 @@
+use futures_core::ready;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+use futures_io::AsyncBufRead;
+use pin_project_lite::pin_project;
+use std::pin::Pin;
+use std::{cmp, error, fmt, io, mem};
+
+/// What a [`LinesWithMax`] stream does after encountering a line which is
+/// longer than its limit.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum LineTooLong {
+    /// Discard the rest of the overlong line and continue with the next one.
+    Skip,
+    /// End the stream.
+    Terminate,
+}
+
+/// Error wrapped in the [`io::Error`] yielded by [`LinesWithMax`] for a line
+/// which is longer than its limit.
+///
+/// The `io::Error` has [`io::ErrorKind::InvalidData`], and this error can be
+/// retrieved from it using [`io::Error::get_ref`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct LineTooLongError {
+    limit: usize,
+}
+
+impl LineTooLongError {
+    /// Returns the maximum line length which was exceeded.
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+}
+
+impl fmt::Display for LineTooLongError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line exceeds the maximum length of {} bytes", self.limit)
+    }
+}
+
+impl error::Error for LineTooLongError {}
+
+pin_project! {
+    /// Stream for the [`lines_with_max`](super::AsyncBufReadExt::lines_with_max) method.
+    #[derive(Debug)]
+    #[must_use = "streams do nothing unless polled"]
+    pub struct LinesWithMax<R> {
+        #[pin]
+        reader: R,
+        bytes: Vec<u8>,
+        limit: usize,
+        on_too_long: LineTooLong,
+        // Whether the rest of an overlong line still has to be discarded.
+        skipping: bool,
+        terminated: bool,
+    }
+}
+
+impl<R: AsyncBufRead> LinesWithMax<R> {
+    pub(super) fn new(reader: R, limit: usize, on_too_long: LineTooLong) -> Self {
+        Self { reader, bytes: Vec::new(), limit, on_too_long, skipping: false, terminated: false }
+    }
+}
+
+// Length of `bytes` without the line terminator that `Lines` strips.
+fn content_len(bytes: &[u8]) -> usize {
+    match bytes {
+        [.., b'\r', b'\n'] => bytes.len() - 2,
+        [.., b'\n'] => bytes.len() - 1,
+        _ => bytes.len(),
+    }
+}
+
+impl<R: AsyncBufRead> Stream for LinesWithMax<R> {
+    type Item = io::Result<String>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        if *this.terminated {
+            return Poll::Ready(None);
+        }
+
+        while *this.skipping {
+            let available = ready!(this.reader.as_mut().poll_fill_buf(cx))?;
+            let (done, used) = match memchr::memchr(b'\n', available) {
+                Some(i) => (true, i + 1),
+                None => (available.is_empty(), available.len()),
+            };
+            this.reader.as_mut().consume(used);
+            *this.skipping = !done;
+        }
+
+        // Never buffer more than a line of `limit` bytes plus a CRLF.
+        let max_bytes = this.limit.saturating_add(2);
+        let too_long = loop {
+            let available = ready!(this.reader.as_mut().poll_fill_buf(cx))?;
+            let available = &available[..cmp::min(available.len(), max_bytes - this.bytes.len())];
+            let (done, used) = match memchr::memchr(b'\n', available) {
+                Some(i) => (true, i + 1),
+                None => (available.is_empty(), available.len()),
+            };
+            this.bytes.extend_from_slice(&available[..used]);
+            this.reader.as_mut().consume(used);
+
+            if done {
+                break content_len(this.bytes) > *this.limit;
+            }
+            // A trailing `\r` may still turn out to be part of a CRLF.
+            let partial_len = match this.bytes.last() {
+                Some(b'\r') => this.bytes.len() - 1,
+                _ => this.bytes.len(),
+            };
+            if partial_len > *this.limit {
+                *this.skipping = true;
+                break true;
+            }
+        };
+
+        if too_long {
+            this.bytes.clear();
+            match this.on_too_long {
+                LineTooLong::Skip => {}
+                LineTooLong::Terminate => *this.terminated = true,
+            }
+            let error = LineTooLongError { limit: *this.limit };
+            return Poll::Ready(Some(Err(io::Error::new(io::ErrorKind::InvalidData, error))));
+        }
+
+        let mut bytes = mem::replace(this.bytes, Vec::new());
+        if bytes.is_empty() {
+            *this.terminated = true;
+            return Poll::Ready(None);
+        }
+        bytes.truncate(content_len(&bytes));
+        match String::from_utf8(bytes) {
+            Ok(line) => Poll::Ready(Some(Ok(line))),
+            Err(_) => Poll::Ready(Some(Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "stream did not contain valid UTF-8",
+            )))),
+        }
+    }
+}
+
+impl<R: AsyncBufRead> FusedStream for LinesWithMax<R> {
+    fn is_terminated(&self) -> bool {
+        self.terminated
+    }
+}
@@ -21,54 +21,97 @@
 use crate::compat::Compat;
 use crate::future::assert_future;
 use crate::stream::assert_stream;
-use std::{pin::Pin, ptr};
+use futures_core::ready;
+use futures_core::task::{Context, Poll};
+use std::future::Future;
+use std::pin::Pin;
+use std::time::Duration;
 
 // Re-export some types from `std::io` so that users don't have to deal
 // with conflicts when `use`ing `futures::io` and `std::io`.
 #[doc(no_inline)]
 pub use std::io::{Error, ErrorKind, IoSlice, IoSliceMut, Result, SeekFrom};
 
-pub use futures_io::{AsyncBufRead, AsyncRead, AsyncSeek, AsyncWrite};
+pub use futures_io::{AsyncBufRead, AsyncRead, AsyncSeek, AsyncWrite, ReadBuf};
 
 // used by `BufReader` and `BufWriter`
 // https://github.com/rust-lang/rust/blob/master/src/libstd/sys_common/io.rs#L1
 const DEFAULT_BUF_SIZE: usize = 8 * 1024;
 
-/// Initializes a buffer if necessary.
-///
-/// A buffer is currently always initialized.
-#[inline]
-unsafe fn initialize<R: AsyncRead>(_reader: &R, buf: &mut [u8]) {
-    ptr::write_bytes(buf.as_mut_ptr(), 0, buf.len())
+// Reads into `buf` with `poll_read_buf`, returning the number of bytes read.
+//
+// Panics if the reader replaced `buf` with a different buffer, as the data in
+// it is taken to have been read into the caller's buffer.
+fn poll_read_buf<R: AsyncRead + ?Sized>(
+    reader: Pin<&mut R>,
+    cx: &mut Context<'_>,
+    buf: &mut ReadBuf<'_>,
+) -> Poll<Result<usize>> {
+    let ptr = buf.filled().as_ptr();
+    let (filled, capacity) = (buf.filled().len(), buf.capacity());
+    ready!(reader.poll_read_buf(cx, buf))?;
+    assert!(
+        buf.filled().as_ptr() == ptr && buf.capacity() == capacity && buf.filled().len() >= filled,
+        "`poll_read_buf` must not replace the buffer it is given"
+    );
+    Poll::Ready(Ok(buf.filled().len() - filled))
 }
 
 mod allow_std;
 pub use self::allow_std::AllowStdIo;
 
+#[cfg(feature = "channel")]
+#[cfg_attr(docsrs, doc(cfg(feature = "channel")))]
+mod blocking_io;
+#[cfg(feature = "channel")]
+#[cfg_attr(docsrs, doc(cfg(feature = "channel")))]
+pub use self::blocking_io::BlockingIo;
+
+mod auto_flush;
+pub use self::auto_flush::AutoFlush;
+
 mod buf_reader;
-pub use self::buf_reader::{BufReader, SeeKRelative};
+pub use self::buf_reader::{BufReader, Peek, SeeKRelative};
 
 mod buf_writer;
-pub use self::buf_writer::BufWriter;
+pub use self::buf_writer::{BufWriter, FlushIntoInner, IntoInnerError};
 
 mod line_writer;
 pub use self::line_writer::LineWriter;
 
+mod bytes;
+pub use self::bytes::Bytes;
+
 mod chain;
 pub use self::chain::Chain;
 
+mod chain_all;
+pub use self::chain_all::{chain_all, ChainAll};
+
 mod close;
 pub use self::close::Close;
 
 mod copy;
 pub use self::copy::{copy, Copy};
 
+mod copy_bidirectional;
+pub use self::copy_bidirectional::{copy_bidirectional, CopyBidirectional, CopyBidirectionalError};
+
 mod copy_buf;
 pub use self::copy_buf::{copy_buf, CopyBuf};
 
+mod copy_until_limit;
+pub use self::copy_until_limit::{copy_until_limit, CopyUntilLimit};
+
+mod copy_with_progress;
+pub use self::copy_with_progress::{copy_with_progress, CopyWithProgress};
+
 mod cursor;
 pub use self::cursor::Cursor;
 
+mod cursor_split;
+pub use self::cursor_split::{ReadCursor, WriteCursor};
+
 mod empty;
 pub use self::empty::{empty, Empty};
 
@@ -78,6 +121,11 @@ pub use self::fill_buf::FillBuf;
 mod flush;
 pub use self::flush::Flush;
 
+mod frame;
+pub use self::frame::{
+    read_frame, read_frame_into, write_frame, ReadFrame, ReadFrameInto, WriteFrame,
+};
+
 #[cfg(feature = "sink")]
 #[cfg_attr(docsrs, doc(cfg(feature = "sink")))]
 mod into_sink;
@@ -88,6 +136,9 @@ pub use self::into_sink::IntoSink;
 mod lines;
 pub use self::lines::Lines;
 
+mod lines_with_max;
+pub use self::lines_with_max::{LineTooLong, LineTooLongError, LinesWithMax};
+
 mod read;
 pub use self::read::Read;
 
@@ -95,35 +146,44 @@ mod read_vectored;
 pub use self::read_vectored::ReadVectored;
 
 mod read_exact;
-pub use self::read_exact::ReadExact;
+pub use self::read_exact::{ReadExact, ReadExactError, ReadExactOrEof};
 
 mod read_line;
 pub use self::read_line::ReadLine;
 
 mod read_to_end;
-pub use self::read_to_end::ReadToEnd;
+pub use self::read_to_end::{ReadToEnd, ReadToEndError, ReadToEndLimited};
 
 mod read_to_string;
-pub use self::read_to_string::ReadToString;
+pub use self::read_to_string::{ReadToString, ReadToStringLimited};
 
 mod read_until;
-pub use self::read_until::ReadUntil;
+pub use self::read_until::{ReadUntil, ReadUntilLimitExceeded, ReadUntilLimited};
 
 mod repeat;
 pub use self::repeat::{repeat, Repeat};
 
 mod seek;
-pub use self::seek::Seek;
+pub use self::seek::{Rewind, Seek};
 
 mod sink;
 pub use self::sink::{sink, Sink};
 
+mod skip;
+pub use self::skip::{SkipExact, SkipUntil};
+
 mod split;
 pub use self::split::{ReadHalf, ReuniteError, WriteHalf};
 
+mod stream_len;
+pub use self::stream_len::StreamLen;
+
 mod take;
 pub use self::take::Take;
 
+mod tee;
+pub use self::tee::Tee;
+
 mod window;
 pub use self::window::Window;
 
@@ -143,6 +203,40 @@ pub use self::write_all_vectored::WriteAllVectored;
 
 /// An extension trait which adds utility methods to `AsyncRead` types.
 pub trait AsyncReadExt: AsyncRead {
+    /// Returns a stream over the bytes of this reader.
+    ///
+    /// The stream yields each byte read from this reader as an
+    /// [`io::Result`]`<u8>`, and ends once the reader reaches EOF. This is
+    /// the asynchronous version of [`std::io::Read::bytes`].
+    ///
+    /// The bytes are read into an internal buffer, so this reader doesn't
+    /// need to be buffered. The returned stream may read more bytes from it
+    /// than it has yielded, which are available from
+    /// [`Bytes::buffer`]. Reads which fail with
+    /// [`Interrupted`](std::io::ErrorKind::Interrupted) are retried.
+    ///
+    /// [`io::Result`]: std::io::Result
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::io::{AsyncReadExt, Cursor};
+    /// use futures::stream::TryStreamExt;
+    ///
+    /// let reader = Cursor::new([1, 2, 3]);
+    /// let bytes: Vec<u8> = reader.bytes().try_collect().await?;
+    ///
+    /// assert_eq!(bytes, [1, 2, 3]);
+    /// # Ok::<(), Box<dyn std::error::Error>>(()) }).unwrap();
+    /// ```
+    fn bytes(self) -> Bytes<Self>
+    where
+        Self: Sized,
+    {
+        assert_stream::<Result<u8>, _>(Bytes::new(self))
+    }
+
     /// Creates an adaptor which will chain this stream with another.
     ///
     /// The returned `AsyncRead` instance will first read all bytes from this object
@@ -264,6 +358,36 @@ pub trait AsyncReadExt: AsyncRead {
         assert_future::<Result<()>, _>(ReadExact::new(self, buf))
     }
 
+    /// Creates a future which will read exactly enough bytes to fill `buf`,
+    /// reporting how many bytes were read if end of file (EOF) is hit sooner.
+    ///
+    /// This is the same as [`read_exact`](AsyncReadExt::read_exact), except
+    /// that hitting EOF early resolves to [`ReadExactError::Eof`], which
+    /// carries the number of bytes that were successfully read into the start
+    /// of `buf`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::io::{AsyncReadExt, Cursor, ReadExactError};
+    ///
+    /// let mut reader = Cursor::new([1, 2, 3, 4]);
+    /// let mut output = [0u8; 5];
+    ///
+    /// let result = reader.read_exact_or_eof(&mut output).await;
+    ///
+    /// assert!(matches!(result, Err(ReadExactError::Eof { read: 4 })));
+    /// assert_eq!(output[..4], [1, 2, 3, 4]);
+    /// # });
+    /// ```
+    fn read_exact_or_eof<'a>(&'a mut self, buf: &'a mut [u8]) -> ReadExactOrEof<'a, Self>
+    where
+        Self: Unpin,
+    {
+        assert_future::<std::result::Result<(), ReadExactError>, _>(ReadExactOrEof::new(self, buf))
+    }
+
     /// Creates a future which will read all the bytes from this `AsyncRead`.
     ///
     /// On success the total number of bytes read is returned.
@@ -316,6 +440,82 @@ pub trait AsyncReadExt: AsyncRead {
         assert_future::<Result<usize>, _>(ReadToString::new(self, buf))
     }
 
+    /// Creates a future which will read all the bytes from this `AsyncRead`,
+    /// but no more than `limit` of them.
+    ///
+    /// On success the total number of bytes read is returned. If the reader
+    /// has more than `limit` bytes to return, the future resolves to
+    /// [`ReadToEndError::LimitExceeded`] instead.
+    ///
+    /// To tell a reader holding exactly `limit` bytes apart from a longer
+    /// one, up to `limit + 1` bytes are read. All bytes read, including in
+    /// the error case, are left in `buf` and the reader is positioned right
+    /// after them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::io::{AsyncReadExt, Cursor, ReadToEndError};
+    ///
+    /// let mut reader = Cursor::new([1, 2, 3, 4]);
+    /// let mut output = Vec::new();
+    ///
+    /// let result = reader.read_to_end_limited(&mut output, 2).await;
+    ///
+    /// assert!(matches!(result, Err(ReadToEndError::LimitExceeded { limit: 2 })));
+    /// assert_eq!(output, vec![1, 2, 3]);
+    /// # });
+    /// ```
+    fn read_to_end_limited<'a>(
+        &'a mut self,
+        buf: &'a mut Vec<u8>,
+        limit: usize,
+    ) -> ReadToEndLimited<'a, Self>
+    where
+        Self: Unpin,
+    {
+        assert_future::<std::result::Result<usize, ReadToEndError>, _>(ReadToEndLimited::new(
+            self, buf, limit,
+        ))
+    }
+
+    /// Creates a future which will read all the bytes from this `AsyncRead`
+    /// into a `String`, but no more than `limit` of them.
+    ///
+    /// This has the same limit semantics as
+    /// [`read_to_end_limited`](AsyncReadExt::read_to_end_limited). If the
+    /// limit cuts a multi-byte character in half, only the complete
+    /// characters before it are appended to `buf`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::io::{AsyncReadExt, Cursor};
+    ///
+    /// let mut reader = Cursor::new(&b"1234"[..]);
+    /// let mut buffer = String::new();
+    ///
+    /// let bytes = reader.read_to_string_limited(&mut buffer, 4).await?;
+    ///
+    /// assert_eq!(bytes, 4);
+    /// assert_eq!(buffer, String::from("1234"));
+    /// # Ok::<(), Box<dyn std::error::Error>>(()) }).unwrap();
+    /// ```
+    fn read_to_string_limited<'a>(
+        &'a mut self,
+        buf: &'a mut String,
+        limit: usize,
+    ) -> ReadToStringLimited<'a, Self>
+    where
+        Self: Unpin,
+    {
+        assert_future::<std::result::Result<usize, ReadToEndError>, _>(ReadToStringLimited::new(
+            self, buf, limit,
+        ))
+    }
+
     /// Helper method for splitting this read/write object into two halves.
     ///
     /// The two halves returned implement the `AsyncRead` and `AsyncWrite`
@@ -379,6 +579,42 @@ pub trait AsyncReadExt: AsyncRead {
         assert_read(Take::new(self, limit))
     }
 
+    /// Creates an AsyncRead adapter which writes all bytes read from this
+    /// reader to `writer` as well.
+    ///
+    /// Each chunk of data read from this reader is written to `writer` in
+    /// full before the read completes, so a slow writer slows down reading.
+    /// At most one chunk is buffered while waiting for the writer. The writer
+    /// isn't flushed; use [`into_parts`](Tee::into_parts) to get it back and
+    /// flush it once done reading.
+    ///
+    /// If writing fails, the read returns that error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::io::{AsyncReadExt, Cursor};
+    ///
+    /// let reader = Cursor::new(&b"hello world"[..]);
+    /// let mut tee = reader.tee(Vec::new());
+    ///
+    /// let mut word = [0; 5];
+    /// tee.read_exact(&mut word).await?;
+    /// assert_eq!(&word, b"hello");
+    ///
+    /// let (_, captured) = tee.into_parts();
+    /// assert_eq!(captured, b"hello");
+    /// # Ok::<(), Box<dyn std::error::Error>>(()) }).unwrap();
+    /// ```
+    fn tee<W>(self, writer: W) -> Tee<Self, W>
+    where
+        W: AsyncWrite,
+        Self: Sized,
+    {
+        assert_read(Tee::new(self, writer))
+    }
+
     /// Wraps an [`AsyncRead`] in a compatibility wrapper that allows it to be
     /// used as a futures 0.1 / tokio-io 0.1 `AsyncRead`. If the wrapped type
     /// implements [`AsyncWrite`] as well, the result will also implement the
@@ -587,6 +823,93 @@ pub trait AsyncWriteExt: AsyncWrite {
     {
         crate::sink::assert_sink::<Item, Error, _>(IntoSink::new(self))
     }
+
+    /// Allow using an [`AsyncWrite`] as a [`Sink`](futures_sink::Sink)`<Item: AsRef<[u8]>>`,
+    /// coalescing small values into a buffer of the given capacity.
+    ///
+    /// This works like [`into_sink`](AsyncWriteExt::into_sink), except that
+    /// values which fit into the remaining capacity of the buffer are copied
+    /// into it, and the buffer is only written to the underlying writer once it
+    /// is full, or when the sink is flushed or closed. Values larger than the
+    /// buffer are written directly once the buffer has been written. This
+    /// reduces the number of writes if many small values are sent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::io::AsyncWriteExt;
+    /// use futures::sink::SinkExt;
+    ///
+    /// let mut writer = vec![];
+    /// let mut sink = (&mut writer).into_sink_buffered(16);
+    ///
+    /// sink.feed("hello ").await?;
+    /// sink.feed("world").await?;
+    /// sink.close().await?;
+    ///
+    /// assert_eq!(writer, b"hello world");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// # })?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[cfg(feature = "sink")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "sink")))]
+    fn into_sink_buffered<Item: AsRef<[u8]>>(self, capacity: usize) -> IntoSink<Self, Item>
+    where
+        Self: Sized,
+    {
+        crate::sink::assert_sink::<Item, Error, _>(IntoSink::with_capacity(self, capacity))
+    }
+
+    /// Wraps this writer so that it is flushed once `every_bytes` bytes have
+    /// been written since the last flush, or `every` has elapsed since the
+    /// first of them was written, whichever happens first.
+    ///
+    /// To stay independent of any runtime, waiting is left to `sleep`, which
+    /// is called with `every` to create a future which completes after that
+    /// long. As there is no task driving the writer on its own, writes only
+    /// check the deadline when they happen; poll
+    /// [`AutoFlush::poll_flush_due`] while the writer is idle to flush the
+    /// bytes written right before in time. Explicit flushes work as usual,
+    /// and closing the writer flushes any remaining bytes first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use core::time::Duration;
+    /// use futures::future;
+    /// use futures::io::{AllowStdIo, AsyncWriteExt};
+    /// use std::io::{BufWriter, Cursor};
+    ///
+    /// let writer = AllowStdIo::new(BufWriter::new(Cursor::new(Vec::new())));
+    /// let mut writer = writer.auto_flush(
+    ///     4,
+    ///     Duration::from_secs(1),
+    ///     // This would be the `sleep` function of a runtime.
+    ///     |_every| future::pending(),
+    /// );
+    ///
+    /// writer.write_all(&[1, 2, 3]).await?;
+    /// assert_eq!(writer.get_ref().get_ref().buffer(), &[1, 2, 3]);
+    /// writer.write_all(&[4, 5]).await?;
+    /// assert_eq!(writer.get_ref().get_ref().buffer(), &[]);
+    /// # Ok::<(), Box<dyn std::error::Error>>(()) }).unwrap();
+    /// ```
+    fn auto_flush<S, Fut>(
+        self,
+        every_bytes: usize,
+        every: Duration,
+        sleep: S,
+    ) -> AutoFlush<Self, S, Fut>
+    where
+        S: FnMut(Duration) -> Fut,
+        Fut: Future<Output = ()>,
+        Self: Sized,
+    {
+        assert_write(AutoFlush::new(self, every_bytes, every, sleep))
+    }
 }
 
 impl<W: AsyncWrite + ?Sized> AsyncWriteExt for W {}
@@ -615,6 +938,45 @@ pub trait AsyncSeekExt: AsyncSeek {
     {
         self.seek(SeekFrom::Current(0))
     }
+
+    /// Creates a future which will seek to the start of the stream.
+    ///
+    /// This is equivalent to `self.seek(SeekFrom::Start(0))`, except that the
+    /// position isn't returned.
+    fn rewind(&mut self) -> Rewind<'_, Self>
+    where
+        Self: Unpin,
+    {
+        assert_future::<Result<()>, _>(Rewind::new(self))
+    }
+
+    /// Creates a future which will return the length of the stream in bytes.
+    ///
+    /// This seeks to the end of the stream to find its length, and then back
+    /// to the original position, unless the stream was already at the end.
+    /// An error while seeking back is returned as well, in which case the
+    /// position of the stream is unspecified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::io::{AsyncReadExt, AsyncSeekExt, Cursor};
+    ///
+    /// let mut cursor = Cursor::new(b"hello world");
+    /// let mut buf = [0; 5];
+    /// cursor.read_exact(&mut buf).await?;
+    ///
+    /// assert_eq!(cursor.stream_len().await?, 11);
+    /// assert_eq!(cursor.stream_position().await?, 5);
+    /// # Ok::<(), Box<dyn std::error::Error>>(()) }).unwrap();
+    /// ```
+    fn stream_len(&mut self) -> StreamLen<'_, Self>
+    where
+        Self: Unpin,
+    {
+        assert_future::<Result<u64>, _>(StreamLen::new(self))
+    }
 }
 
 impl<S: AsyncSeek + ?Sized> AsyncSeekExt for S {}
@@ -723,6 +1085,118 @@ pub trait AsyncBufReadExt: AsyncBufRead {
         assert_future::<Result<usize>, _>(ReadUntil::new(self, byte, buf))
     }
 
+    /// Like [`read_until`](AsyncBufReadExt::read_until), but stops with an
+    /// error if `limit` bytes have been read without finding the delimiter.
+    ///
+    /// This protects against peers that never send the delimiter, which would
+    /// otherwise make `buf` grow without bound. The delimiter counts towards
+    /// `limit`, so a delimiter found as the `limit`-th byte still completes the
+    /// read successfully.
+    ///
+    /// # Errors
+    ///
+    /// If the limit is reached, the returned future resolves to an error of
+    /// kind [`InvalidData`](std::io::ErrorKind::InvalidData) wrapping a
+    /// [`ReadUntilLimitExceeded`]. The `limit` bytes which were read are left
+    /// in `buf`, and no more than that is consumed from the reader, so the
+    /// caller can decide how to continue.
+    ///
+    /// Otherwise, this has the same error semantics as
+    /// [`read_until`](AsyncBufReadExt::read_until).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::io::{AsyncBufReadExt, Cursor};
+    ///
+    /// let mut cursor = Cursor::new(b"lorem-ipsum-dolor");
+    /// let mut buf = vec![];
+    ///
+    /// let num_bytes = cursor.read_until_limited(b'-', &mut buf, 6).await?;
+    /// assert_eq!(num_bytes, 6);
+    /// assert_eq!(buf, b"lorem-");
+    /// buf.clear();
+    ///
+    /// assert!(cursor.read_until_limited(b'-', &mut buf, 4).await.is_err());
+    /// assert_eq!(buf, b"ipsu");
+    /// # Ok::<(), Box<dyn std::error::Error>>(()) }).unwrap();
+    /// ```
+    fn read_until_limited<'a>(
+        &'a mut self,
+        byte: u8,
+        buf: &'a mut Vec<u8>,
+        limit: usize,
+    ) -> ReadUntilLimited<'a, Self>
+    where
+        Self: Unpin,
+    {
+        assert_future::<Result<usize>, _>(ReadUntilLimited::new(self, byte, buf, limit))
+    }
+
+    /// Creates a future which will skip all the bytes of this I/O object
+    /// until the delimiter `byte` or EOF is reached.
+    ///
+    /// This works like [`read_until`](AsyncBufReadExt::read_until), except
+    /// that the bytes are consumed from the buffer without being copied
+    /// anywhere. The returned future resolves to the number of bytes skipped,
+    /// including the delimiter if it was found, which is only 0 if EOF was
+    /// reached immediately.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::io::{AsyncBufReadExt, Cursor};
+    ///
+    /// let mut cursor = Cursor::new(b"header\nbody");
+    /// assert_eq!(cursor.skip_until(b'\n').await?, 7);
+    ///
+    /// let mut body = String::new();
+    /// cursor.read_line(&mut body).await?;
+    /// assert_eq!(body, "body");
+    /// assert_eq!(cursor.skip_until(b'\n').await?, 0);
+    /// # Ok::<(), Box<dyn std::error::Error>>(()) }).unwrap();
+    /// ```
+    fn skip_until(&mut self, byte: u8) -> SkipUntil<'_, Self>
+    where
+        Self: Unpin,
+    {
+        assert_future::<Result<u64>, _>(SkipUntil::new(self, byte))
+    }
+
+    /// Creates a future which will skip exactly `n` bytes of this I/O object.
+    ///
+    /// The bytes are consumed from the buffer without being copied anywhere.
+    ///
+    /// # Errors
+    ///
+    /// If EOF is reached before `n` bytes have been skipped, the returned
+    /// future resolves to an error of kind
+    /// [`UnexpectedEof`](std::io::ErrorKind::UnexpectedEof). The bytes up to
+    /// EOF have been consumed in that case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::io::{AsyncBufReadExt, AsyncReadExt, Cursor};
+    ///
+    /// let mut cursor = Cursor::new(b"\x00\x00\x00\x00data");
+    /// cursor.skip_exact(4).await?;
+    ///
+    /// let mut data = Vec::new();
+    /// cursor.read_to_end(&mut data).await?;
+    /// assert_eq!(data, b"data");
+    /// # Ok::<(), Box<dyn std::error::Error>>(()) }).unwrap();
+    /// ```
+    fn skip_exact(&mut self, n: u64) -> SkipExact<'_, Self>
+    where
+        Self: Unpin,
+    {
+        assert_future::<Result<()>, _>(SkipExact::new(self, n))
+    }
+
     /// Creates a future which will read all the bytes associated with this I/O
     /// object into `buf` until a newline (the 0xA byte) or EOF is reached,
     /// This method is the async equivalent to [`BufRead::read_line`](std::io::BufRead::read_line).
@@ -819,6 +1293,49 @@ pub trait AsyncBufReadExt: AsyncBufRead {
     {
         assert_stream::<Result<String>, _>(Lines::new(self))
     }
+
+    /// Returns a stream over the lines of this reader, which are limited to
+    /// at most `limit` bytes each.
+    ///
+    /// This works like [`lines`](AsyncBufReadExt::lines), but never buffers
+    /// much more than `limit` bytes, protecting against peers which never send
+    /// a newline. The line terminator (`\n` or `\r\n`) doesn't count towards
+    /// the limit.
+    ///
+    /// # Errors
+    ///
+    /// A line longer than `limit` bytes is yielded as an error of kind
+    /// [`InvalidData`](std::io::ErrorKind::InvalidData) wrapping a
+    /// [`LineTooLongError`]. What happens afterwards is decided by
+    /// `on_too_long`: with [`LineTooLong::Skip`], the rest of the line is
+    /// discarded and the stream continues with the next line, while with
+    /// [`LineTooLong::Terminate`], the stream ends.
+    ///
+    /// Otherwise, each line of the stream has the same error semantics as
+    /// [`AsyncBufReadExt::read_line`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::io::{AsyncBufReadExt, Cursor, LineTooLong};
+    /// use futures::stream::StreamExt;
+    ///
+    /// let cursor = Cursor::new(b"lorem\nipsum dolor sit amet\r\ndolor");
+    ///
+    /// let mut lines_stream = cursor.lines_with_max(5, LineTooLong::Skip);
+    /// assert_eq!(lines_stream.next().await.unwrap()?, "lorem");
+    /// assert!(lines_stream.next().await.unwrap().is_err());
+    /// assert_eq!(lines_stream.next().await.unwrap()?, "dolor");
+    /// assert!(lines_stream.next().await.is_none());
+    /// # Ok::<(), Box<dyn std::error::Error>>(()) }).unwrap();
+    /// ```
+    fn lines_with_max(self, limit: usize, on_too_long: LineTooLong) -> LinesWithMax<Self>
+    where
+        Self: Sized,
+    {
+        assert_stream::<Result<String>, _>(LinesWithMax::new(self, limit, on_too_long))
+    }
 }
 
 impl<R: AsyncBufRead + ?Sized> AsyncBufReadExt for R {}
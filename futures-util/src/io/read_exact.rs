@@ -1,10 +1,11 @@
-use crate::io::AsyncRead;
+use crate::io::{AsyncRead, ReadBuf};
 use futures_core::future::Future;
 use futures_core::ready;
 use futures_core::task::{Context, Poll};
 use std::io;
 use std::mem;
 use std::pin::Pin;
+use std::{error, fmt};
 
 /// Future for the [`read_exact`](super::AsyncReadExt::read_exact) method.
 #[derive(Debug)]
@@ -22,21 +23,112 @@ impl<'a, R: AsyncRead + ?Sized + Unpin> ReadExact<'a, R> {
     }
 }
 
+// Reads into `buf` until it is full, advancing `buf` past the bytes read.
+// Resolves to `Ok(false)` if EOF was reached before `buf` was filled.
+fn read_exact_internal<R: AsyncRead + ?Sized>(
+    mut reader: Pin<&mut R>,
+    cx: &mut Context<'_>,
+    buf: &mut &mut [u8],
+) -> Poll<io::Result<bool>> {
+    while !buf.is_empty() {
+        let n = ready!(super::poll_read_buf(reader.as_mut(), cx, &mut ReadBuf::new(buf)))?;
+        {
+            let (_, rest) = mem::replace(buf, &mut []).split_at_mut(n);
+            *buf = rest;
+        }
+        if n == 0 {
+            return Poll::Ready(Ok(false));
+        }
+    }
+    Poll::Ready(Ok(true))
+}
+
 impl<R: AsyncRead + ?Sized + Unpin> Future for ReadExact<'_, R> {
     type Output = io::Result<()>;
 
     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
         let this = &mut *self;
-        while !this.buf.is_empty() {
-            let n = ready!(Pin::new(&mut this.reader).poll_read(cx, this.buf))?;
-            {
-                let (_, rest) = mem::replace(&mut this.buf, &mut []).split_at_mut(n);
-                this.buf = rest;
-            }
-            if n == 0 {
-                return Poll::Ready(Err(io::ErrorKind::UnexpectedEof.into()));
-            }
+        if ready!(read_exact_internal(Pin::new(&mut this.reader), cx, &mut this.buf))? {
+            Poll::Ready(Ok(()))
+        } else {
+            Poll::Ready(Err(io::ErrorKind::UnexpectedEof.into()))
+        }
+    }
+}
+
+/// Future for the [`read_exact_or_eof`](super::AsyncReadExt::read_exact_or_eof) method.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct ReadExactOrEof<'a, R: ?Sized> {
+    reader: &'a mut R,
+    buf: &'a mut [u8],
+    len: usize,
+}
+
+impl<R: ?Sized + Unpin> Unpin for ReadExactOrEof<'_, R> {}
+
+impl<'a, R: AsyncRead + ?Sized + Unpin> ReadExactOrEof<'a, R> {
+    pub(super) fn new(reader: &'a mut R, buf: &'a mut [u8]) -> Self {
+        let len = buf.len();
+        Self { reader, buf, len }
+    }
+}
+
+impl<R: AsyncRead + ?Sized + Unpin> Future for ReadExactOrEof<'_, R> {
+    type Output = Result<(), ReadExactError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        if ready!(read_exact_internal(Pin::new(&mut this.reader), cx, &mut this.buf))? {
+            Poll::Ready(Ok(()))
+        } else {
+            Poll::Ready(Err(ReadExactError::Eof { read: this.len - this.buf.len() }))
+        }
+    }
+}
+
+/// Error returned by [`read_exact_or_eof`](super::AsyncReadExt::read_exact_or_eof).
+#[derive(Debug)]
+pub enum ReadExactError {
+    /// An I/O error occurred while reading.
+    Io(io::Error),
+    /// EOF was reached before the buffer was filled.
+    Eof {
+        /// The number of bytes successfully read into the start of the
+        /// buffer before EOF.
+        read: usize,
+    },
+}
+
+impl fmt::Display for ReadExactError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e) => fmt::Display::fmt(e, f),
+            Self::Eof { read } => write!(f, "unexpected EOF after reading {} bytes", read),
+        }
+    }
+}
+
+impl error::Error for ReadExactError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            Self::Eof { .. } => None,
+        }
+    }
+}
+
+impl From<io::Error> for ReadExactError {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+impl From<ReadExactError> for io::Error {
+    fn from(e: ReadExactError) -> Self {
+        match e {
+            ReadExactError::Io(e) => e,
+            e @ ReadExactError::Eof { .. } => io::Error::new(io::ErrorKind::UnexpectedEof, e),
         }
-        Poll::Ready(Ok(()))
     }
 }
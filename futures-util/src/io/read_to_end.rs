@@ -1,10 +1,14 @@
 use futures_core::future::Future;
 use futures_core::ready;
 use futures_core::task::{Context, Poll};
-use futures_io::AsyncRead;
+use futures_io::{AsyncRead, ReadBuf};
+use std::cmp;
 use std::io;
+use std::mem::MaybeUninit;
 use std::pin::Pin;
+use std::slice;
 use std::vec::Vec;
+use std::{error, fmt};
 
 /// Future for the [`read_to_end`](super::AsyncReadExt::read_to_end) method.
 #[derive(Debug)]
@@ -13,6 +17,8 @@ pub struct ReadToEnd<'a, R: ?Sized> {
     reader: &'a mut R,
     buf: &'a mut Vec<u8>,
     start_len: usize,
+    initialized: usize,
+    yield_threshold: Option<usize>,
 }
 
 impl<R: ?Sized + Unpin> Unpin for ReadToEnd<'_, R> {}
@@ -20,19 +26,100 @@ impl<R: ?Sized + Unpin> Unpin for ReadToEnd<'_, R> {}
 impl<'a, R: AsyncRead + ?Sized + Unpin> ReadToEnd<'a, R> {
     pub(super) fn new(reader: &'a mut R, buf: &'a mut Vec<u8>) -> Self {
         let start_len = buf.len();
-        Self { reader, buf, start_len }
+        Self { reader, buf, start_len, initialized: 0, yield_threshold: None }
+    }
+
+    /// Makes the read yield back to the task's executor once it has read at
+    /// least `bytes` bytes within a single poll.
+    ///
+    /// By default, reading keeps going for as long as the reader is ready,
+    /// which can starve other tasks sharing the executor when reading large
+    /// amounts of data from a reader which is always ready, like an in-memory
+    /// buffer. With a yield threshold, the future instead wakes its task and
+    /// returns [`Poll::Pending`] after reading that many bytes, giving other
+    /// tasks a chance to run before it continues.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::io::{AsyncReadExt, Cursor};
+    ///
+    /// let mut reader = Cursor::new(vec![0u8; 1 << 20]);
+    /// let mut output = Vec::new();
+    ///
+    /// let bytes = reader.read_to_end(&mut output).with_yield_threshold(64 * 1024).await?;
+    /// assert_eq!(bytes, 1 << 20);
+    /// # Ok::<(), Box<dyn std::error::Error>>(()) }).unwrap();
+    /// ```
+    pub fn with_yield_threshold(mut self, bytes: usize) -> Self {
+        self.yield_threshold = Some(bytes);
+        self
     }
 }
 
-struct Guard<'a> {
+/// Future for the [`read_to_end_limited`](super::AsyncReadExt::read_to_end_limited) method.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct ReadToEndLimited<'a, R: ?Sized> {
+    reader: &'a mut R,
     buf: &'a mut Vec<u8>,
-    len: usize,
+    start_len: usize,
+    initialized: usize,
+    limit: usize,
+}
+
+impl<R: ?Sized + Unpin> Unpin for ReadToEndLimited<'_, R> {}
+
+impl<'a, R: AsyncRead + ?Sized + Unpin> ReadToEndLimited<'a, R> {
+    pub(super) fn new(reader: &'a mut R, buf: &'a mut Vec<u8>, limit: usize) -> Self {
+        let start_len = buf.len();
+        Self { reader, buf, start_len, initialized: 0, limit }
+    }
+}
+
+/// Error returned by [`read_to_end_limited`](super::AsyncReadExt::read_to_end_limited)
+/// and [`read_to_string_limited`](super::AsyncReadExt::read_to_string_limited).
+#[derive(Debug)]
+pub enum ReadToEndError {
+    /// An I/O error occurred while reading.
+    Io(io::Error),
+    /// The reader had more than `limit` bytes to return.
+    LimitExceeded {
+        /// The limit that was exceeded.
+        limit: usize,
+    },
 }
 
-impl Drop for Guard<'_> {
-    fn drop(&mut self) {
-        unsafe {
-            self.buf.set_len(self.len);
+impl fmt::Display for ReadToEndError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e) => fmt::Display::fmt(e, f),
+            Self::LimitExceeded { limit } => write!(f, "read limit of {} bytes exceeded", limit),
+        }
+    }
+}
+
+impl error::Error for ReadToEndError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            Self::LimitExceeded { .. } => None,
+        }
+    }
+}
+
+impl From<io::Error> for ReadToEndError {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+impl From<ReadToEndError> for io::Error {
+    fn from(e: ReadToEndError) -> Self {
+        match e {
+            ReadToEndError::Io(e) => e,
+            e @ ReadToEndError::LimitExceeded { .. } => io::Error::new(io::ErrorKind::Other, e),
         }
     }
 }
@@ -44,40 +131,89 @@ impl Drop for Guard<'_> {
 // time is 4,500 times (!) slower than this if the reader has a very small
 // amount of data to return.
 //
-// Because we're extending the buffer with uninitialized data for trusted
-// readers, we need to make sure to truncate that if any of this panics.
-pub(super) fn read_to_end_internal<R: AsyncRead + ?Sized>(
+// The spare capacity of `buf` is read into with `poll_read_buf`, so readers
+// which don't need it to be initialized don't pay for zeroing it.
+// `initialized` tracks how much of the spare capacity is initialized across
+// calls, so readers which do need it don't zero the same memory twice.
+//
+// At most `max` bytes are appended to `buf` past `start_len`; once that many
+// have been read, no further reads are issued. If `yield_threshold` is set,
+// this returns `Poll::Pending` after immediately waking the task once that
+// many bytes have been read within this call.
+fn read_to_end_max_internal<R: AsyncRead + ?Sized>(
     mut rd: Pin<&mut R>,
     cx: &mut Context<'_>,
     buf: &mut Vec<u8>,
     start_len: usize,
+    initialized: &mut usize,
+    max: usize,
+    yield_threshold: Option<usize>,
 ) -> Poll<io::Result<usize>> {
-    let mut g = Guard { len: buf.len(), buf };
+    let poll_start_len = buf.len();
     loop {
-        if g.len == g.buf.len() {
-            unsafe {
-                g.buf.reserve(32);
-                let capacity = g.buf.capacity();
-                g.buf.set_len(capacity);
-                super::initialize(&rd, &mut g.buf[g.len..]);
-            }
+        let remaining = max - (buf.len() - start_len);
+        if remaining == 0 {
+            return Poll::Ready(Ok(max));
+        }
+
+        if buf.len() == buf.capacity() {
+            buf.reserve(32);
+            // The contents of the spare capacity aren't kept when reallocating.
+            *initialized = 0;
         }
 
-        let buf = &mut g.buf[g.len..];
-        match ready!(rd.as_mut().poll_read(cx, buf)) {
-            Ok(0) => return Poll::Ready(Ok(g.len - start_len)),
+        let len = buf.len();
+        // Before Rust 1.37, `Vec::as_mut_ptr` resolves to the slice method,
+        // which returns the same pointer.
+        #[allow(clippy::incompatible_msrv)]
+        let spare = unsafe {
+            &mut slice::from_raw_parts_mut(buf.as_mut_ptr() as *mut MaybeUninit<u8>, buf.capacity())
+                [len..]
+        };
+        let window = cmp::min(spare.len(), remaining);
+        let mut read_buf = ReadBuf::uninit(&mut spare[..window]);
+        // Safety: the caller passes in how much of the spare capacity has
+        // been initialized by previous reads.
+        unsafe { read_buf.assume_init(cmp::min(*initialized, window)) };
+
+        match ready!(super::poll_read_buf(rd.as_mut(), cx, &mut read_buf)) {
+            Ok(0) => return Poll::Ready(Ok(len - start_len)),
             Ok(n) => {
-                // We can't allow bogus values from read. If it is too large, the returned vec could have its length
-                // set past its capacity, or if it overflows the vec could be shortened which could create an invalid
-                // string if this is called via read_to_string.
-                assert!(n <= buf.len());
-                g.len += n;
+                *initialized = cmp::max(*initialized, read_buf.initialized().len()) - n;
+                // Safety: `poll_read_buf` made sure that the first `n` bytes
+                // of the spare capacity have been filled.
+                unsafe { buf.set_len(len + n) };
+                if yield_threshold
+                    .map_or(false, |threshold| buf.len() - poll_start_len >= threshold)
+                {
+                    cx.waker().wake_by_ref();
+                    return Poll::Pending;
+                }
             }
             Err(e) => return Poll::Ready(Err(e)),
         }
     }
 }
 
+pub(super) fn read_to_end_internal<R: AsyncRead + ?Sized>(
+    rd: Pin<&mut R>,
+    cx: &mut Context<'_>,
+    buf: &mut Vec<u8>,
+    start_len: usize,
+    initialized: &mut usize,
+    yield_threshold: Option<usize>,
+) -> Poll<io::Result<usize>> {
+    read_to_end_max_internal(
+        rd,
+        cx,
+        buf,
+        start_len,
+        initialized,
+        usize::max_value(),
+        yield_threshold,
+    )
+}
+
 impl<A> Future for ReadToEnd<'_, A>
 where
     A: AsyncRead + ?Sized + Unpin,
@@ -86,6 +222,51 @@ where
 
     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
         let this = &mut *self;
-        read_to_end_internal(Pin::new(&mut this.reader), cx, this.buf, this.start_len)
+        read_to_end_internal(
+            Pin::new(&mut this.reader),
+            cx,
+            this.buf,
+            this.start_len,
+            &mut this.initialized,
+            this.yield_threshold,
+        )
+    }
+}
+
+// Reads up to `limit + 1` bytes so that a reader holding exactly `limit`
+// bytes can be told apart from one holding more.
+pub(super) fn read_to_end_limited_internal<R: AsyncRead + ?Sized>(
+    rd: Pin<&mut R>,
+    cx: &mut Context<'_>,
+    buf: &mut Vec<u8>,
+    start_len: usize,
+    initialized: &mut usize,
+    limit: usize,
+) -> Poll<Result<usize, ReadToEndError>> {
+    let max = limit.saturating_add(1);
+    let n = ready!(read_to_end_max_internal(rd, cx, buf, start_len, initialized, max, None))?;
+    if n > limit {
+        Poll::Ready(Err(ReadToEndError::LimitExceeded { limit }))
+    } else {
+        Poll::Ready(Ok(n))
+    }
+}
+
+impl<A> Future for ReadToEndLimited<'_, A>
+where
+    A: AsyncRead + ?Sized + Unpin,
+{
+    type Output = Result<usize, ReadToEndError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        read_to_end_limited_internal(
+            Pin::new(&mut this.reader),
+            cx,
+            this.buf,
+            this.start_len,
+            &mut this.initialized,
+            this.limit,
+        )
     }
 }
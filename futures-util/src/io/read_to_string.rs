@@ -1,4 +1,4 @@
-use super::read_to_end::read_to_end_internal;
+use super::read_to_end::{read_to_end_internal, read_to_end_limited_internal, ReadToEndError};
 use futures_core::future::Future;
 use futures_core::ready;
 use futures_core::task::{Context, Poll};
@@ -15,6 +15,7 @@ pub struct ReadToString<'a, R: ?Sized> {
     buf: &'a mut String,
     bytes: Vec<u8>,
     start_len: usize,
+    initialized: usize,
 }
 
 impl<R: ?Sized + Unpin> Unpin for ReadToString<'_, R> {}
@@ -22,7 +23,8 @@ impl<R: ?Sized + Unpin> Unpin for ReadToString<'_, R> {}
 impl<'a, R: AsyncRead + ?Sized + Unpin> ReadToString<'a, R> {
     pub(super) fn new(reader: &'a mut R, buf: &'a mut String) -> Self {
         let start_len = buf.len();
-        Self { reader, bytes: mem::replace(buf, String::new()).into_bytes(), buf, start_len }
+        let bytes = mem::replace(buf, String::new()).into_bytes();
+        Self { reader, bytes, buf, start_len, initialized: 0 }
     }
 }
 
@@ -32,8 +34,9 @@ fn read_to_string_internal<R: AsyncRead + ?Sized>(
     buf: &mut String,
     bytes: &mut Vec<u8>,
     start_len: usize,
+    initialized: &mut usize,
 ) -> Poll<io::Result<usize>> {
-    let ret = ready!(read_to_end_internal(reader, cx, bytes, start_len));
+    let ret = ready!(read_to_end_internal(reader, cx, bytes, start_len, initialized, None));
     if str::from_utf8(bytes).is_err() {
         Poll::Ready(ret.and_then(|_| {
             Err(io::Error::new(io::ErrorKind::InvalidData, "stream did not contain valid UTF-8"))
@@ -53,7 +56,84 @@ where
     type Output = io::Result<usize>;
 
     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        let Self { reader, buf, bytes, start_len } = &mut *self;
-        read_to_string_internal(Pin::new(reader), cx, buf, bytes, *start_len)
+        let Self { reader, buf, bytes, start_len, initialized } = &mut *self;
+        read_to_string_internal(Pin::new(reader), cx, buf, bytes, *start_len, initialized)
+    }
+}
+
+/// Future for the [`read_to_string_limited`](super::AsyncReadExt::read_to_string_limited) method.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct ReadToStringLimited<'a, R: ?Sized> {
+    reader: &'a mut R,
+    buf: &'a mut String,
+    bytes: Vec<u8>,
+    start_len: usize,
+    initialized: usize,
+    limit: usize,
+}
+
+impl<R: ?Sized + Unpin> Unpin for ReadToStringLimited<'_, R> {}
+
+impl<'a, R: AsyncRead + ?Sized + Unpin> ReadToStringLimited<'a, R> {
+    pub(super) fn new(reader: &'a mut R, buf: &'a mut String, limit: usize) -> Self {
+        let start_len = buf.len();
+        let bytes = mem::replace(buf, String::new()).into_bytes();
+        Self { reader, bytes, buf, start_len, initialized: 0, limit }
+    }
+}
+
+fn read_to_string_limited_internal<R: AsyncRead + ?Sized>(
+    reader: Pin<&mut R>,
+    cx: &mut Context<'_>,
+    buf: &mut String,
+    bytes: &mut Vec<u8>,
+    start_len: usize,
+    initialized: &mut usize,
+    limit: usize,
+) -> Poll<Result<usize, ReadToEndError>> {
+    let ret =
+        ready!(read_to_end_limited_internal(reader, cx, bytes, start_len, initialized, limit));
+    let valid_len = match (str::from_utf8(bytes), &ret) {
+        (Ok(_), _) => bytes.len(),
+        // The limit may have cut a multi-byte character in half, in which
+        // case only the complete characters before it are kept.
+        (Err(e), Err(ReadToEndError::LimitExceeded { .. })) if e.error_len().is_none() => {
+            e.valid_up_to()
+        }
+        (Err(_), _) => {
+            return Poll::Ready(ret.and_then(|_| {
+                Err(ReadToEndError::Io(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "stream did not contain valid UTF-8",
+                )))
+            }))
+        }
+    };
+    bytes.truncate(valid_len);
+    debug_assert!(buf.is_empty());
+    // Safety: `bytes` is a valid UTF-8 because `str::from_utf8` succeeded on it
+    // or on the prefix it was truncated to.
+    mem::swap(unsafe { buf.as_mut_vec() }, bytes);
+    Poll::Ready(ret)
+}
+
+impl<A> Future for ReadToStringLimited<'_, A>
+where
+    A: AsyncRead + ?Sized + Unpin,
+{
+    type Output = Result<usize, ReadToEndError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let Self { reader, buf, bytes, start_len, initialized, limit } = &mut *self;
+        read_to_string_limited_internal(
+            Pin::new(reader),
+            cx,
+            buf,
+            bytes,
+            *start_len,
+            initialized,
+            *limit,
+        )
     }
 }
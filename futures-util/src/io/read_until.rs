@@ -2,9 +2,8 @@ use futures_core::future::Future;
 use futures_core::ready;
 use futures_core::task::{Context, Poll};
 use futures_io::AsyncBufRead;
-use std::io;
-use std::mem;
 use std::pin::Pin;
+use std::{cmp, error, fmt, io, mem};
 
 /// Future for the [`read_until`](super::AsyncBufReadExt::read_until) method.
 #[derive(Debug)]
@@ -24,6 +23,51 @@ impl<'a, R: AsyncBufRead + ?Sized + Unpin> ReadUntil<'a, R> {
     }
 }
 
+/// Future for the [`read_until_limited`](super::AsyncBufReadExt::read_until_limited) method.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct ReadUntilLimited<'a, R: ?Sized> {
+    reader: &'a mut R,
+    byte: u8,
+    buf: &'a mut Vec<u8>,
+    read: usize,
+    limit: usize,
+}
+
+impl<R: ?Sized + Unpin> Unpin for ReadUntilLimited<'_, R> {}
+
+impl<'a, R: AsyncBufRead + ?Sized + Unpin> ReadUntilLimited<'a, R> {
+    pub(super) fn new(reader: &'a mut R, byte: u8, buf: &'a mut Vec<u8>, limit: usize) -> Self {
+        Self { reader, byte, buf, read: 0, limit }
+    }
+}
+
+/// Error wrapped in the [`io::Error`] returned by
+/// [`read_until_limited`](super::AsyncBufReadExt::read_until_limited) when the
+/// limit was reached before the delimiter.
+///
+/// The `io::Error` has [`io::ErrorKind::InvalidData`], and this error can be
+/// retrieved from it using [`io::Error::get_ref`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct ReadUntilLimitExceeded {
+    limit: usize,
+}
+
+impl ReadUntilLimitExceeded {
+    /// Returns the limit which was reached.
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+}
+
+impl fmt::Display for ReadUntilLimitExceeded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "delimiter not found within {} bytes", self.limit)
+    }
+}
+
+impl error::Error for ReadUntilLimitExceeded {}
+
 pub(super) fn read_until_internal<R: AsyncBufRead + ?Sized>(
     mut reader: Pin<&mut R>,
     cx: &mut Context<'_>,
@@ -50,6 +94,39 @@ pub(super) fn read_until_internal<R: AsyncBufRead + ?Sized>(
     }
 }
 
+pub(super) fn read_until_limited_internal<R: AsyncBufRead + ?Sized>(
+    mut reader: Pin<&mut R>,
+    cx: &mut Context<'_>,
+    byte: u8,
+    buf: &mut Vec<u8>,
+    read: &mut usize,
+    limit: usize,
+) -> Poll<io::Result<usize>> {
+    loop {
+        if *read >= limit {
+            *read = 0;
+            let error = ReadUntilLimitExceeded { limit };
+            return Poll::Ready(Err(io::Error::new(io::ErrorKind::InvalidData, error)));
+        }
+        let (done, used) = {
+            let available = ready!(reader.as_mut().poll_fill_buf(cx))?;
+            let available = &available[..cmp::min(available.len(), limit - *read)];
+            if let Some(i) = memchr::memchr(byte, available) {
+                buf.extend_from_slice(&available[..=i]);
+                (true, i + 1)
+            } else {
+                buf.extend_from_slice(available);
+                (false, available.len())
+            }
+        };
+        reader.as_mut().consume(used);
+        *read += used;
+        if done || used == 0 {
+            return Poll::Ready(Ok(mem::replace(read, 0)));
+        }
+    }
+}
+
 impl<R: AsyncBufRead + ?Sized + Unpin> Future for ReadUntil<'_, R> {
     type Output = io::Result<usize>;
 
@@ -58,3 +135,12 @@ impl<R: AsyncBufRead + ?Sized + Unpin> Future for ReadUntil<'_, R> {
         read_until_internal(Pin::new(reader), cx, *byte, buf, read)
     }
 }
+
+impl<R: AsyncBufRead + ?Sized + Unpin> Future for ReadUntilLimited<'_, R> {
+    type Output = io::Result<usize>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let Self { reader, byte, buf, read, limit } = &mut *self;
+        read_until_limited_internal(Pin::new(reader), cx, *byte, buf, read, *limit)
+    }
+}
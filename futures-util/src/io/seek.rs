@@ -28,3 +28,26 @@ impl<S: AsyncSeek + ?Sized + Unpin> Future for Seek<'_, S> {
         Pin::new(&mut this.seek).poll_seek(cx, this.pos)
     }
 }
+
+/// Future for the [`rewind`](crate::io::AsyncSeekExt::rewind) method.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Rewind<'a, S: ?Sized> {
+    inner: Seek<'a, S>,
+}
+
+impl<S: ?Sized + Unpin> Unpin for Rewind<'_, S> {}
+
+impl<'a, S: AsyncSeek + ?Sized + Unpin> Rewind<'a, S> {
+    pub(super) fn new(seek: &'a mut S) -> Self {
+        Self { inner: Seek::new(seek, SeekFrom::Start(0)) }
+    }
+}
+
+impl<S: AsyncSeek + ?Sized + Unpin> Future for Rewind<'_, S> {
+    type Output = io::Result<()>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.inner).poll(cx).map_ok(drop)
+    }
+}
@@ -50,6 +50,11 @@ impl AsyncWrite for Sink {
         Poll::Ready(Ok(bufs.iter().map(|b| b.len()).sum()))
     }
 
+    #[inline]
+    fn is_write_vectored(&self) -> bool {
+        true
+    }
+
     #[inline]
     fn poll_flush(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<io::Result<()>> {
         Poll::Ready(Ok(()))
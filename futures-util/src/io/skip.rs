@@ -0,0 +1,87 @@
+use futures_core::future::Future;
+use futures_core::ready;
+use futures_core::task::{Context, Poll};
+use futures_io::AsyncBufRead;
+use std::io;
+use std::pin::Pin;
+
+/// Future for the [`skip_until`](super::AsyncBufReadExt::skip_until) method.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct SkipUntil<'a, R: ?Sized> {
+    reader: &'a mut R,
+    byte: u8,
+    skipped: u64,
+}
+
+impl<R: ?Sized + Unpin> Unpin for SkipUntil<'_, R> {}
+
+impl<'a, R: AsyncBufRead + ?Sized + Unpin> SkipUntil<'a, R> {
+    pub(super) fn new(reader: &'a mut R, byte: u8) -> Self {
+        Self { reader, byte, skipped: 0 }
+    }
+}
+
+impl<R: AsyncBufRead + ?Sized + Unpin> Future for SkipUntil<'_, R> {
+    type Output = io::Result<u64>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let Self { reader, byte, skipped } = &mut *self;
+        let mut reader = Pin::new(reader);
+        loop {
+            let (done, used) = {
+                let available = ready!(reader.as_mut().poll_fill_buf(cx))?;
+                match memchr::memchr(*byte, available) {
+                    Some(i) => (true, i + 1),
+                    None => (false, available.len()),
+                }
+            };
+            reader.as_mut().consume(used);
+            *skipped += used as u64;
+            if done || used == 0 {
+                return Poll::Ready(Ok(*skipped));
+            }
+        }
+    }
+}
+
+/// Future for the [`skip_exact`](super::AsyncBufReadExt::skip_exact) method.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct SkipExact<'a, R: ?Sized> {
+    reader: &'a mut R,
+    remaining: u64,
+}
+
+impl<R: ?Sized + Unpin> Unpin for SkipExact<'_, R> {}
+
+impl<'a, R: AsyncBufRead + ?Sized + Unpin> SkipExact<'a, R> {
+    pub(super) fn new(reader: &'a mut R, n: u64) -> Self {
+        Self { reader, remaining: n }
+    }
+}
+
+impl<R: AsyncBufRead + ?Sized + Unpin> Future for SkipExact<'_, R> {
+    type Output = io::Result<()>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let Self { reader, remaining } = &mut *self;
+        let mut reader = Pin::new(reader);
+        while *remaining > 0 {
+            let used = {
+                let available = ready!(reader.as_mut().poll_fill_buf(cx))?;
+                if available.is_empty() {
+                    return Poll::Ready(Err(io::ErrorKind::UnexpectedEof.into()));
+                }
+                if (available.len() as u64) < *remaining {
+                    available.len()
+                } else {
+                    *remaining as usize
+                }
+            };
+            reader.as_mut().consume(used);
+            *remaining -= used as u64;
+        }
+        Poll::Ready(Ok(()))
+    }
+}
@@ -1,21 +1,44 @@
 use crate::lock::BiLock;
-use core::fmt;
+use core::{cmp, fmt};
 use futures_core::ready;
 use futures_core::task::{Context, Poll};
-use futures_io::{AsyncRead, AsyncWrite, IoSlice, IoSliceMut};
+use futures_io::{AsyncBufRead, AsyncRead, AsyncSeek, AsyncWrite, IoSlice, IoSliceMut, SeekFrom};
 use std::io;
 use std::pin::Pin;
 
 /// The readable half of an object returned from `AsyncRead::split`.
+///
+/// If the object implements [`AsyncBufRead`], so does this half. As the
+/// object can't stay locked while the caller holds on to the data returned by
+/// [`poll_fill_buf`](AsyncBufRead::poll_fill_buf), that data is copied into a
+/// buffer owned by this half. Consuming it is passed on to the object the
+/// next time this half locks it, or when the halves are reunited.
+///
+/// If the object implements [`AsyncSeek`], this half is the one that does,
+/// too.
 #[derive(Debug)]
 pub struct ReadHalf<T> {
     handle: BiLock<T>,
+    buffered: Buffered<T>,
+}
+
+#[derive(Debug)]
+struct Buffered<T> {
+    // Copy of the data returned by the object's `poll_fill_buf`.
+    buf: Vec<u8>,
+    // How much of `buf` has been consumed by the caller, but not yet passed
+    // on to the object.
+    pos: usize,
+    // Passes on consuming data to the object, set once `buf` has been filled.
+    consume: Option<fn(Pin<&mut T>, usize)>,
 }
 
 /// The writable half of an object returned from `AsyncRead::split`.
 #[derive(Debug)]
 pub struct WriteHalf<T> {
     handle: BiLock<T>,
+    // Queried before splitting, as the object can't be locked to do so later.
+    is_write_vectored: bool,
 }
 
 fn lock_and_then<T, U, E, F>(lock: &BiLock<T>, cx: &mut Context<'_>, f: F) -> Poll<Result<U, E>>
@@ -27,18 +50,74 @@ where
 }
 
 pub(super) fn split<T: AsyncRead + AsyncWrite>(t: T) -> (ReadHalf<T>, WriteHalf<T>) {
+    let is_write_vectored = t.is_write_vectored();
     let (a, b) = BiLock::new(t);
-    (ReadHalf { handle: a }, WriteHalf { handle: b })
+    let buffered = Buffered { buf: Vec::new(), pos: 0, consume: None };
+    (ReadHalf { handle: a, buffered }, WriteHalf { handle: b, is_write_vectored })
+}
+
+fn consume<T: AsyncBufRead>(t: Pin<&mut T>, amt: usize) {
+    t.consume(amt)
+}
+
+impl<T> Buffered<T> {
+    // Passes on the data consumed from `buf` and discards the rest of it, as
+    // the object is about to be used directly.
+    fn pass_on_consumed(&mut self, t: Pin<&mut T>) {
+        if self.pos > 0 {
+            (self.consume.unwrap())(t, self.pos);
+        }
+        self.buf.clear();
+        self.pos = 0;
+    }
+
+    fn read(&mut self, buf: &mut [u8]) -> Option<usize> {
+        if self.pos == self.buf.len() {
+            return None;
+        }
+        let n = cmp::min(buf.len(), self.buf.len() - self.pos);
+        buf[..n].copy_from_slice(&self.buf[self.pos..self.pos + n]);
+        self.pos += n;
+        Some(n)
+    }
+}
+
+impl<T> ReadHalf<T> {
+    /// Returns `true` if this `ReadHalf` and `other` originated from the same
+    /// call to `AsyncReadExt::split`, which means they can be `reunite`d.
+    pub fn is_pair_of(&self, other: &WriteHalf<T>) -> bool {
+        self.handle.is_pair_of(&other.handle)
+    }
+}
+
+impl<T> WriteHalf<T> {
+    /// Returns `true` if this `WriteHalf` and `other` originated from the same
+    /// call to `AsyncReadExt::split`, which means they can be `reunite`d.
+    pub fn is_pair_of(&self, other: &ReadHalf<T>) -> bool {
+        other.is_pair_of(self)
+    }
 }
 
 impl<T: Unpin> ReadHalf<T> {
     /// Attempts to put the two "halves" of a split `AsyncRead + AsyncWrite` back
     /// together. Succeeds only if the `ReadHalf<T>` and `WriteHalf<T>` are
     /// a matching pair originating from the same call to `AsyncReadExt::split`.
+    ///
+    /// Otherwise, both halves are returned in the [`ReuniteError`], so they
+    /// can still be used.
     pub fn reunite(self, other: WriteHalf<T>) -> Result<T, ReuniteError<T>> {
-        self.handle
-            .reunite(other.handle)
-            .map_err(|err| ReuniteError(ReadHalf { handle: err.0 }, WriteHalf { handle: err.1 }))
+        let ReadHalf { handle, mut buffered } = self;
+        let WriteHalf { handle: other, is_write_vectored } = other;
+        match handle.reunite(other) {
+            Ok(mut t) => {
+                buffered.pass_on_consumed(Pin::new(&mut t));
+                Ok(t)
+            }
+            Err(err) => Err(ReuniteError(
+                ReadHalf { handle: err.0, buffered },
+                WriteHalf { handle: err.1, is_write_vectored },
+            )),
+        }
     }
 }
 
@@ -57,7 +136,13 @@ impl<R: AsyncRead> AsyncRead for ReadHalf<R> {
         cx: &mut Context<'_>,
         buf: &mut [u8],
     ) -> Poll<io::Result<usize>> {
-        lock_and_then(&self.handle, cx, |l, cx| l.poll_read(cx, buf))
+        let this = self.get_mut();
+        if let Some(n) = this.buffered.read(buf) {
+            return Poll::Ready(Ok(n));
+        }
+        let mut l = ready!(this.handle.poll_lock(cx));
+        this.buffered.pass_on_consumed(l.as_pin_mut());
+        l.as_pin_mut().poll_read(cx, buf)
     }
 
     fn poll_read_vectored(
@@ -65,7 +150,47 @@ impl<R: AsyncRead> AsyncRead for ReadHalf<R> {
         cx: &mut Context<'_>,
         bufs: &mut [IoSliceMut<'_>],
     ) -> Poll<io::Result<usize>> {
-        lock_and_then(&self.handle, cx, |l, cx| l.poll_read_vectored(cx, bufs))
+        let this = self.get_mut();
+        if let Some(buf) = bufs.iter_mut().find(|b| !b.is_empty()) {
+            if let Some(n) = this.buffered.read(buf) {
+                return Poll::Ready(Ok(n));
+            }
+        }
+        let mut l = ready!(this.handle.poll_lock(cx));
+        this.buffered.pass_on_consumed(l.as_pin_mut());
+        l.as_pin_mut().poll_read_vectored(cx, bufs)
+    }
+}
+
+impl<R: AsyncBufRead> AsyncBufRead for ReadHalf<R> {
+    fn poll_fill_buf(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<&[u8]>> {
+        let this = self.get_mut();
+        let buffered = &mut this.buffered;
+        if buffered.pos == buffered.buf.len() {
+            let mut l = ready!(this.handle.poll_lock(cx));
+            buffered.consume = Some(consume::<R>);
+            buffered.pass_on_consumed(l.as_pin_mut());
+            buffered.buf.extend_from_slice(ready!(l.as_pin_mut().poll_fill_buf(cx))?);
+        }
+        Poll::Ready(Ok(&buffered.buf[buffered.pos..]))
+    }
+
+    fn consume(self: Pin<&mut Self>, amt: usize) {
+        let buffered = &mut self.get_mut().buffered;
+        buffered.pos = cmp::min(buffered.pos + amt, buffered.buf.len());
+    }
+}
+
+impl<S: AsyncSeek> AsyncSeek for ReadHalf<S> {
+    fn poll_seek(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        pos: SeekFrom,
+    ) -> Poll<io::Result<u64>> {
+        let this = self.get_mut();
+        let mut l = ready!(this.handle.poll_lock(cx));
+        this.buffered.pass_on_consumed(l.as_pin_mut());
+        l.as_pin_mut().poll_seek(cx, pos)
     }
 }
 
@@ -86,6 +211,10 @@ impl<W: AsyncWrite> AsyncWrite for WriteHalf<W> {
         lock_and_then(&self.handle, cx, |l, cx| l.poll_write_vectored(cx, bufs))
     }
 
+    fn is_write_vectored(&self) -> bool {
+        self.is_write_vectored
+    }
+
     fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
         lock_and_then(&self.handle, cx, |l, cx| l.poll_flush(cx))
     }
@@ -0,0 +1,59 @@
+use crate::io::{AsyncSeek, SeekFrom};
+use futures_core::future::Future;
+use futures_core::ready;
+use futures_core::task::{Context, Poll};
+use std::io;
+use std::pin::Pin;
+
+/// Future for the [`stream_len`](crate::io::AsyncSeekExt::stream_len) method.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct StreamLen<'a, S: ?Sized> {
+    seek: &'a mut S,
+    state: State,
+}
+
+#[derive(Debug)]
+enum State {
+    Start,
+    End { old_pos: u64 },
+    Restore { len: u64, old_pos: u64 },
+}
+
+impl<S: ?Sized + Unpin> Unpin for StreamLen<'_, S> {}
+
+impl<'a, S: AsyncSeek + ?Sized + Unpin> StreamLen<'a, S> {
+    pub(super) fn new(seek: &'a mut S) -> Self {
+        Self { seek, state: State::Start }
+    }
+}
+
+impl<S: AsyncSeek + ?Sized + Unpin> Future for StreamLen<'_, S> {
+    type Output = io::Result<u64>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        loop {
+            match this.state {
+                State::Start => {
+                    let old_pos =
+                        ready!(Pin::new(&mut this.seek).poll_seek(cx, SeekFrom::Current(0)))?;
+                    this.state = State::End { old_pos };
+                }
+                State::End { old_pos } => {
+                    let len = ready!(Pin::new(&mut this.seek).poll_seek(cx, SeekFrom::End(0)))?;
+                    // There is no need to seek back if the stream was already
+                    // at the end.
+                    if len == old_pos {
+                        return Poll::Ready(Ok(len));
+                    }
+                    this.state = State::Restore { len, old_pos };
+                }
+                State::Restore { len, old_pos } => {
+                    ready!(Pin::new(&mut this.seek).poll_seek(cx, SeekFrom::Start(old_pos)))?;
+                    return Poll::Ready(Ok(len));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,131 @@
+use futures_core::ready;
+use futures_core::task::{Context, Poll};
+use futures_io::{AsyncRead, AsyncWrite};
+use pin_project_lite::pin_project;
+use std::pin::Pin;
+use std::{cmp, fmt, io};
+
+pin_project! {
+    /// Reader for the [`tee`](super::AsyncReadExt::tee) method.
+    #[must_use = "readers do nothing unless polled"]
+    pub struct Tee<R, W> {
+        #[pin]
+        reader: R,
+        #[pin]
+        writer: W,
+        // The chunk which was read last, if it hasn't been returned yet.
+        buf: Vec<u8>,
+        written: usize,
+        returned: usize,
+    }
+}
+
+impl<R, W> Tee<R, W>
+where
+    R: AsyncRead,
+    W: AsyncWrite,
+{
+    pub(super) fn new(reader: R, writer: W) -> Self {
+        Self { reader, writer, buf: Vec::new(), written: 0, returned: 0 }
+    }
+
+    /// Gets references to the underlying reader and writer in this `Tee`.
+    pub fn get_ref(&self) -> (&R, &W) {
+        (&self.reader, &self.writer)
+    }
+
+    /// Gets mutable references to the underlying reader and writer in this
+    /// `Tee`.
+    ///
+    /// Care should be taken to avoid modifying the internal I/O state of the
+    /// underlying reader and writer as doing so may corrupt the internal state
+    /// of this `Tee`.
+    pub fn get_mut(&mut self) -> (&mut R, &mut W) {
+        (&mut self.reader, &mut self.writer)
+    }
+
+    /// Gets pinned mutable references to the underlying reader and writer in
+    /// this `Tee`.
+    ///
+    /// Care should be taken to avoid modifying the internal I/O state of the
+    /// underlying reader and writer as doing so may corrupt the internal state
+    /// of this `Tee`.
+    pub fn get_pin_mut(self: Pin<&mut Self>) -> (Pin<&mut R>, Pin<&mut W>) {
+        let this = self.project();
+        (this.reader, this.writer)
+    }
+
+    /// Consumes the `Tee`, returning the underlying reader and writer.
+    ///
+    /// The writer isn't flushed, so this should be done before the writer is
+    /// dropped. Data which has been read but not returned yet, because a read
+    /// has been waiting for the writer, is discarded.
+    pub fn into_parts(self) -> (R, W) {
+        (self.reader, self.writer)
+    }
+}
+
+impl<R, W> fmt::Debug for Tee<R, W>
+where
+    R: fmt::Debug,
+    W: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Tee")
+            .field("reader", &self.reader)
+            .field("writer", &self.writer)
+            .field("pending", &(self.buf.len() - self.returned))
+            .finish()
+    }
+}
+
+impl<R, W> AsyncRead for Tee<R, W>
+where
+    R: AsyncRead,
+    W: AsyncWrite,
+{
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let mut this = self.project();
+
+        // The data is only returned once it has been written, so the last
+        // chunk either has to be written or returned before reading more.
+        let fresh = if *this.returned < this.buf.len() {
+            false
+        } else {
+            let n = ready!(this.reader.poll_read(cx, buf))?;
+            if n == 0 {
+                return Poll::Ready(Ok(0));
+            }
+            this.buf.clear();
+            this.buf.extend_from_slice(&buf[..n]);
+            *this.written = 0;
+            *this.returned = 0;
+            true
+        };
+
+        while *this.written < this.buf.len() {
+            let n = ready!(this.writer.as_mut().poll_write(cx, &this.buf[*this.written..]))?;
+            if n == 0 {
+                return Poll::Ready(Err(io::Error::new(
+                    io::ErrorKind::WriteZero,
+                    "failed to write the whole buffer",
+                )));
+            }
+            *this.written += n;
+        }
+
+        let n = if fresh {
+            this.buf.len()
+        } else {
+            let n = cmp::min(buf.len(), this.buf.len() - *this.returned);
+            buf[..n].copy_from_slice(&this.buf[*this.returned..*this.returned + n]);
+            n
+        };
+        *this.returned += n;
+        Poll::Ready(Ok(n))
+    }
+}
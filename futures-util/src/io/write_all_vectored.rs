@@ -30,7 +30,13 @@ impl<W: AsyncWrite + ?Sized + Unpin> Future for WriteAllVectored<'_, '_, W> {
     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
         let this = &mut *self;
         while !this.bufs.is_empty() {
-            let n = ready!(Pin::new(&mut this.writer).poll_write_vectored(cx, this.bufs))?;
+            let mut writer = Pin::new(&mut this.writer);
+            let n = if writer.is_write_vectored() {
+                ready!(writer.poll_write_vectored(cx, this.bufs))?
+            } else {
+                // `advance_slices` skipped the empty buffers already.
+                ready!(writer.poll_write(cx, &this.bufs[0]))?
+            };
             if n == 0 {
                 return Poll::Ready(Err(io::ErrorKind::WriteZero.into()));
             } else {
@@ -20,8 +20,11 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![allow(clippy::needless_borrow)] // https://github.com/rust-lang/futures-rs/pull/2558#issuecomment-1030745203
 
-#[cfg(all(feature = "bilock", not(feature = "unstable")))]
-compile_error!("The `bilock` feature requires the `unstable` feature as an explicit opt-in to unstable features");
+#[cfg(all(feature = "join-array", not(feature = "unstable")))]
+compile_error!("The `join-array` feature requires the `unstable` feature as an explicit opt-in to unstable features");
+
+#[cfg(all(feature = "into-future", not(feature = "unstable")))]
+compile_error!("The `into-future` feature requires the `unstable` feature as an explicit opt-in to unstable features");
 
 #[cfg(feature = "alloc")]
 extern crate alloc;
@@ -126,6 +129,9 @@ macro_rules! delegate_async_write {
         ) -> core::task::Poll<std::io::Result<usize>> {
             self.project().$field.poll_write_vectored(cx, bufs)
         }
+        fn is_write_vectored(&self) -> bool {
+            self.$field.is_write_vectored()
+        }
         fn poll_flush(
             self: core::pin::Pin<&mut Self>,
             cx: &mut core::task::Context<'_>,
@@ -160,6 +166,14 @@ macro_rules! delegate_async_read {
         ) -> core::task::Poll<std::io::Result<usize>> {
             self.project().$field.poll_read_vectored(cx, bufs)
         }
+
+        fn poll_read_buf(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+            buf: &mut futures_io::ReadBuf<'_>,
+        ) -> core::task::Poll<std::io::Result<()>> {
+            self.project().$field.poll_read_buf(cx, buf)
+        }
     };
 }
 
@@ -333,4 +347,6 @@ pub mod lock;
 mod abortable;
 
 mod fns;
+#[cfg(feature = "std")]
+mod park;
 mod unfold_state;
@@ -4,11 +4,12 @@ use alloc::boxed::Box;
 use alloc::sync::Arc;
 use core::cell::UnsafeCell;
 use core::fmt;
+use core::marker::PhantomData;
+use core::mem;
 use core::ops::{Deref, DerefMut};
 use core::pin::Pin;
 use core::sync::atomic::AtomicUsize;
 use core::sync::atomic::Ordering::SeqCst;
-#[cfg(feature = "bilock")]
 use futures_core::future::Future;
 use futures_core::task::{Context, Poll, Waker};
 
@@ -30,11 +31,7 @@ use futures_core::task::{Context, Poll, Waker};
 /// example a TCP stream could be both a reader and a writer or a framing layer
 /// could be both a stream and a sink for messages. A `BiLock` enables splitting
 /// these two and then using each independently in a futures-powered fashion.
-///
-/// This type is only available when the `bilock` feature of this
-/// library is activated.
 #[derive(Debug)]
-#[cfg_attr(docsrs, doc(cfg(feature = "bilock")))]
 pub struct BiLock<T> {
     arc: Arc<Inner<T>>,
 }
@@ -66,6 +63,31 @@ impl<T> BiLock<T> {
         (Self { arc: arc.clone() }, Self { arc })
     }
 
+    /// Attempt to acquire this lock immediately, without registering the
+    /// current task to be notified.
+    ///
+    /// If the lock is currently held, through either of the two handles, this
+    /// will return `None`. This makes it possible to access the data from
+    /// outside the context of a task.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::lock::BiLock;
+    ///
+    /// let (a, b) = BiLock::new(1);
+    /// let guard = a.try_lock().unwrap();
+    /// assert!(b.try_lock().is_none());
+    /// drop(guard);
+    /// assert_eq!(*b.try_lock().unwrap(), 1);
+    /// ```
+    pub fn try_lock(&self) -> Option<BiLockGuard<'_, T>> {
+        match self.arc.state.compare_exchange(0, 1, SeqCst, SeqCst) {
+            Ok(_) => Some(BiLockGuard { bilock: self }),
+            Err(_) => None,
+        }
+    }
+
     /// Attempt to acquire this lock, returning `Pending` if it can't be
     /// acquired.
     ///
@@ -139,12 +161,16 @@ impl<T> BiLock<T> {
     /// `BiLockGuard<T>`.
     ///
     /// Note that the returned future will never resolve to an error.
-    #[cfg(feature = "bilock")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "bilock")))]
     pub fn lock(&self) -> BiLockAcquire<'_, T> {
         BiLockAcquire { bilock: self }
     }
 
+    /// Returns `true` if this `BiLock<T>` and `other` originated from the same
+    /// call to `BiLock::new`, which means they can be `reunite`d.
+    pub fn is_pair_of(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.arc, &other.arc)
+    }
+
     /// Attempts to put the two "halves" of a `BiLock<T>` back together and
     /// recover the original value. Succeeds only if the two `BiLock<T>`s
     /// originated from the same call to `BiLock::new`.
@@ -152,7 +178,7 @@ impl<T> BiLock<T> {
     where
         T: Unpin,
     {
-        if Arc::ptr_eq(&self.arc, &other.arc) {
+        if self.is_pair_of(&other) {
             drop(other);
             let inner = Arc::try_unwrap(self.arc)
                 .ok()
@@ -195,7 +221,6 @@ impl<T> Drop for Inner<T> {
 
 /// Error indicating two `BiLock<T>`s were not two halves of a whole, and
 /// thus could not be `reunite`d.
-#[cfg_attr(docsrs, doc(cfg(feature = "bilock")))]
 pub struct ReuniteError<T>(pub BiLock<T>, pub BiLock<T>);
 
 impl<T> fmt::Debug for ReuniteError<T> {
@@ -219,7 +244,6 @@ impl<T: core::any::Any> std::error::Error for ReuniteError<T> {}
 /// implementing `Deref` and `DerefMut` to `T`. When dropped, the lock will be
 /// unlocked.
 #[derive(Debug)]
-#[cfg_attr(docsrs, doc(cfg(feature = "bilock")))]
 pub struct BiLockGuard<'a, T> {
     bilock: &'a BiLock<T>,
 }
@@ -249,16 +273,130 @@ impl<T> BiLockGuard<'_, T> {
     }
 }
 
+impl<'a, T> BiLockGuard<'a, T> {
+    /// Returns a locked view over a portion of the locked data.
+    ///
+    /// As the locked data is pinned, it's projected through `Pin` references.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::lock::{BiLock, BiLockGuard};
+    /// use std::pin::Pin;
+    ///
+    /// let (a, b) = BiLock::new((1, "one"));
+    /// let mut first = BiLockGuard::map(a.try_lock().unwrap(), |pair| {
+    ///     Pin::new(&mut Pin::into_inner(pair).0)
+    /// });
+    /// *first += 1;
+    /// assert!(b.try_lock().is_none());
+    /// drop(first);
+    ///
+    /// assert_eq!(a.reunite(b).unwrap(), (2, "one"));
+    /// ```
+    #[inline]
+    pub fn map<U: ?Sized, F>(mut this: Self, f: F) -> MappedBiLockGuard<'a, T, U>
+    where
+        F: FnOnce(Pin<&mut T>) -> Pin<&mut U>,
+    {
+        let bilock = this.bilock;
+        // Safety: the returned reference is only ever handed out pinned again,
+        // unless `U` is `Unpin`.
+        let value: *mut U = unsafe { f(this.as_pin_mut()).get_unchecked_mut() };
+        // Don't run the `drop` method for BiLockGuard. The ownership of the
+        // underlying locked state is being moved to the returned
+        // MappedBiLockGuard.
+        mem::forget(this);
+        MappedBiLockGuard { bilock, value, _marker: PhantomData }
+    }
+}
+
 impl<T> Drop for BiLockGuard<'_, T> {
     fn drop(&mut self) {
         self.bilock.unlock();
     }
 }
 
+/// An RAII guard returned by the `BiLockGuard::map` and
+/// `MappedBiLockGuard::map` methods. When this structure is dropped, the lock
+/// will be unlocked.
+pub struct MappedBiLockGuard<'a, T, U: ?Sized> {
+    bilock: &'a BiLock<T>,
+    value: *mut U,
+    _marker: PhantomData<&'a mut U>,
+}
+
+// The guard gives access to `U`, and shares the lock itself, which is only
+// `Sync` if `T` is `Send`.
+unsafe impl<T: Send, U: ?Sized + Send> Send for MappedBiLockGuard<'_, T, U> {}
+unsafe impl<T: Send, U: ?Sized + Sync> Sync for MappedBiLockGuard<'_, T, U> {}
+
+impl<'a, T, U: ?Sized> MappedBiLockGuard<'a, T, U> {
+    /// Returns a locked view over a portion of the locked data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::lock::{BiLock, BiLockGuard, MappedBiLockGuard};
+    /// use std::pin::Pin;
+    ///
+    /// let (a, _b) = BiLock::new(((1, 2), 3));
+    /// let inner = BiLockGuard::map(a.try_lock().unwrap(), |pair| {
+    ///     Pin::new(&mut Pin::into_inner(pair).0)
+    /// });
+    /// let second = MappedBiLockGuard::map(inner, |pair| Pin::new(&mut Pin::into_inner(pair).1));
+    /// assert_eq!(*second, 2);
+    /// ```
+    #[inline]
+    pub fn map<V: ?Sized, F>(mut this: Self, f: F) -> MappedBiLockGuard<'a, T, V>
+    where
+        F: FnOnce(Pin<&mut U>) -> Pin<&mut V>,
+    {
+        let bilock = this.bilock;
+        // Safety: see `BiLockGuard::map`.
+        let value: *mut V = unsafe { f(this.as_pin_mut()).get_unchecked_mut() };
+        // Don't run the `drop` method for MappedBiLockGuard. The ownership of
+        // the underlying locked state is being moved to the returned
+        // MappedBiLockGuard.
+        mem::forget(this);
+        MappedBiLockGuard { bilock, value, _marker: PhantomData }
+    }
+
+    /// Get a mutable pinned reference to the locked value.
+    pub fn as_pin_mut(&mut self) -> Pin<&mut U> {
+        // Safety: the value was projected from the pinned locked value, and
+        // is only handed out pinned, unless `U` is `Unpin`.
+        unsafe { Pin::new_unchecked(&mut *self.value) }
+    }
+}
+
+impl<T, U: ?Sized + fmt::Debug> fmt::Debug for MappedBiLockGuard<'_, T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MappedBiLockGuard").field("value", &&**self).finish()
+    }
+}
+
+impl<T, U: ?Sized> Deref for MappedBiLockGuard<'_, T, U> {
+    type Target = U;
+    fn deref(&self) -> &U {
+        unsafe { &*self.value }
+    }
+}
+
+impl<T, U: ?Sized + Unpin> DerefMut for MappedBiLockGuard<'_, T, U> {
+    fn deref_mut(&mut self) -> &mut U {
+        unsafe { &mut *self.value }
+    }
+}
+
+impl<T, U: ?Sized> Drop for MappedBiLockGuard<'_, T, U> {
+    fn drop(&mut self) {
+        self.bilock.unlock();
+    }
+}
+
 /// Future returned by `BiLock::lock` which will resolve when the lock is
 /// acquired.
-#[cfg(feature = "bilock")]
-#[cfg_attr(docsrs, doc(cfg(feature = "bilock")))]
 #[must_use = "futures do nothing unless you `.await` or poll them"]
 #[derive(Debug)]
 pub struct BiLockAcquire<'a, T> {
@@ -266,10 +404,8 @@ pub struct BiLockAcquire<'a, T> {
 }
 
 // Pinning is never projected to fields
-#[cfg(feature = "bilock")]
 impl<T> Unpin for BiLockAcquire<'_, T> {}
 
-#[cfg(feature = "bilock")]
 impl<'a, T> Future for BiLockAcquire<'a, T> {
     type Output = BiLockGuard<'a, T>;
 
@@ -1,25 +1,36 @@
+use crate::park::park_on;
 use futures_core::future::{FusedFuture, Future};
 use futures_core::task::{Context, Poll, Waker};
+use futures_task::__private::{enter, Entered};
 use slab::Slab;
 use std::cell::UnsafeCell;
+use std::collections::VecDeque;
 use std::marker::PhantomData;
 use std::ops::{Deref, DerefMut};
 use std::pin::Pin;
 use std::sync::atomic::{AtomicUsize, Ordering};
-use std::sync::Mutex as StdMutex;
-use std::{fmt, mem};
+use std::sync::{Arc, Mutex as StdMutex};
+use std::{fmt, mem, ptr};
 
 /// A futures-aware mutex.
 ///
 /// # Fairness
 ///
-/// This mutex provides no fairness guarantees. Tasks may not acquire the mutex
-/// in the order that they requested the lock, and it's possible for a single task
-/// which repeatedly takes the lock to starve other tasks, which may be left waiting
-/// indefinitely.
+/// A mutex created with [`Mutex::new`] provides no fairness guarantees. Tasks may
+/// not acquire the mutex in the order that they requested the lock, and it's
+/// possible for a single task which repeatedly takes the lock to starve other tasks,
+/// which may be left waiting indefinitely.
+///
+/// A mutex created with [`Mutex::new_fair`] instead serves waiting tasks in the
+/// order they first polled their [`lock`](Mutex::lock) future. When such a mutex
+/// is unlocked while tasks are waiting, it is handed over directly to the task
+/// that has been waiting longest, and [`try_lock`](Mutex::try_lock) fails for as
+/// long as any task is waiting. This bounds how long a waiting task can be
+/// overtaken, at the cost of some throughput under contention.
 pub struct Mutex<T: ?Sized> {
     state: AtomicUsize,
-    waiters: StdMutex<Slab<Waiter>>,
+    fair: bool,
+    waiters: StdMutex<Waiters>,
     value: UnsafeCell<T>,
 }
 
@@ -29,6 +40,7 @@ impl<T: ?Sized> fmt::Debug for Mutex<T> {
         f.debug_struct("Mutex")
             .field("is_locked", &((state & IS_LOCKED) != 0))
             .field("has_waiters", &((state & HAS_WAITERS) != 0))
+            .field("is_fair", &self.fair)
             .finish()
     }
 }
@@ -45,9 +57,18 @@ impl<T: Default> Default for Mutex<T> {
     }
 }
 
+struct Waiters {
+    slab: Slab<Waiter>,
+    // Keys of the waiters in `slab` which are still waiting, in the order they
+    // started waiting. Only maintained for fair mutexes.
+    queue: VecDeque<usize>,
+}
+
 enum Waiter {
     Waiting(Waker),
     Woken,
+    // The lock has been handed over to this waiter; only used by fair mutexes.
+    Granted,
 }
 
 impl Waiter {
@@ -59,9 +80,14 @@ impl Waiter {
     }
 
     fn wake(&mut self) {
-        match mem::replace(self, Self::Woken) {
-            Self::Waiting(waker) => waker.wake(),
-            Self::Woken => {}
+        if let Self::Waiting(waker) = mem::replace(self, Self::Woken) {
+            waker.wake();
+        }
+    }
+
+    fn grant(&mut self) {
+        if let Self::Waiting(waker) = mem::replace(self, Self::Granted) {
+            waker.wake();
         }
     }
 }
@@ -72,9 +98,31 @@ const HAS_WAITERS: usize = 1 << 1;
 impl<T> Mutex<T> {
     /// Creates a new futures-aware mutex.
     pub fn new(t: T) -> Self {
+        Self::with_fairness(t, false)
+    }
+
+    /// Creates a new futures-aware mutex which serves waiting tasks in the
+    /// order they started waiting.
+    ///
+    /// See the [type-level documentation](Mutex#fairness) for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::lock::Mutex;
+    ///
+    /// let mutex = Mutex::new_fair(0);
+    /// assert!(mutex.is_fair());
+    /// ```
+    pub fn new_fair(t: T) -> Self {
+        Self::with_fairness(t, true)
+    }
+
+    fn with_fairness(t: T, fair: bool) -> Self {
         Self {
             state: AtomicUsize::new(0),
-            waiters: StdMutex::new(Slab::new()),
+            fair,
+            waiters: StdMutex::new(Waiters { slab: Slab::new(), queue: VecDeque::new() }),
             value: UnsafeCell::new(t),
         }
     }
@@ -95,18 +143,52 @@ impl<T> Mutex<T> {
 }
 
 impl<T: ?Sized> Mutex<T> {
+    /// Returns whether this mutex was created with [`Mutex::new_fair`], and
+    /// thus serves waiting tasks in the order they started waiting.
+    pub fn is_fair(&self) -> bool {
+        self.fair
+    }
+
     /// Attempt to acquire the lock immediately.
     ///
-    /// If the lock is currently held, this will return `None`.
+    /// If the lock is currently held, this will return `None`. For a fair
+    /// mutex, this will also return `None` if other tasks are waiting for the
+    /// lock.
     pub fn try_lock(&self) -> Option<MutexGuard<'_, T>> {
-        let old_state = self.state.fetch_or(IS_LOCKED, Ordering::Acquire);
-        if (old_state & IS_LOCKED) == 0 {
+        if self.try_acquire() {
             Some(MutexGuard { mutex: self })
         } else {
             None
         }
     }
 
+    /// Attempt to acquire the lock immediately, returning a guard which keeps
+    /// the mutex alive through the `Arc`, rather than borrowing it.
+    ///
+    /// If the lock is currently held, this will return `None`. For a fair
+    /// mutex, this will also return `None` if other tasks are waiting for the
+    /// lock.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::lock::Mutex;
+    /// use std::sync::Arc;
+    ///
+    /// let mutex = Arc::new(Mutex::new(0));
+    /// let guard = mutex.try_lock_owned().unwrap();
+    /// assert!(mutex.try_lock_owned().is_none());
+    /// drop(guard);
+    /// assert!(mutex.try_lock_owned().is_some());
+    /// ```
+    pub fn try_lock_owned(self: &Arc<Self>) -> Option<OwnedMutexGuard<T>> {
+        if self.try_acquire() {
+            Some(OwnedMutexGuard { mutex: self.clone() })
+        } else {
+            None
+        }
+    }
+
     /// Acquire the lock asynchronously.
     ///
     /// This method returns a future that will resolve once the lock has been
@@ -115,6 +197,80 @@ impl<T: ?Sized> Mutex<T> {
         MutexLockFuture { mutex: Some(self), wait_key: WAIT_KEY_NONE }
     }
 
+    /// Acquire the lock asynchronously, resolving to a guard which keeps the
+    /// mutex alive through the `Arc`, rather than borrowing it.
+    ///
+    /// Unlike a [`MutexGuard`], the resulting [`OwnedMutexGuard`] has no
+    /// lifetime, so it can be held across a spawn boundary or stored in a
+    /// struct.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::lock::Mutex;
+    /// use std::sync::Arc;
+    ///
+    /// let mutex = Arc::new(Mutex::new(0));
+    /// let mut guard = mutex.lock_owned().await;
+    /// let handle = std::thread::spawn(move || *guard += 1);
+    /// handle.join().unwrap();
+    ///
+    /// assert_eq!(*mutex.lock().await, 1);
+    /// # });
+    /// ```
+    pub fn lock_owned(self: &Arc<Self>) -> OwnedMutexLockFuture<T> {
+        OwnedMutexLockFuture { mutex: Some(self.clone()), wait_key: WAIT_KEY_NONE }
+    }
+
+    /// Acquire the lock from synchronous code, blocking the current thread
+    /// until the lock has been acquired.
+    ///
+    /// The thread waits in the same queue as the tasks waiting for the lock,
+    /// so a fair mutex serves it in order along with them.
+    ///
+    /// This must not be called from within an asynchronous task: the blocked
+    /// thread may be the one which would run the task holding the lock, in
+    /// which case it never wakes up again. Use [`lock`](Mutex::lock) from
+    /// asynchronous code instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the current thread is running an executor from
+    /// `futures::executor`, or any other executor marking its threads with
+    /// `futures::executor::enter`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::lock::Mutex;
+    /// use std::sync::Arc;
+    /// use std::thread;
+    ///
+    /// let mutex = Arc::new(Mutex::new(0));
+    /// let handle = {
+    ///     let mutex = mutex.clone();
+    ///     thread::spawn(move || *mutex.blocking_lock() += 1)
+    /// };
+    /// handle.join().unwrap();
+    ///
+    /// assert_eq!(*mutex.blocking_lock(), 1);
+    /// ```
+    pub fn blocking_lock(&self) -> MutexGuard<'_, T> {
+        let _enter = enter_blocking();
+        park_on(self.lock())
+    }
+
+    /// Acquire the lock from synchronous code, blocking the current thread
+    /// until the lock has been acquired, and returning a guard which keeps the
+    /// mutex alive through the `Arc`, rather than borrowing it.
+    ///
+    /// See [`blocking_lock`](Mutex::blocking_lock) for when this may be called.
+    pub fn blocking_lock_owned(self: &Arc<Self>) -> OwnedMutexGuard<T> {
+        let _enter = enter_blocking();
+        park_on(self.lock_owned())
+    }
+
     /// Returns a mutable reference to the underlying data.
     ///
     /// Since this call borrows the `Mutex` mutably, no actual locking needs to
@@ -137,44 +293,186 @@ impl<T: ?Sized> Mutex<T> {
         unsafe { &mut *self.value.get() }
     }
 
+    fn try_acquire(&self) -> bool {
+        if self.fair {
+            self.state.compare_exchange(0, IS_LOCKED, Ordering::Acquire, Ordering::Relaxed).is_ok()
+        } else {
+            let old_state = self.state.fetch_or(IS_LOCKED, Ordering::Acquire);
+            (old_state & IS_LOCKED) == 0
+        }
+    }
+
+    // Polls a future acquiring the lock, registering it under `wait_key` if the
+    // lock can't be acquired yet. Returns `true` once the lock has been
+    // acquired.
+    fn poll_lock(&self, wait_key: &mut usize, cx: &mut Context<'_>) -> bool {
+        if self.fair {
+            return self.poll_lock_fair(wait_key, cx);
+        }
+
+        if self.try_acquire() {
+            self.remove_waker(*wait_key, false);
+            *wait_key = WAIT_KEY_NONE;
+            return true;
+        }
+
+        {
+            let mut waiters = self.waiters.lock().unwrap();
+            if *wait_key == WAIT_KEY_NONE {
+                *wait_key = waiters.slab.insert(Waiter::Waiting(cx.waker().clone()));
+                if waiters.slab.len() == 1 {
+                    self.state.fetch_or(HAS_WAITERS, Ordering::Relaxed); // released by mutex unlock
+                }
+            } else {
+                waiters.slab[*wait_key].register(cx.waker());
+            }
+        }
+
+        // Ensure that we haven't raced `MutexGuard::drop`'s unlock path by
+        // attempting to acquire the lock again.
+        if self.try_acquire() {
+            self.remove_waker(*wait_key, false);
+            *wait_key = WAIT_KEY_NONE;
+            return true;
+        }
+
+        false
+    }
+
+    // Removes the waiter of a future which was dropped before it acquired the
+    // lock, waking up another waiter if it had been awoken to acquire it.
+    fn remove_waiter(&self, wait_key: usize) {
+        if self.fair {
+            self.remove_waiter_fair(wait_key);
+        } else {
+            self.remove_waker(wait_key, true);
+        }
+    }
+
     fn remove_waker(&self, wait_key: usize, wake_another: bool) {
         if wait_key != WAIT_KEY_NONE {
             let mut waiters = self.waiters.lock().unwrap();
-            match waiters.remove(wait_key) {
-                Waiter::Waiting(_) => {}
+            match waiters.slab.remove(wait_key) {
+                Waiter::Waiting(_) | Waiter::Granted => {}
                 Waiter::Woken => {
                     // We were awoken, but then dropped before we could
                     // wake up to acquire the lock. Wake up another
                     // waiter.
                     if wake_another {
-                        if let Some((_i, waiter)) = waiters.iter_mut().next() {
+                        if let Some((_i, waiter)) = waiters.slab.iter_mut().next() {
                             waiter.wake();
                         }
                     }
                 }
             }
-            if waiters.is_empty() {
+            if waiters.slab.is_empty() {
                 self.state.fetch_and(!HAS_WAITERS, Ordering::Relaxed); // released by mutex unlock
             }
         }
     }
 
+    // Polls a `MutexLockFuture` of a fair mutex. `HAS_WAITERS` is only changed
+    // while holding `waiters`, and indicates that `waiters.queue` is non-empty.
+    fn poll_lock_fair(&self, wait_key: &mut usize, cx: &mut Context<'_>) -> bool {
+        let mut waiters = self.waiters.lock().unwrap();
+        if *wait_key != WAIT_KEY_NONE {
+            if let Waiter::Granted = waiters.slab[*wait_key] {
+                // The lock was handed over to us by `unlock_fair`.
+                waiters.slab.remove(*wait_key);
+                *wait_key = WAIT_KEY_NONE;
+                return true;
+            }
+            waiters.slab[*wait_key].register(cx.waker());
+            return false;
+        }
+
+        // Either acquire the lock, or make sure that it will be handed over to
+        // us once it's unlocked.
+        let mut state = self.state.load(Ordering::Relaxed);
+        loop {
+            let (new_state, order) = if (state & IS_LOCKED) == 0 {
+                (state | IS_LOCKED, Ordering::Acquire)
+            } else {
+                (state | HAS_WAITERS, Ordering::Relaxed)
+            };
+            match self.state.compare_exchange_weak(state, new_state, order, Ordering::Relaxed) {
+                Ok(_) if (state & IS_LOCKED) == 0 => return true,
+                Ok(_) => break,
+                Err(actual) => state = actual,
+            }
+        }
+        *wait_key = waiters.slab.insert(Waiter::Waiting(cx.waker().clone()));
+        waiters.queue.push_back(*wait_key);
+        false
+    }
+
+    // Removes the waiter of a dropped `MutexLockFuture` of a fair mutex,
+    // passing the lock on if it had already been handed over to it.
+    fn remove_waiter_fair(&self, wait_key: usize) {
+        if wait_key != WAIT_KEY_NONE {
+            let mut waiters = self.waiters.lock().unwrap();
+            match waiters.slab.remove(wait_key) {
+                Waiter::Granted => {
+                    drop(waiters);
+                    self.unlock_fair();
+                }
+                Waiter::Waiting(_) | Waiter::Woken => {
+                    if let Some(pos) = waiters.queue.iter().position(|&key| key == wait_key) {
+                        waiters.queue.remove(pos);
+                    }
+                    if waiters.queue.is_empty() {
+                        self.state.fetch_and(!HAS_WAITERS, Ordering::Relaxed);
+                    }
+                }
+            }
+        }
+    }
+
     // Unlocks the mutex. Called by MutexGuard and MappedMutexGuard when they are
     // dropped.
     fn unlock(&self) {
+        if self.fair {
+            return self.unlock_fair();
+        }
         let old_state = self.state.fetch_and(!IS_LOCKED, Ordering::AcqRel);
         if (old_state & HAS_WAITERS) != 0 {
             let mut waiters = self.waiters.lock().unwrap();
-            if let Some((_i, waiter)) = waiters.iter_mut().next() {
+            if let Some((_i, waiter)) = waiters.slab.iter_mut().next() {
                 waiter.wake();
             }
         }
     }
+
+    fn unlock_fair(&self) {
+        if self.state.compare_exchange(IS_LOCKED, 0, Ordering::Release, Ordering::Relaxed).is_ok() {
+            return;
+        }
+        let mut waiters = self.waiters.lock().unwrap();
+        match waiters.queue.pop_front() {
+            Some(key) => {
+                // Hand the lock over to the longest waiting task, keeping
+                // `IS_LOCKED` set on its behalf.
+                if waiters.queue.is_empty() {
+                    self.state.fetch_and(!HAS_WAITERS, Ordering::Relaxed);
+                }
+                waiters.slab[key].grant();
+            }
+            None => {
+                self.state.fetch_and(!IS_LOCKED, Ordering::Release);
+            }
+        }
+    }
 }
 
 // Sentinel for when no slot in the `Slab` has been dedicated to this object.
 const WAIT_KEY_NONE: usize = usize::max_value();
 
+// Marks the current thread as blocked on a `Mutex`, panicking if it belongs to
+// an executor.
+fn enter_blocking() -> Entered {
+    enter().expect("cannot block on a `Mutex` from within an executor")
+}
+
 /// A future which resolves when the target mutex has been successfully acquired.
 pub struct MutexLockFuture<'a, T: ?Sized> {
     // `None` indicates that the mutex was successfully acquired.
@@ -205,35 +503,15 @@ impl<'a, T: ?Sized> Future for MutexLockFuture<'a, T> {
     type Output = MutexGuard<'a, T>;
 
     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        let mutex = self.mutex.expect("polled MutexLockFuture after completion");
+        let this = &mut *self;
+        let mutex = this.mutex.expect("polled MutexLockFuture after completion");
 
-        if let Some(lock) = mutex.try_lock() {
-            mutex.remove_waker(self.wait_key, false);
-            self.mutex = None;
-            return Poll::Ready(lock);
-        }
-
-        {
-            let mut waiters = mutex.waiters.lock().unwrap();
-            if self.wait_key == WAIT_KEY_NONE {
-                self.wait_key = waiters.insert(Waiter::Waiting(cx.waker().clone()));
-                if waiters.len() == 1 {
-                    mutex.state.fetch_or(HAS_WAITERS, Ordering::Relaxed); // released by mutex unlock
-                }
-            } else {
-                waiters[self.wait_key].register(cx.waker());
-            }
-        }
-
-        // Ensure that we haven't raced `MutexGuard::drop`'s unlock path by
-        // attempting to acquire the lock again.
-        if let Some(lock) = mutex.try_lock() {
-            mutex.remove_waker(self.wait_key, false);
-            self.mutex = None;
-            return Poll::Ready(lock);
+        if mutex.poll_lock(&mut this.wait_key, cx) {
+            this.mutex = None;
+            Poll::Ready(MutexGuard { mutex })
+        } else {
+            Poll::Pending
         }
-
-        Poll::Pending
     }
 }
 
@@ -244,7 +522,58 @@ impl<T: ?Sized> Drop for MutexLockFuture<'_, T> {
             //
             // Remove ourselves from the map, waking up another waiter if we
             // had been awoken to acquire the lock.
-            mutex.remove_waker(self.wait_key, true);
+            mutex.remove_waiter(self.wait_key);
+        }
+    }
+}
+
+/// A future which resolves when the target mutex has been successfully
+/// acquired, owning an `Arc` of the mutex.
+pub struct OwnedMutexLockFuture<T: ?Sized> {
+    // `None` indicates that the mutex was successfully acquired.
+    mutex: Option<Arc<Mutex<T>>>,
+    wait_key: usize,
+}
+
+impl<T: ?Sized> fmt::Debug for OwnedMutexLockFuture<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OwnedMutexLockFuture")
+            .field("was_acquired", &self.mutex.is_none())
+            .field("mutex", &self.mutex)
+            .field(
+                "wait_key",
+                &(if self.wait_key == WAIT_KEY_NONE { None } else { Some(self.wait_key) }),
+            )
+            .finish()
+    }
+}
+
+impl<T: ?Sized> FusedFuture for OwnedMutexLockFuture<T> {
+    fn is_terminated(&self) -> bool {
+        self.mutex.is_none()
+    }
+}
+
+impl<T: ?Sized> Future for OwnedMutexLockFuture<T> {
+    type Output = OwnedMutexGuard<T>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        let mutex = this.mutex.as_ref().expect("polled OwnedMutexLockFuture after completion");
+
+        if mutex.poll_lock(&mut this.wait_key, cx) {
+            Poll::Ready(OwnedMutexGuard { mutex: this.mutex.take().unwrap() })
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl<T: ?Sized> Drop for OwnedMutexLockFuture<T> {
+    fn drop(&mut self) {
+        if let Some(mutex) = &self.mutex {
+            // This future was dropped before it acquired the mutex.
+            mutex.remove_waiter(self.wait_key);
         }
     }
 }
@@ -378,6 +707,145 @@ impl<T: ?Sized, U: ?Sized> DerefMut for MappedMutexGuard<'_, T, U> {
     }
 }
 
+/// An RAII guard returned by the `lock_owned` and `try_lock_owned` methods.
+/// When this structure is dropped (falls out of scope), the lock will be
+/// unlocked.
+///
+/// Unlike [`MutexGuard`], this keeps the mutex alive through an `Arc`, rather
+/// than borrowing it.
+pub struct OwnedMutexGuard<T: ?Sized> {
+    mutex: Arc<Mutex<T>>,
+}
+
+impl<T: ?Sized> OwnedMutexGuard<T> {
+    /// Returns a locked view over a portion of the locked data.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::lock::{Mutex, OwnedMutexGuard};
+    /// use std::sync::Arc;
+    ///
+    /// let data = Arc::new(Mutex::new(Some("value".to_string())));
+    /// {
+    ///     let locked_str = OwnedMutexGuard::map(data.lock_owned().await, |opt| opt.as_mut().unwrap());
+    ///     assert_eq!(&*locked_str, "value");
+    /// }
+    /// # });
+    /// ```
+    #[inline]
+    pub fn map<U: ?Sized, F>(this: Self, f: F) -> OwnedMappedMutexGuard<T, U>
+    where
+        F: FnOnce(&mut T) -> &mut U,
+    {
+        let value: *mut U = f(unsafe { &mut *this.mutex.value.get() });
+        // Don't run the `drop` method for OwnedMutexGuard. The ownership of the
+        // underlying locked state is being moved to the returned
+        // OwnedMappedMutexGuard, along with the `Arc`.
+        let this = mem::ManuallyDrop::new(this);
+        let mutex = unsafe { ptr::read(&this.mutex) };
+        OwnedMappedMutexGuard { mutex, value }
+    }
+}
+
+impl<T: ?Sized + fmt::Debug> fmt::Debug for OwnedMutexGuard<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OwnedMutexGuard")
+            .field("value", &&**self)
+            .field("mutex", &self.mutex)
+            .finish()
+    }
+}
+
+impl<T: ?Sized> Drop for OwnedMutexGuard<T> {
+    fn drop(&mut self) {
+        self.mutex.unlock()
+    }
+}
+
+impl<T: ?Sized> Deref for OwnedMutexGuard<T> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        unsafe { &*self.mutex.value.get() }
+    }
+}
+
+impl<T: ?Sized> DerefMut for OwnedMutexGuard<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { &mut *self.mutex.value.get() }
+    }
+}
+
+/// An RAII guard returned by the `OwnedMutexGuard::map` and
+/// `OwnedMappedMutexGuard::map` methods. When this structure is dropped (falls
+/// out of scope), the lock will be unlocked.
+pub struct OwnedMappedMutexGuard<T: ?Sized, U: ?Sized> {
+    mutex: Arc<Mutex<T>>,
+    value: *mut U,
+}
+
+impl<T: ?Sized, U: ?Sized> OwnedMappedMutexGuard<T, U> {
+    /// Returns a locked view over a portion of the locked data.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::lock::{Mutex, OwnedMappedMutexGuard, OwnedMutexGuard};
+    /// use std::sync::Arc;
+    ///
+    /// let data = Arc::new(Mutex::new(Some("value".to_string())));
+    /// {
+    ///     let locked_str = OwnedMutexGuard::map(data.lock_owned().await, |opt| opt.as_mut().unwrap());
+    ///     let locked_char = OwnedMappedMutexGuard::map(locked_str, |s| s.get_mut(0..1).unwrap());
+    ///     assert_eq!(&*locked_char, "v");
+    /// }
+    /// # });
+    /// ```
+    #[inline]
+    pub fn map<V: ?Sized, F>(this: Self, f: F) -> OwnedMappedMutexGuard<T, V>
+    where
+        F: FnOnce(&mut U) -> &mut V,
+    {
+        let value: *mut V = f(unsafe { &mut *this.value });
+        // Don't run the `drop` method for OwnedMappedMutexGuard. The ownership
+        // of the underlying locked state is being moved to the returned
+        // OwnedMappedMutexGuard, along with the `Arc`.
+        let this = mem::ManuallyDrop::new(this);
+        let mutex = unsafe { ptr::read(&this.mutex) };
+        OwnedMappedMutexGuard { mutex, value }
+    }
+}
+
+impl<T: ?Sized, U: ?Sized + fmt::Debug> fmt::Debug for OwnedMappedMutexGuard<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OwnedMappedMutexGuard")
+            .field("value", &&**self)
+            .field("mutex", &self.mutex)
+            .finish()
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Drop for OwnedMappedMutexGuard<T, U> {
+    fn drop(&mut self) {
+        self.mutex.unlock()
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Deref for OwnedMappedMutexGuard<T, U> {
+    type Target = U;
+    fn deref(&self) -> &U {
+        unsafe { &*self.value }
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> DerefMut for OwnedMappedMutexGuard<T, U> {
+    fn deref_mut(&mut self) -> &mut U {
+        unsafe { &mut *self.value }
+    }
+}
+
 // Mutexes can be moved freely between threads and acquired on any thread so long
 // as the inner value can be safely sent between threads.
 unsafe impl<T: ?Sized + Send> Send for Mutex<T> {}
@@ -386,8 +854,10 @@ unsafe impl<T: ?Sized + Send> Sync for Mutex<T> {}
 // It's safe to switch which thread the acquire is being attempted on so long as
 // `T` can be accessed on that thread.
 unsafe impl<T: ?Sized + Send> Send for MutexLockFuture<'_, T> {}
+unsafe impl<T: ?Sized + Send> Send for OwnedMutexLockFuture<T> {}
 // doesn't have any interesting `&self` methods (only Debug)
 unsafe impl<T: ?Sized> Sync for MutexLockFuture<'_, T> {}
+unsafe impl<T: ?Sized> Sync for OwnedMutexLockFuture<T> {}
 
 // Safe to send since we don't track any thread-specific details-- the inner
 // lock is essentially spinlock-equivalent (attempt to flip an atomic bool)
@@ -395,6 +865,10 @@ unsafe impl<T: ?Sized + Send> Send for MutexGuard<'_, T> {}
 unsafe impl<T: ?Sized + Sync> Sync for MutexGuard<'_, T> {}
 unsafe impl<T: ?Sized + Send, U: ?Sized + Send> Send for MappedMutexGuard<'_, T, U> {}
 unsafe impl<T: ?Sized + Sync, U: ?Sized + Sync> Sync for MappedMutexGuard<'_, T, U> {}
+unsafe impl<T: ?Sized + Send> Send for OwnedMutexGuard<T> {}
+unsafe impl<T: ?Sized + Sync> Sync for OwnedMutexGuard<T> {}
+unsafe impl<T: ?Sized + Send, U: ?Sized + Send> Send for OwnedMappedMutexGuard<T, U> {}
+unsafe impl<T: ?Sized + Sync, U: ?Sized + Sync> Sync for OwnedMappedMutexGuard<T, U> {}
 
 #[test]
 fn test_mutex_guard_debug_not_recurse() {
@@ -0,0 +1,256 @@
+use futures_core::future::{FusedFuture, Future};
+use futures_core::task::{Context, Poll, Waker};
+use slab::Slab;
+use std::collections::VecDeque;
+use std::fmt;
+use std::pin::Pin;
+use std::sync::Mutex as StdMutex;
+
+/// Notifies one or all waiting tasks that an event has happened.
+///
+/// Tasks wait for a notification through the [`notified`](Notify::notified)
+/// future. [`notify_one`](Notify::notify_one) wakes up a single waiting task,
+/// in the order in which they started waiting, or, if no task is waiting,
+/// stores a permit so that the next call to `notified` completes immediately.
+/// At most one permit is stored at a time. In contrast,
+/// [`notify_waiters`](Notify::notify_waiters) wakes up all tasks which are
+/// currently waiting, without storing a permit.
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::lock::Notify;
+///
+/// let notify = Notify::new();
+///
+/// // The permit stored by `notify_one` is consumed by the next waiter.
+/// notify.notify_one();
+/// notify.notified().await;
+/// # });
+/// ```
+pub struct Notify {
+    state: StdMutex<State>,
+}
+
+struct State {
+    // Whether a permit was stored by `notify_one` while no task was waiting.
+    permit: bool,
+    // Incremented by every call to `notify_waiters`.
+    generation: usize,
+    waiters: Slab<Waiter>,
+    // Keys of the waiters in `waiters` which have not been notified yet, in
+    // the order they started waiting.
+    queue: VecDeque<usize>,
+}
+
+struct Waiter {
+    waker: Option<Waker>,
+    notified: Option<Notification>,
+}
+
+#[derive(Clone, Copy)]
+enum Notification {
+    One,
+    All,
+}
+
+impl State {
+    fn notify_one(&mut self) {
+        match self.queue.pop_front() {
+            Some(key) => {
+                let waiter = &mut self.waiters[key];
+                waiter.notified = Some(Notification::One);
+                if let Some(waker) = waiter.waker.take() {
+                    waker.wake();
+                }
+            }
+            None => self.permit = true,
+        }
+    }
+}
+
+impl fmt::Debug for Notify {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let state = self.state.lock().unwrap();
+        f.debug_struct("Notify")
+            .field("has_permit", &state.permit)
+            .field("waiters", &state.queue.len())
+            .finish()
+    }
+}
+
+impl Default for Notify {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Notify {
+    /// Creates a new `Notify` without a stored permit.
+    pub fn new() -> Self {
+        Self {
+            state: StdMutex::new(State {
+                permit: false,
+                generation: 0,
+                waiters: Slab::new(),
+                queue: VecDeque::new(),
+            }),
+        }
+    }
+
+    /// Waits for a notification.
+    ///
+    /// The returned future completes once it is woken by
+    /// [`notify_one`](Notify::notify_one), or immediately if a permit is
+    /// stored, consuming it. It also completes when
+    /// [`notify_waiters`](Notify::notify_waiters) is called any time after
+    /// the future was created, even if it hasn't been polled yet.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::lock::Notify;
+    ///
+    /// let notify = Notify::new();
+    /// let notified = notify.notified();
+    /// notify.notify_waiters();
+    ///
+    /// // `notified` was created before the call, so it completes.
+    /// notified.await;
+    /// # });
+    /// ```
+    pub fn notified(&self) -> Notified<'_> {
+        let generation = self.state.lock().unwrap().generation;
+        Notified { notify: Some(self), generation, wait_key: WAIT_KEY_NONE }
+    }
+
+    /// Wakes up the task which has been waiting longest for a notification.
+    ///
+    /// If no task is waiting, a permit is stored instead, so that the next
+    /// call to [`notified`](Notify::notified) completes immediately.
+    pub fn notify_one(&self) {
+        self.state.lock().unwrap().notify_one();
+    }
+
+    /// Wakes up all tasks which are currently waiting for a notification.
+    ///
+    /// Unlike [`notify_one`](Notify::notify_one), this doesn't store a
+    /// permit if no task is waiting.
+    pub fn notify_waiters(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.generation = state.generation.wrapping_add(1);
+        while let Some(key) = state.queue.pop_front() {
+            let waiter = &mut state.waiters[key];
+            waiter.notified = Some(Notification::All);
+            if let Some(waker) = waiter.waker.take() {
+                waker.wake();
+            }
+        }
+    }
+
+    // Polls for a notification, queueing up a waiter under `wait_key` if there
+    // is none yet. Returns `true` once notified.
+    fn poll_notified(&self, generation: usize, wait_key: &mut usize, cx: &mut Context<'_>) -> bool {
+        let mut state = self.state.lock().unwrap();
+        if *wait_key == WAIT_KEY_NONE {
+            if state.generation != generation {
+                return true;
+            }
+            if state.permit {
+                state.permit = false;
+                return true;
+            }
+            *wait_key =
+                state.waiters.insert(Waiter { waker: Some(cx.waker().clone()), notified: None });
+            state.queue.push_back(*wait_key);
+            false
+        } else if state.waiters[*wait_key].notified.is_some() {
+            state.waiters.remove(*wait_key);
+            *wait_key = WAIT_KEY_NONE;
+            true
+        } else {
+            let waker = &mut state.waiters[*wait_key].waker;
+            match waker {
+                Some(w) if w.will_wake(cx.waker()) => {}
+                _ => *waker = Some(cx.waker().clone()),
+            }
+            false
+        }
+    }
+
+    // Removes a waiter which is dropped before it completed, passing a
+    // notification from `notify_one` on to the next waiter.
+    fn remove_waiter(&self, wait_key: usize) {
+        if wait_key != WAIT_KEY_NONE {
+            let mut state = self.state.lock().unwrap();
+            match state.waiters.remove(wait_key).notified {
+                Some(Notification::One) => state.notify_one(),
+                Some(Notification::All) => {}
+                None => {
+                    if let Some(pos) = state.queue.iter().position(|&key| key == wait_key) {
+                        state.queue.remove(pos);
+                    }
+                }
+            }
+        }
+    }
+}
+
+// Sentinel for when no slot in the `Slab` has been dedicated to this object.
+const WAIT_KEY_NONE: usize = usize::max_value();
+
+/// Future for the [`notified`](Notify::notified) method.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Notified<'a> {
+    // `None` indicates that the notification was received.
+    notify: Option<&'a Notify>,
+    // The number of calls to `notify_waiters` when this future was created.
+    generation: usize,
+    wait_key: usize,
+}
+
+impl fmt::Debug for Notified<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Notified")
+            .field("was_notified", &self.notify.is_none())
+            .field("notify", &self.notify)
+            .field(
+                "wait_key",
+                &(if self.wait_key == WAIT_KEY_NONE { None } else { Some(self.wait_key) }),
+            )
+            .finish()
+    }
+}
+
+impl FusedFuture for Notified<'_> {
+    fn is_terminated(&self) -> bool {
+        self.notify.is_none()
+    }
+}
+
+impl Future for Notified<'_> {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let this = &mut *self;
+        let notify = this.notify.expect("polled Notified after completion");
+
+        if notify.poll_notified(this.generation, &mut this.wait_key, cx) {
+            this.notify = None;
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl Drop for Notified<'_> {
+    fn drop(&mut self) {
+        if let Some(notify) = self.notify {
+            // This future was dropped before it was notified.
+            notify.remove_waiter(self.wait_key);
+        }
+    }
+}
@@ -0,0 +1,694 @@
+use futures_core::future::{FusedFuture, Future};
+use futures_core::task::{Context, Poll, Waker};
+use slab::Slab;
+use std::cell::UnsafeCell;
+use std::collections::VecDeque;
+use std::marker::PhantomData;
+use std::ops::{Deref, DerefMut};
+use std::pin::Pin;
+use std::sync::Mutex as StdMutex;
+use std::{fmt, mem};
+
+/// A futures-aware reader-writer lock.
+///
+/// This lock allows any number of readers or at most one writer to access the
+/// underlying data at any point in time.
+///
+/// # Fairness
+///
+/// Tasks acquire the lock in the order in which they started waiting for it.
+/// Once a writer is waiting, readers that arrive after it queue up behind it
+/// instead of joining the readers that currently hold the lock, so a steady
+/// stream of readers cannot starve writers. When the lock is released, it is
+/// handed over directly to the next waiting writer, or to all consecutively
+/// waiting readers at the front of the queue.
+///
+/// As a consequence, [`try_read`](RwLock::try_read) fails while a writer is
+/// waiting, even if the lock is currently only held by readers.
+pub struct RwLock<T: ?Sized> {
+    state: StdMutex<State>,
+    value: UnsafeCell<T>,
+}
+
+struct State {
+    // Number of readers currently holding the lock.
+    readers: usize,
+    // Whether a writer currently holds the lock.
+    writer: bool,
+    waiters: Slab<Waiter>,
+    // Keys of the waiters in `waiters` which have not been granted the lock
+    // yet, in the order they started waiting.
+    queue: VecDeque<usize>,
+}
+
+struct Waiter {
+    kind: Kind,
+    waker: Option<Waker>,
+    granted: bool,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Read,
+    Write,
+}
+
+impl State {
+    fn try_acquire(&mut self, kind: Kind) -> bool {
+        if !self.queue.is_empty() || self.writer {
+            return false;
+        }
+        match kind {
+            Kind::Read => self.readers += 1,
+            Kind::Write if self.readers == 0 => self.writer = true,
+            Kind::Write => return false,
+        }
+        true
+    }
+
+    fn release(&mut self, kind: Kind) {
+        match kind {
+            Kind::Read => self.readers -= 1,
+            Kind::Write => self.writer = false,
+        }
+        self.grant();
+    }
+
+    // Hands the lock over to as many waiters from the front of the queue as
+    // possible, waking them up.
+    fn grant(&mut self) {
+        while let Some(&key) = self.queue.front() {
+            let waiter = &mut self.waiters[key];
+            match waiter.kind {
+                Kind::Read if !self.writer => self.readers += 1,
+                Kind::Write if !self.writer && self.readers == 0 => self.writer = true,
+                _ => break,
+            }
+            self.queue.pop_front();
+            waiter.granted = true;
+            if let Some(waker) = waiter.waker.take() {
+                waker.wake();
+            }
+            if self.writer {
+                break;
+            }
+        }
+    }
+}
+
+impl<T: ?Sized> fmt::Debug for RwLock<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let state = self.state.lock().unwrap();
+        f.debug_struct("RwLock")
+            .field("readers", &state.readers)
+            .field("is_write_locked", &state.writer)
+            .field("waiters", &state.queue.len())
+            .finish()
+    }
+}
+
+impl<T> From<T> for RwLock<T> {
+    fn from(t: T) -> Self {
+        Self::new(t)
+    }
+}
+
+impl<T: Default> Default for RwLock<T> {
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+impl<T> RwLock<T> {
+    /// Creates a new futures-aware reader-writer lock.
+    pub fn new(t: T) -> Self {
+        Self {
+            state: StdMutex::new(State {
+                readers: 0,
+                writer: false,
+                waiters: Slab::new(),
+                queue: VecDeque::new(),
+            }),
+            value: UnsafeCell::new(t),
+        }
+    }
+
+    /// Consumes this lock, returning the underlying data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::lock::RwLock;
+    ///
+    /// let lock = RwLock::new(0);
+    /// assert_eq!(lock.into_inner(), 0);
+    /// ```
+    pub fn into_inner(self) -> T {
+        self.value.into_inner()
+    }
+}
+
+impl<T: ?Sized> RwLock<T> {
+    /// Attempt to acquire shared read access immediately.
+    ///
+    /// If the lock is currently held by a writer, or a writer is waiting to
+    /// acquire it, this will return `None`.
+    pub fn try_read(&self) -> Option<RwLockReadGuard<'_, T>> {
+        if self.state.lock().unwrap().try_acquire(Kind::Read) {
+            Some(RwLockReadGuard { rwlock: self })
+        } else {
+            None
+        }
+    }
+
+    /// Attempt to acquire exclusive write access immediately.
+    ///
+    /// If the lock is currently held by any reader or writer, this will return
+    /// `None`.
+    pub fn try_write(&self) -> Option<RwLockWriteGuard<'_, T>> {
+        if self.state.lock().unwrap().try_acquire(Kind::Write) {
+            Some(RwLockWriteGuard { rwlock: self })
+        } else {
+            None
+        }
+    }
+
+    /// Acquire shared read access asynchronously.
+    ///
+    /// This method returns a future that will resolve once read access has
+    /// been successfully acquired.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::lock::RwLock;
+    ///
+    /// let lock = RwLock::new(1);
+    ///
+    /// let reader1 = lock.read().await;
+    /// let reader2 = lock.read().await;
+    /// assert_eq!(*reader1 + *reader2, 2);
+    /// assert!(lock.try_write().is_none());
+    /// # });
+    /// ```
+    pub fn read(&self) -> RwLockReadFuture<'_, T> {
+        RwLockReadFuture { rwlock: Some(self), wait_key: WAIT_KEY_NONE }
+    }
+
+    /// Acquire exclusive write access asynchronously.
+    ///
+    /// This method returns a future that will resolve once write access has
+    /// been successfully acquired.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::lock::RwLock;
+    ///
+    /// let lock = RwLock::new(1);
+    ///
+    /// let mut writer = lock.write().await;
+    /// *writer += 1;
+    /// assert!(lock.try_read().is_none());
+    /// drop(writer);
+    ///
+    /// assert_eq!(*lock.read().await, 2);
+    /// # });
+    /// ```
+    pub fn write(&self) -> RwLockWriteFuture<'_, T> {
+        RwLockWriteFuture { rwlock: Some(self), wait_key: WAIT_KEY_NONE }
+    }
+
+    /// Returns a mutable reference to the underlying data.
+    ///
+    /// Since this call borrows the `RwLock` mutably, no actual locking needs to
+    /// take place -- the mutable borrow statically guarantees no locks exist.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::lock::RwLock;
+    ///
+    /// let mut lock = RwLock::new(0);
+    /// *lock.get_mut() = 10;
+    /// assert_eq!(*lock.read().await, 10);
+    /// # });
+    /// ```
+    pub fn get_mut(&mut self) -> &mut T {
+        // We know statically that there are no other references to `self`, so
+        // there's no need to lock anything.
+        unsafe { &mut *self.value.get() }
+    }
+
+    // Polls for access of the given kind, queueing up a waiter under
+    // `wait_key` if it can't be acquired immediately. Returns `true` once the
+    // lock has been acquired.
+    fn poll_acquire(&self, kind: Kind, wait_key: &mut usize, cx: &mut Context<'_>) -> bool {
+        let mut state = self.state.lock().unwrap();
+        if *wait_key == WAIT_KEY_NONE {
+            if state.try_acquire(kind) {
+                return true;
+            }
+            *wait_key = state.waiters.insert(Waiter {
+                kind,
+                waker: Some(cx.waker().clone()),
+                granted: false,
+            });
+            state.queue.push_back(*wait_key);
+            false
+        } else if state.waiters[*wait_key].granted {
+            state.waiters.remove(*wait_key);
+            *wait_key = WAIT_KEY_NONE;
+            true
+        } else {
+            let waker = &mut state.waiters[*wait_key].waker;
+            match waker {
+                Some(w) if w.will_wake(cx.waker()) => {}
+                _ => *waker = Some(cx.waker().clone()),
+            }
+            false
+        }
+    }
+
+    // Removes a waiter which is dropped before it acquired the lock, giving
+    // the lock to the next waiters if it had already been granted.
+    fn remove_waiter(&self, wait_key: usize) {
+        if wait_key != WAIT_KEY_NONE {
+            let mut state = self.state.lock().unwrap();
+            let waiter = state.waiters.remove(wait_key);
+            if waiter.granted {
+                state.release(waiter.kind);
+            } else {
+                if let Some(pos) = state.queue.iter().position(|&key| key == wait_key) {
+                    state.queue.remove(pos);
+                }
+                // A waiting writer may have been holding back the readers
+                // queued behind it.
+                state.grant();
+            }
+        }
+    }
+
+    // Releases access of the given kind. Called by the guards when they are
+    // dropped.
+    fn unlock(&self, kind: Kind) {
+        self.state.lock().unwrap().release(kind);
+    }
+}
+
+// Sentinel for when no slot in the `Slab` has been dedicated to this object.
+const WAIT_KEY_NONE: usize = usize::max_value();
+
+/// A future which resolves when shared read access to the target lock has been
+/// successfully acquired.
+pub struct RwLockReadFuture<'a, T: ?Sized> {
+    // `None` indicates that the lock was successfully acquired.
+    rwlock: Option<&'a RwLock<T>>,
+    wait_key: usize,
+}
+
+impl<T: ?Sized> fmt::Debug for RwLockReadFuture<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RwLockReadFuture")
+            .field("was_acquired", &self.rwlock.is_none())
+            .field("rwlock", &self.rwlock)
+            .field(
+                "wait_key",
+                &(if self.wait_key == WAIT_KEY_NONE { None } else { Some(self.wait_key) }),
+            )
+            .finish()
+    }
+}
+
+impl<T: ?Sized> FusedFuture for RwLockReadFuture<'_, T> {
+    fn is_terminated(&self) -> bool {
+        self.rwlock.is_none()
+    }
+}
+
+impl<'a, T: ?Sized> Future for RwLockReadFuture<'a, T> {
+    type Output = RwLockReadGuard<'a, T>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        let rwlock = this.rwlock.expect("polled RwLockReadFuture after completion");
+
+        if rwlock.poll_acquire(Kind::Read, &mut this.wait_key, cx) {
+            this.rwlock = None;
+            Poll::Ready(RwLockReadGuard { rwlock })
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl<T: ?Sized> Drop for RwLockReadFuture<'_, T> {
+    fn drop(&mut self) {
+        if let Some(rwlock) = self.rwlock {
+            // This future was dropped before it acquired the lock.
+            rwlock.remove_waiter(self.wait_key);
+        }
+    }
+}
+
+/// A future which resolves when exclusive write access to the target lock has
+/// been successfully acquired.
+pub struct RwLockWriteFuture<'a, T: ?Sized> {
+    // `None` indicates that the lock was successfully acquired.
+    rwlock: Option<&'a RwLock<T>>,
+    wait_key: usize,
+}
+
+impl<T: ?Sized> fmt::Debug for RwLockWriteFuture<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RwLockWriteFuture")
+            .field("was_acquired", &self.rwlock.is_none())
+            .field("rwlock", &self.rwlock)
+            .field(
+                "wait_key",
+                &(if self.wait_key == WAIT_KEY_NONE { None } else { Some(self.wait_key) }),
+            )
+            .finish()
+    }
+}
+
+impl<T: ?Sized> FusedFuture for RwLockWriteFuture<'_, T> {
+    fn is_terminated(&self) -> bool {
+        self.rwlock.is_none()
+    }
+}
+
+impl<'a, T: ?Sized> Future for RwLockWriteFuture<'a, T> {
+    type Output = RwLockWriteGuard<'a, T>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        let rwlock = this.rwlock.expect("polled RwLockWriteFuture after completion");
+
+        if rwlock.poll_acquire(Kind::Write, &mut this.wait_key, cx) {
+            this.rwlock = None;
+            Poll::Ready(RwLockWriteGuard { rwlock })
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl<T: ?Sized> Drop for RwLockWriteFuture<'_, T> {
+    fn drop(&mut self) {
+        if let Some(rwlock) = self.rwlock {
+            // This future was dropped before it acquired the lock.
+            rwlock.remove_waiter(self.wait_key);
+        }
+    }
+}
+
+/// An RAII guard returned by the `read` and `try_read` methods.
+/// When this structure is dropped (falls out of scope), the shared read access
+/// will be released.
+pub struct RwLockReadGuard<'a, T: ?Sized> {
+    rwlock: &'a RwLock<T>,
+}
+
+impl<'a, T: ?Sized> RwLockReadGuard<'a, T> {
+    /// Returns a read-locked view over a portion of the locked data.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::lock::{RwLock, RwLockReadGuard};
+    ///
+    /// let data = RwLock::new(Some("value".to_string()));
+    /// {
+    ///     let locked_str = RwLockReadGuard::map(data.read().await, |opt| opt.as_ref().unwrap());
+    ///     assert_eq!(&*locked_str, "value");
+    ///     assert!(data.try_write().is_none());
+    /// }
+    /// assert!(data.try_write().is_some());
+    /// # });
+    /// ```
+    #[inline]
+    pub fn map<U: ?Sized, F>(this: Self, f: F) -> MappedRwLockReadGuard<'a, T, U>
+    where
+        F: FnOnce(&T) -> &U,
+    {
+        let rwlock = this.rwlock;
+        let value = f(unsafe { &*this.rwlock.value.get() });
+        // Don't run the `drop` method for RwLockReadGuard. The ownership of the
+        // underlying locked state is being moved to the returned
+        // MappedRwLockReadGuard.
+        mem::forget(this);
+        MappedRwLockReadGuard { rwlock, value, _marker: PhantomData }
+    }
+}
+
+impl<T: ?Sized + fmt::Debug> fmt::Debug for RwLockReadGuard<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RwLockReadGuard")
+            .field("value", &&**self)
+            .field("rwlock", &self.rwlock)
+            .finish()
+    }
+}
+
+impl<T: ?Sized> Drop for RwLockReadGuard<'_, T> {
+    fn drop(&mut self) {
+        self.rwlock.unlock(Kind::Read)
+    }
+}
+
+impl<T: ?Sized> Deref for RwLockReadGuard<'_, T> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        unsafe { &*self.rwlock.value.get() }
+    }
+}
+
+/// An RAII guard returned by the `write` and `try_write` methods.
+/// When this structure is dropped (falls out of scope), the exclusive write
+/// access will be released.
+pub struct RwLockWriteGuard<'a, T: ?Sized> {
+    rwlock: &'a RwLock<T>,
+}
+
+impl<'a, T: ?Sized> RwLockWriteGuard<'a, T> {
+    /// Returns a write-locked view over a portion of the locked data.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::lock::{RwLock, RwLockWriteGuard};
+    ///
+    /// let data = RwLock::new(Some("value".to_string()));
+    /// {
+    ///     let mut locked_str = RwLockWriteGuard::map(data.write().await, |opt| opt.as_mut().unwrap());
+    ///     locked_str.push('s');
+    /// }
+    /// assert_eq!(data.read().await.as_deref(), Some("values"));
+    /// # });
+    /// ```
+    #[inline]
+    pub fn map<U: ?Sized, F>(this: Self, f: F) -> MappedRwLockWriteGuard<'a, T, U>
+    where
+        F: FnOnce(&mut T) -> &mut U,
+    {
+        let rwlock = this.rwlock;
+        let value = f(unsafe { &mut *this.rwlock.value.get() });
+        // Don't run the `drop` method for RwLockWriteGuard. The ownership of the
+        // underlying locked state is being moved to the returned
+        // MappedRwLockWriteGuard.
+        mem::forget(this);
+        MappedRwLockWriteGuard { rwlock, value, _marker: PhantomData }
+    }
+}
+
+impl<T: ?Sized + fmt::Debug> fmt::Debug for RwLockWriteGuard<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RwLockWriteGuard")
+            .field("value", &&**self)
+            .field("rwlock", &self.rwlock)
+            .finish()
+    }
+}
+
+impl<T: ?Sized> Drop for RwLockWriteGuard<'_, T> {
+    fn drop(&mut self) {
+        self.rwlock.unlock(Kind::Write)
+    }
+}
+
+impl<T: ?Sized> Deref for RwLockWriteGuard<'_, T> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        unsafe { &*self.rwlock.value.get() }
+    }
+}
+
+impl<T: ?Sized> DerefMut for RwLockWriteGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { &mut *self.rwlock.value.get() }
+    }
+}
+
+/// An RAII guard returned by the `RwLockReadGuard::map` and
+/// `MappedRwLockReadGuard::map` methods. When this structure is dropped (falls
+/// out of scope), the shared read access will be released.
+pub struct MappedRwLockReadGuard<'a, T: ?Sized, U: ?Sized> {
+    rwlock: &'a RwLock<T>,
+    value: *const U,
+    _marker: PhantomData<&'a U>,
+}
+
+impl<'a, T: ?Sized, U: ?Sized> MappedRwLockReadGuard<'a, T, U> {
+    /// Returns a read-locked view over a portion of the locked data.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::lock::{MappedRwLockReadGuard, RwLock, RwLockReadGuard};
+    ///
+    /// let data = RwLock::new(Some("value".to_string()));
+    /// {
+    ///     let locked_str = RwLockReadGuard::map(data.read().await, |opt| opt.as_ref().unwrap());
+    ///     let locked_char = MappedRwLockReadGuard::map(locked_str, |s| s.get(0..1).unwrap());
+    ///     assert_eq!(&*locked_char, "v");
+    /// }
+    /// # });
+    /// ```
+    #[inline]
+    pub fn map<V: ?Sized, F>(this: Self, f: F) -> MappedRwLockReadGuard<'a, T, V>
+    where
+        F: FnOnce(&U) -> &V,
+    {
+        let rwlock = this.rwlock;
+        let value = f(unsafe { &*this.value });
+        // Don't run the `drop` method for MappedRwLockReadGuard. The ownership
+        // of the underlying locked state is being moved to the returned
+        // MappedRwLockReadGuard.
+        mem::forget(this);
+        MappedRwLockReadGuard { rwlock, value, _marker: PhantomData }
+    }
+}
+
+impl<T: ?Sized, U: ?Sized + fmt::Debug> fmt::Debug for MappedRwLockReadGuard<'_, T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MappedRwLockReadGuard")
+            .field("value", &&**self)
+            .field("rwlock", &self.rwlock)
+            .finish()
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Drop for MappedRwLockReadGuard<'_, T, U> {
+    fn drop(&mut self) {
+        self.rwlock.unlock(Kind::Read)
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Deref for MappedRwLockReadGuard<'_, T, U> {
+    type Target = U;
+    fn deref(&self) -> &U {
+        unsafe { &*self.value }
+    }
+}
+
+/// An RAII guard returned by the `RwLockWriteGuard::map` and
+/// `MappedRwLockWriteGuard::map` methods. When this structure is dropped
+/// (falls out of scope), the exclusive write access will be released.
+pub struct MappedRwLockWriteGuard<'a, T: ?Sized, U: ?Sized> {
+    rwlock: &'a RwLock<T>,
+    value: *mut U,
+    _marker: PhantomData<&'a mut U>,
+}
+
+impl<'a, T: ?Sized, U: ?Sized> MappedRwLockWriteGuard<'a, T, U> {
+    /// Returns a write-locked view over a portion of the locked data.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::lock::{MappedRwLockWriteGuard, RwLock, RwLockWriteGuard};
+    ///
+    /// let data = RwLock::new(Some("value".to_string()));
+    /// {
+    ///     let locked_str = RwLockWriteGuard::map(data.write().await, |opt| opt.as_mut().unwrap());
+    ///     let mut locked_char = MappedRwLockWriteGuard::map(locked_str, |s| s.get_mut(0..1).unwrap());
+    ///     locked_char.make_ascii_uppercase();
+    /// }
+    /// assert_eq!(data.read().await.as_deref(), Some("Value"));
+    /// # });
+    /// ```
+    #[inline]
+    pub fn map<V: ?Sized, F>(this: Self, f: F) -> MappedRwLockWriteGuard<'a, T, V>
+    where
+        F: FnOnce(&mut U) -> &mut V,
+    {
+        let rwlock = this.rwlock;
+        let value = f(unsafe { &mut *this.value });
+        // Don't run the `drop` method for MappedRwLockWriteGuard. The ownership
+        // of the underlying locked state is being moved to the returned
+        // MappedRwLockWriteGuard.
+        mem::forget(this);
+        MappedRwLockWriteGuard { rwlock, value, _marker: PhantomData }
+    }
+}
+
+impl<T: ?Sized, U: ?Sized + fmt::Debug> fmt::Debug for MappedRwLockWriteGuard<'_, T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MappedRwLockWriteGuard")
+            .field("value", &&**self)
+            .field("rwlock", &self.rwlock)
+            .finish()
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Drop for MappedRwLockWriteGuard<'_, T, U> {
+    fn drop(&mut self) {
+        self.rwlock.unlock(Kind::Write)
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Deref for MappedRwLockWriteGuard<'_, T, U> {
+    type Target = U;
+    fn deref(&self) -> &U {
+        unsafe { &*self.value }
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> DerefMut for MappedRwLockWriteGuard<'_, T, U> {
+    fn deref_mut(&mut self) -> &mut U {
+        unsafe { &mut *self.value }
+    }
+}
+
+// Reader-writer locks can be moved freely between threads so long as the inner
+// value can be, and shared between threads so long as the inner value can be
+// both sent (to writers) and shared (between readers).
+unsafe impl<T: ?Sized + Send> Send for RwLock<T> {}
+unsafe impl<T: ?Sized + Send + Sync> Sync for RwLock<T> {}
+
+// It's safe to switch which thread the acquire is being attempted on so long as
+// the resulting guard could be sent to that thread.
+unsafe impl<T: ?Sized + Sync> Send for RwLockReadFuture<'_, T> {}
+unsafe impl<T: ?Sized + Send> Send for RwLockWriteFuture<'_, T> {}
+// doesn't have any interesting `&self` methods (only Debug)
+unsafe impl<T: ?Sized> Sync for RwLockReadFuture<'_, T> {}
+unsafe impl<T: ?Sized> Sync for RwLockWriteFuture<'_, T> {}
+
+// A read guard only gives out shared references, while a write guard behaves
+// like a mutable reference to the inner value.
+unsafe impl<T: ?Sized + Sync> Send for RwLockReadGuard<'_, T> {}
+unsafe impl<T: ?Sized + Sync> Sync for RwLockReadGuard<'_, T> {}
+unsafe impl<T: ?Sized + Send> Send for RwLockWriteGuard<'_, T> {}
+unsafe impl<T: ?Sized + Sync> Sync for RwLockWriteGuard<'_, T> {}
+unsafe impl<T: ?Sized + Sync, U: ?Sized + Sync> Send for MappedRwLockReadGuard<'_, T, U> {}
+unsafe impl<T: ?Sized + Sync, U: ?Sized + Sync> Sync for MappedRwLockReadGuard<'_, T, U> {}
+unsafe impl<T: ?Sized + Send, U: ?Sized + Send> Send for MappedRwLockWriteGuard<'_, T, U> {}
+unsafe impl<T: ?Sized + Sync, U: ?Sized + Sync> Sync for MappedRwLockWriteGuard<'_, T, U> {}
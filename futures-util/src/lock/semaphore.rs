@@ -0,0 +1,430 @@
+use futures_core::future::{FusedFuture, Future};
+use futures_core::task::{Context, Poll, Waker};
+use slab::Slab;
+use std::collections::VecDeque;
+use std::fmt;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex as StdMutex};
+
+/// A futures-aware counting semaphore.
+///
+/// A semaphore holds a number of permits, which tasks acquire before accessing
+/// a shared resource and release when they are done with it, for example to
+/// bound the number of connections that are open at once.
+///
+/// # Fairness
+///
+/// Tasks acquire permits in the order in which they started waiting for them.
+/// When permits are released, they are handed over directly to the waiters at
+/// the front of the queue, for as long as there are enough permits for the
+/// first of them. A task waiting for many permits thus holds back the tasks
+/// queued behind it, even if they would only need a few, and
+/// [`try_acquire`](Semaphore::try_acquire) fails for as long as any task is
+/// waiting.
+pub struct Semaphore {
+    state: StdMutex<State>,
+}
+
+struct State {
+    // Number of permits which are neither held nor granted to a waiter.
+    permits: usize,
+    waiters: Slab<Waiter>,
+    // Keys of the waiters in `waiters` which have not been granted their
+    // permits yet, in the order they started waiting.
+    queue: VecDeque<usize>,
+}
+
+struct Waiter {
+    permits: usize,
+    waker: Option<Waker>,
+    granted: bool,
+}
+
+impl State {
+    fn try_acquire(&mut self, permits: usize) -> bool {
+        if !self.queue.is_empty() || self.permits < permits {
+            return false;
+        }
+        self.permits -= permits;
+        true
+    }
+
+    fn release(&mut self, permits: usize) {
+        self.permits =
+            self.permits.checked_add(permits).expect("futures: Semaphore permits overflowed");
+        self.grant();
+    }
+
+    // Hands permits over to as many waiters from the front of the queue as
+    // possible, waking them up.
+    fn grant(&mut self) {
+        while let Some(&key) = self.queue.front() {
+            let waiter = &mut self.waiters[key];
+            if waiter.permits > self.permits {
+                break;
+            }
+            self.permits -= waiter.permits;
+            self.queue.pop_front();
+            waiter.granted = true;
+            if let Some(waker) = waiter.waker.take() {
+                waker.wake();
+            }
+        }
+    }
+}
+
+impl fmt::Debug for Semaphore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let state = self.state.lock().unwrap();
+        f.debug_struct("Semaphore")
+            .field("permits", &state.permits)
+            .field("waiters", &state.queue.len())
+            .finish()
+    }
+}
+
+impl Semaphore {
+    /// Creates a new semaphore holding the given number of permits.
+    pub fn new(permits: usize) -> Self {
+        Self {
+            state: StdMutex::new(State { permits, waiters: Slab::new(), queue: VecDeque::new() }),
+        }
+    }
+
+    /// Returns the number of permits which are currently available.
+    pub fn available_permits(&self) -> usize {
+        self.state.lock().unwrap().permits
+    }
+
+    /// Adds `n` new permits to the semaphore, handing them over to waiting
+    /// tasks if there are any.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the number of available permits overflows
+    /// a `usize`.
+    pub fn add_permits(&self, n: usize) {
+        self.state.lock().unwrap().release(n);
+    }
+
+    /// Attempt to acquire a permit immediately.
+    ///
+    /// If no permit is available, or other tasks are waiting for permits, this
+    /// will return `None`.
+    pub fn try_acquire(&self) -> Option<SemaphorePermit<'_>> {
+        self.try_acquire_many(1)
+    }
+
+    /// Attempt to acquire `n` permits immediately.
+    ///
+    /// If fewer than `n` permits are available, or other tasks are waiting for
+    /// permits, this will return `None`.
+    pub fn try_acquire_many(&self, n: usize) -> Option<SemaphorePermit<'_>> {
+        if self.state.lock().unwrap().try_acquire(n) {
+            Some(SemaphorePermit { semaphore: self, permits: n })
+        } else {
+            None
+        }
+    }
+
+    /// Attempt to acquire a permit immediately, returning a permit which keeps
+    /// the semaphore alive through the `Arc`, rather than borrowing it.
+    ///
+    /// If no permit is available, or other tasks are waiting for permits, this
+    /// will return `None`.
+    pub fn try_acquire_owned(self: &Arc<Self>) -> Option<OwnedSemaphorePermit> {
+        if self.state.lock().unwrap().try_acquire(1) {
+            Some(OwnedSemaphorePermit { semaphore: self.clone(), permits: 1 })
+        } else {
+            None
+        }
+    }
+
+    /// Acquire a permit asynchronously.
+    ///
+    /// This method returns a future that will resolve once a permit has been
+    /// successfully acquired.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::lock::Semaphore;
+    ///
+    /// let semaphore = Semaphore::new(2);
+    ///
+    /// let permit1 = semaphore.acquire().await;
+    /// let _permit2 = semaphore.acquire().await;
+    /// assert!(semaphore.try_acquire().is_none());
+    /// drop(permit1);
+    /// assert_eq!(semaphore.available_permits(), 1);
+    /// # });
+    /// ```
+    pub fn acquire(&self) -> SemaphoreAcquireFuture<'_> {
+        self.acquire_many(1)
+    }
+
+    /// Acquire `n` permits at once asynchronously.
+    ///
+    /// This method returns a future that will resolve once `n` permits are
+    /// available and have been successfully acquired. If the semaphore never
+    /// holds that many permits, the future never resolves.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::lock::Semaphore;
+    ///
+    /// let semaphore = Semaphore::new(3);
+    ///
+    /// let permits = semaphore.acquire_many(3).await;
+    /// assert_eq!(semaphore.available_permits(), 0);
+    /// drop(permits);
+    /// assert_eq!(semaphore.available_permits(), 3);
+    /// # });
+    /// ```
+    pub fn acquire_many(&self, n: usize) -> SemaphoreAcquireFuture<'_> {
+        SemaphoreAcquireFuture { semaphore: Some(self), permits: n, wait_key: WAIT_KEY_NONE }
+    }
+
+    /// Acquire a permit asynchronously, resolving to a permit which keeps the
+    /// semaphore alive through the `Arc`, rather than borrowing it.
+    ///
+    /// Unlike a [`SemaphorePermit`], the resulting [`OwnedSemaphorePermit`]
+    /// has no lifetime, so it can be held across a spawn boundary or stored in
+    /// a struct.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::lock::Semaphore;
+    /// use std::sync::Arc;
+    ///
+    /// let semaphore = Arc::new(Semaphore::new(1));
+    /// let permit = semaphore.acquire_owned().await;
+    /// let handle = std::thread::spawn(move || drop(permit));
+    /// handle.join().unwrap();
+    ///
+    /// assert_eq!(semaphore.available_permits(), 1);
+    /// # });
+    /// ```
+    pub fn acquire_owned(self: &Arc<Self>) -> OwnedSemaphoreAcquireFuture {
+        OwnedSemaphoreAcquireFuture {
+            semaphore: Some(self.clone()),
+            permits: 1,
+            wait_key: WAIT_KEY_NONE,
+        }
+    }
+
+    // Polls for `permits` permits, queueing up a waiter under `wait_key` if
+    // they can't be acquired immediately. Returns `true` once the permits have
+    // been acquired.
+    fn poll_acquire(&self, permits: usize, wait_key: &mut usize, cx: &mut Context<'_>) -> bool {
+        let mut state = self.state.lock().unwrap();
+        if *wait_key == WAIT_KEY_NONE {
+            if state.try_acquire(permits) {
+                return true;
+            }
+            *wait_key = state.waiters.insert(Waiter {
+                permits,
+                waker: Some(cx.waker().clone()),
+                granted: false,
+            });
+            state.queue.push_back(*wait_key);
+            false
+        } else if state.waiters[*wait_key].granted {
+            state.waiters.remove(*wait_key);
+            *wait_key = WAIT_KEY_NONE;
+            true
+        } else {
+            let waker = &mut state.waiters[*wait_key].waker;
+            match waker {
+                Some(w) if w.will_wake(cx.waker()) => {}
+                _ => *waker = Some(cx.waker().clone()),
+            }
+            false
+        }
+    }
+
+    // Removes a waiter which is dropped before it acquired its permits,
+    // releasing them again if they had already been granted.
+    fn remove_waiter(&self, wait_key: usize) {
+        if wait_key != WAIT_KEY_NONE {
+            let mut state = self.state.lock().unwrap();
+            let waiter = state.waiters.remove(wait_key);
+            if waiter.granted {
+                state.release(waiter.permits);
+            } else {
+                if let Some(pos) = state.queue.iter().position(|&key| key == wait_key) {
+                    state.queue.remove(pos);
+                }
+                // The waiter may have been holding back the ones queued behind
+                // it.
+                state.grant();
+            }
+        }
+    }
+}
+
+// Sentinel for when no slot in the `Slab` has been dedicated to this object.
+const WAIT_KEY_NONE: usize = usize::max_value();
+
+/// A future which resolves when the requested permits of the target semaphore
+/// have been successfully acquired.
+pub struct SemaphoreAcquireFuture<'a> {
+    // `None` indicates that the permits were successfully acquired.
+    semaphore: Option<&'a Semaphore>,
+    permits: usize,
+    wait_key: usize,
+}
+
+impl fmt::Debug for SemaphoreAcquireFuture<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SemaphoreAcquireFuture")
+            .field("was_acquired", &self.semaphore.is_none())
+            .field("semaphore", &self.semaphore)
+            .field("permits", &self.permits)
+            .field(
+                "wait_key",
+                &(if self.wait_key == WAIT_KEY_NONE { None } else { Some(self.wait_key) }),
+            )
+            .finish()
+    }
+}
+
+impl FusedFuture for SemaphoreAcquireFuture<'_> {
+    fn is_terminated(&self) -> bool {
+        self.semaphore.is_none()
+    }
+}
+
+impl<'a> Future for SemaphoreAcquireFuture<'a> {
+    type Output = SemaphorePermit<'a>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        let semaphore = this.semaphore.expect("polled SemaphoreAcquireFuture after completion");
+
+        if semaphore.poll_acquire(this.permits, &mut this.wait_key, cx) {
+            this.semaphore = None;
+            Poll::Ready(SemaphorePermit { semaphore, permits: this.permits })
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl Drop for SemaphoreAcquireFuture<'_> {
+    fn drop(&mut self) {
+        if let Some(semaphore) = self.semaphore {
+            // This future was dropped before it acquired its permits.
+            semaphore.remove_waiter(self.wait_key);
+        }
+    }
+}
+
+/// A future which resolves when a permit of the target semaphore has been
+/// successfully acquired, resolving to an [`OwnedSemaphorePermit`].
+pub struct OwnedSemaphoreAcquireFuture {
+    // `None` indicates that the permits were successfully acquired.
+    semaphore: Option<Arc<Semaphore>>,
+    permits: usize,
+    wait_key: usize,
+}
+
+impl fmt::Debug for OwnedSemaphoreAcquireFuture {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OwnedSemaphoreAcquireFuture")
+            .field("was_acquired", &self.semaphore.is_none())
+            .field("semaphore", &self.semaphore)
+            .field("permits", &self.permits)
+            .field(
+                "wait_key",
+                &(if self.wait_key == WAIT_KEY_NONE { None } else { Some(self.wait_key) }),
+            )
+            .finish()
+    }
+}
+
+impl FusedFuture for OwnedSemaphoreAcquireFuture {
+    fn is_terminated(&self) -> bool {
+        self.semaphore.is_none()
+    }
+}
+
+impl Future for OwnedSemaphoreAcquireFuture {
+    type Output = OwnedSemaphorePermit;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        let semaphore =
+            this.semaphore.as_ref().expect("polled OwnedSemaphoreAcquireFuture after completion");
+
+        if semaphore.poll_acquire(this.permits, &mut this.wait_key, cx) {
+            let semaphore = this.semaphore.take().unwrap();
+            Poll::Ready(OwnedSemaphorePermit { semaphore, permits: this.permits })
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl Drop for OwnedSemaphoreAcquireFuture {
+    fn drop(&mut self) {
+        if let Some(semaphore) = &self.semaphore {
+            // This future was dropped before it acquired its permits.
+            semaphore.remove_waiter(self.wait_key);
+        }
+    }
+}
+
+/// An RAII guard returned by the `acquire`, `acquire_many`, `try_acquire` and
+/// `try_acquire_many` methods. When this structure is dropped (falls out of
+/// scope), its permits will be released.
+pub struct SemaphorePermit<'a> {
+    semaphore: &'a Semaphore,
+    permits: usize,
+}
+
+impl fmt::Debug for SemaphorePermit<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SemaphorePermit")
+            .field("permits", &self.permits)
+            .field("semaphore", &self.semaphore)
+            .finish()
+    }
+}
+
+impl Drop for SemaphorePermit<'_> {
+    fn drop(&mut self) {
+        self.semaphore.add_permits(self.permits);
+    }
+}
+
+/// An RAII guard returned by the `acquire_owned` and `try_acquire_owned`
+/// methods. When this structure is dropped (falls out of scope), its permit
+/// will be released.
+///
+/// Unlike [`SemaphorePermit`], this keeps the semaphore alive through an
+/// `Arc`, rather than borrowing it.
+pub struct OwnedSemaphorePermit {
+    semaphore: Arc<Semaphore>,
+    permits: usize,
+}
+
+impl fmt::Debug for OwnedSemaphorePermit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OwnedSemaphorePermit")
+            .field("permits", &self.permits)
+            .field("semaphore", &self.semaphore)
+            .finish()
+    }
+}
+
+impl Drop for OwnedSemaphorePermit {
+    fn drop(&mut self) {
+        self.semaphore.add_permits(self.permits);
+    }
+}
@@ -0,0 +1,29 @@
+use futures_core::future::Future;
+use futures_core::task::{Context, Poll};
+use futures_task::{waker, ArcWake};
+use std::pin::Pin;
+use std::sync::Arc;
+use std::thread::{self, Thread};
+
+// Wakes up a thread blocked in `park_on`.
+struct ThreadWaker(Thread);
+
+impl ArcWake for ThreadWaker {
+    fn wake_by_ref(arc_self: &Arc<Self>) {
+        arc_self.0.unpark();
+    }
+}
+
+// Drives `future` to completion on the current thread, parking it while the
+// future is pending.
+pub(crate) fn park_on<F: Future + Unpin>(mut future: F) -> F::Output {
+    let waker = waker(Arc::new(ThreadWaker(thread::current())));
+    let mut cx = Context::from_waker(&waker);
+    loop {
+        if let Poll::Ready(output) = Pin::new(&mut future).poll(&mut cx) {
+            return output;
+        }
+        // Spurious wakeups just poll the future again.
+        thread::park();
+    }
+}
@@ -47,6 +47,9 @@ pub use self::send_all::SendAll;
 mod unfold;
 pub use self::unfold::{unfold, Unfold};
 
+mod unfold_with_close;
+pub use self::unfold_with_close::{unfold_with_close, UnfoldWithClose};
+
 mod with;
 pub use self::with::With;
 
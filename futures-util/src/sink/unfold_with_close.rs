@@ -0,0 +1,133 @@
+use super::assert_sink;
+use crate::unfold_state::UnfoldState;
+use core::{future::Future, pin::Pin};
+use futures_core::ready;
+use futures_core::task::{Context, Poll};
+use futures_sink::Sink;
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Sink for the [`unfold_with_close`] function.
+    #[derive(Debug)]
+    #[must_use = "sinks do nothing unless polled"]
+    pub struct UnfoldWithClose<T, F, Fut, C, CFut> {
+        function: F,
+        close: Option<C>,
+        #[pin]
+        state: UnfoldState<T, Fut>,
+        #[pin]
+        closing: Option<CFut>,
+        close_failed: bool,
+    }
+}
+
+/// Create a sink from a function which processes one item at a time, and a
+/// function which is called with the final state when the sink is closed.
+///
+/// This works like [`unfold`](super::unfold), except that once all items
+/// have been processed, closing the sink calls `close` with the state and
+/// waits for the returned future, which allows finalizing the state, e.g. by
+/// committing a transaction. An error of that future is returned from
+/// closing the sink. `close` is called at most once; closing the sink again
+/// after that has succeeded does nothing, and panics if it failed.
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::sink::{self, SinkExt};
+///
+/// let mut total = 0;
+/// let unfold = sink::unfold_with_close(
+///     0,
+///     |sum, i: i32| async move { Ok::<_, std::convert::Infallible>(sum + i) },
+///     |sum| {
+///         total = sum;
+///         async { Ok(()) }
+///     },
+/// );
+/// futures::pin_mut!(unfold);
+/// unfold.send(5).await?;
+/// unfold.send(7).await?;
+/// unfold.close().await?;
+/// assert_eq!(total, 12);
+/// # Ok::<(), std::convert::Infallible>(()) }).unwrap();
+/// ```
+pub fn unfold_with_close<T, F, Fut, C, CFut, Item, E>(
+    init: T,
+    function: F,
+    close: C,
+) -> UnfoldWithClose<T, F, Fut, C, CFut>
+where
+    F: FnMut(T, Item) -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+    C: FnOnce(T) -> CFut,
+    CFut: Future<Output = Result<(), E>>,
+{
+    assert_sink::<Item, E, _>(UnfoldWithClose {
+        function,
+        close: Some(close),
+        state: UnfoldState::Value { value: init },
+        closing: None,
+        close_failed: false,
+    })
+}
+
+impl<T, F, Fut, C, CFut, Item, E> Sink<Item> for UnfoldWithClose<T, F, Fut, C, CFut>
+where
+    F: FnMut(T, Item) -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+    C: FnOnce(T) -> CFut,
+    CFut: Future<Output = Result<(), E>>,
+{
+    type Error = E;
+
+    fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.poll_flush(cx)
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: Item) -> Result<(), Self::Error> {
+        let mut this = self.project();
+        let future = match this.state.as_mut().take_value() {
+            Some(value) => (this.function)(value, item),
+            None => panic!("start_send called without poll_ready being called first"),
+        };
+        this.state.set(UnfoldState::Future { future });
+        Ok(())
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let mut this = self.project();
+        Poll::Ready(if let Some(future) = this.state.as_mut().project_future() {
+            match ready!(future.poll(cx)) {
+                Ok(state) => {
+                    this.state.set(UnfoldState::Value { value: state });
+                    Ok(())
+                }
+                Err(err) => Err(err),
+            }
+        } else {
+            Ok(())
+        })
+    }
+
+    fn poll_close(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        ready!(self.as_mut().poll_flush(cx))?;
+        let mut this = self.project();
+
+        if let Some(value) = this.state.as_mut().take_value() {
+            let close = this.close.take().expect("the state is only taken when closing");
+            this.closing.set(Some(close(value)));
+        }
+
+        if let Some(closing) = this.closing.as_mut().as_pin_mut() {
+            let res = ready!(closing.poll(cx));
+            this.closing.set(None);
+            *this.close_failed = res.is_err();
+            return Poll::Ready(res);
+        }
+        // Reporting success here would hide the error returned before.
+        assert!(!*this.close_failed, "poll_close called after the close function failed");
+        Poll::Ready(Ok(()))
+    }
+}
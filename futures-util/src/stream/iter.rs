@@ -1,5 +1,11 @@
 use super::assert_stream;
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "alloc")]
+use super::{BufferUnordered, Buffered, StreamExt};
 use core::pin::Pin;
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "alloc")]
+use futures_core::future::Future;
 use futures_core::stream::Stream;
 use futures_core::task::{Context, Poll};
 
@@ -33,6 +39,80 @@ where
     assert_stream::<I::Item, _>(Iter { iter: i.into_iter() })
 }
 
+/// Creates a stream which runs the futures of `iter`, at most `n` of them at
+/// a time, and yields their outputs in the order of `iter`.
+///
+/// This is a shorthand for
+/// [`iter(futures).buffered(n)`](super::StreamExt::buffered). If `n` is `None`,
+/// the number of futures running at the same time is unlimited.
+///
+/// This function is only available when the `std` or `alloc` feature of this
+/// library is activated, and it is activated by default.
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::future;
+/// use futures::stream::{self, StreamExt};
+///
+/// let futures = (1..=4).map(|i| future::ready(i * 10));
+/// let stream = stream::buffered(futures, 2);
+/// assert_eq!(stream.collect::<Vec<_>>().await, vec![10, 20, 30, 40]);
+/// # });
+/// ```
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "alloc")]
+pub fn buffered<I>(iter: I, n: impl Into<Option<usize>>) -> Buffered<Iter<I::IntoIter>>
+where
+    I: IntoIterator,
+    I::Item: Future,
+{
+    assert_stream::<<I::Item as Future>::Output, _>(self::iter(iter).buffered(n))
+}
+
+/// Creates a stream which runs the futures of `iter`, at most `n` of them at
+/// a time, and yields their outputs in the order in which they complete.
+///
+/// This is a shorthand for
+/// [`iter(futures).buffer_unordered(n)`](super::StreamExt::buffer_unordered).
+/// If `n` is `None`, the number of futures running at the same time is
+/// unlimited.
+///
+/// This function is only available when the `std` or `alloc` feature of this
+/// library is activated, and it is activated by default.
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::channel::oneshot;
+/// use futures::stream::{self, StreamExt};
+///
+/// let (send_one, recv_one) = oneshot::channel();
+/// let (send_two, recv_two) = oneshot::channel();
+/// let mut stream = stream::buffer_unordered(vec![recv_one, recv_two], 2);
+///
+/// send_two.send(2).unwrap();
+/// assert_eq!(stream.next().await, Some(Ok(2)));
+/// send_one.send(1).unwrap();
+/// assert_eq!(stream.next().await, Some(Ok(1)));
+/// assert_eq!(stream.next().await, None);
+/// # });
+/// ```
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "alloc")]
+pub fn buffer_unordered<I>(
+    iter: I,
+    n: impl Into<Option<usize>>,
+) -> BufferUnordered<Iter<I::IntoIter>>
+where
+    I: IntoIterator,
+    I::Item: Future,
+{
+    assert_stream::<<I::Item as Future>::Output, _>(self::iter(iter).buffer_unordered(n))
+}
+
 impl<I> Stream for Iter<I>
 where
     I: Iterator,
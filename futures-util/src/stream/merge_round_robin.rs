@@ -0,0 +1,136 @@
+use super::assert_stream;
+use crate::stream::StreamExt;
+use alloc::vec::Vec;
+use core::pin::Pin;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+
+/// Stream for the [`merge_round_robin`] function.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct MergeRoundRobin<St> {
+    streams: Vec<St>,
+    cursor: usize,
+}
+
+impl<St: Unpin> Unpin for MergeRoundRobin<St> {}
+
+/// Merges the items of a list of streams of the same type, taking turns
+/// between them.
+///
+/// The streams are polled in turn, starting with the one after the stream
+/// which yielded the previous item, and skipping those which are pending, so
+/// a stream which is always ready can't keep the others from making
+/// progress. Streams which have ended are removed from the rotation, and the
+/// returned stream ends once all of them have.
+///
+/// Unlike [`select_all`](super::select_all), this polls all the remaining
+/// streams until one of them is ready, rather than only those which have been
+/// woken, so it is best suited for a small number of streams.
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::stream::{self, StreamExt};
+///
+/// let merged = stream::merge_round_robin(vec![
+///     stream::iter(vec![1, 2, 3]),
+///     stream::iter(vec![10]),
+///     stream::iter(vec![100, 200]),
+/// ]);
+///
+/// assert_eq!(merged.collect::<Vec<_>>().await, [1, 10, 100, 2, 200, 3]);
+/// # });
+/// ```
+pub fn merge_round_robin<St>(streams: Vec<St>) -> MergeRoundRobin<St>
+where
+    St: Stream + Unpin,
+{
+    assert_stream::<St::Item, _>(MergeRoundRobin { streams, cursor: 0 })
+}
+
+impl<St> MergeRoundRobin<St> {
+    /// Returns the position of the stream which is polled first next time,
+    /// among the streams which haven't ended yet.
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// Returns the streams which haven't ended yet, in the order they are
+    /// polled in, starting from position 0.
+    pub fn get_ref(&self) -> &[St] {
+        &self.streams
+    }
+
+    /// Returns the number of streams which haven't ended yet.
+    pub fn len(&self) -> usize {
+        self.streams.len()
+    }
+
+    /// Returns `true` if all the streams have ended.
+    pub fn is_empty(&self) -> bool {
+        self.streams.is_empty()
+    }
+
+    /// Consumes this combinator, returning the streams which haven't ended
+    /// yet.
+    pub fn into_inner(self) -> Vec<St> {
+        self.streams
+    }
+}
+
+impl<St> Stream for MergeRoundRobin<St>
+where
+    St: Stream + Unpin,
+{
+    type Item = St::Item;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+        let mut remaining = this.streams.len();
+        while remaining > 0 {
+            remaining -= 1;
+            if this.cursor >= this.streams.len() {
+                this.cursor = 0;
+            }
+            match this.streams[this.cursor].poll_next_unpin(cx) {
+                Poll::Ready(Some(item)) => {
+                    this.cursor = (this.cursor + 1) % this.streams.len();
+                    return Poll::Ready(Some(item));
+                }
+                // The next stream moves into this position.
+                Poll::Ready(None) => drop(this.streams.remove(this.cursor)),
+                Poll::Pending => this.cursor += 1,
+            }
+        }
+
+        if this.cursor >= this.streams.len() {
+            this.cursor = 0;
+        }
+        if this.streams.is_empty() {
+            Poll::Ready(None)
+        } else {
+            Poll::Pending
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.streams.iter().map(|st| st.size_hint()).fold((0, Some(0)), |(lo, hi), (l, h)| {
+            let hi = match (hi, h) {
+                (Some(a), Some(b)) => a.checked_add(b),
+                _ => None,
+            };
+            (lo.saturating_add(l), hi)
+        })
+    }
+}
+
+impl<St> FusedStream for MergeRoundRobin<St>
+where
+    St: Stream + Unpin,
+{
+    fn is_terminated(&self) -> bool {
+        self.streams.is_empty()
+    }
+}
@@ -18,28 +18,43 @@ pub use futures_core::stream::{FusedStream, Stream, TryStream};
 #[allow(clippy::module_inception)]
 mod stream;
 pub use self::stream::{
-    Chain, Collect, Concat, Cycle, Enumerate, Filter, FilterMap, FlatMap, Flatten, Fold, ForEach,
-    Fuse, Inspect, Map, Next, NextIf, NextIfEq, Peek, PeekMut, Peekable, Scan, SelectNextSome,
-    Skip, SkipWhile, StreamExt, StreamFuture, Take, TakeUntil, TakeWhile, Then, TryFold,
-    TryForEach, Unzip, Zip,
+    Chain, ChainWith, Cloned, Collect, Concat, Copied, Cycle, Enumerate, Filter, FilterMap,
+    FlatMap, Flatten, Fold, ForEach, Fuse, Inspect, InspectPending, InspectPoll, InspectReady, Map,
+    Next, NextIf, NextIfEq, Peek, PeekMut, Peekable, Scan, SelectNextSome, Skip, SkipWhile,
+    StreamExt, StreamFuture, Take, TakeUntil, TakeWhile, Then, TryFold, TryForEach, Unzip,
+    WithKeepalive, Zip,
 };
 
 #[cfg(feature = "std")]
 pub use self::stream::CatchUnwind;
 
 #[cfg(feature = "alloc")]
-pub use self::stream::Chunks;
+pub use self::stream::{Chunks, InvalidChunkSize};
 
 #[cfg(feature = "alloc")]
 pub use self::stream::ReadyChunks;
 
+#[cfg(feature = "std")]
+pub use self::stream::{ChunkedStreams, StreamChunk};
+
 #[cfg(feature = "sink")]
 #[cfg_attr(docsrs, doc(cfg(feature = "sink")))]
 pub use self::stream::Forward;
 
 #[cfg(not(futures_no_atomic_cas))]
 #[cfg(feature = "alloc")]
-pub use self::stream::{BufferUnordered, Buffered, ForEachConcurrent, TryForEachConcurrent};
+pub use self::stream::{
+    BufferUnordered, Buffered, BufferedFlatOrdered, ForEachConcurrent, TryForEachConcurrent,
+};
+
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "std")]
+pub use self::stream::BufferUnorderedCatchUnwind;
+
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "channel")]
+#[cfg_attr(docsrs, doc(cfg(feature = "channel")))]
+pub use self::stream::MapSpawned;
 
 #[cfg(not(futures_no_atomic_cas))]
 #[cfg(feature = "sink")]
@@ -49,9 +64,10 @@ pub use self::stream::{ReuniteError, SplitSink, SplitStream};
 
 mod try_stream;
 pub use self::try_stream::{
-    try_unfold, AndThen, ErrInto, InspectErr, InspectOk, IntoStream, MapErr, MapOk, OrElse,
-    TryCollect, TryConcat, TryFilter, TryFilterMap, TryFlatten, TryNext, TrySkipWhile,
-    TryStreamExt, TryTakeWhile, TryUnfold,
+    try_unfold, AndThen, Elapsed, ErrInto, FlattenError, InspectErr, InspectOk, IntoStream,
+    LogAndSkipErrors, MapErr, MapOk, OrElse, TryCollect, TryConcat, TryFilter, TryFilterMap,
+    TryFlatten, TryFlattenTagged, TryNext, TryNextTimeout, TrySkipWhile, TryStreamExt,
+    TryTakeWhile, TryUnfold,
 };
 
 #[cfg(feature = "io")]
@@ -73,6 +89,9 @@ pub use self::try_stream::{TryChunks, TryChunksError};
 // Primitive streams
 
 mod iter;
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "alloc")]
+pub use self::iter::{buffer_unordered, buffered};
 pub use self::iter::{iter, Iter};
 
 mod repeat;
@@ -87,6 +106,9 @@ pub use self::empty::{empty, Empty};
 mod once;
 pub use self::once::{once, Once};
 
+mod once_with;
+pub use self::once_with::{once_with, OnceWith};
+
 mod pending;
 pub use self::pending::{pending, Pending};
 
@@ -96,15 +118,26 @@ pub use self::poll_fn::{poll_fn, PollFn};
 mod poll_immediate;
 pub use self::poll_immediate::{poll_immediate, PollImmediate};
 
+mod retry;
+pub use self::retry::{retry, retry_with_backoff, Retry, RetryWithBackoff};
+
 mod select;
 pub use self::select::{select, Select};
 
 mod select_with_strategy;
 pub use self::select_with_strategy::{select_with_strategy, PollNext, SelectWithStrategy};
 
+#[cfg(feature = "alloc")]
+mod merge_round_robin;
+#[cfg(feature = "alloc")]
+pub use self::merge_round_robin::{merge_round_robin, MergeRoundRobin};
+
 mod unfold;
 pub use self::unfold::{unfold, Unfold};
 
+mod zip;
+pub use self::zip::{zip3, zip4, Zip3, Zip4};
+
 #[cfg(not(futures_no_atomic_cas))]
 #[cfg(feature = "alloc")]
 mod futures_ordered;
@@ -132,8 +165,14 @@ pub use self::select_all::{select_all, SelectAll};
 #[cfg(feature = "alloc")]
 mod abortable;
 #[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "std")]
+pub use crate::abortable::AbortSet;
+#[cfg(not(futures_no_atomic_cas))]
 #[cfg(feature = "alloc")]
-pub use crate::abortable::{AbortHandle, AbortRegistration, Abortable, Aborted};
+pub use crate::abortable::{
+    AbortHandle, AbortOnDropHandle, AbortRegistration, Abortable, AbortableWith, Aborted,
+    AbortedWith,
+};
 #[cfg(not(futures_no_atomic_cas))]
 #[cfg(feature = "alloc")]
 pub use abortable::abortable;
@@ -0,0 +1,35 @@
+use super::assert_stream;
+use super::Once;
+use crate::future::{lazy_then, LazyThen};
+use futures_core::future::Future;
+
+/// Creates a stream of a single element, yielded by the future which the
+/// given closure creates once the stream is first polled.
+///
+/// Unlike [`once`](super::once()), this doesn't create the future until it's
+/// needed, so no work is wasted if the stream is dropped without ever being
+/// polled. The closure is called at most once.
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::stream::{self, StreamExt};
+///
+/// let stream = stream::once_with(|| async { 17 });
+/// let collected = stream.collect::<Vec<i32>>().await;
+/// assert_eq!(collected, vec![17]);
+/// # });
+/// ```
+pub fn once_with<F, Fut>(f: F) -> OnceWith<F, Fut>
+where
+    F: FnOnce() -> Fut,
+    Fut: Future,
+{
+    assert_stream::<Fut::Output, _>(OnceWith { inner: Once::new(lazy_then(f)) })
+}
+
+delegate_all!(
+    /// Stream for the [`once_with`] function.
+    OnceWith<F, Fut>(
+        Once<LazyThen<F, Fut>>
+    ): Debug + Stream + FusedStream
+);
@@ -0,0 +1,241 @@
+use super::assert_stream;
+use crate::future::{ready, Ready};
+use core::fmt;
+use core::pin::Pin;
+use futures_core::future::Future;
+use futures_core::ready;
+use futures_core::stream::{FusedStream, Stream, TryStream};
+use futures_core::task::{Context, Poll};
+use pin_project_lite::pin_project;
+
+/// Creates a stream which yields the items of the streams created by
+/// `factory`, creating a new one whenever the current one yields an error.
+///
+/// The first stream is created on the first poll. Whenever the current stream
+/// yields an error, it is dropped and `factory` is called to create a new
+/// stream to continue with, up to `max` times over the lifetime of the
+/// returned stream. After that, the next error is yielded, and the returned
+/// stream ends. It also ends once the current stream does.
+///
+/// See [`retry_with_backoff`] for waiting between retries.
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::stream::{self, StreamExt};
+///
+/// let mut attempt = 0;
+/// let stream = stream::retry(
+///     || {
+///         attempt += 1;
+///         stream::iter(vec![Ok(attempt), Err("lost connection")])
+///     },
+///     2,
+/// );
+///
+/// let result = stream.collect::<Vec<_>>().await;
+/// assert_eq!(result, vec![Ok(1), Ok(2), Ok(3), Err("lost connection")]);
+/// # });
+/// ```
+pub fn retry<F, St>(factory: F, max: usize) -> Retry<F, St>
+where
+    F: FnMut() -> St,
+    St: TryStream,
+{
+    let inner = retry_with_backoff(factory, max, no_backoff as fn(usize) -> Ready<()>);
+    assert_stream::<Result<St::Ok, St::Error>, _>(Retry { inner })
+}
+
+fn no_backoff(_: usize) -> Ready<()> {
+    ready(())
+}
+
+/// Creates a stream which yields the items of the streams created by
+/// `factory`, creating a new one whenever the current one yields an error,
+/// after waiting for `backoff`.
+///
+/// This works like [`retry`], except that before each retry, `backoff` is
+/// called with the number of the retry, starting at 1, and the returned
+/// future is awaited before `factory` is called to create the next stream.
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::future;
+/// use futures::stream::{self, StreamExt};
+///
+/// let mut delays = Vec::new();
+/// let stream = stream::retry_with_backoff(
+///     || stream::iter(vec![Ok(()), Err("lost connection")]),
+///     3,
+///     |retry| {
+///         // This could wait for 100 ms, 200 ms, 400 ms, ...
+///         delays.push(100 << (retry - 1));
+///         future::ready(())
+///     },
+/// );
+///
+/// assert_eq!(stream.count().await, 5);
+/// assert_eq!(delays, [100, 200, 400]);
+/// # });
+/// ```
+pub fn retry_with_backoff<F, St, B, Fut>(
+    factory: F,
+    max: usize,
+    backoff: B,
+) -> RetryWithBackoff<F, St, B, Fut>
+where
+    F: FnMut() -> St,
+    St: TryStream,
+    B: FnMut(usize) -> Fut,
+    Fut: Future<Output = ()>,
+{
+    assert_stream::<Result<St::Ok, St::Error>, _>(RetryWithBackoff {
+        factory,
+        backoff,
+        stream: None,
+        delay: None,
+        retries: 0,
+        max,
+        done: false,
+    })
+}
+
+pin_project! {
+    /// Stream for the [`retry`] function.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct Retry<F, St> {
+        #[pin]
+        inner: RetryWithBackoff<F, St, fn(usize) -> Ready<()>, Ready<()>>,
+    }
+}
+
+impl<F, St> fmt::Debug for Retry<F, St>
+where
+    St: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Retry")
+            .field("stream", &self.inner.stream)
+            .field("retries", &self.inner.retries)
+            .field("max", &self.inner.max)
+            .finish()
+    }
+}
+
+impl<F, St> Stream for Retry<F, St>
+where
+    F: FnMut() -> St,
+    St: TryStream,
+{
+    type Item = Result<St::Ok, St::Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.project().inner.poll_next(cx)
+    }
+}
+
+impl<F, St> FusedStream for Retry<F, St>
+where
+    F: FnMut() -> St,
+    St: TryStream,
+{
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}
+
+pin_project! {
+    /// Stream for the [`retry_with_backoff`] function.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct RetryWithBackoff<F, St, B, Fut> {
+        factory: F,
+        backoff: B,
+        #[pin]
+        stream: Option<St>,
+        #[pin]
+        delay: Option<Fut>,
+        retries: usize,
+        max: usize,
+        done: bool,
+    }
+}
+
+impl<F, St, B, Fut> fmt::Debug for RetryWithBackoff<F, St, B, Fut>
+where
+    St: fmt::Debug,
+    Fut: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RetryWithBackoff")
+            .field("stream", &self.stream)
+            .field("delay", &self.delay)
+            .field("retries", &self.retries)
+            .field("max", &self.max)
+            .finish()
+    }
+}
+
+impl<F, St, B, Fut> Stream for RetryWithBackoff<F, St, B, Fut>
+where
+    F: FnMut() -> St,
+    St: TryStream,
+    B: FnMut(usize) -> Fut,
+    Fut: Future<Output = ()>,
+{
+    type Item = Result<St::Ok, St::Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        if *this.done {
+            return Poll::Ready(None);
+        }
+
+        loop {
+            if let Some(delay) = this.delay.as_mut().as_pin_mut() {
+                ready!(delay.poll(cx));
+                this.delay.set(None);
+            }
+
+            let stream = match this.stream.as_mut().as_pin_mut() {
+                Some(stream) => stream,
+                None => {
+                    this.stream.set(Some((this.factory)()));
+                    this.stream.as_mut().as_pin_mut().unwrap()
+                }
+            };
+
+            match ready!(stream.try_poll_next(cx)) {
+                Some(Ok(item)) => return Poll::Ready(Some(Ok(item))),
+                Some(Err(e)) => {
+                    this.stream.set(None);
+                    if *this.retries == *this.max {
+                        *this.done = true;
+                        return Poll::Ready(Some(Err(e)));
+                    }
+                    *this.retries += 1;
+                    this.delay.set(Some((this.backoff)(*this.retries)));
+                }
+                None => {
+                    this.stream.set(None);
+                    *this.done = true;
+                    return Poll::Ready(None);
+                }
+            }
+        }
+    }
+}
+
+impl<F, St, B, Fut> FusedStream for RetryWithBackoff<F, St, B, Fut>
+where
+    F: FnMut() -> St,
+    St: TryStream,
+    B: FnMut(usize) -> Fut,
+    Fut: Future<Output = ()>,
+{
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
@@ -1,4 +1,5 @@
 use crate::stream::{Fuse, FuturesUnordered, StreamExt};
+use alloc::vec::Vec;
 use core::fmt;
 use core::num::NonZeroUsize;
 use core::pin::Pin;
@@ -55,6 +56,24 @@ where
     }
 
     delegate_access_inner!(stream, St, (.));
+
+    /// Consumes this combinator, returning the futures that were still in
+    /// flight, so that they can be reclaimed instead of being dropped.
+    ///
+    /// The futures are returned in no particular order. Items of the
+    /// underlying stream that were not yet pulled into the buffer are not
+    /// included; use [`get_mut`](Self::get_mut) beforehand to drain them if
+    /// needed.
+    ///
+    /// This is only available for [`Unpin`] futures, because the buffered
+    /// futures may already have been polled and thus cannot be moved out
+    /// otherwise. `!Unpin` futures can be boxed with [`Box::pin`] first.
+    pub fn into_in_flight(self) -> Vec<St::Item>
+    where
+        St::Item: Unpin,
+    {
+        self.in_progress_queue.into_iter().collect()
+    }
 }
 
 impl<St> Stream for BufferUnordered<St>
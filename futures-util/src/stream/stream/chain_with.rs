@@ -0,0 +1,88 @@
+use core::fmt;
+use core::pin::Pin;
+use futures_core::ready;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Stream for the [`chain_with`](super::StreamExt::chain_with) method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct ChainWith<St1, St2, F> {
+        #[pin]
+        first: Option<St1>,
+        f: Option<F>,
+        #[pin]
+        second: Option<St2>,
+    }
+}
+
+impl<St1, St2, F> fmt::Debug for ChainWith<St1, St2, F>
+where
+    St1: fmt::Debug,
+    St2: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ChainWith")
+            .field("first", &self.first)
+            .field("second", &self.second)
+            .finish()
+    }
+}
+
+impl<St1, St2, F> ChainWith<St1, St2, F>
+where
+    St1: Stream,
+    St2: Stream<Item = St1::Item>,
+    F: FnOnce() -> St2,
+{
+    pub(super) fn new(stream1: St1, f: F) -> Self {
+        Self { first: Some(stream1), f: Some(f), second: None }
+    }
+}
+
+impl<St1, St2, F> FusedStream for ChainWith<St1, St2, F>
+where
+    St1: Stream,
+    St2: FusedStream<Item = St1::Item>,
+    F: FnOnce() -> St2,
+{
+    fn is_terminated(&self) -> bool {
+        self.first.is_none() && self.second.as_ref().map_or(true, |s| s.is_terminated())
+    }
+}
+
+impl<St1, St2, F> Stream for ChainWith<St1, St2, F>
+where
+    St1: Stream,
+    St2: Stream<Item = St1::Item>,
+    F: FnOnce() -> St2,
+{
+    type Item = St1::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        if let Some(first) = this.first.as_mut().as_pin_mut() {
+            if let Some(item) = ready!(first.poll_next(cx)) {
+                return Poll::Ready(Some(item));
+            }
+            this.first.set(None);
+        }
+        if let Some(f) = this.f.take() {
+            this.second.set(Some(f()));
+        }
+        match this.second.as_pin_mut() {
+            Some(second) => second.poll_next(cx),
+            None => Poll::Ready(None),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match (&self.first, &self.second) {
+            // Nothing is known about the second stream until it's created.
+            (Some(first), _) => (first.size_hint().0, None),
+            (None, Some(second)) => second.size_hint(),
+            (None, None) => (0, Some(0)),
+        }
+    }
+}
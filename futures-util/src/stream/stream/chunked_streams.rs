@@ -0,0 +1,196 @@
+use alloc::boxed::Box;
+use core::fmt;
+use core::pin::Pin;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll, Waker};
+use std::sync::{Arc, Mutex};
+
+struct State<St: Stream> {
+    stream: Pin<Box<St>>,
+    // The first item of the current chunk, read ahead by the outer stream.
+    peeked: Option<St::Item>,
+    // Items of the current chunk which haven't been yielded yet. If the chunk
+    // was dropped early, the outer stream skips these.
+    remaining: usize,
+    // Whether the current chunk is still being consumed.
+    active: bool,
+    // Identifies the current chunk, so that earlier ones can't affect it.
+    chunk: usize,
+    done: bool,
+    waker: Option<Waker>,
+}
+
+impl<St: Stream> State<St> {
+    fn finish_chunk(&mut self) {
+        self.active = false;
+        if let Some(waker) = self.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+/// Stream for the [`chunked_streams`](super::StreamExt::chunked_streams)
+/// method.
+#[must_use = "streams do nothing unless polled"]
+pub struct ChunkedStreams<St: Stream> {
+    state: Arc<Mutex<State<St>>>,
+    cap: usize,
+}
+
+impl<St: Stream> Unpin for ChunkedStreams<St> {}
+
+impl<St: Stream> ChunkedStreams<St> {
+    pub(super) fn new(stream: St, capacity: usize) -> Self {
+        assert!(capacity > 0);
+        let state = State {
+            stream: Box::pin(stream),
+            peeked: None,
+            remaining: 0,
+            active: false,
+            chunk: 0,
+            done: false,
+            waker: None,
+        };
+        Self { state: Arc::new(Mutex::new(state)), cap: capacity }
+    }
+}
+
+impl<St: Stream> fmt::Debug for ChunkedStreams<St> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ChunkedStreams").field("cap", &self.cap).finish()
+    }
+}
+
+impl<St: Stream> Stream for ChunkedStreams<St> {
+    type Item = StreamChunk<St>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut state = self.state.lock().unwrap();
+        if state.active {
+            state.waker = Some(cx.waker().clone());
+            return Poll::Pending;
+        }
+
+        // Skip whatever is left of a chunk which was dropped early.
+        while state.remaining > 0 && !state.done {
+            if state.peeked.take().is_none() {
+                match state.stream.as_mut().poll_next(cx) {
+                    Poll::Ready(Some(_)) => {}
+                    Poll::Ready(None) => state.done = true,
+                    Poll::Pending => return Poll::Pending,
+                }
+            }
+            state.remaining -= 1;
+        }
+        if state.done {
+            return Poll::Ready(None);
+        }
+
+        // Only start a chunk once it's known not to be empty.
+        match state.stream.as_mut().poll_next(cx) {
+            Poll::Ready(Some(item)) => {
+                state.peeked = Some(item);
+                state.remaining = self.cap;
+                state.active = true;
+                state.chunk = state.chunk.wrapping_add(1);
+                Poll::Ready(Some(StreamChunk { state: self.state.clone(), id: state.chunk }))
+            }
+            Poll::Ready(None) => {
+                state.done = true;
+                Poll::Ready(None)
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+impl<St: Stream> FusedStream for ChunkedStreams<St> {
+    fn is_terminated(&self) -> bool {
+        let state = self.state.lock().unwrap();
+        state.done && !state.active
+    }
+}
+
+/// A sub-stream of at most a fixed number of items, yielded by
+/// [`ChunkedStreams`].
+///
+/// The items are read from the stream which [`ChunkedStreams`] wraps, so it
+/// yields the next chunk only once this one has been consumed or dropped.
+/// Items of a chunk which is dropped early are skipped.
+#[must_use = "streams do nothing unless polled"]
+pub struct StreamChunk<St: Stream> {
+    state: Arc<Mutex<State<St>>>,
+    id: usize,
+}
+
+impl<St: Stream> Unpin for StreamChunk<St> {}
+
+impl<St: Stream> StreamChunk<St> {
+    fn is_current(&self, state: &State<St>) -> bool {
+        state.active && state.chunk == self.id
+    }
+}
+
+impl<St: Stream> fmt::Debug for StreamChunk<St> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let state = self.state.lock().unwrap();
+        let remaining = if self.is_current(&state) { state.remaining } else { 0 };
+        f.debug_struct("StreamChunk").field("remaining", &remaining).finish()
+    }
+}
+
+impl<St: Stream> Stream for StreamChunk<St> {
+    type Item = St::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut state = self.state.lock().unwrap();
+        if !self.is_current(&state) {
+            return Poll::Ready(None);
+        }
+
+        let item = match state.peeked.take() {
+            Some(item) => item,
+            None => match state.stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(item)) => item,
+                Poll::Ready(None) => {
+                    state.done = true;
+                    state.finish_chunk();
+                    return Poll::Ready(None);
+                }
+                Poll::Pending => return Poll::Pending,
+            },
+        };
+        state.remaining -= 1;
+        if state.remaining == 0 {
+            state.finish_chunk();
+        }
+        Poll::Ready(Some(item))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let state = self.state.lock().unwrap();
+        if !self.is_current(&state) {
+            return (0, Some(0));
+        }
+        let lower = if state.peeked.is_some() { 1 } else { 0 };
+        (lower, Some(state.remaining))
+    }
+}
+
+impl<St: Stream> FusedStream for StreamChunk<St> {
+    fn is_terminated(&self) -> bool {
+        !self.is_current(&self.state.lock().unwrap())
+    }
+}
+
+impl<St: Stream> Drop for StreamChunk<St> {
+    fn drop(&mut self) {
+        // A poisoned lock means the wrapped stream panicked, so there's no
+        // use in waking the outer stream.
+        if let Ok(mut state) = self.state.lock() {
+            if self.is_current(&state) {
+                state.finish_chunk();
+            }
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use crate::stream::Fuse;
 use alloc::vec::Vec;
+use core::fmt;
 use core::mem;
 use core::pin::Pin;
 use futures_core::ready;
@@ -27,7 +28,17 @@ where
 {
     pub(super) fn new(stream: St, capacity: usize) -> Self {
         assert!(capacity > 0);
+        Self::new_unchecked(stream, capacity)
+    }
 
+    pub(super) fn try_new(stream: St, capacity: usize) -> Result<Self, InvalidChunkSize> {
+        if capacity == 0 {
+            return Err(InvalidChunkSize);
+        }
+        Ok(Self::new_unchecked(stream, capacity))
+    }
+
+    fn new_unchecked(stream: St, capacity: usize) -> Self {
         Self {
             stream: super::Fuse::new(stream),
             items: Vec::with_capacity(capacity),
@@ -104,3 +115,18 @@ where
 
     delegate_sink!(stream, Item);
 }
+
+/// Error returned by [`try_chunks_cap`](super::StreamExt::try_chunks_cap) and
+/// [`try_ready_chunks_cap`](super::StreamExt::try_ready_chunks_cap) when the
+/// requested capacity is zero.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct InvalidChunkSize;
+
+impl fmt::Display for InvalidChunkSize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "chunk capacity must be greater than zero")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidChunkSize {}
@@ -59,6 +59,18 @@ where
             }
         })
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.next.as_ref().map_or((0, Some(0)), Stream::size_hint);
+        match self.stream.size_hint() {
+            // No more inner streams are coming, so the active one is all
+            // that's left.
+            (_, Some(0)) => (lower, upper),
+            // Nothing is known about how long the remaining inner streams
+            // are.
+            _ => (lower, None),
+        }
+    }
 }
 
 // Forwarding impl of Sink from the underlying stream
@@ -0,0 +1,78 @@
+use crate::future::{poll_inspected, PollEvent};
+use core::fmt;
+use core::pin::Pin;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+#[cfg(feature = "sink")]
+use futures_sink::Sink;
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Stream for the [`inspect_poll`](super::StreamExt::inspect_poll) method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct InspectPoll<St, F> {
+        #[pin]
+        stream: St,
+        f: F,
+        polls: usize,
+    }
+}
+
+impl<St, F> fmt::Debug for InspectPoll<St, F>
+where
+    St: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InspectPoll")
+            .field("stream", &self.stream)
+            .field("polls", &self.polls)
+            .finish()
+    }
+}
+
+impl<St, F> InspectPoll<St, F> {
+    pub(super) fn new(stream: St, f: F) -> Self {
+        Self { stream, f, polls: 0 }
+    }
+
+    delegate_access_inner!(stream, St, ());
+}
+
+impl<St, F> FusedStream for InspectPoll<St, F>
+where
+    St: FusedStream,
+    F: FnMut(PollEvent),
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+impl<St, F> Stream for InspectPoll<St, F>
+where
+    St: Stream,
+    F: FnMut(PollEvent),
+{
+    type Item = St::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<St::Item>> {
+        let this = self.project();
+        let stream = this.stream;
+        poll_inspected(this.polls, this.f, || stream.poll_next(cx))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+#[cfg(feature = "sink")]
+impl<S, F, Item> Sink<Item> for InspectPoll<S, F>
+where
+    S: Sink<Item>,
+{
+    type Error = S::Error;
+
+    delegate_sink!(stream, Item);
+}
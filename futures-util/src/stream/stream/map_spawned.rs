@@ -0,0 +1,128 @@
+use crate::future::{lazy, RemoteHandle};
+use crate::stream::{Fuse, FuturesUnordered, StreamExt};
+use crate::task::{Spawn, SpawnError, SpawnExt};
+use alloc::sync::Arc;
+use core::fmt;
+use core::num::NonZeroUsize;
+use core::pin::Pin;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Stream for the [`map_spawned`](super::StreamExt::map_spawned) method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct MapSpawned<St, Sp, F, B> {
+        #[pin]
+        stream: Fuse<St>,
+        in_progress_queue: FuturesUnordered<RemoteHandle<B>>,
+        spawner: Sp,
+        f: Arc<F>,
+        limit: Option<NonZeroUsize>,
+    }
+}
+
+impl<St, Sp, F, B> fmt::Debug for MapSpawned<St, Sp, F, B>
+where
+    St: fmt::Debug,
+    Sp: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MapSpawned")
+            .field("stream", &self.stream)
+            .field("in_progress_queue", &self.in_progress_queue)
+            .field("spawner", &self.spawner)
+            .field("limit", &self.limit)
+            .finish()
+    }
+}
+
+impl<St, Sp, F, B> MapSpawned<St, Sp, F, B>
+where
+    St: Stream,
+    St::Item: Send + 'static,
+    Sp: Spawn,
+    F: Fn(St::Item) -> B + Send + Sync + 'static,
+    B: Send + 'static,
+{
+    pub(super) fn new(stream: St, spawner: Sp, limit: Option<usize>, f: F) -> Self {
+        Self {
+            stream: super::Fuse::new(stream),
+            in_progress_queue: FuturesUnordered::new(),
+            spawner,
+            f: Arc::new(f),
+            // Note: `limit` = 0 gets ignored.
+            limit: limit.and_then(NonZeroUsize::new),
+        }
+    }
+
+    delegate_access_inner!(stream, St, (.));
+}
+
+impl<St, Sp, F, B> Stream for MapSpawned<St, Sp, F, B>
+where
+    St: Stream,
+    St::Item: Send + 'static,
+    Sp: Spawn,
+    F: Fn(St::Item) -> B + Send + Sync + 'static,
+    B: Send + 'static,
+{
+    type Item = Result<B, SpawnError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        // First up, try to spawn off as many tasks as possible by filling up
+        // our queue of tasks.
+        while this.limit.map(|limit| this.in_progress_queue.len() < limit.get()).unwrap_or(true) {
+            match this.stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(item)) => {
+                    let f = this.f.clone();
+                    match this.spawner.spawn_with_handle(lazy(move |_| f(item))) {
+                        Ok(handle) => this.in_progress_queue.push(handle),
+                        Err(e) => return Poll::Ready(Some(Err(e))),
+                    }
+                }
+                Poll::Ready(None) | Poll::Pending => break,
+            }
+        }
+
+        // Attempt to pull the next value from the in_progress_queue
+        match this.in_progress_queue.poll_next_unpin(cx) {
+            Poll::Ready(Some(output)) => return Poll::Ready(Some(Ok(output))),
+            Poll::Pending => return Poll::Pending,
+            Poll::Ready(None) => {}
+        }
+
+        // If more values are still coming from the stream, we're not done yet
+        if this.stream.is_done() {
+            Poll::Ready(None)
+        } else {
+            Poll::Pending
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let queue_len = self.in_progress_queue.len();
+        let (lower, upper) = self.stream.size_hint();
+        let lower = lower.saturating_add(queue_len);
+        let upper = match upper {
+            Some(x) => x.checked_add(queue_len),
+            None => None,
+        };
+        (lower, upper)
+    }
+}
+
+impl<St, Sp, F, B> FusedStream for MapSpawned<St, Sp, F, B>
+where
+    St: Stream,
+    St::Item: Send + 'static,
+    Sp: Spawn,
+    F: Fn(St::Item) -> B + Send + Sync + 'static,
+    B: Send + 'static,
+{
+    fn is_terminated(&self) -> bool {
+        self.in_progress_queue.is_terminated() && self.stream.is_terminated()
+    }
+}
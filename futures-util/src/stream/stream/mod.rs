@@ -3,13 +3,14 @@
 //! This module contains a number of functions for working with `Stream`s,
 //! including the `StreamExt` trait which adds methods to `Stream` types.
 
-use crate::future::{assert_future, Either};
+use crate::future::{assert_future, Either, PollEvent};
 use crate::stream::assert_stream;
 #[cfg(feature = "alloc")]
 use alloc::boxed::Box;
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
 use core::pin::Pin;
+use core::time::Duration;
 #[cfg(feature = "alloc")]
 use futures_core::stream::{BoxStream, LocalBoxStream};
 use futures_core::{
@@ -20,12 +21,19 @@ use futures_core::{
 #[cfg(feature = "sink")]
 use futures_sink::Sink;
 
-use crate::fns::{inspect_fn, InspectFn};
+use crate::fns::{cloned_fn, copied_fn, inspect_fn, ClonedFn, CopiedFn, InspectFn};
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "alloc")]
+use crate::fns::{collect_fn, CollectFn};
 
 mod chain;
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
 pub use self::chain::Chain;
 
+mod chain_with;
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::chain_with::ChainWith;
+
 mod collect;
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
 pub use self::collect::Collect;
@@ -112,10 +120,36 @@ delegate_all!(
     ): Debug + Sink + Stream + FusedStream + AccessInner[St, (.)] + New[|x: St, f: F| map::Map::new(x, inspect_fn(f))]
 );
 
+mod inspect_pending;
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::inspect_pending::InspectPending;
+
+mod inspect_poll;
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::inspect_poll::InspectPoll;
+
+mod inspect_ready;
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::inspect_ready::InspectReady;
+
 mod map;
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
 pub use self::map::Map;
 
+delegate_all!(
+    /// Stream for the [`cloned`](StreamExt::cloned) method.
+    Cloned<St>(
+        map::Map<St, ClonedFn>
+    ): Debug + Sink + Stream + FusedStream + AccessInner[St, (.)] + New[|x: St| map::Map::new(x, cloned_fn())]
+);
+
+delegate_all!(
+    /// Stream for the [`copied`](StreamExt::copied) method.
+    Copied<St>(
+        map::Map<St, CopiedFn>
+    ): Debug + Sink + Stream + FusedStream + AccessInner[St, (.)] + New[|x: St| map::Map::new(x, copied_fn())]
+);
+
 delegate_all!(
     /// Stream for the [`flat_map`](StreamExt::flat_map) method.
     FlatMap<St, U, F>(
@@ -167,6 +201,10 @@ mod try_fold;
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
 pub use self::try_fold::TryFold;
 
+mod with_keepalive;
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::with_keepalive::WithKeepalive;
+
 mod zip;
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
 pub use self::zip::Zip;
@@ -175,7 +213,13 @@ pub use self::zip::Zip;
 mod chunks;
 #[cfg(feature = "alloc")]
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
-pub use self::chunks::Chunks;
+pub use self::chunks::{Chunks, InvalidChunkSize};
+
+#[cfg(feature = "std")]
+mod chunked_streams;
+#[cfg(feature = "std")]
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::chunked_streams::{ChunkedStreams, StreamChunk};
 
 #[cfg(feature = "alloc")]
 mod ready_chunks;
@@ -195,6 +239,26 @@ mod buffer_unordered;
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
 pub use self::buffer_unordered::BufferUnordered;
 
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "channel")]
+mod map_spawned;
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "channel")]
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::map_spawned::MapSpawned;
+
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "std")]
+delegate_all!(
+    /// Stream for the
+    /// [`buffer_unordered_catch_unwind`](StreamExt::buffer_unordered_catch_unwind)
+    /// method.
+    BufferUnorderedCatchUnwind<St>(
+        BufferUnordered<Map<St, fn(St::Item) -> crate::future::CatchUnwind<St::Item>>>
+    ): Debug + Sink + Stream + FusedStream + AccessInner[St, (. .)] + New[|x: St, n: Option<usize>| BufferUnordered::new(Map::new(x, crate::future::FutureExt::catch_unwind as fn(_) -> _), n)]
+    where St: Stream, St::Item: Future, St::Item: std::panic::UnwindSafe
+);
+
 #[cfg(not(futures_no_atomic_cas))]
 #[cfg(feature = "alloc")]
 mod buffered;
@@ -203,6 +267,16 @@ mod buffered;
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
 pub use self::buffered::Buffered;
 
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "alloc")]
+delegate_all!(
+    /// Stream for the [`buffered_flat_ordered`](StreamExt::buffered_flat_ordered) method.
+    BufferedFlatOrdered<St>(
+        Buffered<Map<St, CollectFn<Vec<<St::Item as Stream>::Item>>>>
+    ): Debug + Sink + Stream + AccessInner[St, (. .)] + New[|x: St, n: usize| Buffered::new(Map::new(x, collect_fn()), Some(n))]
+    where St: Stream, St::Item: Stream
+);
+
 #[cfg(not(futures_no_atomic_cas))]
 #[cfg(feature = "alloc")]
 mod flatten_unordered;
@@ -296,6 +370,8 @@ pub trait StreamExt: Stream {
     ///
     /// The returned future can be used to compose streams and futures together
     /// by placing everything into the "world of futures".
+    /// It can also split off a distinct first message, such as a handshake,
+    /// while the rest of the stream is moved elsewhere.
     ///
     /// Note that because `into_future` moves the stream, the [`Stream`] type
     /// must be [`Unpin`]. If you want to use `into_future` with a
@@ -357,6 +433,58 @@ pub trait StreamExt: Stream {
         assert_stream::<T, _>(Map::new(self, f))
     }
 
+    /// Creates a stream which clones all of this stream's items.
+    ///
+    /// This is useful when the stream yields references into data it doesn't
+    /// own, such as a reusable buffer, but the items are needed by value
+    /// downstream. Like [`Iterator::cloned`], this is equivalent to
+    /// `.map(|x| x.clone())`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let names = vec!["a".to_string(), "b".to_string()];
+    /// let stream = stream::iter(&names).cloned();
+    ///
+    /// assert_eq!(names, stream.collect::<Vec<String>>().await);
+    /// # });
+    /// ```
+    fn cloned<'a, T>(self) -> Cloned<Self>
+    where
+        T: Clone + 'a,
+        Self: Stream<Item = &'a T> + Sized,
+    {
+        assert_stream::<T, _>(Cloned::new(self))
+    }
+
+    /// Creates a stream which copies all of this stream's items.
+    ///
+    /// This works like [`cloned`](StreamExt::cloned), but for [`Copy`] types,
+    /// like [`Iterator::copied`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let numbers = [1, 2, 3];
+    /// let stream = stream::iter(&numbers).copied();
+    ///
+    /// assert_eq!(vec![1, 2, 3], stream.collect::<Vec<i32>>().await);
+    /// # });
+    /// ```
+    fn copied<'a, T>(self) -> Copied<Self>
+    where
+        T: Copy + 'a,
+        Self: Stream<Item = &'a T> + Sized,
+    {
+        assert_stream::<T, _>(Copied::new(self))
+    }
+
     /// Creates a stream which gives the current iteration count as well as
     /// the next value.
     ///
@@ -750,6 +878,13 @@ pub trait StreamExt: Stream {
 
     /// Flattens a stream of streams into just one continuous stream.
     ///
+    /// The size hint of the returned stream has the lower bound of the inner
+    /// stream which is currently being flattened. As the lengths of the inner
+    /// streams which haven't been yielded yet are unknown, there is only an
+    /// upper bound once the outer stream reports that it has no more of them,
+    /// so the hint is `(0, None)` until an inner stream is being flattened,
+    /// unless the outer stream is known to be empty.
+    ///
     /// # Examples
     ///
     /// ```
@@ -1063,6 +1198,51 @@ pub trait StreamExt: Stream {
         assert_stream::<Self::Item, _>(TakeUntil::new(self, fut))
     }
 
+    /// Yields an item created by `make` whenever this stream hasn't yielded
+    /// anything for `interval`, for example to keep a connection alive.
+    ///
+    /// To stay independent of any runtime, waiting is left to `sleep`, which
+    /// is called with `interval` to create a future which completes after
+    /// that long. A timer is started whenever this stream returns
+    /// `Poll::Pending` while none is running, and dropped whenever this stream
+    /// yields an item or ends, so the interval restarts after every item,
+    /// including the ones created by `make`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use core::time::Duration;
+    /// use futures::future;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let messages = stream::iter(vec!["hello", "world"]).chain(stream::pending());
+    /// let stream = messages.with_keepalive(
+    ///     Duration::from_secs(30),
+    ///     // This would be the `sleep` function of a runtime.
+    ///     |_interval| future::ready(()),
+    ///     || "ping",
+    /// );
+    ///
+    /// let items = stream.take(4).collect::<Vec<_>>().await;
+    /// assert_eq!(items, vec!["hello", "world", "ping", "ping"]);
+    /// # });
+    /// ```
+    fn with_keepalive<S, Fut, F>(
+        self,
+        interval: Duration,
+        sleep: S,
+        make: F,
+    ) -> WithKeepalive<Self, S, Fut, F>
+    where
+        S: FnMut(Duration) -> Fut,
+        Fut: Future<Output = ()>,
+        F: FnMut() -> Self::Item,
+        Self: Sized,
+    {
+        assert_stream::<Self::Item, _>(WithKeepalive::new(self, interval, sleep, make))
+    }
+
     /// Runs this stream to completion, executing the provided asynchronous
     /// closure for each element on the stream.
     ///
@@ -1520,6 +1700,40 @@ pub trait StreamExt: Stream {
         assert_stream::<<Self::Item as Future>::Output, _>(Buffered::new(self, n.into()))
     }
 
+    /// An adaptor for collecting several inner streams concurrently, while
+    /// keeping their order.
+    ///
+    /// If this stream's items are streams, then this adaptor will drive up to
+    /// `n` of them at once, collecting each of them into a `Vec`, and yield
+    /// those in the same order as the inner streams were yielded by the
+    /// underlying stream. Note: a limit of zero is interpreted as no limit at
+    /// all.
+    ///
+    /// This method is only available when the `std` or `alloc` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream_of_streams = stream::iter(vec![stream::iter(1..=3), stream::iter(4..=5)]);
+    /// let collected = stream_of_streams.buffered_flat_ordered(2).collect::<Vec<_>>().await;
+    ///
+    /// assert_eq!(collected, vec![vec![1, 2, 3], vec![4, 5]]);
+    /// # });
+    /// ```
+    #[cfg(not(futures_no_atomic_cas))]
+    #[cfg(feature = "alloc")]
+    fn buffered_flat_ordered(self, n: usize) -> BufferedFlatOrdered<Self>
+    where
+        Self::Item: Stream,
+        Self: Sized,
+    {
+        assert_stream::<Vec<<Self::Item as Stream>::Item>, _>(BufferedFlatOrdered::new(self, n))
+    }
+
     /// An adaptor for creating a buffered list of pending futures (unordered).
     ///
     /// If this stream's item can be converted into a future, then this adaptor
@@ -1569,6 +1783,114 @@ pub trait StreamExt: Stream {
         assert_stream::<<Self::Item as Future>::Output, _>(BufferUnordered::new(self, n.into()))
     }
 
+    /// An adaptor like [`buffer_unordered`](StreamExt::buffer_unordered)
+    /// which catches panics of the buffered futures.
+    ///
+    /// A future which panics yields `Err` with the panic's payload, while the
+    /// other buffered futures keep being polled. Futures which complete yield
+    /// `Ok` with their output. Panics of the stream itself are not caught.
+    ///
+    /// As with [`FutureExt::catch_unwind`](crate::future::FutureExt::catch_unwind),
+    /// the futures must be [`UnwindSafe`](std::panic::UnwindSafe).
+    ///
+    /// This method is only available when the `std` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::future;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let futures = stream::iter(vec![1, 2, 3]).map(|i| {
+    ///     future::lazy(move |_| {
+    ///         if i == 2 {
+    ///             panic!("failed on 2");
+    ///         }
+    ///         i
+    ///     })
+    /// });
+    /// let results: Vec<_> = futures.buffer_unordered_catch_unwind(10).collect().await;
+    ///
+    /// assert_eq!(results.len(), 3);
+    /// assert_eq!(results[0].as_ref().ok(), Some(&1));
+    /// assert!(results[1].is_err());
+    /// assert_eq!(results[2].as_ref().ok(), Some(&3));
+    /// # });
+    /// ```
+    #[cfg(not(futures_no_atomic_cas))]
+    #[cfg(feature = "std")]
+    fn buffer_unordered_catch_unwind(
+        self,
+        n: impl Into<Option<usize>>,
+    ) -> BufferUnorderedCatchUnwind<Self>
+    where
+        Self::Item: Future + std::panic::UnwindSafe,
+        Self: Sized,
+    {
+        assert_stream::<Result<<Self::Item as Future>::Output, Box<dyn std::any::Any + Send>>, _>(
+            BufferUnorderedCatchUnwind::new(self, n.into()),
+        )
+    }
+
+    /// Maps this stream's items to a different type, running the closure for
+    /// each item as a separate task on the given spawner, and yielding the
+    /// results in the order the tasks finish.
+    ///
+    /// This makes it possible to spread CPU-bound work over the threads of an
+    /// executor such as a thread pool. The first argument after the spawner is
+    /// an optional limit on the number of tasks in flight at once, with the
+    /// same meaning as the limit of
+    /// [`for_each_concurrent`](StreamExt::for_each_concurrent).
+    ///
+    /// If spawning the task for an item fails, the item is dropped and the
+    /// [`SpawnError`](crate::task::SpawnError) is yielded in its place. If the
+    /// closure panics, the panic is propagated to the task polling this
+    /// stream. Dropping this stream cancels the tasks which haven't finished.
+    ///
+    /// This method is only available when the `channel` feature of this
+    /// library is activated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::executor::ThreadPool;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let pool = ThreadPool::new().unwrap();
+    /// let stream = stream::iter(1..=4).map_spawned(pool, 2, |x| x * x);
+    ///
+    /// let mut squares: Vec<_> = stream.map(Result::unwrap).collect().await;
+    /// squares.sort();
+    /// assert_eq!(squares, vec![1, 4, 9, 16]);
+    /// # });
+    /// ```
+    #[cfg(not(futures_no_atomic_cas))]
+    #[cfg(feature = "channel")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "channel")))]
+    fn map_spawned<Sp, B, F>(
+        self,
+        spawner: Sp,
+        limit: impl Into<Option<usize>>,
+        f: F,
+    ) -> MapSpawned<Self, Sp, F, B>
+    where
+        Self::Item: Send + 'static,
+        Sp: crate::task::Spawn,
+        F: Fn(Self::Item) -> B + Send + Sync + 'static,
+        B: Send + 'static,
+        Self: Sized,
+    {
+        assert_stream::<Result<B, crate::task::SpawnError>, _>(MapSpawned::new(
+            self,
+            spawner,
+            limit.into(),
+            f,
+        ))
+    }
+
     /// An adapter for zipping two streams together.
     ///
     /// The zipped stream waits for both streams to produce an item, and then
@@ -1630,6 +1952,35 @@ pub trait StreamExt: Stream {
         assert_stream::<Self::Item, _>(Chain::new(self, other))
     }
 
+    /// Adapter for chaining two streams, where the second stream is only
+    /// created once the first one has ended.
+    ///
+    /// This works like [`chain`](StreamExt::chain), but takes a closure which
+    /// is called exactly once to create the second stream, when the first
+    /// stream reaches its end. If the first stream never ends, or the resulting
+    /// stream is dropped before it does, the closure isn't called at all, which
+    /// avoids wasting the work of creating the second stream.
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream1 = stream::iter(vec![1, 2]);
+    /// let stream = stream1.chain_with(|| stream::iter(vec![3, 4]));
+    ///
+    /// let result: Vec<_> = stream.collect().await;
+    /// assert_eq!(result, vec![1, 2, 3, 4]);
+    /// # });
+    /// ```
+    fn chain_with<St, F>(self, f: F) -> ChainWith<Self, St, F>
+    where
+        St: Stream<Item = Self::Item>,
+        F: FnOnce() -> St,
+        Self: Sized,
+    {
+        assert_stream::<Self::Item, _>(ChainWith::new(self, f))
+    }
+
     /// Creates a new stream which exposes a `peek` method.
     ///
     /// Calling `peek` returns a reference to the next item in the stream.
@@ -1657,7 +2008,9 @@ pub trait StreamExt: Stream {
     ///
     /// # Panics
     ///
-    /// This method will panic if `capacity` is zero.
+    /// This method will panic if `capacity` is zero. Use
+    /// [`try_chunks_cap`](StreamExt::try_chunks_cap) if `capacity` isn't known
+    /// to be non-zero.
     #[cfg(feature = "alloc")]
     fn chunks(self, capacity: usize) -> Chunks<Self>
     where
@@ -1666,6 +2019,81 @@ pub trait StreamExt: Stream {
         assert_stream::<Vec<Self::Item>, _>(Chunks::new(self, capacity))
     }
 
+    /// Like [`chunks`](StreamExt::chunks), but returns an error instead of
+    /// panicking if `capacity` is zero.
+    ///
+    /// This is useful when the chunk size comes from configuration, so that an
+    /// invalid value is reported when the stream is set up.
+    ///
+    /// This method is only available when the `std` or `alloc` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, InvalidChunkSize, StreamExt};
+    ///
+    /// assert_eq!(stream::iter(1..=3).try_chunks_cap(0).err(), Some(InvalidChunkSize));
+    ///
+    /// let chunks = stream::iter(1..=3).try_chunks_cap(2).unwrap();
+    /// assert_eq!(chunks.collect::<Vec<_>>().await, vec![vec![1, 2], vec![3]]);
+    /// # });
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn try_chunks_cap(self, capacity: usize) -> Result<Chunks<Self>, InvalidChunkSize>
+    where
+        Self: Sized,
+    {
+        Chunks::try_new(self, capacity).map(assert_stream::<Vec<Self::Item>, _>)
+    }
+
+    /// An adaptor for splitting the stream into sub-streams of at most
+    /// `capacity` items each.
+    ///
+    /// Unlike [`chunks`](StreamExt::chunks), this doesn't buffer the items of
+    /// a chunk. Each sub-stream reads its items straight from this stream, so
+    /// the returned stream doesn't yield the next sub-stream until the current
+    /// one has been consumed or dropped. The items of a sub-stream which is
+    /// dropped early are skipped. Sub-streams are never empty; the last one
+    /// may have fewer than `capacity` items if this stream ends.
+    ///
+    /// This method is only available when the `std` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `capacity` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let mut chunks = stream::iter(1..=5).chunked_streams(2);
+    ///
+    /// let first = chunks.next().await.unwrap();
+    /// assert_eq!(first.collect::<Vec<_>>().await, vec![1, 2]);
+    ///
+    /// // Dropping a chunk early skips the rest of its items.
+    /// let mut second = chunks.next().await.unwrap();
+    /// assert_eq!(second.next().await, Some(3));
+    /// drop(second);
+    ///
+    /// let third = chunks.next().await.unwrap();
+    /// assert_eq!(third.collect::<Vec<_>>().await, vec![5]);
+    /// assert!(chunks.next().await.is_none());
+    /// # });
+    /// ```
+    #[cfg(feature = "std")]
+    fn chunked_streams(self, capacity: usize) -> ChunkedStreams<Self>
+    where
+        Self: Sized,
+    {
+        assert_stream::<StreamChunk<Self>, _>(ChunkedStreams::new(self, capacity))
+    }
+
     /// An adaptor for chunking up ready items of the stream inside a vector.
     ///
     /// This combinator will attempt to pull ready items from this stream and
@@ -1683,7 +2111,9 @@ pub trait StreamExt: Stream {
     ///
     /// # Panics
     ///
-    /// This method will panic if `capacity` is zero.
+    /// This method will panic if `capacity` is zero. Use
+    /// [`try_ready_chunks_cap`](StreamExt::try_ready_chunks_cap) if `capacity`
+    /// isn't known to be non-zero.
     #[cfg(feature = "alloc")]
     fn ready_chunks(self, capacity: usize) -> ReadyChunks<Self>
     where
@@ -1692,6 +2122,19 @@ pub trait StreamExt: Stream {
         assert_stream::<Vec<Self::Item>, _>(ReadyChunks::new(self, capacity))
     }
 
+    /// Like [`ready_chunks`](StreamExt::ready_chunks), but returns an error
+    /// instead of panicking if `capacity` is zero.
+    ///
+    /// This method is only available when the `std` or `alloc` feature of this
+    /// library is activated, and it is activated by default.
+    #[cfg(feature = "alloc")]
+    fn try_ready_chunks_cap(self, capacity: usize) -> Result<ReadyChunks<Self>, InvalidChunkSize>
+    where
+        Self: Sized,
+    {
+        ReadyChunks::try_new(self, capacity).map(assert_stream::<Vec<Self::Item>, _>)
+    }
+
     /// A future that completes after the given stream has been fully processed
     /// into the sink and the sink has been flushed and closed.
     ///
@@ -1749,6 +2192,87 @@ pub trait StreamExt: Stream {
         assert_stream::<Self::Item, _>(Inspect::new(self, f))
     }
 
+    /// Calls `f` each time this stream returns `Poll::Pending`, afterwards
+    /// passing the result on.
+    ///
+    /// Together with [`inspect_ready`](StreamExt::inspect_ready), this can be
+    /// used to find out how often a stage of a pipeline has to wait for this
+    /// stream compared to how often it gets an item, for example to tell
+    /// whether this stream or its consumer is the bottleneck.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    /// use futures_test::stream::StreamTestExt;
+    ///
+    /// let mut waits = 0;
+    /// let mut items = 0;
+    /// let stream = stream::iter(1..=3)
+    ///     .interleave_pending()
+    ///     .inspect_pending(|| waits += 1)
+    ///     .inspect_ready(|| items += 1);
+    ///
+    /// assert_eq!(stream.collect::<Vec<_>>().await, vec![1, 2, 3]);
+    /// assert_eq!((waits, items), (4, 3));
+    /// # });
+    /// ```
+    fn inspect_pending<F>(self, f: F) -> InspectPending<Self, F>
+    where
+        F: FnMut(),
+        Self: Sized,
+    {
+        assert_stream::<Self::Item, _>(InspectPending::new(self, f))
+    }
+
+    /// Calls `f` after each poll of this stream with information about that
+    /// poll.
+    ///
+    /// This works like [`FutureExt::inspect_poll`](crate::future::FutureExt::inspect_poll):
+    /// the [`PollEvent`] passed to `f` holds the
+    /// index of the poll, whether it returned `Poll::Ready` and, when the `std`
+    /// feature of this library is activated, how long it took. The index
+    /// counts all polls of this stream, not just those for one item.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    /// use futures_test::stream::StreamTestExt;
+    ///
+    /// let mut events = Vec::new();
+    /// let stream = stream::iter(1..=2)
+    ///     .interleave_pending()
+    ///     .inspect_poll(|event| events.push((event.index(), event.is_ready())));
+    ///
+    /// assert_eq!(stream.collect::<Vec<_>>().await, vec![1, 2]);
+    /// assert_eq!(events, [(0, false), (1, true), (2, false), (3, true), (4, false), (5, true)]);
+    /// # });
+    /// ```
+    fn inspect_poll<F>(self, f: F) -> InspectPoll<Self, F>
+    where
+        F: FnMut(PollEvent),
+        Self: Sized,
+    {
+        assert_stream::<Self::Item, _>(InspectPoll::new(self, f))
+    }
+
+    /// Calls `f` each time this stream yields an item, afterwards passing it
+    /// on.
+    ///
+    /// Unlike [`inspect`](StreamExt::inspect), `f` doesn't get to see the
+    /// item. See [`inspect_pending`](StreamExt::inspect_pending) for an
+    /// example.
+    fn inspect_ready<F>(self, f: F) -> InspectReady<Self, F>
+    where
+        F: FnMut(),
+        Self: Sized,
+    {
+        assert_stream::<Self::Item, _>(InspectReady::new(self, f))
+    }
+
     /// Wrap this stream in an `Either` stream, making it the left-hand variant
     /// of that `Either`.
     ///
@@ -1,3 +1,4 @@
+use super::InvalidChunkSize;
 use crate::stream::Fuse;
 use alloc::vec::Vec;
 use core::mem;
@@ -26,7 +27,17 @@ where
 {
     pub(super) fn new(stream: St, capacity: usize) -> Self {
         assert!(capacity > 0);
+        Self::new_unchecked(stream, capacity)
+    }
+
+    pub(super) fn try_new(stream: St, capacity: usize) -> Result<Self, InvalidChunkSize> {
+        if capacity == 0 {
+            return Err(InvalidChunkSize);
+        }
+        Ok(Self::new_unchecked(stream, capacity))
+    }
 
+    fn new_unchecked(stream: St, capacity: usize) -> Self {
         Self {
             stream: super::Fuse::new(stream),
             items: Vec::with_capacity(capacity),
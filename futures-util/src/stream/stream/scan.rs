@@ -55,6 +55,23 @@ where
     }
 
     delegate_access_inner!(stream, St, ());
+
+    /// Returns a reference to the current state.
+    ///
+    /// This is `None` while the future returned by the closure is in
+    /// progress, and once that future has resolved to `None`, as the state
+    /// has been consumed then.
+    pub fn state(&self) -> Option<&S> {
+        self.state.value()
+    }
+
+    /// Consumes this combinator, returning the current state.
+    ///
+    /// Once the underlying stream has ended, this is the final state. See
+    /// [`state`](Scan::state) for when there is no state to return.
+    pub fn into_state(self) -> Option<S> {
+        self.state.into_value()
+    }
 }
 
 impl<B, St, S, Fut, F> Stream for Scan<St, S, Fut, F>
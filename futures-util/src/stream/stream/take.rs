@@ -23,6 +23,54 @@ impl<St: Stream> Take<St> {
         Self { stream, remaining: n }
     }
 
+    /// Returns the number of items this stream may still yield.
+    ///
+    /// This is zero once the underlying stream has ended, even if fewer items
+    /// than the limit were yielded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let mut take = stream::iter(1..=10).take(3);
+    /// assert_eq!(take.next().await, Some(1));
+    /// assert_eq!(take.remaining(), 2);
+    /// # });
+    /// ```
+    pub fn remaining(&self) -> usize {
+        self.remaining
+    }
+
+    /// Sets the number of items this stream may still yield, extending or
+    /// shortening the limit it was created with.
+    ///
+    /// If the underlying stream has already ended, it will be polled again
+    /// after the limit is raised, so it should be fused if it doesn't support
+    /// that.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let mut take = stream::iter(1..=10).take(1);
+    /// assert_eq!(take.next().await, Some(1));
+    /// assert_eq!(take.next().await, None);
+    ///
+    /// take.set_remaining(2);
+    /// assert_eq!(take.by_ref().collect::<Vec<_>>().await, [2, 3]);
+    ///
+    /// let rest = take.into_inner();
+    /// assert_eq!(rest.collect::<Vec<_>>().await, (4..=10).collect::<Vec<_>>());
+    /// # });
+    /// ```
+    pub fn set_remaining(&mut self, n: usize) {
+        self.remaining = n;
+    }
+
     delegate_access_inner!(stream, St, ());
 }
 
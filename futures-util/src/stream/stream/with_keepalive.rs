@@ -0,0 +1,107 @@
+use core::fmt;
+use core::pin::Pin;
+use core::time::Duration;
+use futures_core::future::Future;
+use futures_core::ready;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+#[cfg(feature = "sink")]
+use futures_sink::Sink;
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Stream for the [`with_keepalive`](super::StreamExt::with_keepalive) method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct WithKeepalive<St, S, Fut, F> {
+        #[pin]
+        stream: St,
+        #[pin]
+        timer: Option<Fut>,
+        interval: Duration,
+        sleep: S,
+        make: F,
+    }
+}
+
+impl<St, S, Fut, F> fmt::Debug for WithKeepalive<St, S, Fut, F>
+where
+    St: fmt::Debug,
+    Fut: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WithKeepalive")
+            .field("stream", &self.stream)
+            .field("timer", &self.timer)
+            .field("interval", &self.interval)
+            .finish()
+    }
+}
+
+impl<St, S, Fut, F> WithKeepalive<St, S, Fut, F>
+where
+    St: Stream,
+    S: FnMut(Duration) -> Fut,
+    Fut: Future<Output = ()>,
+    F: FnMut() -> St::Item,
+{
+    pub(super) fn new(stream: St, interval: Duration, sleep: S, make: F) -> Self {
+        Self { stream, timer: None, interval, sleep, make }
+    }
+
+    delegate_access_inner!(stream, St, ());
+}
+
+impl<St, S, Fut, F> FusedStream for WithKeepalive<St, S, Fut, F>
+where
+    St: FusedStream,
+    S: FnMut(Duration) -> Fut,
+    Fut: Future<Output = ()>,
+    F: FnMut() -> St::Item,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+impl<St, S, Fut, F> Stream for WithKeepalive<St, S, Fut, F>
+where
+    St: Stream,
+    S: FnMut(Duration) -> Fut,
+    Fut: Future<Output = ()>,
+    F: FnMut() -> St::Item,
+{
+    type Item = St::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<St::Item>> {
+        let mut this = self.project();
+
+        if let Poll::Ready(item) = this.stream.poll_next(cx) {
+            // Any activity restarts the timer.
+            this.timer.set(None);
+            return Poll::Ready(item);
+        }
+
+        if this.timer.is_none() {
+            this.timer.set(Some((this.sleep)(*this.interval)));
+        }
+        ready!(this.timer.as_mut().as_pin_mut().unwrap().poll(cx));
+        this.timer.set(None);
+        Poll::Ready(Some((this.make)()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, _) = self.stream.size_hint();
+        (lower, None)
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+#[cfg(feature = "sink")]
+impl<St, S, Fut, F, Item> Sink<Item> for WithKeepalive<St, S, Fut, F>
+where
+    St: Sink<Item>,
+{
+    type Error = St::Error;
+
+    delegate_sink!(stream, Item);
+}
@@ -51,6 +51,10 @@ mod into_stream;
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
 pub use self::into_stream::IntoStream;
 
+mod log_and_skip_errors;
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::log_and_skip_errors::LogAndSkipErrors;
+
 delegate_all!(
     /// Stream for the [`map_ok`](super::TryStreamExt::map_ok) method.
     MapOk<St, F>(
@@ -73,6 +77,10 @@ mod try_next;
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
 pub use self::try_next::TryNext;
 
+mod try_next_timeout;
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::try_next_timeout::{Elapsed, TryNextTimeout};
+
 mod try_filter;
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
 pub use self::try_filter::TryFilter;
@@ -98,6 +106,10 @@ mod try_flatten;
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
 pub use self::try_flatten::TryFlatten;
 
+mod try_flatten_tagged;
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::try_flatten_tagged::{FlattenError, TryFlattenTagged};
+
 mod try_collect;
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
 pub use self::try_collect::TryCollect;
@@ -377,6 +389,38 @@ pub trait TryStreamExt: TryStream {
         assert_stream::<Result<Self::Ok, Self::Error>, _>(IntoStream::new(self))
     }
 
+    /// Converts this stream into a [`Stream`](futures_core::stream::Stream)
+    /// of its successful values, passing each error to the provided closure
+    /// and skipping it.
+    ///
+    /// Unlike most of the combinators on `TryStreamExt`, this doesn't
+    /// short-circuit: the stream carries on past any number of errors and only
+    /// ends when the underlying stream does. This is handy for "best effort"
+    /// processing, where failures are logged and otherwise ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt, TryStreamExt};
+    ///
+    /// let stream = stream::iter(vec![Ok(1), Err("bad"), Ok(2), Err("worse"), Ok(3)]);
+    ///
+    /// let mut errors = Vec::new();
+    /// let values: Vec<i32> = stream.log_and_skip_errors(|e| errors.push(e)).collect().await;
+    ///
+    /// assert_eq!(values, vec![1, 2, 3]);
+    /// assert_eq!(errors, vec!["bad", "worse"]);
+    /// # });
+    /// ```
+    fn log_and_skip_errors<F>(self, f: F) -> LogAndSkipErrors<Self, F>
+    where
+        F: FnMut(Self::Error),
+        Self: Sized,
+    {
+        assert_stream::<Self::Ok, _>(LogAndSkipErrors::new(self, f))
+    }
+
     /// Creates a future that attempts to resolve the next item in the stream.
     /// If an error is encountered before the next item, the error is returned
     /// instead.
@@ -404,6 +448,40 @@ pub trait TryStreamExt: TryStream {
         assert_future::<Result<Option<Self::Ok>, Self::Error>, _>(TryNext::new(self))
     }
 
+    /// Creates a future that attempts to resolve the next item in the stream,
+    /// unless `timer` completes first.
+    ///
+    /// This works like [`try_next`](TryStreamExt::try_next), except that if
+    /// `timer` completes before the next item or error is available, the
+    /// returned future resolves to [`Elapsed`], leaving the stream as it is,
+    /// so it can be tried again. If both are ready at the same time, the item
+    /// is returned.
+    ///
+    /// `timer` is typically a sleep future from a runtime.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::future;
+    /// use futures::stream::{self, StreamExt, TryStreamExt};
+    ///
+    /// let mut stream = stream::iter(vec![Ok::<_, ()>(1)]).chain(stream::pending());
+    ///
+    /// assert_eq!(stream.try_next_timeout(future::ready(())).await, Ok(Ok(Some(1))));
+    /// assert!(stream.try_next_timeout(future::ready(())).await.is_err());
+    /// # })
+    /// ```
+    fn try_next_timeout<T>(&mut self, timer: T) -> TryNextTimeout<'_, Self, T>
+    where
+        T: Future,
+        Self: Unpin,
+    {
+        assert_future::<Result<Result<Option<Self::Ok>, Self::Error>, Elapsed>, _>(
+            TryNextTimeout::new(self, timer),
+        )
+    }
+
     /// Skip elements on this stream while the provided asynchronous predicate
     /// resolves to `true`.
     ///
@@ -596,7 +674,11 @@ pub trait TryStreamExt: TryStream {
     /// [`Some(item)`](Some) then the stream will yield the value `item`, but if
     /// it resolves to [`None`] then the next value will be produced.
     ///
-    /// All errors are passed through without filtering in this combinator.
+    /// All errors are passed through without filtering in this combinator:
+    /// an error produced by this stream is yielded as is, without calling
+    /// `f`, and if the future returned by `f` resolves to an error, that error
+    /// is yielded in place of the item. In both cases the stream itself keeps
+    /// going, so polling it again moves on to the next value.
     ///
     /// Note that this function consumes the stream passed into it and returns a
     /// wrapped version of it, similar to the existing `filter_map` methods in
@@ -681,6 +763,46 @@ pub trait TryStreamExt: TryStream {
         )
     }
 
+    /// Flattens a stream of streams into just one continuous stream, like
+    /// [`try_flatten`](TryStreamExt::try_flatten), but keeps the errors of the
+    /// outer and the inner streams apart.
+    ///
+    /// An error of this stream is yielded as [`FlattenError::Outer`], and an
+    /// error of one of the streams it yields as [`FlattenError::Inner`], so
+    /// the two error types don't need to be unified, and callers can react
+    /// differently depending on where an error came from. As with
+    /// `try_flatten`, errors don't end the stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, FlattenError, StreamExt, TryStreamExt};
+    ///
+    /// let inner = stream::iter(vec![Ok(1), Err("read failed")]);
+    /// let outer = stream::iter(vec![Ok(inner), Err(404)]);
+    ///
+    /// let mut stream = outer.try_flatten_tagged();
+    /// assert_eq!(stream.next().await, Some(Ok(1)));
+    /// assert_eq!(stream.next().await, Some(Err(FlattenError::Inner("read failed"))));
+    /// assert_eq!(stream.next().await, Some(Err(FlattenError::Outer(404))));
+    /// assert_eq!(stream.next().await, None);
+    /// # });
+    /// ```
+    fn try_flatten_tagged(self) -> TryFlattenTagged<Self>
+    where
+        Self::Ok: TryStream,
+        Self: Sized,
+    {
+        assert_stream::<
+            Result<
+                <Self::Ok as TryStream>::Ok,
+                FlattenError<Self::Error, <Self::Ok as TryStream>::Error>,
+            >,
+            _,
+        >(TryFlattenTagged::new(self))
+    }
+
     /// Attempt to concatenate all items of a stream into a single
     /// extendable destination, returning a future representing the end result.
     ///
@@ -0,0 +1,119 @@
+use core::fmt;
+use core::pin::Pin;
+use futures_core::ready;
+use futures_core::stream::{FusedStream, Stream, TryStream};
+use futures_core::task::{Context, Poll};
+#[cfg(feature = "sink")]
+use futures_sink::Sink;
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Stream for the [`try_flatten_tagged`](super::TryStreamExt::try_flatten_tagged) method.
+    #[derive(Debug)]
+    #[must_use = "streams do nothing unless polled"]
+    pub struct TryFlattenTagged<St>
+    where
+        St: TryStream,
+    {
+        #[pin]
+        stream: St,
+        #[pin]
+        next: Option<St::Ok>,
+    }
+}
+
+/// Error type for the [`try_flatten_tagged`](super::TryStreamExt::try_flatten_tagged)
+/// method, recording which of the streams an error came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FlattenError<E1, E2> {
+    /// An error of the outer stream, which yields the inner streams.
+    Outer(E1),
+    /// An error of one of the inner streams.
+    Inner(E2),
+}
+
+// Only the origin is displayed, the error itself is the `source`.
+impl<E1, E2> fmt::Display for FlattenError<E1, E2> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Outer(_) => write!(f, "error in the outer stream"),
+            Self::Inner(_) => write!(f, "error in an inner stream"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E1, E2> std::error::Error for FlattenError<E1, E2>
+where
+    E1: std::error::Error + 'static,
+    E2: std::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Outer(e) => Some(e),
+            Self::Inner(e) => Some(e),
+        }
+    }
+}
+
+impl<St> TryFlattenTagged<St>
+where
+    St: TryStream,
+    St::Ok: TryStream,
+{
+    pub(super) fn new(stream: St) -> Self {
+        Self { stream, next: None }
+    }
+
+    delegate_access_inner!(stream, St, ());
+}
+
+impl<St> FusedStream for TryFlattenTagged<St>
+where
+    St: TryStream + FusedStream,
+    St::Ok: TryStream,
+{
+    fn is_terminated(&self) -> bool {
+        self.next.is_none() && self.stream.is_terminated()
+    }
+}
+
+impl<St> Stream for TryFlattenTagged<St>
+where
+    St: TryStream,
+    St::Ok: TryStream,
+{
+    type Item =
+        Result<<St::Ok as TryStream>::Ok, FlattenError<St::Error, <St::Ok as TryStream>::Error>>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        Poll::Ready(loop {
+            if let Some(s) = this.next.as_mut().as_pin_mut() {
+                match ready!(s.try_poll_next(cx)) {
+                    Some(Ok(item)) => break Some(Ok(item)),
+                    Some(Err(e)) => break Some(Err(FlattenError::Inner(e))),
+                    None => this.next.set(None),
+                }
+            } else {
+                match ready!(this.stream.as_mut().try_poll_next(cx)) {
+                    Some(Ok(s)) => this.next.set(Some(s)),
+                    Some(Err(e)) => break Some(Err(FlattenError::Outer(e))),
+                    None => break None,
+                }
+            }
+        })
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+#[cfg(feature = "sink")]
+impl<S, Item> Sink<Item> for TryFlattenTagged<S>
+where
+    S: TryStream + Sink<Item>,
+{
+    type Error = <S as Sink<Item>>::Error;
+
+    delegate_sink!(stream, Item);
+}
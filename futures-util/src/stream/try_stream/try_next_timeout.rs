@@ -0,0 +1,52 @@
+use crate::stream::TryStreamExt;
+use core::fmt;
+use core::pin::Pin;
+use futures_core::future::Future;
+use futures_core::ready;
+use futures_core::stream::TryStream;
+use futures_core::task::{Context, Poll};
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Future for the [`try_next_timeout`](super::TryStreamExt::try_next_timeout) method.
+    #[derive(Debug)]
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct TryNextTimeout<'a, St: ?Sized, T> {
+        stream: &'a mut St,
+        #[pin]
+        timer: T,
+    }
+}
+
+impl<'a, St: ?Sized + TryStream + Unpin, T: Future> TryNextTimeout<'a, St, T> {
+    pub(super) fn new(stream: &'a mut St, timer: T) -> Self {
+        Self { stream, timer }
+    }
+}
+
+impl<St: ?Sized + TryStream + Unpin, T: Future> Future for TryNextTimeout<'_, St, T> {
+    type Output = Result<Result<Option<St::Ok>, St::Error>, Elapsed>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        if let Poll::Ready(item) = this.stream.try_poll_next_unpin(cx) {
+            return Poll::Ready(Ok(item.transpose()));
+        }
+        ready!(this.timer.poll(cx));
+        Poll::Ready(Err(Elapsed(())))
+    }
+}
+
+/// Error returned by [`try_next_timeout`](super::TryStreamExt::try_next_timeout)
+/// if the timer completed before the next item was available.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Elapsed(());
+
+impl fmt::Display for Elapsed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "timer elapsed before the next item was available")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Elapsed {}
@@ -0,0 +1,189 @@
+//! Definition of the `Zip3` and `Zip4` combinators, zipping more than two
+//! streams at once.
+
+use super::assert_stream;
+use crate::stream::{Fuse, StreamExt};
+use core::cmp;
+use core::pin::Pin;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+use pin_project_lite::pin_project;
+
+macro_rules! generate {
+    ($(
+        $(#[$doc:meta])*
+        ($Zip:ident, <$($St:ident: $stream:ident, $queued:ident),*>),
+    )*) => ($(
+        pin_project! {
+            $(#[$doc])*
+            #[derive(Debug)]
+            #[must_use = "streams do nothing unless polled"]
+            pub struct $Zip<$($St: Stream),*> {
+                $(#[pin] $stream: Fuse<$St>, $queued: Option<$St::Item>,)*
+            }
+        }
+
+        impl<$($St: Stream),*> $Zip<$($St),*> {
+            fn new($($stream: $St),*) -> Self {
+                Self { $($stream: $stream.fuse(), $queued: None),* }
+            }
+
+            /// Acquires a reference to the underlying streams that this
+            /// combinator is pulling from.
+            pub fn get_ref(&self) -> ($(&$St),*) {
+                ($(self.$stream.get_ref()),*)
+            }
+
+            /// Acquires a mutable reference to the underlying streams that this
+            /// combinator is pulling from.
+            ///
+            /// Note that care must be taken to avoid tampering with the state of the
+            /// stream which may otherwise confuse this combinator.
+            pub fn get_mut(&mut self) -> ($(&mut $St),*) {
+                ($(self.$stream.get_mut()),*)
+            }
+
+            /// Acquires a pinned mutable reference to the underlying streams that this
+            /// combinator is pulling from.
+            ///
+            /// Note that care must be taken to avoid tampering with the state of the
+            /// stream which may otherwise confuse this combinator.
+            pub fn get_pin_mut(self: Pin<&mut Self>) -> ($(Pin<&mut $St>),*) {
+                let this = self.project();
+                ($(this.$stream.get_pin_mut()),*)
+            }
+
+            /// Consumes this combinator, returning the underlying streams.
+            ///
+            /// Note that this may discard intermediate state of this combinator, so
+            /// care should be taken to avoid losing resources when this is called.
+            pub fn into_inner(self) -> ($($St),*) {
+                ($(self.$stream.into_inner()),*)
+            }
+        }
+
+        impl<$($St: Stream),*> FusedStream for $Zip<$($St),*> {
+            fn is_terminated(&self) -> bool {
+                $((self.$stream.is_terminated() && self.$queued.is_none()))||*
+            }
+        }
+
+        impl<$($St: Stream),*> Stream for $Zip<$($St),*> {
+            type Item = ($($St::Item),*);
+
+            fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+                let mut this = self.project();
+
+                $(
+                    if this.$queued.is_none() {
+                        if let Poll::Ready(Some(item)) = this.$stream.as_mut().poll_next(cx) {
+                            *this.$queued = Some(item);
+                        }
+                    }
+                )*
+
+                if $(this.$queued.is_some())&&* {
+                    Poll::Ready(Some(($(this.$queued.take().unwrap()),*)))
+                } else if $((this.$stream.is_done() && this.$queued.is_none()))||* {
+                    Poll::Ready(None)
+                } else {
+                    Poll::Pending
+                }
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let mut lower = usize::max_value();
+                let mut upper: Option<usize> = None;
+                $(
+                    let queued_len = if self.$queued.is_some() { 1 } else { 0 };
+                    let (stream_lower, stream_upper) = self.$stream.size_hint();
+                    lower = cmp::min(lower, stream_lower.saturating_add(queued_len));
+                    if let Some(x) = stream_upper {
+                        let x = x.saturating_add(queued_len);
+                        upper = Some(upper.map_or(x, |y| cmp::min(x, y)));
+                    }
+                )*
+                (lower, upper)
+            }
+        }
+    )*)
+}
+
+generate! {
+    /// Stream for the [`zip3`] function.
+    (Zip3, <St1: stream1, queued1, St2: stream2, queued2, St3: stream3, queued3>),
+
+    /// Stream for the [`zip4`] function.
+    (Zip4, <
+        St1: stream1, queued1,
+        St2: stream2, queued2,
+        St3: stream3, queued3,
+        St4: stream4, queued4
+    >),
+}
+
+/// Zips three streams together, yielding a tuple of an item from each.
+///
+/// This works like [`StreamExt::zip`], but yields flat tuples rather than
+/// nesting them. Each stream is polled once per call to `poll_next` until it
+/// has an item queued, and the returned stream ends as soon as one of the
+/// streams ends without an item queued.
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::stream::{self, StreamExt};
+///
+/// let a = stream::iter(vec![1, 2, 3]);
+/// let b = stream::iter(vec!['a', 'b', 'c']);
+/// let c = stream::iter(vec!["x", "y"]);
+///
+/// let zipped = stream::zip3(a, b, c);
+/// assert_eq!(zipped.collect::<Vec<_>>().await, vec![(1, 'a', "x"), (2, 'b', "y")]);
+/// # });
+/// ```
+pub fn zip3<St1, St2, St3>(stream1: St1, stream2: St2, stream3: St3) -> Zip3<St1, St2, St3>
+where
+    St1: Stream,
+    St2: Stream,
+    St3: Stream,
+{
+    assert_stream::<(St1::Item, St2::Item, St3::Item), _>(Zip3::new(stream1, stream2, stream3))
+}
+
+/// Zips four streams together, yielding a tuple of an item from each.
+///
+/// See [`zip3`] for details.
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::stream::{self, StreamExt};
+///
+/// let a = stream::iter(vec![1, 2]);
+/// let b = stream::iter(vec!['a', 'b']);
+/// let c = stream::iter(vec!["x", "y"]);
+/// let d = stream::iter(vec![true, false]);
+///
+/// let zipped = stream::zip4(a, b, c, d);
+/// assert_eq!(zipped.collect::<Vec<_>>().await, vec![(1, 'a', "x", true), (2, 'b', "y", false)]);
+/// # });
+/// ```
+pub fn zip4<St1, St2, St3, St4>(
+    stream1: St1,
+    stream2: St2,
+    stream3: St3,
+    stream4: St4,
+) -> Zip4<St1, St2, St3, St4>
+where
+    St1: Stream,
+    St2: Stream,
+    St3: Stream,
+    St4: Stream,
+{
+    assert_stream::<(St1::Item, St2::Item, St3::Item, St4::Item), _>(Zip4::new(
+        stream1, stream2, stream3, stream4,
+    ))
+}
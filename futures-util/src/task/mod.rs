@@ -33,5 +33,12 @@ pub use futures_task::{waker_ref, WakerRef};
 #[cfg(not(futures_no_atomic_cas))]
 pub use futures_core::task::__internal::AtomicWaker;
 
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "alloc")]
+mod waker_set;
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "alloc")]
+pub use self::waker_set::{RegistrationKey, WakerSet};
+
 mod spawn;
 pub use self::spawn::{LocalSpawnExt, SpawnExt};
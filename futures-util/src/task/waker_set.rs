@@ -0,0 +1,208 @@
+use alloc::collections::VecDeque;
+#[cfg(not(feature = "std"))]
+use core::cell::UnsafeCell;
+use core::fmt;
+use core::mem;
+#[cfg(not(feature = "std"))]
+use core::sync::atomic::{AtomicBool, Ordering};
+use core::task::Waker;
+#[cfg(feature = "std")]
+use std::sync::{Mutex, PoisonError};
+
+/// A set of wakers to wake up when a resource awaited by several tasks
+/// becomes available.
+///
+/// Unlike [`AtomicWaker`](super::AtomicWaker), which holds a single waker and
+/// replaces it on each registration, every call to
+/// [`register`](WakerSet::register) adds a waker to the set, so all of the
+/// tasks waiting on the resource can be woken up. A registration is removed
+/// from the set when its waker is woken, or when it is passed to
+/// [`deregister`](WakerSet::deregister), which a task should do if it stops
+/// waiting before it has been woken, as otherwise the set keeps growing.
+///
+/// The set is protected by a lock which is only held to add or remove
+/// registrations, waking up the removed wakers after it has been released.
+/// This is a [`std::sync::Mutex`], or a spinlock without the `std` feature.
+///
+/// # Examples
+///
+/// ```
+/// use futures::task::{noop_waker, WakerSet};
+///
+/// let set = WakerSet::new();
+/// let first = set.register(&noop_waker());
+/// let second = set.register(&noop_waker());
+/// assert_eq!(set.len(), 2);
+///
+/// // The task waiting with `second` was cancelled.
+/// set.deregister(second);
+/// assert!(set.wake_one());
+/// assert!(!set.wake_one());
+///
+/// // `first` has been woken up already, so this does nothing.
+/// set.deregister(first);
+/// ```
+pub struct WakerSet {
+    #[cfg(feature = "std")]
+    state: Mutex<State>,
+    #[cfg(not(feature = "std"))]
+    locked: AtomicBool,
+    #[cfg(not(feature = "std"))]
+    state: UnsafeCell<State>,
+}
+
+struct State {
+    // The key of the next registration. Keys aren't reused, so a stale key
+    // can't remove a later registration.
+    next_key: u64,
+    // The registrations which haven't been woken up yet, in the order they
+    // were added, which is also the order of their keys.
+    wakers: VecDeque<(u64, Waker)>,
+}
+
+impl State {
+    fn position(&self, key: u64) -> Option<usize> {
+        // The keys are sorted, so each half of the ring buffer can be searched.
+        let (front, back) = self.wakers.as_slices();
+        match back.first() {
+            Some(&(first, _)) if key >= first => {
+                let i = back.binary_search_by_key(&key, |&(k, _)| k).ok()?;
+                Some(front.len() + i)
+            }
+            _ => front.binary_search_by_key(&key, |&(k, _)| k).ok(),
+        }
+    }
+}
+
+/// A key identifying a registration in a [`WakerSet`], returned by
+/// [`WakerSet::register`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RegistrationKey(u64);
+
+// The state is only accessed while `locked` is held.
+#[cfg(not(feature = "std"))]
+unsafe impl Send for WakerSet {}
+#[cfg(not(feature = "std"))]
+unsafe impl Sync for WakerSet {}
+
+impl fmt::Debug for WakerSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WakerSet").field("len", &self.len()).finish()
+    }
+}
+
+impl Default for WakerSet {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WakerSet {
+    /// Creates a new, empty `WakerSet`.
+    pub fn new() -> Self {
+        let state = State { next_key: 0, wakers: VecDeque::new() };
+        #[cfg(feature = "std")]
+        {
+            Self { state: Mutex::new(state) }
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            Self { locked: AtomicBool::new(false), state: UnsafeCell::new(state) }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn with_state<R>(&self, f: impl FnOnce(&mut State) -> R) -> R {
+        // No user code runs while the lock is held, so the state is consistent
+        // even if the lock was poisoned.
+        f(&mut self.state.lock().unwrap_or_else(PoisonError::into_inner))
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn with_state<R>(&self, f: impl FnOnce(&mut State) -> R) -> R {
+        while self
+            .locked
+            .compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            while self.locked.load(Ordering::Relaxed) {
+                // `core::hint::spin_loop` replaces this, but requires a newer
+                // compiler than this crate supports.
+                #[allow(deprecated)]
+                core::sync::atomic::spin_loop_hint();
+            }
+        }
+        // Wakers are only cloned, woken and dropped outside of the critical
+        // sections, so no user code runs while the lock is held.
+        let result = f(unsafe { &mut *self.state.get() });
+        self.locked.store(false, Ordering::Release);
+        result
+    }
+
+    /// Adds a clone of `waker` to the set, returning a key which can be used
+    /// to remove it again.
+    ///
+    /// Each call adds a new registration, even if `waker` is registered
+    /// already; a task which is polled again before it was woken should
+    /// [`deregister`](WakerSet::deregister) its previous registration first.
+    pub fn register(&self, waker: &Waker) -> RegistrationKey {
+        let waker = waker.clone();
+        self.with_state(|state| {
+            let key = state.next_key;
+            state.next_key += 1;
+            state.wakers.push_back((key, waker));
+            RegistrationKey(key)
+        })
+    }
+
+    /// Removes the registration identified by `key` from the set, without
+    /// waking it up.
+    ///
+    /// Does nothing if the registration was woken up or removed already.
+    /// Returns whether it was still registered.
+    pub fn deregister(&self, key: RegistrationKey) -> bool {
+        let waker = self.with_state(|state| {
+            let pos = state.position(key.0)?;
+            state.wakers.remove(pos)
+        });
+        // Drop the waker outside of the lock.
+        waker.is_some()
+    }
+
+    /// Wakes up the registration which was added first, removing it from the
+    /// set.
+    ///
+    /// Returns `false` if the set was empty.
+    pub fn wake_one(&self) -> bool {
+        match self.with_state(|state| state.wakers.pop_front()) {
+            Some((_, waker)) => {
+                waker.wake();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Wakes up all registrations, leaving the set empty.
+    ///
+    /// Returns the number of wakers which were woken up. Registrations which
+    /// are added while the wakers are being woken up stay in the set.
+    pub fn wake_all(&self) -> usize {
+        let wakers = self.with_state(|state| mem::replace(&mut state.wakers, VecDeque::new()));
+        let len = wakers.len();
+        for (_, waker) in wakers {
+            waker.wake();
+        }
+        len
+    }
+
+    /// Returns the number of registrations in the set.
+    pub fn len(&self) -> usize {
+        self.with_state(|state| state.wakers.len())
+    }
+
+    /// Returns `true` if there are no registrations in the set.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
@@ -34,6 +34,20 @@ impl<T, Fut> UnfoldState<T, Fut> {
         }
     }
 
+    pub(crate) fn value(&self) -> Option<&T> {
+        match self {
+            Self::Value { value } => Some(value),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn into_value(self) -> Option<T> {
+        match self {
+            Self::Value { value } => Some(value),
+            _ => None,
+        }
+    }
+
     pub(crate) fn project_future(self: Pin<&mut Self>) -> Option<Pin<&mut Fut>> {
         match self.project() {
             UnfoldStateProj::Future { future } => Some(future),
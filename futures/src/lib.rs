@@ -96,8 +96,11 @@
 ))]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
-#[cfg(all(feature = "bilock", not(feature = "unstable")))]
-compile_error!("The `bilock` feature requires the `unstable` feature as an explicit opt-in to unstable features");
+#[cfg(all(feature = "join-array", not(feature = "unstable")))]
+compile_error!("The `join-array` feature requires the `unstable` feature as an explicit opt-in to unstable features");
+
+#[cfg(all(feature = "into-future", not(feature = "unstable")))]
+compile_error!("The `into-future` feature requires the `unstable` feature as an explicit opt-in to unstable features");
 
 #[doc(no_inline)]
 pub use futures_core::future::{Future, TryFuture};
@@ -193,12 +196,12 @@ pub mod executor {
 
     pub use futures_executor::{
         block_on, block_on_stream, enter, BlockingStream, Enter, EnterError, LocalPool,
-        LocalSpawner,
+        LocalSpawner, RunReport,
     };
 
     #[cfg(feature = "thread-pool")]
     #[cfg_attr(docsrs, doc(cfg(feature = "thread-pool")))]
-    pub use futures_executor::{ThreadPool, ThreadPoolBuilder};
+    pub use futures_executor::{PanicPolicy, ShutdownHandle, ThreadPool, ThreadPoolBuilder};
 }
 
 #[cfg(feature = "compat")]
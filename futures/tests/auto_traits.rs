@@ -78,6 +78,12 @@ pub mod channel {
     assert_not_impl!(mpsc::Receiver<*const ()>: Sync);
     assert_impl!(mpsc::Receiver<PhantomPinned>: Unpin);
 
+    assert_impl!(mpsc::RecvMany<'_, ()>: Send);
+    assert_not_impl!(mpsc::RecvMany<'_, *const ()>: Send);
+    assert_impl!(mpsc::RecvMany<'_, ()>: Sync);
+    assert_not_impl!(mpsc::RecvMany<'_, *const ()>: Sync);
+    assert_impl!(mpsc::RecvMany<'_, PhantomPinned>: Unpin);
+
     assert_impl!(mpsc::SendError: Send);
     assert_impl!(mpsc::SendError: Sync);
     assert_impl!(mpsc::SendError: Unpin);
@@ -205,6 +211,18 @@ pub mod executor {
     assert_not_impl!(LocalSpawner: Sync);
     assert_impl!(LocalSpawner: Unpin);
 
+    assert_impl!(PanicPolicy: Send);
+    assert_impl!(PanicPolicy: Sync);
+    assert_impl!(PanicPolicy: Unpin);
+
+    assert_impl!(RunReport: Send);
+    assert_impl!(RunReport: Sync);
+    assert_impl!(RunReport: Unpin);
+
+    assert_impl!(ShutdownHandle: Send);
+    assert_impl!(ShutdownHandle: Sync);
+    assert_impl!(ShutdownHandle: Unpin);
+
     assert_impl!(ThreadPool: Send);
     assert_impl!(ThreadPool: Sync);
     assert_impl!(ThreadPool: Unpin);
@@ -222,11 +240,21 @@ pub mod future {
     assert_impl!(AbortHandle: Send);
     assert_impl!(AbortHandle: Sync);
     assert_impl!(AbortHandle: Unpin);
+    assert_not_impl!(AbortHandle<*const ()>: Send);
+    assert_not_impl!(AbortHandle<*const ()>: Sync);
+
+    assert_impl!(AbortOnDropHandle: Send);
+    assert_impl!(AbortOnDropHandle: Sync);
+    assert_impl!(AbortOnDropHandle: Unpin);
 
     assert_impl!(AbortRegistration: Send);
     assert_impl!(AbortRegistration: Sync);
     assert_impl!(AbortRegistration: Unpin);
 
+    assert_impl!(AbortSet: Send);
+    assert_impl!(AbortSet: Sync);
+    assert_impl!(AbortSet: Unpin);
+
     assert_impl!(Abortable<SendFuture>: Send);
     assert_not_impl!(Abortable<LocalFuture>: Send);
     assert_impl!(Abortable<SyncFuture>: Sync);
@@ -234,10 +262,30 @@ pub mod future {
     assert_impl!(Abortable<UnpinFuture>: Unpin);
     assert_not_impl!(Abortable<PinnedFuture>: Unpin);
 
+    assert_impl!(AbortableWith<SendFuture, ()>: Send);
+    assert_not_impl!(AbortableWith<LocalFuture, ()>: Send);
+    assert_impl!(AbortableWith<SyncFuture, ()>: Sync);
+    assert_not_impl!(AbortableWith<LocalFuture, ()>: Sync);
+    assert_impl!(AbortableWith<UnpinFuture, PhantomPinned>: Unpin);
+    assert_not_impl!(AbortableWith<PinnedFuture, ()>: Unpin);
+
     assert_impl!(Aborted: Send);
     assert_impl!(Aborted: Sync);
     assert_impl!(Aborted: Unpin);
 
+    assert_impl!(AbortedWith<()>: Send);
+    assert_not_impl!(AbortedWith<*const ()>: Send);
+    assert_impl!(AbortedWith<()>: Sync);
+    assert_not_impl!(AbortedWith<*const ()>: Sync);
+    assert_impl!(AbortedWith<()>: Unpin);
+    assert_not_impl!(AbortedWith<PhantomPinned>: Unpin);
+
+    assert_impl!(AlwaysReady<()>: Send);
+    assert_not_impl!(AlwaysReady<*const ()>: Send);
+    assert_impl!(AlwaysReady<()>: Sync);
+    assert_not_impl!(AlwaysReady<*const ()>: Sync);
+    assert_impl!(AlwaysReady<PhantomPinned>: Unpin);
+
     assert_impl!(AndThen<SendFuture, SendFuture, ()>: Send);
     assert_not_impl!(AndThen<SendFuture, LocalFuture, ()>: Send);
     assert_not_impl!(AndThen<LocalFuture, SendFuture, ()>: Send);
@@ -257,6 +305,19 @@ pub mod future {
     assert_impl!(CatchUnwind<UnpinFuture>: Unpin);
     assert_not_impl!(CatchUnwind<PinnedFuture>: Unpin);
 
+    assert_impl!(CatchUnwindMessage<SendFuture>: Send);
+    assert_not_impl!(CatchUnwindMessage<LocalFuture>: Send);
+    assert_impl!(CatchUnwindMessage<SyncFuture>: Sync);
+    assert_not_impl!(CatchUnwindMessage<LocalFuture>: Sync);
+    assert_impl!(CatchUnwindMessage<UnpinFuture>: Unpin);
+    assert_not_impl!(CatchUnwindMessage<PinnedFuture>: Unpin);
+
+    assert_impl!(CompletionWatcher<()>: Send);
+    assert_not_impl!(CompletionWatcher<*const ()>: Send);
+    assert_impl!(CompletionWatcher<()>: Sync);
+    assert_not_impl!(CompletionWatcher<*const ()>: Sync);
+    assert_impl!(CompletionWatcher<PhantomPinned>: Unpin);
+
     assert_impl!(ErrInto<SendTryFuture, *const ()>: Send);
     assert_not_impl!(ErrInto<LocalTryFuture, ()>: Send);
     assert_impl!(ErrInto<SyncTryFuture, *const ()>: Sync);
@@ -332,6 +393,15 @@ pub mod future {
     assert_impl!(InspectOk<UnpinFuture, PhantomPinned>: Unpin);
     assert_not_impl!(InspectOk<PhantomPinned, PhantomPinned>: Unpin);
 
+    assert_impl!(InspectPoll<SendFuture, ()>: Send);
+    assert_not_impl!(InspectPoll<SendFuture, *const ()>: Send);
+    assert_not_impl!(InspectPoll<LocalFuture, ()>: Send);
+    assert_impl!(InspectPoll<SyncFuture, ()>: Sync);
+    assert_not_impl!(InspectPoll<SyncFuture, *const ()>: Sync);
+    assert_not_impl!(InspectPoll<LocalFuture, ()>: Sync);
+    assert_impl!(InspectPoll<UnpinFuture, PhantomPinned>: Unpin);
+    assert_not_impl!(InspectPoll<PhantomPinned, PhantomPinned>: Unpin);
+
     assert_impl!(IntoFuture<SendFuture>: Send);
     assert_not_impl!(IntoFuture<LocalFuture>: Send);
     assert_impl!(IntoFuture<SyncFuture>: Sync);
@@ -370,12 +440,62 @@ pub mod future {
     assert_not_impl!(JoinAll<SyncFuture>: Sync);
     assert_impl!(JoinAll<PinnedFuture>: Unpin);
 
+    assert_impl!(JoinAllRecoverable<SendFuture<()>>: Send);
+    assert_not_impl!(JoinAllRecoverable<LocalFuture>: Send);
+    assert_not_impl!(JoinAllRecoverable<SendFuture>: Send);
+    assert_impl!(JoinAllRecoverable<SyncFuture<()>>: Sync);
+    assert_not_impl!(JoinAllRecoverable<LocalFuture>: Sync);
+    assert_not_impl!(JoinAllRecoverable<SyncFuture>: Sync);
+    assert_impl!(JoinAllRecoverable<PinnedFuture>: Unpin);
+
+    assert_impl!(JoinAllResults<SendTryFuture<(), ()>>: Send);
+    assert_not_impl!(JoinAllResults<LocalTryFuture>: Send);
+    assert_not_impl!(JoinAllResults<SendTryFuture>: Send);
+    assert_impl!(JoinAllResults<SyncTryFuture<(), ()>>: Sync);
+    assert_not_impl!(JoinAllResults<LocalTryFuture>: Sync);
+    assert_not_impl!(JoinAllResults<SyncTryFuture>: Sync);
+    assert_impl!(JoinAllResults<PinnedTryFuture>: Unpin);
+
+    assert_impl!(JoinAllStreaming<SendFuture<()>>: Send);
+    assert_not_impl!(JoinAllStreaming<LocalFuture>: Send);
+    assert_not_impl!(JoinAllStreaming<SendFuture>: Send);
+    assert_impl!(JoinAllStreaming<SyncFuture<()>>: Sync);
+    assert_not_impl!(JoinAllStreaming<LocalFuture>: Sync);
+    assert_not_impl!(JoinAllStreaming<SyncFuture>: Sync);
+    assert_impl!(JoinAllStreaming<PinnedFuture>: Unpin);
+
+    #[cfg(feature = "join-array")]
+    assert_impl!(JoinArray<SendFuture<()>, 2>: Send);
+    #[cfg(feature = "join-array")]
+    assert_not_impl!(JoinArray<SendFuture, 2>: Send);
+    #[cfg(feature = "join-array")]
+    assert_not_impl!(JoinArray<LocalFuture<()>, 2>: Send);
+    #[cfg(feature = "join-array")]
+    assert_impl!(JoinArray<SyncFuture<()>, 2>: Sync);
+    #[cfg(feature = "join-array")]
+    assert_not_impl!(JoinArray<SyncFuture, 2>: Sync);
+    #[cfg(feature = "join-array")]
+    assert_not_impl!(JoinArray<LocalFuture<()>, 2>: Sync);
+    #[cfg(feature = "join-array")]
+    assert_impl!(JoinArray<UnpinFuture, 2>: Unpin);
+    #[cfg(feature = "join-array")]
+    assert_not_impl!(JoinArray<PinnedFuture, 2>: Unpin);
+
     assert_impl!(Lazy<()>: Send);
     assert_not_impl!(Lazy<*const ()>: Send);
     assert_impl!(Lazy<()>: Sync);
     assert_not_impl!(Lazy<*const ()>: Sync);
     assert_impl!(Lazy<PhantomPinned>: Unpin);
 
+    assert_impl!(LazyThen<(), SendFuture<()>>: Send);
+    assert_not_impl!(LazyThen<*const (), SendFuture<()>>: Send);
+    assert_not_impl!(LazyThen<(), LocalFuture<()>>: Send);
+    assert_impl!(LazyThen<(), SyncFuture<()>>: Sync);
+    assert_not_impl!(LazyThen<*const (), SyncFuture<()>>: Sync);
+    assert_not_impl!(LazyThen<(), LocalFuture<()>>: Sync);
+    assert_impl!(LazyThen<PhantomPinned, UnpinFuture>: Unpin);
+    assert_not_impl!(LazyThen<(), PinnedFuture>: Unpin);
+
     assert_not_impl!(LocalFutureObj<()>: Send);
     assert_not_impl!(LocalFutureObj<()>: Sync);
     assert_impl!(LocalFutureObj<PhantomPinned>: Unpin);
@@ -458,6 +578,10 @@ pub mod future {
     assert_not_impl!(OrElse<PinnedFuture, UnpinFuture, PhantomPinned>: Unpin);
     assert_not_impl!(OrElse<UnpinFuture, PinnedFuture, PhantomPinned>: Unpin);
 
+    assert_impl!(Panicked: Send);
+    assert_impl!(Panicked: Sync);
+    assert_impl!(Panicked: Unpin);
+
     assert_impl!(Pending<()>: Send);
     assert_not_impl!(Pending<*const ()>: Send);
     assert_impl!(Pending<()>: Sync);
@@ -470,6 +594,10 @@ pub mod future {
     assert_not_impl!(PollFn<*const ()>: Sync);
     assert_impl!(PollFn<PhantomPinned>: Unpin);
 
+    assert_impl!(PollEvent: Send);
+    assert_impl!(PollEvent: Sync);
+    assert_impl!(PollEvent: Unpin);
+
     assert_impl!(PollImmediate<SendStream>: Send);
     assert_not_impl!(PollImmediate<LocalStream<()>>: Send);
     assert_impl!(PollImmediate<SyncStream>: Sync);
@@ -498,6 +626,18 @@ pub mod future {
     assert_not_impl!(RemoteHandle<*const ()>: Sync);
     assert_impl!(RemoteHandle<PhantomPinned>: Unpin);
 
+    assert_impl!(Retry<SendFuture, (), (), SendFuture>: Send);
+    assert_not_impl!(Retry<LocalFuture, (), (), SendFuture>: Send);
+    assert_not_impl!(Retry<SendFuture, *const (), (), SendFuture>: Send);
+    assert_not_impl!(Retry<SendFuture, (), (), LocalFuture>: Send);
+    assert_impl!(Retry<SyncFuture, (), (), SyncFuture>: Sync);
+    assert_not_impl!(Retry<LocalFuture, (), (), SyncFuture>: Sync);
+    assert_not_impl!(Retry<SyncFuture, (), *const (), SyncFuture>: Sync);
+    assert_not_impl!(Retry<SyncFuture, (), (), LocalFuture>: Sync);
+    assert_impl!(Retry<UnpinFuture, PhantomPinned, PhantomPinned, UnpinFuture>: Unpin);
+    assert_not_impl!(Retry<PinnedFuture, (), (), UnpinFuture>: Unpin);
+    assert_not_impl!(Retry<UnpinFuture, (), (), PinnedFuture>: Unpin);
+
     assert_impl!(Select<SendFuture, SendFuture>: Send);
     assert_not_impl!(Select<SendFuture, LocalFuture>: Send);
     assert_not_impl!(Select<LocalFuture, SendFuture>: Send);
@@ -515,6 +655,16 @@ pub mod future {
     assert_impl!(SelectAll<UnpinFuture>: Unpin);
     assert_not_impl!(SelectAll<PinnedFuture>: Unpin);
 
+    assert_impl!(SelectNamed<SendFuture, SendFuture>: Send);
+    assert_not_impl!(SelectNamed<SendFuture, LocalFuture>: Send);
+    assert_not_impl!(SelectNamed<LocalFuture, SendFuture>: Send);
+    assert_impl!(SelectNamed<SyncFuture, SyncFuture>: Sync);
+    assert_not_impl!(SelectNamed<SyncFuture, LocalFuture>: Sync);
+    assert_not_impl!(SelectNamed<LocalFuture, SyncFuture>: Sync);
+    assert_impl!(SelectNamed<UnpinFuture, UnpinFuture>: Unpin);
+    assert_not_impl!(SelectNamed<PinnedFuture, UnpinFuture>: Unpin);
+    assert_not_impl!(SelectNamed<UnpinFuture, PinnedFuture>: Unpin);
+
     assert_impl!(SelectOk<SendFuture>: Send);
     assert_not_impl!(SelectOk<LocalFuture>: Send);
     assert_impl!(SelectOk<SyncFuture>: Sync);
@@ -522,12 +672,36 @@ pub mod future {
     assert_impl!(SelectOk<UnpinFuture>: Unpin);
     assert_not_impl!(SelectOk<PinnedFuture>: Unpin);
 
+    assert_impl!(SelectOkAllErrors<SendTryFuture<(), ()>>: Send);
+    assert_not_impl!(SelectOkAllErrors<SendTryFuture<(), *const ()>>: Send);
+    assert_not_impl!(SelectOkAllErrors<LocalTryFuture<(), ()>>: Send);
+    assert_impl!(SelectOkAllErrors<SyncTryFuture<(), ()>>: Sync);
+    assert_not_impl!(SelectOkAllErrors<SyncTryFuture<(), *const ()>>: Sync);
+    assert_not_impl!(SelectOkAllErrors<LocalTryFuture<(), ()>>: Sync);
+    assert_impl!(SelectOkAllErrors<UnpinTryFuture>: Unpin);
+    assert_not_impl!(SelectOkAllErrors<PinnedTryFuture>: Unpin);
+
+    assert_impl!(SelectResult<SendFuture<()>, SendFuture<()>>: Send);
+    assert_not_impl!(SelectResult<SendFuture, SendFuture<()>>: Send);
+    assert_not_impl!(SelectResult<SendFuture<()>, LocalFuture<()>>: Send);
+    assert_impl!(SelectResult<SyncFuture<()>, SyncFuture<()>>: Sync);
+    assert_not_impl!(SelectResult<SyncFuture, SyncFuture<()>>: Sync);
+    assert_not_impl!(SelectResult<SyncFuture<()>, LocalFuture<()>>: Sync);
+    assert_impl!(SelectResult<UnpinFuture<()>, UnpinFuture<()>>: Unpin);
+    assert_not_impl!(SelectResult<PinnedFuture<()>, UnpinFuture<()>>: Unpin);
+
     assert_impl!(Shared<SendFuture<()>>: Send);
     assert_not_impl!(Shared<SendFuture>: Send);
     assert_not_impl!(Shared<LocalFuture>: Send);
     assert_not_impl!(Shared<SyncFuture<()>>: Sync);
     assert_impl!(Shared<PinnedFuture>: Unpin);
 
+    assert_impl!(SharedArc<SendFuture<()>>: Send);
+    assert_not_impl!(SharedArc<SendFuture>: Send);
+    assert_not_impl!(SharedArc<LocalFuture>: Send);
+    assert_not_impl!(SharedArc<SyncFuture<()>>: Sync);
+    assert_impl!(SharedArc<PinnedFuture>: Unpin);
+
     assert_impl!(Then<SendFuture, SendFuture, ()>: Send);
     assert_not_impl!(Then<SendFuture, SendFuture, *const ()>: Send);
     assert_not_impl!(Then<SendFuture, LocalFuture, ()>: Send);
@@ -584,6 +758,51 @@ pub mod future {
     assert_not_impl!(TryJoinAll<SyncTryFuture>: Sync);
     assert_impl!(TryJoinAll<PinnedTryFuture>: Unpin);
 
+    assert_impl!(TryJoinAllPartial<SendTryFuture<()>>: Send);
+    assert_not_impl!(TryJoinAllPartial<LocalTryFuture>: Send);
+    assert_not_impl!(TryJoinAllPartial<SendTryFuture>: Send);
+    assert_impl!(TryJoinAllPartial<SyncTryFuture<()>>: Sync);
+    assert_not_impl!(TryJoinAllPartial<LocalTryFuture>: Sync);
+    assert_not_impl!(TryJoinAllPartial<SyncTryFuture>: Sync);
+    assert_impl!(TryJoinAllPartial<PinnedTryFuture>: Unpin);
+
+    assert_impl!(TryJoinAllStreaming<SendTryFuture<(), ()>>: Send);
+    assert_not_impl!(TryJoinAllStreaming<LocalTryFuture>: Send);
+    assert_not_impl!(TryJoinAllStreaming<SendTryFuture>: Send);
+    assert_impl!(TryJoinAllStreaming<SyncTryFuture<(), ()>>: Sync);
+    assert_not_impl!(TryJoinAllStreaming<LocalTryFuture>: Sync);
+    assert_not_impl!(TryJoinAllStreaming<SyncTryFuture>: Sync);
+    assert_impl!(TryJoinAllStreaming<PinnedTryFuture>: Unpin);
+
+    #[cfg(feature = "join-array")]
+    assert_impl!(TryJoinArray<SendTryFuture<(), ()>, 2>: Send);
+    #[cfg(feature = "join-array")]
+    assert_not_impl!(TryJoinArray<SendTryFuture, 2>: Send);
+    #[cfg(feature = "join-array")]
+    assert_not_impl!(TryJoinArray<LocalTryFuture<(), ()>, 2>: Send);
+    #[cfg(feature = "join-array")]
+    assert_impl!(TryJoinArray<SyncTryFuture<(), ()>, 2>: Sync);
+    #[cfg(feature = "join-array")]
+    assert_not_impl!(TryJoinArray<SyncTryFuture, 2>: Sync);
+    #[cfg(feature = "join-array")]
+    assert_not_impl!(TryJoinArray<LocalTryFuture<(), ()>, 2>: Sync);
+    #[cfg(feature = "join-array")]
+    assert_impl!(TryJoinArray<UnpinTryFuture, 2>: Unpin);
+    #[cfg(feature = "join-array")]
+    assert_not_impl!(TryJoinArray<PinnedTryFuture, 2>: Unpin);
+
+    assert_impl!(TryJoinWithCleanup<SendTryFuture<(), ()>, (), SendFuture<()>>: Send);
+    assert_not_impl!(TryJoinWithCleanup<LocalTryFuture<(), ()>, (), SendFuture<()>>: Send);
+    assert_not_impl!(TryJoinWithCleanup<SendTryFuture<(), ()>, *const (), SendFuture<()>>: Send);
+    assert_not_impl!(TryJoinWithCleanup<SendTryFuture<(), ()>, (), LocalFuture<()>>: Send);
+    assert_not_impl!(TryJoinWithCleanup<SendTryFuture<()>, (), SendFuture<()>>: Send);
+    assert_impl!(TryJoinWithCleanup<SyncTryFuture<(), ()>, (), SyncFuture<()>>: Sync);
+    assert_not_impl!(TryJoinWithCleanup<LocalTryFuture<(), ()>, (), SyncFuture<()>>: Sync);
+    assert_not_impl!(TryJoinWithCleanup<SyncTryFuture<(), ()>, *const (), SyncFuture<()>>: Sync);
+    assert_not_impl!(TryJoinWithCleanup<SyncTryFuture<(), ()>, (), LocalFuture<()>>: Sync);
+    assert_impl!(TryJoinWithCleanup<UnpinTryFuture, PhantomPinned, PinnedFuture<()>>: Unpin);
+    assert_not_impl!(TryJoinWithCleanup<PinnedTryFuture, (), UnpinFuture<()>>: Unpin);
+
     assert_impl!(TrySelect<SendFuture, SendFuture>: Send);
     assert_not_impl!(TrySelect<SendFuture, LocalFuture>: Send);
     assert_not_impl!(TrySelect<LocalFuture, SendFuture>: Send);
@@ -616,6 +835,12 @@ pub mod future {
     assert_not_impl!(WeakShared<SyncFuture<()>>: Sync);
     assert_impl!(WeakShared<PinnedFuture>: Unpin);
 
+    assert_impl!(WeakSharedArc<SendFuture<()>>: Send);
+    assert_not_impl!(WeakSharedArc<SendFuture>: Send);
+    assert_not_impl!(WeakSharedArc<LocalFuture>: Send);
+    assert_not_impl!(WeakSharedArc<SyncFuture<()>>: Sync);
+    assert_impl!(WeakSharedArc<PinnedFuture>: Unpin);
+
     assert_impl!(Either<SendFuture, SendFuture>: Send);
     assert_not_impl!(Either<SendFuture, LocalFuture>: Send);
     assert_not_impl!(Either<LocalFuture, SendFuture>: Send);
@@ -656,6 +881,23 @@ pub mod io {
     assert_not_impl!(AllowStdIo<*const ()>: Sync);
     assert_impl!(AllowStdIo<PhantomPinned>: Unpin);
 
+    assert_impl!(AutoFlush<(), (), SendFuture>: Send);
+    assert_not_impl!(AutoFlush<*const (), (), SendFuture>: Send);
+    assert_not_impl!(AutoFlush<(), *const (), SendFuture>: Send);
+    assert_not_impl!(AutoFlush<(), (), LocalFuture>: Send);
+    assert_impl!(AutoFlush<(), (), SyncFuture>: Sync);
+    assert_not_impl!(AutoFlush<*const (), (), SyncFuture>: Sync);
+    assert_not_impl!(AutoFlush<(), *const (), SyncFuture>: Sync);
+    assert_not_impl!(AutoFlush<(), (), LocalFuture>: Sync);
+    assert_impl!(AutoFlush<(), PhantomPinned, UnpinFuture>: Unpin);
+    assert_not_impl!(AutoFlush<PhantomPinned, (), UnpinFuture>: Unpin);
+    assert_not_impl!(AutoFlush<(), (), PinnedFuture>: Unpin);
+
+    assert_impl!(BlockingIo<()>: Send);
+    // `BlockingIo` is `Sync` only if `std::sync::mpsc::Sender` is, which
+    // depends on the version of std.
+    assert_impl!(BlockingIo<PhantomPinned>: Unpin);
+
     assert_impl!(BufReader<()>: Send);
     assert_not_impl!(BufReader<*const ()>: Send);
     assert_impl!(BufReader<()>: Sync);
@@ -670,6 +912,13 @@ pub mod io {
     assert_impl!(BufWriter<()>: Unpin);
     assert_not_impl!(BufWriter<PhantomPinned>: Unpin);
 
+    assert_impl!(Bytes<()>: Send);
+    assert_not_impl!(Bytes<*const ()>: Send);
+    assert_impl!(Bytes<()>: Sync);
+    assert_not_impl!(Bytes<*const ()>: Sync);
+    assert_impl!(Bytes<()>: Unpin);
+    assert_not_impl!(Bytes<PhantomPinned>: Unpin);
+
     assert_impl!(Chain<(), ()>: Send);
     assert_not_impl!(Chain<(), *const ()>: Send);
     assert_not_impl!(Chain<*const (), ()>: Send);
@@ -680,6 +929,13 @@ pub mod io {
     assert_not_impl!(Chain<(), PhantomPinned>: Unpin);
     assert_not_impl!(Chain<PhantomPinned, ()>: Unpin);
 
+    assert_impl!(ChainAll<()>: Send);
+    assert_not_impl!(ChainAll<*const ()>: Send);
+    assert_impl!(ChainAll<()>: Sync);
+    assert_not_impl!(ChainAll<*const ()>: Sync);
+    assert_impl!(ChainAll<()>: Unpin);
+    assert_not_impl!(ChainAll<PhantomPinned>: Unpin);
+
     assert_impl!(Close<'_, ()>: Send);
     assert_not_impl!(Close<'_, *const ()>: Send);
     assert_impl!(Close<'_, ()>: Sync);
@@ -696,6 +952,18 @@ pub mod io {
     assert_impl!(Copy<(), PhantomPinned>: Unpin);
     assert_not_impl!(Copy<PhantomPinned, ()>: Unpin);
 
+    assert_impl!(CopyBidirectional<'_, (), ()>: Send);
+    assert_not_impl!(CopyBidirectional<'_, (), *const ()>: Send);
+    assert_not_impl!(CopyBidirectional<'_, *const (), ()>: Send);
+    assert_impl!(CopyBidirectional<'_, (), ()>: Sync);
+    assert_not_impl!(CopyBidirectional<'_, (), *const ()>: Sync);
+    assert_not_impl!(CopyBidirectional<'_, *const (), ()>: Sync);
+    assert_impl!(CopyBidirectional<'_, PhantomPinned, PhantomPinned>: Unpin);
+
+    assert_impl!(CopyBidirectionalError: Send);
+    assert_impl!(CopyBidirectionalError: Sync);
+    assert_impl!(CopyBidirectionalError: Unpin);
+
     assert_impl!(CopyBuf<(), ()>: Send);
     assert_not_impl!(CopyBuf<(), *const ()>: Send);
     assert_not_impl!(CopyBuf<*const (), ()>: Send);
@@ -705,6 +973,26 @@ pub mod io {
     assert_impl!(CopyBuf<(), PhantomPinned>: Unpin);
     assert_not_impl!(CopyBuf<PhantomPinned, ()>: Unpin);
 
+    assert_impl!(CopyUntilLimit<(), ()>: Send);
+    assert_not_impl!(CopyUntilLimit<(), *const ()>: Send);
+    assert_not_impl!(CopyUntilLimit<*const (), ()>: Send);
+    assert_impl!(CopyUntilLimit<(), ()>: Sync);
+    assert_not_impl!(CopyUntilLimit<(), *const ()>: Sync);
+    assert_not_impl!(CopyUntilLimit<*const (), ()>: Sync);
+    assert_impl!(CopyUntilLimit<(), PhantomPinned>: Unpin);
+    assert_not_impl!(CopyUntilLimit<PhantomPinned, ()>: Unpin);
+
+    assert_impl!(CopyWithProgress<(), (), ()>: Send);
+    assert_not_impl!(CopyWithProgress<(), *const (), ()>: Send);
+    assert_not_impl!(CopyWithProgress<*const (), (), ()>: Send);
+    assert_not_impl!(CopyWithProgress<(), (), *const ()>: Send);
+    assert_impl!(CopyWithProgress<(), (), ()>: Sync);
+    assert_not_impl!(CopyWithProgress<(), *const (), ()>: Sync);
+    assert_not_impl!(CopyWithProgress<*const (), (), ()>: Sync);
+    assert_not_impl!(CopyWithProgress<(), (), *const ()>: Sync);
+    assert_impl!(CopyWithProgress<(), PhantomPinned, PhantomPinned>: Unpin);
+    assert_not_impl!(CopyWithProgress<PhantomPinned, (), ()>: Unpin);
+
     assert_impl!(Cursor<()>: Send);
     assert_not_impl!(Cursor<*const ()>: Send);
     assert_impl!(Cursor<()>: Sync);
@@ -729,6 +1017,20 @@ pub mod io {
     assert_impl!(Flush<'_, ()>: Unpin);
     assert_not_impl!(Flush<'_, PhantomPinned>: Unpin);
 
+    assert_impl!(FlushIntoInner<()>: Send);
+    assert_not_impl!(FlushIntoInner<*const ()>: Send);
+    assert_impl!(FlushIntoInner<()>: Sync);
+    assert_not_impl!(FlushIntoInner<*const ()>: Sync);
+    assert_impl!(FlushIntoInner<()>: Unpin);
+    assert_not_impl!(FlushIntoInner<PhantomPinned>: Unpin);
+
+    assert_impl!(IntoInnerError<()>: Send);
+    assert_not_impl!(IntoInnerError<*const ()>: Send);
+    assert_impl!(IntoInnerError<()>: Sync);
+    assert_not_impl!(IntoInnerError<*const ()>: Sync);
+    assert_impl!(IntoInnerError<()>: Unpin);
+    assert_not_impl!(IntoInnerError<PhantomPinned>: Unpin);
+
     assert_impl!(IntoSink<(), ()>: Send);
     assert_not_impl!(IntoSink<(), *const ()>: Send);
     assert_not_impl!(IntoSink<*const (), ()>: Send);
@@ -738,6 +1040,14 @@ pub mod io {
     assert_impl!(IntoSink<(), PhantomPinned>: Unpin);
     assert_not_impl!(IntoSink<PhantomPinned, ()>: Unpin);
 
+    assert_impl!(LineTooLong: Send);
+    assert_impl!(LineTooLong: Sync);
+    assert_impl!(LineTooLong: Unpin);
+
+    assert_impl!(LineTooLongError: Send);
+    assert_impl!(LineTooLongError: Sync);
+    assert_impl!(LineTooLongError: Unpin);
+
     assert_impl!(Lines<()>: Send);
     assert_not_impl!(Lines<*const ()>: Send);
     assert_impl!(Lines<()>: Sync);
@@ -745,6 +1055,19 @@ pub mod io {
     assert_impl!(Lines<()>: Unpin);
     assert_not_impl!(Lines<PhantomPinned>: Unpin);
 
+    assert_impl!(LinesWithMax<()>: Send);
+    assert_not_impl!(LinesWithMax<*const ()>: Send);
+    assert_impl!(LinesWithMax<()>: Sync);
+    assert_not_impl!(LinesWithMax<*const ()>: Sync);
+    assert_impl!(LinesWithMax<()>: Unpin);
+    assert_not_impl!(LinesWithMax<PhantomPinned>: Unpin);
+
+    assert_impl!(Peek<'_, ()>: Send);
+    assert_not_impl!(Peek<'_, *const ()>: Send);
+    assert_impl!(Peek<'_, ()>: Sync);
+    assert_not_impl!(Peek<'_, *const ()>: Sync);
+    assert_impl!(Peek<'_, PhantomPinned>: Unpin);
+
     assert_impl!(Read<'_, ()>: Send);
     assert_not_impl!(Read<'_, *const ()>: Send);
     assert_impl!(Read<'_, ()>: Sync);
@@ -752,6 +1075,14 @@ pub mod io {
     assert_impl!(Read<'_, ()>: Unpin);
     assert_not_impl!(Read<'_, PhantomPinned>: Unpin);
 
+    assert_impl!(ReadBuf<'_>: Send);
+    assert_impl!(ReadBuf<'_>: Sync);
+    assert_impl!(ReadBuf<'_>: Unpin);
+
+    assert_impl!(ReadCursor: Send);
+    assert_impl!(ReadCursor: Sync);
+    assert_impl!(ReadCursor: Unpin);
+
     assert_impl!(ReadExact<'_, ()>: Send);
     assert_not_impl!(ReadExact<'_, *const ()>: Send);
     assert_impl!(ReadExact<'_, ()>: Sync);
@@ -759,6 +1090,31 @@ pub mod io {
     assert_impl!(ReadExact<'_, ()>: Unpin);
     assert_not_impl!(ReadExact<'_, PhantomPinned>: Unpin);
 
+    assert_impl!(ReadExactError: Send);
+    assert_impl!(ReadExactError: Sync);
+    assert_impl!(ReadExactError: Unpin);
+
+    assert_impl!(ReadExactOrEof<'_, ()>: Send);
+    assert_not_impl!(ReadExactOrEof<'_, *const ()>: Send);
+    assert_impl!(ReadExactOrEof<'_, ()>: Sync);
+    assert_not_impl!(ReadExactOrEof<'_, *const ()>: Sync);
+    assert_impl!(ReadExactOrEof<'_, ()>: Unpin);
+    assert_not_impl!(ReadExactOrEof<'_, PhantomPinned>: Unpin);
+
+    assert_impl!(ReadFrame<'_, ()>: Send);
+    assert_not_impl!(ReadFrame<'_, *const ()>: Send);
+    assert_impl!(ReadFrame<'_, ()>: Sync);
+    assert_not_impl!(ReadFrame<'_, *const ()>: Sync);
+    assert_impl!(ReadFrame<'_, ()>: Unpin);
+    assert_not_impl!(ReadFrame<'_, PhantomPinned>: Unpin);
+
+    assert_impl!(ReadFrameInto<'_, ()>: Send);
+    assert_not_impl!(ReadFrameInto<'_, *const ()>: Send);
+    assert_impl!(ReadFrameInto<'_, ()>: Sync);
+    assert_not_impl!(ReadFrameInto<'_, *const ()>: Sync);
+    assert_impl!(ReadFrameInto<'_, ()>: Unpin);
+    assert_not_impl!(ReadFrameInto<'_, PhantomPinned>: Unpin);
+
     assert_impl!(ReadHalf<()>: Send);
     assert_not_impl!(ReadHalf<*const ()>: Send);
     assert_impl!(ReadHalf<()>: Sync);
@@ -779,6 +1135,17 @@ pub mod io {
     assert_impl!(ReadToEnd<'_, ()>: Unpin);
     assert_not_impl!(ReadToEnd<'_, PhantomPinned>: Unpin);
 
+    assert_impl!(ReadToEndError: Send);
+    assert_impl!(ReadToEndError: Sync);
+    assert_impl!(ReadToEndError: Unpin);
+
+    assert_impl!(ReadToEndLimited<'_, ()>: Send);
+    assert_not_impl!(ReadToEndLimited<'_, *const ()>: Send);
+    assert_impl!(ReadToEndLimited<'_, ()>: Sync);
+    assert_not_impl!(ReadToEndLimited<'_, *const ()>: Sync);
+    assert_impl!(ReadToEndLimited<'_, ()>: Unpin);
+    assert_not_impl!(ReadToEndLimited<'_, PhantomPinned>: Unpin);
+
     assert_impl!(ReadToString<'_, ()>: Send);
     assert_not_impl!(ReadToString<'_, *const ()>: Send);
     assert_impl!(ReadToString<'_, ()>: Sync);
@@ -786,6 +1153,13 @@ pub mod io {
     assert_impl!(ReadToString<'_, ()>: Unpin);
     assert_not_impl!(ReadToString<'_, PhantomPinned>: Unpin);
 
+    assert_impl!(ReadToStringLimited<'_, ()>: Send);
+    assert_not_impl!(ReadToStringLimited<'_, *const ()>: Send);
+    assert_impl!(ReadToStringLimited<'_, ()>: Sync);
+    assert_not_impl!(ReadToStringLimited<'_, *const ()>: Sync);
+    assert_impl!(ReadToStringLimited<'_, ()>: Unpin);
+    assert_not_impl!(ReadToStringLimited<'_, PhantomPinned>: Unpin);
+
     assert_impl!(ReadUntil<'_, ()>: Send);
     assert_not_impl!(ReadUntil<'_, *const ()>: Send);
     assert_impl!(ReadUntil<'_, ()>: Sync);
@@ -793,6 +1167,17 @@ pub mod io {
     assert_impl!(ReadUntil<'_, ()>: Unpin);
     assert_not_impl!(ReadUntil<'_, PhantomPinned>: Unpin);
 
+    assert_impl!(ReadUntilLimitExceeded: Send);
+    assert_impl!(ReadUntilLimitExceeded: Sync);
+    assert_impl!(ReadUntilLimitExceeded: Unpin);
+
+    assert_impl!(ReadUntilLimited<'_, ()>: Send);
+    assert_not_impl!(ReadUntilLimited<'_, *const ()>: Send);
+    assert_impl!(ReadUntilLimited<'_, ()>: Sync);
+    assert_not_impl!(ReadUntilLimited<'_, *const ()>: Sync);
+    assert_impl!(ReadUntilLimited<'_, ()>: Unpin);
+    assert_not_impl!(ReadUntilLimited<'_, PhantomPinned>: Unpin);
+
     assert_impl!(ReadVectored<'_, '_, ()>: Send);
     assert_not_impl!(ReadVectored<'_, '_, *const ()>: Send);
     assert_impl!(ReadVectored<'_, '_, ()>: Sync);
@@ -810,6 +1195,13 @@ pub mod io {
     assert_not_impl!(ReuniteError<*const ()>: Sync);
     assert_impl!(ReuniteError<PhantomPinned>: Unpin);
 
+    assert_impl!(Rewind<'_, ()>: Send);
+    assert_not_impl!(Rewind<'_, *const ()>: Send);
+    assert_impl!(Rewind<'_, ()>: Sync);
+    assert_not_impl!(Rewind<'_, *const ()>: Sync);
+    assert_impl!(Rewind<'_, ()>: Unpin);
+    assert_not_impl!(Rewind<'_, PhantomPinned>: Unpin);
+
     assert_impl!(Seek<'_, ()>: Send);
     assert_not_impl!(Seek<'_, *const ()>: Send);
     assert_impl!(Seek<'_, ()>: Sync);
@@ -827,6 +1219,27 @@ pub mod io {
     assert_impl!(Sink: Sync);
     assert_impl!(Sink: Unpin);
 
+    assert_impl!(SkipExact<'_, ()>: Send);
+    assert_not_impl!(SkipExact<'_, *const ()>: Send);
+    assert_impl!(SkipExact<'_, ()>: Sync);
+    assert_not_impl!(SkipExact<'_, *const ()>: Sync);
+    assert_impl!(SkipExact<'_, ()>: Unpin);
+    assert_not_impl!(SkipExact<'_, PhantomPinned>: Unpin);
+
+    assert_impl!(SkipUntil<'_, ()>: Send);
+    assert_not_impl!(SkipUntil<'_, *const ()>: Send);
+    assert_impl!(SkipUntil<'_, ()>: Sync);
+    assert_not_impl!(SkipUntil<'_, *const ()>: Sync);
+    assert_impl!(SkipUntil<'_, ()>: Unpin);
+    assert_not_impl!(SkipUntil<'_, PhantomPinned>: Unpin);
+
+    assert_impl!(StreamLen<'_, ()>: Send);
+    assert_not_impl!(StreamLen<'_, *const ()>: Send);
+    assert_impl!(StreamLen<'_, ()>: Sync);
+    assert_not_impl!(StreamLen<'_, *const ()>: Sync);
+    assert_impl!(StreamLen<'_, ()>: Unpin);
+    assert_not_impl!(StreamLen<'_, PhantomPinned>: Unpin);
+
     assert_impl!(Take<()>: Send);
     assert_not_impl!(Take<*const ()>: Send);
     assert_impl!(Take<()>: Sync);
@@ -834,6 +1247,16 @@ pub mod io {
     assert_impl!(Take<()>: Unpin);
     assert_not_impl!(Take<PhantomPinned>: Unpin);
 
+    assert_impl!(Tee<(), ()>: Send);
+    assert_not_impl!(Tee<*const (), ()>: Send);
+    assert_not_impl!(Tee<(), *const ()>: Send);
+    assert_impl!(Tee<(), ()>: Sync);
+    assert_not_impl!(Tee<*const (), ()>: Sync);
+    assert_not_impl!(Tee<(), *const ()>: Sync);
+    assert_impl!(Tee<(), ()>: Unpin);
+    assert_not_impl!(Tee<PhantomPinned, ()>: Unpin);
+    assert_not_impl!(Tee<(), PhantomPinned>: Unpin);
+
     assert_impl!(Window<()>: Send);
     assert_not_impl!(Window<*const ()>: Send);
     assert_impl!(Window<()>: Sync);
@@ -869,6 +1292,17 @@ pub mod io {
     // #[cfg(feature = "write-all-vectored")]
     // assert_not_impl!(WriteAllVectored<'_, PhantomPinned>: Unpin);
 
+    assert_impl!(WriteCursor: Send);
+    assert_impl!(WriteCursor: Sync);
+    assert_impl!(WriteCursor: Unpin);
+
+    assert_impl!(WriteFrame<'_, ()>: Send);
+    assert_not_impl!(WriteFrame<'_, *const ()>: Send);
+    assert_impl!(WriteFrame<'_, ()>: Sync);
+    assert_not_impl!(WriteFrame<'_, *const ()>: Sync);
+    assert_impl!(WriteFrame<'_, ()>: Unpin);
+    assert_not_impl!(WriteFrame<'_, PhantomPinned>: Unpin);
+
     assert_impl!(WriteHalf<()>: Send);
     assert_not_impl!(WriteHalf<*const ()>: Send);
     assert_impl!(WriteHalf<()>: Sync);
@@ -888,39 +1322,32 @@ pub mod lock {
     use super::*;
     use futures::lock::*;
 
-    #[cfg(feature = "bilock")]
     assert_impl!(BiLock<()>: Send);
-    #[cfg(feature = "bilock")]
     assert_not_impl!(BiLock<*const ()>: Send);
-    #[cfg(feature = "bilock")]
     assert_impl!(BiLock<()>: Sync);
-    #[cfg(feature = "bilock")]
     assert_not_impl!(BiLock<*const ()>: Sync);
-    #[cfg(feature = "bilock")]
     assert_impl!(BiLock<PhantomPinned>: Unpin);
 
-    #[cfg(feature = "bilock")]
     assert_impl!(BiLockAcquire<'_, ()>: Send);
-    #[cfg(feature = "bilock")]
     assert_not_impl!(BiLockAcquire<'_, *const ()>: Send);
-    #[cfg(feature = "bilock")]
     assert_impl!(BiLockAcquire<'_, ()>: Sync);
-    #[cfg(feature = "bilock")]
     assert_not_impl!(BiLockAcquire<'_, *const ()>: Sync);
-    #[cfg(feature = "bilock")]
     assert_impl!(BiLockAcquire<'_, PhantomPinned>: Unpin);
 
-    #[cfg(feature = "bilock")]
     assert_impl!(BiLockGuard<'_, ()>: Send);
-    #[cfg(feature = "bilock")]
     assert_not_impl!(BiLockGuard<'_, *const ()>: Send);
-    #[cfg(feature = "bilock")]
     assert_impl!(BiLockGuard<'_, ()>: Sync);
-    #[cfg(feature = "bilock")]
     assert_not_impl!(BiLockGuard<'_, *const ()>: Sync);
-    #[cfg(feature = "bilock")]
     assert_impl!(BiLockGuard<'_, PhantomPinned>: Unpin);
 
+    assert_impl!(MappedBiLockGuard<'_, (), ()>: Send);
+    assert_not_impl!(MappedBiLockGuard<'_, (), *const ()>: Send);
+    assert_not_impl!(MappedBiLockGuard<'_, *const (), ()>: Send);
+    assert_impl!(MappedBiLockGuard<'_, (), ()>: Sync);
+    assert_not_impl!(MappedBiLockGuard<'_, (), *const ()>: Sync);
+    assert_not_impl!(MappedBiLockGuard<'_, *const (), ()>: Sync);
+    assert_impl!(MappedBiLockGuard<'_, PhantomPinned, PhantomPinned>: Unpin);
+
     assert_impl!(MappedMutexGuard<'_, (), ()>: Send);
     assert_not_impl!(MappedMutexGuard<'_, (), *const ()>: Send);
     assert_not_impl!(MappedMutexGuard<'_, *const (), ()>: Send);
@@ -929,6 +1356,22 @@ pub mod lock {
     assert_not_impl!(MappedMutexGuard<'_, *const (), ()>: Sync);
     assert_impl!(MappedMutexGuard<'_, PhantomPinned, PhantomPinned>: Unpin);
 
+    assert_impl!(MappedRwLockReadGuard<'_, (), ()>: Send);
+    assert_not_impl!(MappedRwLockReadGuard<'_, (), *const ()>: Send);
+    assert_not_impl!(MappedRwLockReadGuard<'_, *const (), ()>: Send);
+    assert_impl!(MappedRwLockReadGuard<'_, (), ()>: Sync);
+    assert_not_impl!(MappedRwLockReadGuard<'_, (), *const ()>: Sync);
+    assert_not_impl!(MappedRwLockReadGuard<'_, *const (), ()>: Sync);
+    assert_impl!(MappedRwLockReadGuard<'_, PhantomPinned, PhantomPinned>: Unpin);
+
+    assert_impl!(MappedRwLockWriteGuard<'_, (), ()>: Send);
+    assert_not_impl!(MappedRwLockWriteGuard<'_, (), *const ()>: Send);
+    assert_not_impl!(MappedRwLockWriteGuard<'_, *const (), ()>: Send);
+    assert_impl!(MappedRwLockWriteGuard<'_, (), ()>: Sync);
+    assert_not_impl!(MappedRwLockWriteGuard<'_, (), *const ()>: Sync);
+    assert_not_impl!(MappedRwLockWriteGuard<'_, *const (), ()>: Sync);
+    assert_impl!(MappedRwLockWriteGuard<'_, PhantomPinned, PhantomPinned>: Unpin);
+
     assert_impl!(Mutex<()>: Send);
     assert_not_impl!(Mutex<*const ()>: Send);
     assert_impl!(Mutex<()>: Sync);
@@ -947,16 +1390,88 @@ pub mod lock {
     assert_impl!(MutexLockFuture<'_, *const ()>: Sync);
     assert_impl!(MutexLockFuture<'_, PhantomPinned>: Unpin);
 
-    #[cfg(feature = "bilock")]
+    assert_impl!(Notified<'_>: Send);
+    assert_impl!(Notified<'_>: Sync);
+    assert_impl!(Notified<'_>: Unpin);
+
+    assert_impl!(Notify: Send);
+    assert_impl!(Notify: Sync);
+    assert_impl!(Notify: Unpin);
+
+    assert_impl!(OwnedMappedMutexGuard<(), ()>: Send);
+    assert_not_impl!(OwnedMappedMutexGuard<(), *const ()>: Send);
+    assert_not_impl!(OwnedMappedMutexGuard<*const (), ()>: Send);
+    assert_impl!(OwnedMappedMutexGuard<(), ()>: Sync);
+    assert_not_impl!(OwnedMappedMutexGuard<(), *const ()>: Sync);
+    assert_not_impl!(OwnedMappedMutexGuard<*const (), ()>: Sync);
+    assert_impl!(OwnedMappedMutexGuard<PhantomPinned, PhantomPinned>: Unpin);
+
+    assert_impl!(OwnedMutexGuard<()>: Send);
+    assert_not_impl!(OwnedMutexGuard<*const ()>: Send);
+    assert_impl!(OwnedMutexGuard<()>: Sync);
+    assert_not_impl!(OwnedMutexGuard<*const ()>: Sync);
+    assert_not_impl!(OwnedMutexGuard<std::cell::Cell<()>>: Sync);
+    assert_impl!(OwnedMutexGuard<PhantomPinned>: Unpin);
+
+    assert_impl!(OwnedMutexLockFuture<()>: Send);
+    assert_not_impl!(OwnedMutexLockFuture<*const ()>: Send);
+    assert_impl!(OwnedMutexLockFuture<*const ()>: Sync);
+    assert_impl!(OwnedMutexLockFuture<PhantomPinned>: Unpin);
+
+    assert_impl!(OwnedSemaphoreAcquireFuture: Send);
+    assert_impl!(OwnedSemaphoreAcquireFuture: Sync);
+    assert_impl!(OwnedSemaphoreAcquireFuture: Unpin);
+
+    assert_impl!(OwnedSemaphorePermit: Send);
+    assert_impl!(OwnedSemaphorePermit: Sync);
+    assert_impl!(OwnedSemaphorePermit: Unpin);
+
     assert_impl!(ReuniteError<()>: Send);
-    #[cfg(feature = "bilock")]
     assert_not_impl!(ReuniteError<*const ()>: Send);
-    #[cfg(feature = "bilock")]
     assert_impl!(ReuniteError<()>: Sync);
-    #[cfg(feature = "bilock")]
     assert_not_impl!(ReuniteError<*const ()>: Sync);
-    #[cfg(feature = "bilock")]
     assert_impl!(ReuniteError<PhantomPinned>: Unpin);
+
+    assert_impl!(RwLock<()>: Send);
+    assert_not_impl!(RwLock<*const ()>: Send);
+    assert_impl!(RwLock<()>: Sync);
+    assert_not_impl!(RwLock<*const ()>: Sync);
+    assert_impl!(RwLock<()>: Unpin);
+    assert_not_impl!(RwLock<PhantomPinned>: Unpin);
+
+    assert_impl!(RwLockReadFuture<'_, ()>: Send);
+    assert_not_impl!(RwLockReadFuture<'_, *const ()>: Send);
+    assert_impl!(RwLockReadFuture<'_, *const ()>: Sync);
+    assert_impl!(RwLockReadFuture<'_, PhantomPinned>: Unpin);
+
+    assert_impl!(RwLockReadGuard<'_, ()>: Send);
+    assert_not_impl!(RwLockReadGuard<'_, *const ()>: Send);
+    assert_impl!(RwLockReadGuard<'_, ()>: Sync);
+    assert_not_impl!(RwLockReadGuard<'_, *const ()>: Sync);
+    assert_impl!(RwLockReadGuard<'_, PhantomPinned>: Unpin);
+
+    assert_impl!(RwLockWriteFuture<'_, ()>: Send);
+    assert_not_impl!(RwLockWriteFuture<'_, *const ()>: Send);
+    assert_impl!(RwLockWriteFuture<'_, *const ()>: Sync);
+    assert_impl!(RwLockWriteFuture<'_, PhantomPinned>: Unpin);
+
+    assert_impl!(RwLockWriteGuard<'_, ()>: Send);
+    assert_not_impl!(RwLockWriteGuard<'_, *const ()>: Send);
+    assert_impl!(RwLockWriteGuard<'_, ()>: Sync);
+    assert_not_impl!(RwLockWriteGuard<'_, *const ()>: Sync);
+    assert_impl!(RwLockWriteGuard<'_, PhantomPinned>: Unpin);
+
+    assert_impl!(Semaphore: Send);
+    assert_impl!(Semaphore: Sync);
+    assert_impl!(Semaphore: Unpin);
+
+    assert_impl!(SemaphoreAcquireFuture<'_>: Send);
+    assert_impl!(SemaphoreAcquireFuture<'_>: Sync);
+    assert_impl!(SemaphoreAcquireFuture<'_>: Unpin);
+
+    assert_impl!(SemaphorePermit<'_>: Send);
+    assert_impl!(SemaphorePermit<'_>: Sync);
+    assert_impl!(SemaphorePermit<'_>: Unpin);
 }
 
 /// Assert Send/Sync/Unpin for all public types in `futures::sink`.
@@ -1061,6 +1576,22 @@ pub mod sink {
     assert_impl!(Unfold<PhantomPinned, PhantomPinned, ()>: Unpin);
     assert_not_impl!(Unfold<PinnedSink<()>, (), PhantomPinned>: Unpin);
 
+    assert_impl!(UnfoldWithClose<(), (), (), (), ()>: Send);
+    assert_not_impl!(UnfoldWithClose<*const (), (), (), (), ()>: Send);
+    assert_not_impl!(UnfoldWithClose<(), *const (), (), (), ()>: Send);
+    assert_not_impl!(UnfoldWithClose<(), (), *const (), (), ()>: Send);
+    assert_not_impl!(UnfoldWithClose<(), (), (), *const (), ()>: Send);
+    assert_not_impl!(UnfoldWithClose<(), (), (), (), *const ()>: Send);
+    assert_impl!(UnfoldWithClose<(), (), (), (), ()>: Sync);
+    assert_not_impl!(UnfoldWithClose<*const (), (), (), (), ()>: Sync);
+    assert_not_impl!(UnfoldWithClose<(), *const (), (), (), ()>: Sync);
+    assert_not_impl!(UnfoldWithClose<(), (), *const (), (), ()>: Sync);
+    assert_not_impl!(UnfoldWithClose<(), (), (), *const (), ()>: Sync);
+    assert_not_impl!(UnfoldWithClose<(), (), (), (), *const ()>: Sync);
+    assert_impl!(UnfoldWithClose<PhantomPinned, PhantomPinned, (), PhantomPinned, ()>: Unpin);
+    assert_not_impl!(UnfoldWithClose<(), (), PhantomPinned, (), ()>: Unpin);
+    assert_not_impl!(UnfoldWithClose<(), (), (), (), PhantomPinned>: Unpin);
+
     assert_impl!(With<(), *const (), *const (), (), ()>: Send);
     assert_not_impl!(With<*const (), (), (), (), ()>: Send);
     assert_not_impl!(With<(), (), (), *const (), ()>: Send);
@@ -1091,7 +1622,7 @@ pub mod sink {
 /// Assert Send/Sync/Unpin for all public types in `futures::stream`.
 pub mod stream {
     use super::*;
-    use futures::{io, stream::*};
+    use futures::{future, io, stream::*};
 
     assert_impl!(AndThen<(), (), ()>: Send);
     assert_not_impl!(AndThen<*const (), (), ()>: Send);
@@ -1114,6 +1645,15 @@ pub mod stream {
     assert_impl!(BufferUnordered<UnpinStream>: Unpin);
     assert_not_impl!(BufferUnordered<PinnedStream>: Unpin);
 
+    assert_impl!(BufferUnorderedCatchUnwind<SendStream<future::Ready<()>>>: Send);
+    assert_not_impl!(BufferUnorderedCatchUnwind<SendStream<future::Ready<*const ()>>>: Send);
+    assert_not_impl!(BufferUnorderedCatchUnwind<LocalStream<future::Ready<()>>>: Send);
+    assert_impl!(BufferUnorderedCatchUnwind<SyncStream<future::Ready<()>>>: Sync);
+    assert_not_impl!(BufferUnorderedCatchUnwind<SyncStream<future::Ready<*const ()>>>: Sync);
+    assert_not_impl!(BufferUnorderedCatchUnwind<LocalStream<future::Ready<()>>>: Sync);
+    assert_impl!(BufferUnorderedCatchUnwind<UnpinStream<future::Ready<()>>>: Unpin);
+    assert_not_impl!(BufferUnorderedCatchUnwind<PinnedStream<future::Ready<()>>>: Unpin);
+
     assert_impl!(Buffered<SendStream<SendFuture<()>>>: Send);
     assert_not_impl!(Buffered<SendStream<SendFuture>>: Send);
     assert_not_impl!(Buffered<SendStream<LocalFuture>>: Send);
@@ -1125,6 +1665,17 @@ pub mod stream {
     assert_impl!(Buffered<UnpinStream<PinnedFuture>>: Unpin);
     assert_not_impl!(Buffered<PinnedStream<PinnedFuture>>: Unpin);
 
+    assert_impl!(BufferedFlatOrdered<SendStream<SendStream<()>>>: Send);
+    assert_not_impl!(BufferedFlatOrdered<SendStream<SendStream>>: Send);
+    assert_not_impl!(BufferedFlatOrdered<SendStream<LocalStream<()>>>: Send);
+    assert_not_impl!(BufferedFlatOrdered<LocalStream<SendStream<()>>>: Send);
+    assert_impl!(BufferedFlatOrdered<SyncStream<SyncStream<()>>>: Sync);
+    assert_not_impl!(BufferedFlatOrdered<SyncStream<SyncStream>>: Sync);
+    assert_not_impl!(BufferedFlatOrdered<SyncStream<LocalStream<()>>>: Sync);
+    assert_not_impl!(BufferedFlatOrdered<LocalStream<SyncStream<()>>>: Sync);
+    assert_impl!(BufferedFlatOrdered<UnpinStream<PinnedStream>>: Unpin);
+    assert_not_impl!(BufferedFlatOrdered<PinnedStream<PinnedStream>>: Unpin);
+
     assert_impl!(CatchUnwind<SendStream>: Send);
     assert_not_impl!(CatchUnwind<LocalStream>: Send);
     assert_impl!(CatchUnwind<SyncStream>: Sync);
@@ -1142,6 +1693,25 @@ pub mod stream {
     assert_not_impl!(Chain<(), PhantomPinned>: Unpin);
     assert_not_impl!(Chain<PhantomPinned, ()>: Unpin);
 
+    assert_impl!(ChainWith<(), (), ()>: Send);
+    assert_not_impl!(ChainWith<(), (), *const ()>: Send);
+    assert_not_impl!(ChainWith<(), *const (), ()>: Send);
+    assert_not_impl!(ChainWith<*const (), (), ()>: Send);
+    assert_impl!(ChainWith<(), (), ()>: Sync);
+    assert_not_impl!(ChainWith<(), (), *const ()>: Sync);
+    assert_not_impl!(ChainWith<(), *const (), ()>: Sync);
+    assert_not_impl!(ChainWith<*const (), (), ()>: Sync);
+    assert_impl!(ChainWith<(), (), PhantomPinned>: Unpin);
+    assert_not_impl!(ChainWith<(), PhantomPinned, ()>: Unpin);
+    assert_not_impl!(ChainWith<PhantomPinned, (), ()>: Unpin);
+
+    assert_impl!(ChunkedStreams<SendStream<()>>: Send);
+    assert_not_impl!(ChunkedStreams<SendStream>: Send);
+    assert_not_impl!(ChunkedStreams<LocalStream>: Send);
+    assert_impl!(ChunkedStreams<SendStream<()>>: Sync);
+    assert_not_impl!(ChunkedStreams<LocalStream>: Sync);
+    assert_impl!(ChunkedStreams<PinnedStream>: Unpin);
+
     assert_impl!(Chunks<SendStream<()>>: Send);
     assert_not_impl!(Chunks<SendStream>: Send);
     assert_not_impl!(Chunks<LocalStream>: Send);
@@ -1151,6 +1721,13 @@ pub mod stream {
     assert_impl!(Chunks<UnpinStream>: Unpin);
     assert_not_impl!(Chunks<PinnedStream>: Unpin);
 
+    assert_impl!(Cloned<()>: Send);
+    assert_not_impl!(Cloned<*const ()>: Send);
+    assert_impl!(Cloned<()>: Sync);
+    assert_not_impl!(Cloned<*const ()>: Sync);
+    assert_impl!(Cloned<()>: Unpin);
+    assert_not_impl!(Cloned<PhantomPinned>: Unpin);
+
     assert_impl!(Collect<(), ()>: Send);
     assert_not_impl!(Collect<*const (), ()>: Send);
     assert_not_impl!(Collect<(), *const ()>: Send);
@@ -1169,6 +1746,13 @@ pub mod stream {
     assert_impl!(Concat<UnpinStream>: Unpin);
     assert_not_impl!(Concat<PinnedStream>: Unpin);
 
+    assert_impl!(Copied<()>: Send);
+    assert_not_impl!(Copied<*const ()>: Send);
+    assert_impl!(Copied<()>: Sync);
+    assert_not_impl!(Copied<*const ()>: Sync);
+    assert_impl!(Copied<()>: Unpin);
+    assert_not_impl!(Copied<PhantomPinned>: Unpin);
+
     assert_impl!(Cycle<()>: Send);
     assert_not_impl!(Cycle<*const ()>: Send);
     assert_impl!(Cycle<()>: Sync);
@@ -1176,6 +1760,10 @@ pub mod stream {
     assert_impl!(Cycle<()>: Unpin);
     assert_not_impl!(Cycle<PhantomPinned>: Unpin);
 
+    assert_impl!(Elapsed: Send);
+    assert_impl!(Elapsed: Sync);
+    assert_impl!(Elapsed: Unpin);
+
     assert_impl!(Empty<()>: Send);
     assert_not_impl!(Empty<*const ()>: Send);
     assert_impl!(Empty<()>: Sync);
@@ -1335,6 +1923,33 @@ pub mod stream {
     assert_impl!(Inspect<(), PhantomPinned>: Unpin);
     assert_not_impl!(Inspect<PhantomPinned, ()>: Unpin);
 
+    assert_impl!(InspectPending<(), ()>: Send);
+    assert_not_impl!(InspectPending<*const (), ()>: Send);
+    assert_not_impl!(InspectPending<(), *const ()>: Send);
+    assert_impl!(InspectPending<(), ()>: Sync);
+    assert_not_impl!(InspectPending<*const (), ()>: Sync);
+    assert_not_impl!(InspectPending<(), *const ()>: Sync);
+    assert_impl!(InspectPending<(), PhantomPinned>: Unpin);
+    assert_not_impl!(InspectPending<PhantomPinned, ()>: Unpin);
+
+    assert_impl!(InspectPoll<(), ()>: Send);
+    assert_not_impl!(InspectPoll<*const (), ()>: Send);
+    assert_not_impl!(InspectPoll<(), *const ()>: Send);
+    assert_impl!(InspectPoll<(), ()>: Sync);
+    assert_not_impl!(InspectPoll<*const (), ()>: Sync);
+    assert_not_impl!(InspectPoll<(), *const ()>: Sync);
+    assert_impl!(InspectPoll<(), PhantomPinned>: Unpin);
+    assert_not_impl!(InspectPoll<PhantomPinned, ()>: Unpin);
+
+    assert_impl!(InspectReady<(), ()>: Send);
+    assert_not_impl!(InspectReady<*const (), ()>: Send);
+    assert_not_impl!(InspectReady<(), *const ()>: Send);
+    assert_impl!(InspectReady<(), ()>: Sync);
+    assert_not_impl!(InspectReady<*const (), ()>: Sync);
+    assert_not_impl!(InspectReady<(), *const ()>: Sync);
+    assert_impl!(InspectReady<(), PhantomPinned>: Unpin);
+    assert_not_impl!(InspectReady<PhantomPinned, ()>: Unpin);
+
     assert_impl!(InspectErr<(), ()>: Send);
     assert_not_impl!(InspectErr<*const (), ()>: Send);
     assert_not_impl!(InspectErr<(), *const ()>: Send);
@@ -1368,12 +1983,25 @@ pub mod stream {
     assert_impl!(IntoStream<()>: Unpin);
     assert_not_impl!(IntoStream<PhantomPinned>: Unpin);
 
+    assert_impl!(InvalidChunkSize: Send);
+    assert_impl!(InvalidChunkSize: Sync);
+    assert_impl!(InvalidChunkSize: Unpin);
+
     assert_impl!(Iter<()>: Send);
     assert_not_impl!(Iter<*const ()>: Send);
     assert_impl!(Iter<()>: Sync);
     assert_not_impl!(Iter<*const ()>: Sync);
     assert_impl!(Iter<PhantomPinned>: Unpin);
 
+    assert_impl!(LogAndSkipErrors<(), ()>: Send);
+    assert_not_impl!(LogAndSkipErrors<*const (), ()>: Send);
+    assert_not_impl!(LogAndSkipErrors<(), *const ()>: Send);
+    assert_impl!(LogAndSkipErrors<(), ()>: Sync);
+    assert_not_impl!(LogAndSkipErrors<*const (), ()>: Sync);
+    assert_not_impl!(LogAndSkipErrors<(), *const ()>: Sync);
+    assert_impl!(LogAndSkipErrors<(), PhantomPinned>: Unpin);
+    assert_not_impl!(LogAndSkipErrors<PhantomPinned, ()>: Unpin);
+
     assert_impl!(Map<(), ()>: Send);
     assert_not_impl!(Map<*const (), ()>: Send);
     assert_not_impl!(Map<(), *const ()>: Send);
@@ -1401,6 +2029,26 @@ pub mod stream {
     assert_impl!(MapOk<(), PhantomPinned>: Unpin);
     assert_not_impl!(MapOk<PhantomPinned, ()>: Unpin);
 
+    assert_impl!(MapSpawned<(), (), (), ()>: Send);
+    assert_not_impl!(MapSpawned<(), (), (), *const ()>: Send);
+    assert_not_impl!(MapSpawned<(), (), *const (), ()>: Send);
+    assert_not_impl!(MapSpawned<(), *const (), (), ()>: Send);
+    assert_not_impl!(MapSpawned<*const (), (), (), ()>: Send);
+    assert_impl!(MapSpawned<(), (), (), ()>: Sync);
+    assert_not_impl!(MapSpawned<(), (), (), *const ()>: Sync);
+    assert_not_impl!(MapSpawned<(), (), *const (), ()>: Sync);
+    assert_not_impl!(MapSpawned<(), *const (), (), ()>: Sync);
+    assert_not_impl!(MapSpawned<*const (), (), (), ()>: Sync);
+    assert_impl!(MapSpawned<(), PhantomPinned, PhantomPinned, PhantomPinned>: Unpin);
+    assert_not_impl!(MapSpawned<PhantomPinned, (), (), ()>: Unpin);
+
+    assert_impl!(MergeRoundRobin<()>: Send);
+    assert_not_impl!(MergeRoundRobin<*const ()>: Send);
+    assert_impl!(MergeRoundRobin<()>: Sync);
+    assert_not_impl!(MergeRoundRobin<*const ()>: Sync);
+    assert_impl!(MergeRoundRobin<()>: Unpin);
+    assert_not_impl!(MergeRoundRobin<PhantomPinned>: Unpin);
+
     assert_impl!(Next<'_, ()>: Send);
     assert_not_impl!(Next<'_, *const ()>: Send);
     assert_impl!(Next<'_, ()>: Sync);
@@ -1435,6 +2083,15 @@ pub mod stream {
     assert_impl!(Once<()>: Unpin);
     assert_not_impl!(Once<PhantomPinned>: Unpin);
 
+    assert_impl!(OnceWith<(), ()>: Send);
+    assert_not_impl!(OnceWith<(), *const ()>: Send);
+    assert_not_impl!(OnceWith<*const (), ()>: Send);
+    assert_impl!(OnceWith<(), ()>: Sync);
+    assert_not_impl!(OnceWith<(), *const ()>: Sync);
+    assert_not_impl!(OnceWith<*const (), ()>: Sync);
+    assert_impl!(OnceWith<PhantomPinned, ()>: Unpin);
+    assert_not_impl!(OnceWith<(), PhantomPinned>: Unpin);
+
     assert_impl!(OrElse<(), (), ()>: Send);
     assert_not_impl!(OrElse<*const (), (), ()>: Send);
     assert_not_impl!(OrElse<(), *const (), ()>: Send);
@@ -1514,6 +2171,29 @@ pub mod stream {
     assert_impl!(RepeatWith<fn() -> ()>: Unpin);
     // assert_impl!(RepeatWith<PhantomPinned>: Unpin);
 
+    assert_impl!(Retry<(), ()>: Send);
+    assert_not_impl!(Retry<*const (), ()>: Send);
+    assert_not_impl!(Retry<(), *const ()>: Send);
+    assert_impl!(Retry<(), ()>: Sync);
+    assert_not_impl!(Retry<*const (), ()>: Sync);
+    assert_not_impl!(Retry<(), *const ()>: Sync);
+    assert_impl!(Retry<PhantomPinned, ()>: Unpin);
+    assert_not_impl!(Retry<(), PhantomPinned>: Unpin);
+
+    assert_impl!(RetryWithBackoff<(), (), (), ()>: Send);
+    assert_not_impl!(RetryWithBackoff<*const (), (), (), ()>: Send);
+    assert_not_impl!(RetryWithBackoff<(), *const (), (), ()>: Send);
+    assert_not_impl!(RetryWithBackoff<(), (), *const (), ()>: Send);
+    assert_not_impl!(RetryWithBackoff<(), (), (), *const ()>: Send);
+    assert_impl!(RetryWithBackoff<(), (), (), ()>: Sync);
+    assert_not_impl!(RetryWithBackoff<*const (), (), (), ()>: Sync);
+    assert_not_impl!(RetryWithBackoff<(), *const (), (), ()>: Sync);
+    assert_not_impl!(RetryWithBackoff<(), (), *const (), ()>: Sync);
+    assert_not_impl!(RetryWithBackoff<(), (), (), *const ()>: Sync);
+    assert_impl!(RetryWithBackoff<PhantomPinned, (), PhantomPinned, ()>: Unpin);
+    assert_not_impl!(RetryWithBackoff<(), PhantomPinned, (), ()>: Unpin);
+    assert_not_impl!(RetryWithBackoff<(), (), (), PhantomPinned>: Unpin);
+
     assert_impl!(ReuniteError<(), ()>: Send);
     assert_not_impl!(ReuniteError<*const (), ()>: Send);
     assert_not_impl!(ReuniteError<(), *const ()>: Send);
@@ -1593,6 +2273,13 @@ pub mod stream {
     assert_not_impl!(SplitStream<*const ()>: Sync);
     assert_impl!(SplitStream<PhantomPinned>: Unpin);
 
+    assert_impl!(StreamChunk<SendStream<()>>: Send);
+    assert_not_impl!(StreamChunk<SendStream>: Send);
+    assert_not_impl!(StreamChunk<LocalStream>: Send);
+    assert_impl!(StreamChunk<SendStream<()>>: Sync);
+    assert_not_impl!(StreamChunk<LocalStream>: Sync);
+    assert_impl!(StreamChunk<PinnedStream>: Unpin);
+
     assert_impl!(StreamFuture<()>: Send);
     assert_not_impl!(StreamFuture<*const ()>: Send);
     assert_impl!(StreamFuture<()>: Sync);
@@ -1721,6 +2408,14 @@ pub mod stream {
     assert_not_impl!(TryFlatten<UnpinTryStream>: Unpin);
     assert_not_impl!(TryFlatten<PinnedTryStream>: Unpin);
 
+    assert_impl!(TryFlattenTagged<SendTryStream<()>>: Send);
+    assert_not_impl!(TryFlattenTagged<SendTryStream>: Send);
+    assert_impl!(TryFlattenTagged<SyncTryStream<()>>: Sync);
+    assert_not_impl!(TryFlattenTagged<LocalTryStream<()>>: Sync);
+    assert_impl!(TryFlattenTagged<UnpinTryStream<()>>: Unpin);
+    assert_not_impl!(TryFlattenTagged<UnpinTryStream>: Unpin);
+    assert_not_impl!(TryFlattenTagged<PinnedTryStream>: Unpin);
+
     assert_impl!(TryFold<(), (), (), ()>: Send);
     assert_not_impl!(TryFold<*const (), (), (), ()>: Send);
     assert_not_impl!(TryFold<(), *const (), (), ()>: Send);
@@ -1765,6 +2460,15 @@ pub mod stream {
     assert_impl!(TryNext<'_, ()>: Unpin);
     assert_not_impl!(TryNext<'_, PhantomPinned>: Unpin);
 
+    assert_impl!(TryNextTimeout<'_, (), ()>: Send);
+    assert_not_impl!(TryNextTimeout<'_, *const (), ()>: Send);
+    assert_not_impl!(TryNextTimeout<'_, (), *const ()>: Send);
+    assert_impl!(TryNextTimeout<'_, (), ()>: Sync);
+    assert_not_impl!(TryNextTimeout<'_, *const (), ()>: Sync);
+    assert_not_impl!(TryNextTimeout<'_, (), *const ()>: Sync);
+    assert_impl!(TryNextTimeout<'_, PhantomPinned, ()>: Unpin);
+    assert_not_impl!(TryNextTimeout<'_, (), PhantomPinned>: Unpin);
+
     assert_impl!(TrySkipWhile<SendTryStream<()>, (), ()>: Send);
     assert_not_impl!(TrySkipWhile<LocalTryStream<()>, (), ()>: Send);
     assert_not_impl!(TrySkipWhile<SendTryStream, (), ()>: Send);
@@ -1826,6 +2530,20 @@ pub mod stream {
     assert_impl!(Unzip<(), PhantomPinned, PhantomPinned>: Unpin);
     assert_not_impl!(Unzip<PhantomPinned, (), ()>: Unpin);
 
+    assert_impl!(WithKeepalive<(), (), (), ()>: Send);
+    assert_not_impl!(WithKeepalive<*const (), (), (), ()>: Send);
+    assert_not_impl!(WithKeepalive<(), *const (), (), ()>: Send);
+    assert_not_impl!(WithKeepalive<(), (), *const (), ()>: Send);
+    assert_not_impl!(WithKeepalive<(), (), (), *const ()>: Send);
+    assert_impl!(WithKeepalive<(), (), (), ()>: Sync);
+    assert_not_impl!(WithKeepalive<*const (), (), (), ()>: Sync);
+    assert_not_impl!(WithKeepalive<(), *const (), (), ()>: Sync);
+    assert_not_impl!(WithKeepalive<(), (), *const (), ()>: Sync);
+    assert_not_impl!(WithKeepalive<(), (), (), *const ()>: Sync);
+    assert_impl!(WithKeepalive<(), PhantomPinned, (), PhantomPinned>: Unpin);
+    assert_not_impl!(WithKeepalive<PhantomPinned, (), (), ()>: Unpin);
+    assert_not_impl!(WithKeepalive<(), (), PhantomPinned, ()>: Unpin);
+
     assert_impl!(Zip<SendStream<()>, SendStream<()>>: Send);
     assert_not_impl!(Zip<SendStream, SendStream<()>>: Send);
     assert_not_impl!(Zip<SendStream<()>, SendStream>: Send);
@@ -1840,6 +2558,24 @@ pub mod stream {
     assert_not_impl!(Zip<UnpinStream, PinnedStream>: Unpin);
     assert_not_impl!(Zip<PinnedStream, UnpinStream>: Unpin);
 
+    assert_impl!(Zip3<SendStream<()>, SendStream<()>, SendStream<()>>: Send);
+    assert_not_impl!(Zip3<SendStream, SendStream<()>, SendStream<()>>: Send);
+    assert_not_impl!(Zip3<SendStream<()>, SendStream<()>, LocalStream<()>>: Send);
+    assert_impl!(Zip3<SyncStream<()>, SyncStream<()>, SyncStream<()>>: Sync);
+    assert_not_impl!(Zip3<SyncStream, SyncStream<()>, SyncStream<()>>: Sync);
+    assert_not_impl!(Zip3<SyncStream<()>, SyncStream<()>, LocalStream<()>>: Sync);
+    assert_impl!(Zip3<UnpinStream, UnpinStream, UnpinStream>: Unpin);
+    assert_not_impl!(Zip3<UnpinStream, UnpinStream, PinnedStream>: Unpin);
+
+    assert_impl!(Zip4<SendStream<()>, SendStream<()>, SendStream<()>, SendStream<()>>: Send);
+    assert_not_impl!(Zip4<SendStream, SendStream<()>, SendStream<()>, SendStream<()>>: Send);
+    assert_not_impl!(Zip4<SendStream<()>, SendStream<()>, SendStream<()>, LocalStream<()>>: Send);
+    assert_impl!(Zip4<SyncStream<()>, SyncStream<()>, SyncStream<()>, SyncStream<()>>: Sync);
+    assert_not_impl!(Zip4<SyncStream, SyncStream<()>, SyncStream<()>, SyncStream<()>>: Sync);
+    assert_not_impl!(Zip4<SyncStream<()>, SyncStream<()>, SyncStream<()>, LocalStream<()>>: Sync);
+    assert_impl!(Zip4<UnpinStream, UnpinStream, UnpinStream, UnpinStream>: Unpin);
+    assert_not_impl!(Zip4<UnpinStream, UnpinStream, UnpinStream, PinnedStream>: Unpin);
+
     assert_impl!(futures_unordered::Iter<()>: Send);
     assert_not_impl!(futures_unordered::Iter<*const ()>: Send);
     assert_impl!(futures_unordered::Iter<()>: Sync);
@@ -1893,6 +2629,10 @@ pub mod task {
     assert_not_impl!(LocalFutureObj<()>: Sync);
     assert_impl!(LocalFutureObj<PhantomPinned>: Unpin);
 
+    assert_impl!(RegistrationKey: Send);
+    assert_impl!(RegistrationKey: Sync);
+    assert_impl!(RegistrationKey: Unpin);
+
     assert_impl!(SpawnError: Send);
     assert_impl!(SpawnError: Sync);
     assert_impl!(SpawnError: Unpin);
@@ -1900,4 +2640,8 @@ pub mod task {
     assert_impl!(WakerRef<'_>: Send);
     assert_impl!(WakerRef<'_>: Sync);
     assert_impl!(WakerRef<'_>: Unpin);
+
+    assert_impl!(WakerSet: Send);
+    assert_impl!(WakerSet: Sync);
+    assert_impl!(WakerSet: Unpin);
 }
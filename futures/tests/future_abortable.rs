@@ -1,8 +1,13 @@
 use futures::channel::oneshot;
 use futures::executor::block_on;
-use futures::future::{abortable, Aborted, FutureExt};
+use futures::future::{
+    self, abortable, AbortHandle, AbortSet, Abortable, AbortableWith, Aborted, AbortedWith,
+    FutureExt,
+};
 use futures::task::{Context, Poll};
 use futures_test::task::new_count_waker;
+use std::sync::Arc;
+use std::thread;
 
 #[test]
 fn abortable_works() {
@@ -42,3 +47,131 @@ fn abortable_resolves() {
     assert!(!abortable_rx.is_aborted());
     assert_eq!(Ok(Ok(())), block_on(abortable_rx));
 }
+
+#[derive(Clone, Debug, PartialEq)]
+enum Reason {
+    Timeout,
+    Shutdown,
+    Superseded,
+}
+
+#[test]
+fn abortable_with_reasons() {
+    let (_tx1, rx1) = oneshot::channel::<()>();
+    let (_tx2, rx2) = oneshot::channel::<()>();
+    let (handle1, reg1) = AbortHandle::new_pair_with();
+    let (handle2, reg2) = AbortHandle::new_pair_with();
+    let mut first = AbortableWith::new(rx1, reg1);
+    let mut second = AbortableWith::new(rx2, reg2);
+
+    let (waker, counter) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+    assert!(first.poll_unpin(&mut cx).is_pending());
+    assert!(second.poll_unpin(&mut cx).is_pending());
+
+    // Only the first reason given through any of the clones is kept.
+    let clone1 = handle1.clone();
+    clone1.abort_with(Reason::Timeout);
+    handle1.abort_with(Reason::Shutdown);
+    handle2.clone().abort_with(Reason::Superseded);
+    assert_eq!(counter, 2);
+
+    assert!(first.is_aborted());
+    assert_eq!(first.reason(), Some(Reason::Timeout));
+    assert_eq!(
+        first.poll_unpin(&mut cx).map_err(AbortedWith::into_reason),
+        Poll::Ready(Err(Reason::Timeout))
+    );
+    assert_eq!(
+        second.poll_unpin(&mut cx).map_err(AbortedWith::into_reason),
+        Poll::Ready(Err(Reason::Superseded))
+    );
+}
+
+#[test]
+fn abortable_with_resolves() {
+    let (tx, rx) = oneshot::channel::<()>();
+    let (_handle, reg) = AbortHandle::<Reason>::new_pair_with();
+    let future = AbortableWith::new(rx, reg);
+
+    tx.send(()).unwrap();
+
+    assert!(!future.is_aborted());
+    assert_eq!(future.reason(), None);
+    assert_eq!(Ok(Ok(())), block_on(future));
+}
+
+#[test]
+fn abort_on_drop_handle() {
+    let (_tx1, rx1) = oneshot::channel::<()>();
+    let (_tx2, rx2) = oneshot::channel::<()>();
+    let (first, first_handle) = abortable(rx1);
+    let (second, second_handle) = abortable(rx2);
+    let first_guard = first_handle.on_drop();
+    let _second_guard = second_handle.on_drop();
+
+    drop(first_guard);
+    assert!(first.is_aborted());
+    assert!(!second.is_aborted());
+    assert_eq!(Err(Aborted), block_on(first));
+}
+
+#[test]
+fn abort_set() {
+    let set = AbortSet::new();
+    let (_tx1, rx1) = oneshot::channel::<()>();
+    let (_tx2, rx2) = oneshot::channel::<()>();
+    let mut first = Abortable::new(rx1, set.register());
+    let mut second = Abortable::new(rx2, set.register());
+
+    let (waker, counter) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+    assert_eq!(Poll::Pending, first.poll_unpin(&mut cx));
+    assert_eq!(Poll::Pending, second.poll_unpin(&mut cx));
+
+    set.abort_all();
+    assert!(set.is_aborted());
+    assert_eq!(counter, 2);
+    assert_eq!(Poll::Ready(Err(Aborted)), first.poll_unpin(&mut cx));
+    assert_eq!(Poll::Ready(Err(Aborted)), second.poll_unpin(&mut cx));
+
+    // Tasks registered afterwards are aborted right away.
+    let (_tx3, rx3) = oneshot::channel::<()>();
+    let third = Abortable::new(rx3, set.register());
+    assert!(third.is_aborted());
+    assert_eq!(Err(Aborted), block_on(third));
+}
+
+#[test]
+fn abort_set_drop() {
+    let set = AbortSet::new();
+    let (tx1, rx1) = oneshot::channel::<()>();
+    let first = Abortable::new(rx1, set.register());
+    drop(set);
+    tx1.send(()).unwrap();
+    assert_eq!(Ok(Ok(())), block_on(first));
+
+    let mut set = AbortSet::new();
+    set.abort_on_drop(true);
+    let (_tx2, rx2) = oneshot::channel::<()>();
+    let second = Abortable::new(rx2, set.register());
+    drop(set);
+    assert_eq!(Err(Aborted), block_on(second));
+}
+
+#[test]
+fn abort_set_register_from_threads() {
+    let set = Arc::new(AbortSet::new());
+    let threads: Vec<_> = (0..4)
+        .map(|_| {
+            let set = set.clone();
+            thread::spawn(move || Abortable::new(future::pending::<()>(), set.register()))
+        })
+        .collect();
+    let futures: Vec<_> = threads.into_iter().map(|t| t.join().unwrap()).collect();
+
+    set.abort_all();
+    for future in futures {
+        assert_eq!(Err(Aborted), block_on(future));
+    }
+}
@@ -0,0 +1,37 @@
+use futures::future::{self, FusedFuture};
+use futures::task::Poll;
+use futures::FutureExt;
+use futures_test::task::noop_context;
+use std::cell::Cell;
+use std::mem;
+
+#[test]
+fn zero_sized() {
+    let fut = future::always_ready(|| 5u64);
+    assert_eq!(mem::size_of_val(&fut), 0);
+    assert_eq!(futures::executor::block_on(fut), 5);
+}
+
+#[test]
+fn polls_repeatedly() {
+    let calls = Cell::new(0);
+    let mut fut = future::always_ready(|| {
+        calls.set(calls.get() + 1);
+        calls.get()
+    });
+    let mut cx = noop_context();
+
+    assert!(!fut.is_terminated());
+    assert_eq!(fut.poll_unpin(&mut cx), Poll::Ready(1));
+    assert!(!fut.is_terminated());
+    assert_eq!(fut.poll_unpin(&mut cx), Poll::Ready(2));
+    assert_eq!(fut.poll_unpin(&mut cx), Poll::Ready(3));
+}
+
+#[test]
+fn copy() {
+    let fut = future::always_ready(|| "a");
+    let copy = fut;
+    assert_eq!(futures::executor::block_on(fut), "a");
+    assert_eq!(futures::executor::block_on(copy), "a");
+}
@@ -0,0 +1,87 @@
+use futures::executor::block_on;
+use futures::future::{self, FutureExt, Panicked};
+use std::error::Error;
+use std::panic;
+
+#[test]
+fn catch_unwind_message_str() {
+    let future = future::lazy(|_| -> i32 { panic!("static message") });
+
+    assert_eq!(block_on(future.catch_unwind_message()), Err("static message".to_string()));
+}
+
+#[test]
+fn catch_unwind_message_string() {
+    let id = 3;
+    let future = future::lazy(move |_| -> i32 { panic!("worker {} died", id) });
+
+    assert_eq!(block_on(future.catch_unwind_message()), Err("worker 3 died".to_string()));
+}
+
+#[test]
+fn catch_unwind_message_other_payload() {
+    let future = future::lazy(|_| -> i32 { panic::panic_any(42) });
+
+    assert_eq!(block_on(future.catch_unwind_message()), Err("Box<dyn Any>".to_string()));
+}
+
+#[test]
+fn catch_unwind_message_no_panic() {
+    let future = future::ready(1);
+
+    assert_eq!(block_on(future.catch_unwind_message()), Ok(1));
+}
+
+#[test]
+fn panicked_message_str() {
+    let future = future::lazy(|_| -> i32 { panic!("static message") });
+    let panicked = Panicked::from(block_on(future.catch_unwind()).unwrap_err());
+
+    assert_eq!(panicked.message(), Some("static message"));
+    assert_eq!(panicked.to_string(), "static message");
+    assert_eq!(*panicked.downcast::<&'static str>().unwrap(), "static message");
+}
+
+#[test]
+fn panicked_message_string() {
+    let id = 3;
+    let future = future::lazy(move |_| -> i32 { panic!("worker {} died", id) });
+    let panicked = Panicked::from(block_on(future.catch_unwind()).unwrap_err());
+
+    assert_eq!(panicked.message(), Some("worker 3 died"));
+    assert_eq!(panicked.to_string(), "worker 3 died");
+    assert_eq!(*panicked.downcast::<String>().unwrap(), "worker 3 died");
+}
+
+#[test]
+fn panicked_custom_payload() {
+    #[derive(Debug, PartialEq)]
+    struct Payload(u32);
+
+    let future = future::lazy(|_| -> i32 { panic::panic_any(Payload(7)) });
+    let panicked = Panicked::from(block_on(future.catch_unwind()).unwrap_err());
+
+    assert_eq!(panicked.message(), None);
+    assert_eq!(panicked.to_string(), "Box<dyn Any>");
+    let panicked = panicked.downcast::<String>().unwrap_err();
+    assert_eq!(*panicked.downcast::<Payload>().unwrap(), Payload(7));
+}
+
+#[test]
+fn panicked_into_any() {
+    let future = future::lazy(|_| -> i32 { panic::panic_any(42u8) });
+    let panicked = Panicked::from(block_on(future.catch_unwind()).unwrap_err());
+
+    assert_eq!(panicked.into_any().downcast_ref::<u8>(), Some(&42));
+}
+
+#[test]
+fn panicked_question_mark() {
+    fn run() -> Result<i32, Box<dyn Error + Send + Sync>> {
+        let future = future::lazy(|_| -> i32 { panic!("failed") });
+        let output = block_on(future.catch_unwind()).map_err(Panicked::from)?;
+        Ok(output)
+    }
+
+    assert_eq!(run().unwrap_err().to_string(), "failed");
+}
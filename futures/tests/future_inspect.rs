@@ -1,5 +1,8 @@
 use futures::executor::block_on;
 use futures::future::{self, FutureExt};
+use futures_test::future::FutureTestExt;
+use std::thread;
+use std::time::Duration;
 
 #[test]
 fn smoke() {
@@ -14,3 +17,32 @@ fn smoke() {
 
     assert_eq!(counter, 40);
 }
+
+#[test]
+fn inspect_poll() {
+    let mut events = Vec::new();
+
+    {
+        let work =
+            future::ready(1).pending_once().pending_once().inspect_poll(|event| events.push(event));
+        assert_eq!(block_on(work), 1);
+    }
+
+    assert_eq!(events.len(), 3);
+    for (i, event) in events.iter().enumerate() {
+        assert_eq!(event.index(), i);
+        assert_eq!(event.is_ready(), i == 2);
+    }
+}
+
+#[test]
+#[cfg(not(target_arch = "wasm32"))] // Instant::now panics there
+fn inspect_poll_records_elapsed() {
+    let mut elapsed = Duration::from_secs(0);
+
+    let work = future::lazy(|_| thread::sleep(Duration::from_millis(10)))
+        .inspect_poll(|event| elapsed = event.elapsed());
+    block_on(work);
+
+    assert!(elapsed >= Duration::from_millis(10));
+}
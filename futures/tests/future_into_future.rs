@@ -0,0 +1,58 @@
+#![cfg(feature = "into-future")]
+
+use futures::executor::block_on;
+use futures::future::{self, Either, Ready};
+use std::future::IntoFuture;
+
+// A builder which is turned into a future by awaiting it, rather than by
+// calling a terminal method.
+struct Request {
+    value: i32,
+}
+
+impl Request {
+    fn new() -> Self {
+        Self { value: 0 }
+    }
+
+    fn value(mut self, value: i32) -> Self {
+        self.value = value;
+        self
+    }
+}
+
+impl IntoFuture for Request {
+    type Output = Result<i32, String>;
+    type IntoFuture = Ready<Self::Output>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        if self.value < 0 {
+            future::err(format!("negative value {}", self.value))
+        } else {
+            future::ok(self.value)
+        }
+    }
+}
+
+#[test]
+fn join_into_future() {
+    let (a, b) = block_on(future::join(Request::new().value(1), async { 2 }));
+    assert_eq!((a, b), (Ok(1), 2));
+}
+
+#[test]
+fn try_join_into_future() {
+    let pair = future::try_join(Request::new().value(1), Request::new().value(2));
+    assert_eq!(block_on(pair), Ok((1, 2)));
+
+    let pair = future::try_join(Request::new().value(1), Request::new().value(-2));
+    assert_eq!(block_on(pair), Err("negative value -2".to_string()));
+}
+
+#[test]
+fn select_into_future() {
+    match block_on(future::select(future::pending::<()>(), Request::new().value(3))) {
+        Either::Right((value, _)) => assert_eq!(value, Ok(3)),
+        Either::Left(_) => panic!("pending future completed"),
+    }
+}
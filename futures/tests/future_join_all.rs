@@ -1,5 +1,9 @@
+use futures::channel::oneshot;
 use futures::executor::block_on;
-use futures::future::{join_all, ready, Future, JoinAll};
+use futures::future::{
+    join_all, join_all_recoverable, join_all_results, ready, Future, FutureExt, JoinAll,
+    TryFutureExt,
+};
 use futures::pin_mut;
 use std::fmt::Debug;
 
@@ -39,3 +43,55 @@ fn join_all_iter_lifetime() {
 fn join_all_from_iter() {
     assert_done(vec![ready(1), ready(2)].into_iter().collect::<JoinAll<_>>(), vec![1, 2])
 }
+
+#[test]
+fn join_all_recoverable_into_partial() {
+    let (tx1, rx1) = oneshot::channel::<i32>();
+    let (_tx2, rx2) = oneshot::channel::<i32>();
+    let (tx3, rx3) = oneshot::channel::<i32>();
+    let mut all = join_all_recoverable(vec![rx1, rx2, rx3]);
+    let cx = &mut futures_test::task::noop_context();
+
+    tx3.send(3).unwrap();
+    assert!(all.poll_unpin(cx).is_pending());
+    tx1.send(1).unwrap();
+    assert!(all.poll_unpin(cx).is_pending());
+
+    let partial = all.into_partial();
+    assert_eq!(partial, [Some(Ok(1)), None, Some(Ok(3))]);
+}
+
+#[test]
+fn join_all_recoverable_completes() {
+    assert_done(join_all_recoverable(vec![ready(1), ready(2)]), vec![1, 2]);
+
+    let mut all = join_all_recoverable(vec![ready(1)]);
+    assert_eq!(block_on(&mut all), [1]);
+    assert_eq!(all.into_partial(), []);
+}
+
+#[test]
+fn join_all_results_collects_every_error() {
+    let (txs, rxs): (Vec<_>, Vec<_>) =
+        (0..5).map(|_| oneshot::channel::<Result<u32, u32>>()).unzip();
+    let all = join_all_results(rxs.into_iter().map(|rx| rx.map(Result::unwrap)));
+    pin_mut!(all);
+    let cx = &mut futures_test::task::noop_context();
+
+    // Complete them out of order.
+    for (i, tx) in txs.into_iter().enumerate().rev() {
+        assert!(all.as_mut().poll(cx).is_pending());
+        let i = i as u32;
+        tx.send(if i % 2 == 0 { Ok(i) } else { Err(i * 10) }).unwrap();
+    }
+    assert_eq!(all.poll(cx), std::task::Poll::Ready(Err(vec![(1, 10), (3, 30)])));
+}
+
+#[test]
+fn join_all_results_ok_in_order() {
+    let futures = (0..100).map(|i| ready(Ok::<_, ()>(i)).map_ok(|i| i * 2));
+    assert_done(join_all_results(futures), Ok((0..100).map(|i| i * 2).collect()));
+
+    let futures = (0..100).map(|i| ready(if i == 42 { Err(i) } else { Ok(i) }));
+    assert_done(join_all_results(futures), Err(vec![(42, 42)]));
+}
@@ -0,0 +1,64 @@
+use futures::channel::oneshot;
+use futures::executor::block_on;
+use futures::future::{self, join_all_streaming, try_join_all_streaming};
+use futures::stream::{FusedStream, Stream, StreamExt};
+use futures::task::Poll;
+use futures_test::task::noop_context;
+
+// Completes the futures in an order unrelated to their positions, checking
+// that each output is tagged with the position of the future it came from.
+fn completion_order(count: usize) {
+    let (senders, receivers): (Vec<_>, Vec<_>) = (0..count).map(|_| oneshot::channel()).unzip();
+    let mut stream = join_all_streaming(receivers);
+    let cx = &mut noop_context();
+    assert_eq!(stream.size_hint(), (count, Some(count)));
+    assert_eq!(stream.poll_next_unpin(cx), Poll::Pending);
+
+    let mut senders: Vec<_> = senders.into_iter().map(Some).collect();
+    // `count` is coprime with 7, so this visits every position once.
+    for (completed, i) in (0..count).map(|i| (i * 7 + 3) % count).enumerate() {
+        senders[i].take().unwrap().send(i * 10).unwrap();
+        assert_eq!(stream.poll_next_unpin(cx), Poll::Ready(Some((i, Ok(i * 10)))));
+        assert_eq!(stream.size_hint(), (count - completed - 1, Some(count - completed - 1)));
+    }
+
+    assert_eq!(stream.poll_next_unpin(cx), Poll::Ready(None));
+    assert!(stream.is_terminated());
+}
+
+#[test]
+fn completion_order_small() {
+    completion_order(5);
+}
+
+#[test]
+fn completion_order_big() {
+    completion_order(64);
+}
+
+#[test]
+fn empty() {
+    let mut stream = join_all_streaming(Vec::<future::Ready<()>>::new());
+    assert!(stream.is_terminated());
+    assert_eq!(block_on(stream.next()), None);
+}
+
+#[test]
+fn try_join_all_streaming_ends_at_first_error() {
+    let (tx1, rx1) = oneshot::channel::<Result<i32, &str>>();
+    let (tx2, rx2) = oneshot::channel::<Result<i32, &str>>();
+    let (tx3, rx3) = oneshot::channel::<Result<i32, &str>>();
+    let futures = vec![rx1, rx2, rx3].into_iter().map(|rx| async { rx.await.unwrap() });
+    let mut stream = try_join_all_streaming(futures);
+    let cx = &mut noop_context();
+
+    tx3.send(Ok(3)).unwrap();
+    assert_eq!(stream.poll_next_unpin(cx), Poll::Ready(Some(Ok((2, 3)))));
+    tx2.send(Err("failed")).unwrap();
+    assert_eq!(stream.poll_next_unpin(cx), Poll::Ready(Some(Err("failed"))));
+    assert!(stream.is_terminated());
+    assert_eq!(stream.poll_next_unpin(cx), Poll::Ready(None));
+
+    // The remaining future was dropped.
+    assert!(tx1.send(Ok(1)).is_err());
+}
@@ -0,0 +1,81 @@
+#![cfg(feature = "join-array")]
+
+use futures::executor::block_on;
+use futures::future::{self, join_array, try_join_array, FusedFuture, FutureExt, LocalBoxFuture};
+use futures::task::Poll;
+use futures_test::future::FutureTestExt;
+use futures_test::task::noop_context;
+use std::cell::Cell;
+
+#[test]
+fn join_array_empty() {
+    let futures: [future::Ready<i32>; 0] = [];
+    let mut fut = join_array(futures);
+    assert!(!fut.is_terminated());
+    assert_eq!(block_on(&mut fut), []);
+    assert!(fut.is_terminated());
+}
+
+#[test]
+fn join_array_one() {
+    assert_eq!(block_on(join_array([future::ready(1)])), [1]);
+}
+
+#[test]
+fn join_array_preserves_order() {
+    let futures = [
+        future::ready(1).pending_once().boxed(),
+        future::ready(2).boxed(),
+        future::ready(3).pending_once().pending_once().boxed(),
+    ];
+    let mut fut = join_array(futures);
+    let mut cx = noop_context();
+    assert_eq!(fut.poll_unpin(&mut cx), Poll::Pending);
+    assert_eq!(fut.poll_unpin(&mut cx), Poll::Pending);
+    assert_eq!(fut.poll_unpin(&mut cx), Poll::Ready([1, 2, 3]));
+    assert!(fut.is_terminated());
+}
+
+#[test]
+fn try_join_array_empty() {
+    let futures: [future::Ready<Result<i32, ()>>; 0] = [];
+    assert_eq!(block_on(try_join_array(futures)), Ok([]));
+}
+
+#[test]
+fn try_join_array_one() {
+    assert_eq!(block_on(try_join_array([future::ok::<_, ()>(1)])), Ok([1]));
+    assert_eq!(block_on(try_join_array([future::err::<i32, _>(1)])), Err(1));
+}
+
+#[test]
+fn try_join_array_error_drops_others() {
+    struct OnDrop<'a>(&'a Cell<usize>);
+    impl Drop for OnDrop<'_> {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    fn pending(guard: OnDrop<'_>) -> LocalBoxFuture<'_, Result<i32, i32>> {
+        future::poll_fn(move |_| {
+            let _ = &guard;
+            Poll::Pending
+        })
+        .boxed_local()
+    }
+
+    let dropped = Cell::new(0);
+    let futures = [
+        pending(OnDrop(&dropped)),
+        future::err(2).pending_once().boxed_local(),
+        pending(OnDrop(&dropped)),
+    ];
+    let mut fut = try_join_array(futures);
+    let mut cx = noop_context();
+    assert_eq!(fut.poll_unpin(&mut cx), Poll::Pending);
+    assert_eq!(dropped.get(), 0);
+    assert_eq!(fut.poll_unpin(&mut cx), Poll::Ready(Err(2)));
+    assert_eq!(dropped.get(), 2);
+    assert!(fut.is_terminated());
+}
@@ -0,0 +1,43 @@
+use futures::executor::block_on;
+use futures::future::{self, FusedFuture, FutureExt};
+use futures::task::Poll;
+use futures_test::future::FutureTestExt;
+use futures_test::task::noop_context;
+use std::cell::Cell;
+
+#[test]
+fn not_run_if_dropped() {
+    let ran = Cell::new(false);
+    let fut = future::lazy_then(|| {
+        ran.set(true);
+        future::ready(1)
+    });
+    drop(fut);
+    assert!(!ran.get());
+}
+
+#[test]
+fn runs_on_first_poll() {
+    let calls = Cell::new(0);
+    let mut fut = future::lazy_then(|| {
+        calls.set(calls.get() + 1);
+        future::ready(1).pending_once()
+    });
+    let mut cx = noop_context();
+    assert_eq!(calls.get(), 0);
+    assert!(!fut.is_terminated());
+
+    assert_eq!(fut.poll_unpin(&mut cx), Poll::Pending);
+    assert_eq!(calls.get(), 1);
+    assert!(!fut.is_terminated());
+
+    assert_eq!(fut.poll_unpin(&mut cx), Poll::Ready(1));
+    assert_eq!(calls.get(), 1);
+    assert!(fut.is_terminated());
+}
+
+#[test]
+fn async_block() {
+    let fut = future::lazy_then(|| async { 2 });
+    assert_eq!(block_on(fut), 2);
+}
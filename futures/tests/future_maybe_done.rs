@@ -0,0 +1,65 @@
+use futures::executor::block_on;
+use futures::future::{self, maybe_done, try_maybe_done, Future, MaybeDone, TryMaybeDone};
+use futures::task::{Context, Poll};
+use std::pin::Pin;
+
+// Joins several `Unpin` futures, harvesting their outputs without pinning.
+struct JoinUnpin<Fut: Future + Unpin> {
+    futures: Vec<MaybeDone<Fut>>,
+}
+
+impl<Fut: Future + Unpin> Future for JoinUnpin<Fut> {
+    type Output = Vec<Fut::Output>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        for fut in &mut self.futures {
+            if !fut.is_done() {
+                let _ = Pin::new(fut).poll(cx);
+            }
+        }
+        if self.futures.iter().all(MaybeDone::is_done) {
+            Poll::Ready(
+                self.futures.iter_mut().map(|fut| fut.take_output_unpin().unwrap()).collect(),
+            )
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+#[test]
+fn harvest_unpin() {
+    let futures = vec![future::ready(1), future::ready(2), future::ready(3)];
+    let join = JoinUnpin { futures: futures.into_iter().map(maybe_done).collect() };
+    assert_eq!(block_on(join), vec![1, 2, 3]);
+}
+
+#[test]
+fn output_mut_and_take_output_unpin() {
+    let mut fut = maybe_done(future::ready(1));
+    assert!(!fut.is_done());
+    assert_eq!(fut.output_mut_unpin(), None);
+    assert_eq!(fut.take_output_unpin(), None);
+
+    block_on(&mut fut);
+    assert!(fut.is_done());
+    *fut.output_mut_unpin().unwrap() += 1;
+    assert_eq!(fut.take_output_unpin(), Some(2));
+    assert!(fut.is_done());
+    assert_eq!(fut.take_output_unpin(), None);
+}
+
+#[test]
+fn try_maybe_done_unpin() {
+    let mut ok: TryMaybeDone<_> = try_maybe_done(future::ok::<i32, ()>(1));
+    assert!(!ok.is_done());
+    assert_eq!(block_on(&mut ok), Ok(()));
+    assert!(ok.is_done());
+    *ok.output_mut_unpin().unwrap() += 1;
+    assert_eq!(ok.take_output_unpin(), Some(2));
+
+    let mut err = try_maybe_done(future::err::<i32, ()>(()));
+    assert_eq!(block_on(&mut err), Err(()));
+    assert!(err.is_done());
+    assert_eq!(err.take_output_unpin(), None);
+}
@@ -0,0 +1,26 @@
+use futures::future::{self, FusedFuture, FutureExt};
+use futures::stream::StreamExt;
+use futures::task::Poll;
+use futures_test::future::FutureTestExt;
+use futures_test::task::noop_context;
+
+#[test]
+fn keeps_pending_future_to_poll_again() {
+    let mut fut = future::poll_immediate(future::ready(7).pending_once());
+    let cx = &mut noop_context();
+
+    assert_eq!(fut.poll_unpin(cx), Poll::Ready(None));
+    assert!(!fut.is_terminated());
+    assert_eq!(fut.poll_unpin(cx), Poll::Ready(Some(7)));
+    assert!(fut.is_terminated());
+}
+
+#[test]
+fn stream_yields_each_poll() {
+    let mut stream = future::poll_immediate(future::ready(7).pending_once());
+    let cx = &mut noop_context();
+
+    assert_eq!(stream.poll_next_unpin(cx), Poll::Ready(Some(Poll::Pending)));
+    assert_eq!(stream.poll_next_unpin(cx), Poll::Ready(Some(Poll::Ready(7))));
+    assert_eq!(stream.poll_next_unpin(cx), Poll::Ready(None));
+}
@@ -0,0 +1,118 @@
+use futures::channel::oneshot;
+use futures::executor::block_on;
+use futures::future::{self, FutureExt};
+use futures::task::Context;
+use futures_test::task::{new_count_waker, noop_context};
+use std::panic::{self, AssertUnwindSafe};
+
+#[test]
+fn is_finished_and_try_take() {
+    let (tx, rx) = oneshot::channel::<i32>();
+    let (mut remote, mut handle) = rx.map(Result::unwrap).remote_handle();
+    let mut cx = noop_context();
+
+    assert!(remote.poll_unpin(&mut cx).is_pending());
+    assert!(!handle.is_finished());
+    assert_eq!(handle.try_take(), None);
+
+    tx.send(5).unwrap();
+    assert!(!handle.is_finished());
+    assert!(remote.poll_unpin(&mut cx).is_ready());
+    assert!(handle.is_finished());
+    assert_eq!(handle.try_take(), Some(5));
+
+    // The output can only be taken once.
+    assert!(handle.is_finished());
+    assert_eq!(handle.try_take(), None);
+}
+
+#[test]
+fn try_take_after_remote_dropped() {
+    let (remote, mut handle) = future::pending::<()>().remote_handle();
+    drop(remote);
+    assert!(!handle.is_finished());
+    assert_eq!(handle.try_take(), None);
+}
+
+#[test]
+fn try_take_resumes_panic() {
+    let (remote, mut handle) =
+        future::lazy(|_| -> () { panic!("remote panicked") }).remote_handle();
+    block_on(remote);
+    assert!(handle.is_finished());
+
+    let result = panic::catch_unwind(AssertUnwindSafe(|| handle.try_take()));
+    assert_eq!(*result.unwrap_err().downcast::<&str>().unwrap(), "remote panicked");
+}
+
+#[test]
+fn poll_finished_keeps_output() {
+    let (tx, rx) = oneshot::channel::<i32>();
+    let (mut remote, mut handle) = rx.map(Result::unwrap).remote_handle();
+    let (waker, counter) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+
+    assert!(handle.poll_finished(&mut cx).is_pending());
+    assert!(remote.poll_unpin(&mut noop_context()).is_pending());
+    tx.send(5).unwrap();
+    assert!(remote.poll_unpin(&mut noop_context()).is_ready());
+    assert_eq!(counter, 1);
+
+    assert!(handle.is_finished());
+    assert!(handle.poll_finished(&mut cx).is_ready());
+    assert!(handle.poll_finished(&mut cx).is_ready());
+    assert_eq!(block_on(handle), 5);
+}
+
+#[test]
+fn poll_finished_after_remote_panicked() {
+    let (remote, mut handle) =
+        future::lazy(|_| -> () { panic!("remote panicked") }).remote_handle();
+    let mut cx = noop_context();
+
+    assert!(!handle.is_finished());
+    block_on(remote);
+    assert!(handle.is_finished());
+    assert!(handle.poll_finished(&mut cx).is_ready());
+
+    let result = panic::catch_unwind(AssertUnwindSafe(|| handle.try_take()));
+    assert!(result.is_err());
+}
+
+#[test]
+fn detach() {
+    let (tx, rx) = oneshot::channel::<i32>();
+    let (mut remote, handle) = rx.map(Result::unwrap).remote_handle();
+    let mut cx = noop_context();
+
+    let mut watcher = handle.detach();
+    assert!(!watcher.is_finished());
+    assert!(watcher.poll_unpin(&mut cx).is_pending());
+
+    // Detaching doesn't cancel the remote future.
+    assert!(remote.poll_unpin(&mut cx).is_pending());
+    tx.send(5).unwrap();
+    assert!(remote.poll_unpin(&mut cx).is_ready());
+    assert!(watcher.is_finished());
+    assert!(block_on(watcher).is_ok());
+}
+
+#[test]
+fn detach_reports_panic() {
+    let (remote, handle) = future::lazy(|_| -> () { panic!("remote panicked") }).remote_handle();
+    let watcher = handle.detach();
+    block_on(remote);
+
+    let payload = block_on(watcher).unwrap_err();
+    assert_eq!(*payload.downcast::<&str>().unwrap(), "remote panicked");
+}
+
+#[test]
+fn detach_after_remote_dropped() {
+    let (remote, handle) = future::pending::<()>().remote_handle();
+    let watcher = handle.detach();
+    drop(remote);
+
+    let payload = block_on(watcher).unwrap_err();
+    assert!(payload.downcast::<oneshot::Canceled>().is_ok());
+}
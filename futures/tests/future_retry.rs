@@ -0,0 +1,56 @@
+use futures::executor::block_on;
+use futures::future::{self, Future, TryFutureExt};
+use futures::task::Poll;
+use futures_test::future::FutureTestExt;
+use futures_test::task::noop_context;
+use std::cell::Cell;
+use std::pin::Pin;
+
+#[test]
+fn retry_until_success() {
+    let attempts = Cell::new(0);
+    let attempt = || {
+        attempts.set(attempts.get() + 1);
+        future::ready(if attempts.get() < 3 { Err(attempts.get()) } else { Ok("done") })
+    };
+
+    let mut seen = Vec::new();
+    let future = attempt().retry(attempt, |&err, failed| {
+        seen.push((err, failed));
+        Some(future::ready(()))
+    });
+    assert_eq!(block_on(future), Ok("done"));
+    assert_eq!(attempts.get(), 3);
+    assert_eq!(seen, [(1, 1), (2, 2)]);
+}
+
+#[test]
+fn retry_returns_last_error_once_policy_gives_up() {
+    let attempts = Cell::new(0);
+    let attempt = || {
+        attempts.set(attempts.get() + 1);
+        future::ready(Err::<(), _>(attempts.get()))
+    };
+
+    let future = attempt()
+        .retry(attempt, |_, failed| if failed < 4 { Some(future::ready(())) } else { None });
+    assert_eq!(block_on(future), Err(4));
+    assert_eq!(attempts.get(), 4);
+}
+
+#[test]
+fn retry_waits_for_delay() {
+    let attempts = Cell::new(0);
+    let attempt = || {
+        attempts.set(attempts.get() + 1);
+        future::ready(if attempts.get() < 2 { Err(()) } else { Ok(()) })
+    };
+
+    let mut future = attempt().retry(attempt, |_, _| Some(future::ready(()).pending_once()));
+    let mut cx = noop_context();
+    assert_eq!(Pin::new(&mut future).poll(&mut cx), Poll::Pending);
+    // The next attempt is only created once the delay has completed.
+    assert_eq!(attempts.get(), 1);
+    assert_eq!(Pin::new(&mut future).poll(&mut cx), Poll::Ready(Ok(())));
+    assert_eq!(attempts.get(), 2);
+}
@@ -0,0 +1,30 @@
+use futures::executor::block_on;
+use futures::future::{self, FusedFuture, FutureExt, SelectResult};
+use futures_test::future::FutureTestExt;
+use futures_test::task::noop_context;
+
+#[test]
+fn select_named_first() {
+    match block_on(future::select_named(future::ready(1), future::pending::<()>())) {
+        SelectResult::First(1, _) => {}
+        _ => panic!("expected the first future to complete"),
+    }
+}
+
+#[test]
+fn select_named_second_returns_first() {
+    let first = future::ready(1).pending_once();
+    let second = future::ready("two");
+    match block_on(future::select_named(first, second)) {
+        SelectResult::Second("two", first) => assert_eq!(block_on(first), 1),
+        _ => panic!("expected the second future to complete"),
+    }
+}
+
+#[test]
+fn select_named_is_terminated() {
+    let mut fut = future::select_named(future::ready(1), future::ready(2));
+    assert!(!fut.is_terminated());
+    assert!(fut.poll_unpin(&mut noop_context()).is_ready());
+    assert!(fut.is_terminated());
+}
@@ -1,6 +1,10 @@
 use futures::executor::block_on;
-use futures::future::{ready, select_all};
+use futures::future::{ready, select_all, Future, FutureExt};
+use futures::task::{Context, Poll, Waker};
+use futures_test::task::noop_context;
+use std::cell::{Cell, RefCell};
 use std::collections::HashSet;
+use std::pin::Pin;
 
 #[test]
 fn smoke() {
@@ -23,3 +27,80 @@ fn smoke() {
     assert!(c.is_empty());
     assert!(v.is_empty());
 }
+
+// A future which counts how often it's polled, and completes once `ready` is
+// set.
+struct Counted<'a> {
+    id: usize,
+    polls: &'a Cell<usize>,
+    ready: &'a Cell<Option<usize>>,
+    waker: &'a RefCell<Option<Waker>>,
+}
+
+impl Future for Counted<'_> {
+    type Output = usize;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<usize> {
+        self.polls.set(self.polls.get() + 1);
+        if self.ready.get() == Some(self.id) {
+            Poll::Ready(self.id)
+        } else {
+            if self.ready.get().is_none() && self.id == 0 {
+                *self.waker.borrow_mut() = Some(cx.waker().clone());
+            }
+            Poll::Pending
+        }
+    }
+}
+
+// A future which completes right away with its id if it's ready, or never.
+struct Tagged {
+    id: usize,
+    ready: bool,
+}
+
+impl Future for Tagged {
+    type Output = usize;
+
+    fn poll(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<usize> {
+        if self.ready {
+            Poll::Ready(self.id)
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+#[test]
+fn remaining_in_original_order() {
+    for &n in &[5, 1000] {
+        let futures: Vec<_> = (0..n).map(|id| Tagged { id, ready: id == 3 }).collect();
+        let (i, idx, rest) = block_on(select_all(futures));
+        assert_eq!((i, idx), (3, 3));
+        let ids: Vec<_> = rest.iter().map(|f| f.id).collect();
+        assert_eq!(ids, (0..n).filter(|&id| id != 3).collect::<Vec<_>>());
+    }
+}
+
+#[test]
+fn only_woken_futures_are_polled() {
+    const N: usize = 1000;
+    let polls = Cell::new(0);
+    let ready = Cell::new(None);
+    let waker = RefCell::new(None);
+    let futures: Vec<_> =
+        (0..N).map(|id| Counted { id, polls: &polls, ready: &ready, waker: &waker }).collect();
+    let mut select = select_all(futures);
+    let mut cx = noop_context();
+
+    assert!(select.poll_unpin(&mut cx).is_pending());
+    assert_eq!(polls.get(), N);
+
+    ready.set(Some(0));
+    waker.borrow_mut().take().unwrap().wake();
+    match select.poll_unpin(&mut cx) {
+        Poll::Ready((0, 0, rest)) => assert_eq!(rest.len(), N - 1),
+        _ => panic!("expected the first future to complete"),
+    }
+    assert_eq!(polls.get(), N + 1);
+}
@@ -1,5 +1,8 @@
+use futures::channel::oneshot;
 use futures::executor::block_on;
-use futures::future::{err, ok, select_ok};
+use futures::future::{err, ok, select_ok, select_ok_all_errors, FutureExt};
+use futures::task::Poll;
+use futures_test::task::noop_context;
 
 #[test]
 fn ignore_err() {
@@ -28,3 +31,65 @@ fn last_err() {
     let i = block_on(select_ok(v)).err().unwrap();
     assert_eq!(i, 3);
 }
+
+#[test]
+fn all_errors_in_input_order() {
+    let v = vec![err::<(), _>(1), err(2), err(3)];
+
+    let errors = block_on(select_ok_all_errors(v)).err().unwrap();
+    assert_eq!(errors, [1, 2, 3]);
+}
+
+#[test]
+fn all_errors_first_succeeds_immediately() {
+    let v = vec![ok(1), err(2), err(3)];
+
+    let (i, v) = block_on(select_ok_all_errors(v)).ok().unwrap();
+    assert_eq!(i, 1);
+    assert_eq!(v.len(), 2);
+
+    let errors = block_on(select_ok_all_errors(v)).err().unwrap();
+    assert_eq!(errors, [2, 3]);
+}
+
+#[test]
+fn all_errors_interleaved() {
+    let (tx1, rx1) = oneshot::channel::<Result<(), i32>>();
+    let (tx2, rx2) = oneshot::channel::<Result<(), i32>>();
+    let (tx3, rx3) = oneshot::channel::<Result<(), i32>>();
+    let v = vec![rx1, rx2, rx3].into_iter().map(|rx| rx.map(Result::unwrap));
+    let mut fut = select_ok_all_errors(v);
+    let mut cx = noop_context();
+
+    assert!(fut.poll_unpin(&mut cx).is_pending());
+    tx2.send(Err(2)).unwrap();
+    assert!(fut.poll_unpin(&mut cx).is_pending());
+    tx3.send(Err(3)).unwrap();
+    assert!(fut.poll_unpin(&mut cx).is_pending());
+    tx1.send(Err(1)).unwrap();
+    match fut.poll_unpin(&mut cx) {
+        Poll::Ready(Err(errors)) => assert_eq!(errors, [1, 2, 3]),
+        _ => panic!("expected all the errors"),
+    }
+}
+
+#[test]
+fn all_errors_success_after_failures() {
+    let (tx1, rx1) = oneshot::channel::<Result<i32, i32>>();
+    let (tx2, rx2) = oneshot::channel::<Result<i32, i32>>();
+    let (_tx3, rx3) = oneshot::channel::<Result<i32, i32>>();
+    let v = vec![rx1, rx2, rx3].into_iter().map(|rx| rx.map(Result::unwrap));
+    let mut fut = select_ok_all_errors(v);
+    let mut cx = noop_context();
+
+    tx1.send(Err(1)).unwrap();
+    assert!(fut.poll_unpin(&mut cx).is_pending());
+    tx2.send(Ok(2)).unwrap();
+    match fut.poll_unpin(&mut cx) {
+        Poll::Ready(Ok((i, rest))) => {
+            assert_eq!(i, 2);
+            assert_eq!(rest.len(), 1);
+        }
+        _ => panic!("expected a success"),
+    }
+}
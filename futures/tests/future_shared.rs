@@ -152,6 +152,89 @@ fn downgrade() {
     assert!(shared2.downgrade().is_none());
 }
 
+#[test]
+fn weak_shared_does_not_keep_future_alive() {
+    let (tx, rx) = oneshot::channel::<i32>();
+    let shared = rx.shared();
+    let weak = shared.downgrade().unwrap();
+
+    // Dropping the last `Shared` cancels the future, even though it hasn't
+    // completed yet.
+    drop(shared);
+    assert!(tx.is_canceled());
+    assert!(weak.upgrade().is_none());
+
+    // Once resolved, a `Shared` upgraded from a `WeakShared` yields the
+    // cached output.
+    let shared = future::ready(1).shared();
+    let clone = shared.clone();
+    let weak = shared.downgrade().unwrap();
+    assert_eq!(block_on(shared), 1);
+    let upgraded = weak.upgrade().unwrap();
+    assert_eq!(upgraded.peek(), Some(&1));
+    drop(clone);
+    assert_eq!(block_on(upgraded), 1);
+    assert!(weak.upgrade().is_none());
+}
+
+#[test]
+fn counts_and_ptr_eq() {
+    let (tx, rx) = oneshot::channel::<i32>();
+    let shared = rx.shared();
+    assert_eq!(shared.strong_count(), Some(1));
+    assert_eq!(shared.weak_count(), Some(0));
+
+    let mut shared2 = shared.clone();
+    let weak = shared.downgrade().unwrap();
+    assert_eq!(shared.strong_count(), Some(2));
+    assert_eq!(shared2.weak_count(), Some(1));
+    assert!(shared.ptr_eq(&shared2));
+    assert!(!shared.ptr_eq(&oneshot::channel::<i32>().1.shared()));
+
+    tx.send(42).unwrap();
+    assert_eq!(block_on(shared).unwrap(), 42);
+    assert_eq!(shared2.strong_count(), Some(1));
+
+    let shared3 = weak.upgrade().unwrap();
+    assert_eq!(block_on(&mut shared2).unwrap(), 42);
+    // A `Shared` which has completed doesn't refer to the future anymore.
+    assert_eq!(shared2.strong_count(), None);
+    assert_eq!(shared2.weak_count(), None);
+    assert!(!shared2.ptr_eq(&shared3));
+    assert_eq!(shared3.strong_count(), Some(1));
+}
+
+#[test]
+fn try_into_inner() {
+    // Unique, even after being polled.
+    let (tx, rx) = oneshot::channel::<i32>();
+    let mut shared = rx.shared();
+    assert!(shared.poll_unpin(&mut futures_test::task::noop_context()).is_pending());
+    let rx = shared.try_into_inner().unwrap();
+    tx.send(1).unwrap();
+    assert_eq!(block_on(rx), Ok(1));
+
+    // Not unique.
+    let shared = future::ready(2).shared();
+    let clone = shared.clone();
+    let shared = shared.try_into_inner().unwrap_err();
+    assert!(shared.ptr_eq(&clone));
+    drop(clone);
+
+    // A `WeakShared` doesn't prevent unwrapping, but can't be upgraded afterwards.
+    let weak = shared.downgrade().unwrap();
+    assert_eq!(block_on(shared.try_into_inner().unwrap()), 2);
+    assert!(weak.upgrade().is_none());
+
+    // Already completed by another `Shared`.
+    let shared = future::ready(3).shared();
+    assert_eq!(block_on(shared.clone()), 3);
+    let mut shared = shared.try_into_inner().unwrap_err();
+    assert_eq!(shared.peek(), Some(&3));
+    assert_eq!(block_on(&mut shared), 3);
+    assert!(shared.try_into_inner().is_err());
+}
+
 #[test]
 fn dont_clone_in_single_owner_shared_future() {
     let counter = CountClone(Rc::new(Cell::new(0)));
@@ -226,3 +309,52 @@ fn poll_while_panic() {
     let _s = S {};
     panic!("test_marker");
 }
+
+#[test]
+fn shared_arc_outputs_are_pointer_equal() {
+    // Not `Clone`, so this couldn't be used with `shared`.
+    struct Output(i32);
+
+    let (tx, rx) = oneshot::channel::<i32>();
+    let f = rx.map(|x| Output(x.unwrap())).shared_arc();
+    let join_handles = (0..4)
+        .map(|_| {
+            let cloned_future = f.clone();
+            thread::spawn(move || block_on(cloned_future))
+        })
+        .collect::<Vec<_>>();
+
+    tx.send(6).unwrap();
+
+    let output = block_on(f);
+    assert_eq!(output.0, 6);
+    for join_handle in join_handles {
+        assert!(std::sync::Arc::ptr_eq(&join_handle.join().unwrap(), &output));
+    }
+}
+
+#[test]
+fn shared_arc_peek_and_downgrade() {
+    let mut local_pool = LocalPool::new();
+    let spawn = local_pool.spawner();
+
+    let (tx, rx) = oneshot::channel::<i32>();
+    let f1 = rx.shared_arc();
+    let f2 = f1.clone();
+    let weak = f1.downgrade().unwrap();
+    assert!(f1.peek().is_none());
+
+    let f3 = weak.upgrade().unwrap();
+    assert!(f3.ptr_eq(&f1));
+    drop(f3);
+
+    spawn.spawn_local_obj(LocalFutureObj::new(Box::new(f1.map(|_| ())))).unwrap();
+    local_pool.run_until_stalled();
+    tx.send(42).unwrap();
+    local_pool.run_until_stalled();
+
+    assert_eq!(**f2.peek().unwrap(), Ok(42));
+    let peeked = f2.peek().unwrap().clone();
+    assert!(std::sync::Arc::ptr_eq(&peeked, &block_on(f2)));
+    assert!(weak.upgrade().is_none());
+}
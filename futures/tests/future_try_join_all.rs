@@ -44,3 +44,88 @@ fn try_join_all_from_iter() {
         Ok::<_, usize>(vec![1, 2]),
     )
 }
+
+#[test]
+fn try_join_with_cleanup_cleans_up_pending() {
+    use futures::channel::oneshot;
+    use futures::future::{pending, try_join_with_cleanup, FutureExt};
+    use std::cell::RefCell;
+
+    let cleaned_up = RefCell::new(Vec::new());
+    let (tx, rx) = oneshot::channel::<()>();
+
+    let futures = vec![
+        ok::<u32, u32>(1).boxed_local(),
+        pending().boxed_local(),
+        async {
+            rx.await.unwrap();
+            Err(3)
+        }
+        .boxed_local(),
+        pending().boxed_local(),
+    ];
+    let fut = try_join_with_cleanup(futures, |_fut| {
+        let i = cleaned_up.borrow().len();
+        cleaned_up.borrow_mut().push(i);
+        async {}
+    });
+
+    tx.send(()).unwrap();
+    assert_done(fut, Err(3));
+    assert_eq!(*cleaned_up.borrow(), vec![0, 1]);
+
+    assert_done(
+        try_join_with_cleanup(vec![ok::<u32, u32>(1), ok(2)], |_| async { unreachable!() }),
+        Ok(vec![1, 2]),
+    );
+}
+
+#[test]
+fn try_join_with_cleanup_awaits_cleanup() {
+    use futures::channel::oneshot;
+    use futures::future::{try_join_with_cleanup, FutureExt};
+    use futures::task::Poll;
+    use futures_test::task::noop_context;
+
+    let (finalize_tx, finalize_rx) = oneshot::channel::<()>();
+    let mut finalize_rx = Some(finalize_rx);
+    let futures = vec![err::<u32, u32>(1).boxed(), futures::future::pending().boxed()];
+
+    let mut fut = try_join_with_cleanup(futures, |_fut| {
+        let rx = finalize_rx.take().unwrap();
+        async move { rx.await.unwrap() }
+    });
+
+    let mut cx = noop_context();
+    assert_eq!(fut.poll_unpin(&mut cx), Poll::Pending);
+    finalize_tx.send(()).unwrap();
+    assert_eq!(fut.poll_unpin(&mut cx), Poll::Ready(Err(1)));
+}
+
+#[test]
+fn try_join_all_partial_keeps_completed_outputs() {
+    use futures::channel::oneshot;
+    use futures::future::{try_join_all_partial, FutureExt, TryFutureExt};
+    use futures::task::Poll;
+
+    let (txs, rxs): (Vec<_>, Vec<_>) =
+        (0..4).map(|_| oneshot::channel::<Result<u32, u32>>()).unzip();
+    let all = try_join_all_partial(rxs.into_iter().map(|rx| rx.map(Result::unwrap).into_future()));
+    pin_mut!(all);
+    let cx = &mut futures_test::task::noop_context();
+
+    let mut txs = txs.into_iter().map(Some).collect::<Vec<_>>();
+    let mut send = |i: usize, v| txs[i].take().unwrap().send(v).unwrap();
+    send(3, Ok(30));
+    send(0, Ok(0));
+    assert!(all.as_mut().poll(cx).is_pending());
+    send(2, Err(2));
+    // The future at index 1 is canceled.
+    assert_eq!(all.poll(cx), Poll::Ready(Err((2, vec![Some(0), None, None, Some(30)]))));
+
+    assert_done(try_join_all_partial(vec![ok(1), ok(2)]), Ok::<_, (u32, _)>(vec![1, 2]));
+    assert_done(
+        try_join_all_partial(vec![ok(1).boxed(), err(2).boxed()]),
+        Err((2, vec![Some(1), None])),
+    );
+}
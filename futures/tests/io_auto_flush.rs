@@ -0,0 +1,117 @@
+use futures::channel::oneshot;
+use futures::executor::block_on;
+use futures::future::{self, FutureExt};
+use futures::io::{AsyncWrite, AsyncWriteExt};
+use futures::task::{Context, Poll};
+use futures_test::task::new_count_waker;
+use std::cell::RefCell;
+use std::io;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::time::Duration;
+
+#[derive(Debug, Default)]
+struct Tracked {
+    written: Vec<u8>,
+    flushes: usize,
+    closed: bool,
+}
+
+impl AsyncWrite for Tracked {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        self.written.extend_from_slice(buf);
+        Poll::Ready(Ok(buf.len()))
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.flushes += 1;
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.closed = true;
+        Poll::Ready(Ok(()))
+    }
+}
+
+#[test]
+fn flushes_after_every_bytes() {
+    let mut writer =
+        Tracked::default().auto_flush(4, Duration::from_secs(1), |_| future::pending());
+
+    block_on(writer.write_all(&[1, 2, 3])).unwrap();
+    assert_eq!(writer.get_ref().flushes, 0);
+    block_on(writer.write_all(&[4, 5])).unwrap();
+    assert_eq!(writer.get_ref().flushes, 1);
+    block_on(writer.write_all(&[6])).unwrap();
+    assert_eq!(writer.get_ref().flushes, 1);
+    assert_eq!(writer.get_ref().written, [1, 2, 3, 4, 5, 6]);
+}
+
+#[test]
+fn flushes_after_timer() {
+    let timers = Rc::new(RefCell::new(Vec::new()));
+    let sleep = {
+        let timers = timers.clone();
+        move |every| {
+            assert_eq!(every, Duration::from_secs(1));
+            let (tx, rx) = oneshot::channel::<()>();
+            timers.borrow_mut().push(tx);
+            rx.map(|_| ())
+        }
+    };
+    let mut writer = Tracked::default().auto_flush(100, Duration::from_secs(1), sleep);
+
+    block_on(writer.write_all(&[1])).unwrap();
+    block_on(writer.write_all(&[2])).unwrap();
+    // The timer is only started by the first write after a flush.
+    assert_eq!(timers.borrow().len(), 1);
+    assert_eq!(writer.get_ref().flushes, 0);
+
+    timers.borrow_mut().remove(0).send(()).unwrap();
+    block_on(writer.write_all(&[3])).unwrap();
+    assert_eq!(writer.get_ref().flushes, 1);
+    assert_eq!(timers.borrow().len(), 1);
+
+    // An explicit flush restarts the interval.
+    block_on(writer.flush()).unwrap();
+    assert_eq!(writer.get_ref().flushes, 2);
+    assert!(timers.borrow_mut().remove(0).send(()).is_err());
+}
+
+#[test]
+fn poll_flush_due_flushes_idle_writer() {
+    let (tx, rx) = oneshot::channel::<()>();
+    let mut rx = Some(rx);
+    let sleep = move |_| rx.take().unwrap().map(|_| ());
+    let mut writer = Tracked::default().auto_flush(100, Duration::from_secs(1), sleep);
+    let (waker, count) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+
+    // Nothing to flush yet.
+    assert!(Pin::new(&mut writer).poll_flush_due(&mut cx).is_pending());
+    block_on(writer.write_all(&[1])).unwrap();
+    assert!(Pin::new(&mut writer).poll_flush_due(&mut cx).is_pending());
+    assert_eq!(writer.get_ref().flushes, 0);
+
+    tx.send(()).unwrap();
+    assert_eq!(count, 1);
+    assert!(Pin::new(&mut writer).poll_flush_due(&mut cx).is_ready());
+    assert_eq!(writer.get_ref().flushes, 1);
+    assert!(Pin::new(&mut writer).poll_flush_due(&mut cx).is_pending());
+}
+
+#[test]
+fn close_flushes_remaining_bytes() {
+    let mut writer =
+        Tracked::default().auto_flush(4, Duration::from_secs(1), |_| future::pending());
+
+    block_on(writer.write_all(&[1])).unwrap();
+    block_on(writer.close()).unwrap();
+    assert_eq!(writer.get_ref().flushes, 1);
+    assert!(writer.get_ref().closed);
+}
@@ -0,0 +1,160 @@
+use futures::executor::{block_on, LocalPool};
+use futures::future::poll_fn;
+use futures::io::{
+    AsyncRead, AsyncReadExt, AsyncSeek, AsyncSeekExt, AsyncWriteExt, BlockingIo, SeekFrom,
+};
+use futures::task::{LocalSpawnExt, Poll};
+use futures_test::task::noop_context;
+use std::io::{self, Cursor, Read, Seek};
+use std::pin::Pin;
+use std::sync::mpsc;
+
+// Reader which blocks until it is told how many bytes to produce.
+struct GatedReader(mpsc::Receiver<usize>);
+
+impl Read for GatedReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.0.recv().unwrap_or(0).min(buf.len());
+        for b in &mut buf[..n] {
+            *b = 7;
+        }
+        Ok(n)
+    }
+}
+
+// Cursor whose reads and seeks block until they are allowed to go ahead, or
+// the gate is dropped.
+struct GatedCursor {
+    gate: mpsc::Receiver<()>,
+    inner: Cursor<Vec<u8>>,
+}
+
+impl Read for GatedCursor {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let _ = self.gate.recv();
+        self.inner.read(buf)
+    }
+}
+
+impl Seek for GatedCursor {
+    fn seek(&mut self, pos: io::SeekFrom) -> io::Result<u64> {
+        let _ = self.gate.recv();
+        self.inner.seek(pos)
+    }
+}
+
+#[test]
+fn blocking_read_keeps_executor_responsive() {
+    let (tx, rx) = mpsc::channel();
+    let mut reader = BlockingIo::new(GatedReader(rx));
+
+    let mut pool = LocalPool::new();
+    // If reading blocked the executor, this task would never get to unblock
+    // the reader.
+    pool.spawner()
+        .spawn_local(async move {
+            tx.send(3).unwrap();
+            tx.send(2).unwrap();
+        })
+        .unwrap();
+
+    let data = pool.run_until(async move {
+        let mut data = Vec::new();
+        reader.read_to_end(&mut data).await.unwrap();
+        data
+    });
+    assert_eq!(data, [7; 5]);
+}
+
+#[test]
+fn blocking_write_seek_read() {
+    let mut io = BlockingIo::new(Cursor::new(Vec::new()));
+
+    block_on(async {
+        io.write_all(b"hello world").await.unwrap();
+        io.flush().await.unwrap();
+        assert_eq!(io.seek(SeekFrom::Start(6)).await.unwrap(), 6);
+
+        let mut buf = [0; 3];
+        io.read_exact(&mut buf).await.unwrap();
+        assert_eq!(&buf, b"wor");
+        assert_eq!(io.seek(SeekFrom::Current(-3)).await.unwrap(), 6);
+        io.write_all(b"there").await.unwrap();
+        io.seek(SeekFrom::Start(0)).await.unwrap();
+
+        let mut contents = String::new();
+        io.read_to_string(&mut contents).await.unwrap();
+        assert_eq!(contents, "hello there");
+    });
+}
+
+#[test]
+fn blocking_read_ahead_is_accounted_for_when_seeking() {
+    let (gate, rx) = mpsc::channel();
+    let mut io = BlockingIo::new(GatedCursor { gate: rx, inner: Cursor::new(b"abcdef".to_vec()) });
+    let mut cx = noop_context();
+
+    // Start a read with a large buffer, then finish it with a smaller one.
+    let mut large = [0; 6];
+    assert!(Pin::new(&mut io).poll_read(&mut cx, &mut large).is_pending());
+    drop(gate);
+    let mut small = [0; 2];
+    let n = block_on(poll_fn(|cx| Pin::new(&mut io).poll_read(cx, &mut small))).unwrap();
+    assert_eq!(n, 2);
+    assert_eq!(&small, b"ab");
+
+    block_on(async {
+        assert_eq!(io.seek(SeekFrom::Current(0)).await.unwrap(), 2);
+        let mut rest = Vec::new();
+        io.read_to_end(&mut rest).await.unwrap();
+        assert_eq!(rest, b"cdef");
+    });
+}
+
+#[test]
+fn blocking_abandoned_seek_is_not_reported_by_the_next_one() {
+    let (gate, rx) = mpsc::channel();
+    let mut io = BlockingIo::new(GatedCursor { gate: rx, inner: Cursor::new(b"abcdef".to_vec()) });
+    let mut cx = noop_context();
+
+    assert!(Pin::new(&mut io).poll_seek(&mut cx, SeekFrom::Start(5)).is_pending());
+    drop(gate);
+
+    block_on(async {
+        assert_eq!(io.seek(SeekFrom::Start(2)).await.unwrap(), 2);
+        let mut rest = Vec::new();
+        io.read_to_end(&mut rest).await.unwrap();
+        assert_eq!(rest, b"cdef");
+    });
+}
+
+#[test]
+fn blocking_with_spawner() {
+    let mut pool = LocalPool::new();
+    let mut io = BlockingIo::with_spawner(Cursor::new(Vec::new()), &pool.spawner()).unwrap();
+
+    let contents = pool.run_until(async move {
+        io.write_all(b"hello world").await.unwrap();
+        io.seek(SeekFrom::Start(6)).await.unwrap();
+        let mut contents = String::new();
+        io.read_to_string(&mut contents).await.unwrap();
+        contents
+    });
+    assert_eq!(contents, "world");
+}
+
+#[test]
+fn blocking_drop_does_not_wait() {
+    let (tx, rx) = mpsc::channel();
+    let mut reader = BlockingIo::new(GatedReader(rx));
+    let mut cx = noop_context();
+
+    let mut buf = [0; 4];
+    assert_eq!(
+        Pin::new(&mut reader).poll_read(&mut cx, &mut buf).map(|r| r.unwrap()),
+        Poll::Pending
+    );
+    // The read is still blocked on the thread.
+    drop(reader);
+    tx.send(1).unwrap();
+}
@@ -190,6 +190,79 @@ fn test_buffered_reader_seek_relative() {
     });
 }
 
+#[test]
+fn test_buffered_reader_seek_relative_in_buffer_does_no_io() {
+    #[derive(Default)]
+    struct Counts {
+        reads: usize,
+        seeks: usize,
+    }
+
+    struct CountingReader<'a> {
+        inner: futures::io::Cursor<&'a [u8]>,
+        counts: &'a std::cell::RefCell<Counts>,
+    }
+
+    impl AsyncRead for CountingReader<'_> {
+        fn poll_read(
+            mut self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &mut [u8],
+        ) -> Poll<io::Result<usize>> {
+            self.counts.borrow_mut().reads += 1;
+            Pin::new(&mut self.inner).poll_read(cx, buf)
+        }
+    }
+
+    impl AsyncSeek for CountingReader<'_> {
+        fn poll_seek(
+            mut self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            pos: SeekFrom,
+        ) -> Poll<io::Result<u64>> {
+            self.counts.borrow_mut().seeks += 1;
+            Pin::new(&mut self.inner).poll_seek(cx, pos)
+        }
+    }
+
+    block_on(async {
+        let counts = std::cell::RefCell::new(Counts::default());
+        let inner: &[u8] = &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let reader = CountingReader { inner: futures::io::Cursor::new(inner), counts: &counts };
+        let reader = BufReader::with_capacity(4, reader);
+        pin_mut!(reader);
+
+        assert_eq!(reader.as_mut().fill_buf().await.unwrap(), &[0, 1, 2, 3][..]);
+        assert_eq!(counts.borrow().reads, 1);
+
+        // In range: only the buffer position moves.
+        reader.as_mut().seek_relative(3).await.unwrap();
+        assert_eq!(reader.buffer(), &[3][..]);
+        reader.as_mut().seek_relative(-2).await.unwrap();
+        assert_eq!(reader.buffer(), &[1, 2, 3][..]);
+        reader.as_mut().seek_relative(3).await.unwrap();
+        assert_eq!(reader.buffer(), &[][..]);
+        assert_eq!(counts.borrow().reads, 1);
+        assert_eq!(counts.borrow().seeks, 0);
+
+        // Out of range: falls back to the underlying reader.
+        reader.as_mut().seek_relative(2).await.unwrap();
+        assert_eq!(counts.borrow().seeks, 1);
+        assert_eq!(reader.as_mut().fill_buf().await.unwrap(), &[6, 7, 8, 9][..]);
+        assert_eq!(counts.borrow().reads, 2);
+
+        reader.as_mut().seek_relative(3).await.unwrap();
+        reader.as_mut().seek_relative(-2).await.unwrap();
+        assert_eq!(counts.borrow().seeks, 1);
+        assert_eq!(reader.buffer(), &[7, 8, 9][..]);
+
+        // The reported position accounts for the buffered bytes.
+        assert_eq!(reader.stream_position().await.unwrap(), 7);
+        assert_eq!(reader.as_mut().fill_buf().await.unwrap(), &[7, 8, 9][..]);
+        assert_eq!(counts.borrow().reads, 3);
+    });
+}
+
 #[test]
 fn test_buffered_reader_invalidated_after_read() {
     block_on(async {
@@ -430,3 +503,38 @@ fn maybe_pending_seek() {
     Pin::new(&mut reader).consume(1);
     assert_eq!(run(reader.seek(SeekFrom::Current(-2))).ok(), Some(3));
 }
+
+#[test]
+fn peek_across_short_reads() {
+    use futures_test::io::AsyncReadTestExt;
+
+    let inner: &[u8] = &[5, 6, 7, 0, 1, 2, 3, 4];
+    let reader = BufReader::with_capacity(6, inner.limited(2).interleave_pending());
+    pin_mut!(reader);
+
+    assert_eq!(run(reader.as_mut().fill_buf()).ok(), Some(&[5, 6][..]));
+    reader.as_mut().consume(1);
+    assert_eq!(run(reader.as_mut().peek(5)).ok(), Some(&[6, 7, 0, 1, 2][..]));
+    // Peeking again doesn't read more.
+    assert_eq!(run(reader.as_mut().peek(3)).ok(), Some(&[6, 7, 0][..]));
+
+    let mut buf = [0; 3];
+    assert_eq!(run(reader.read(&mut buf)).ok(), Some(3));
+    assert_eq!(buf, [6, 7, 0]);
+
+    // At EOF, fewer bytes than asked for are returned.
+    assert_eq!(run(reader.as_mut().peek(6)).ok(), Some(&[1, 2, 3, 4][..]));
+    let mut rest = Vec::new();
+    assert_eq!(run(reader.read_to_end(&mut rest)).ok(), Some(4));
+    assert_eq!(rest, [1, 2, 3, 4]);
+}
+
+#[test]
+fn peek_larger_than_capacity() {
+    let inner: &[u8] = &[5, 6, 7, 0, 1, 2, 3, 4];
+    let mut reader = BufReader::with_capacity(4, inner);
+
+    let err = block_on(Pin::new(&mut reader).peek(5)).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    assert_eq!(block_on(Pin::new(&mut reader).peek(4)).unwrap(), [5, 6, 7, 0]);
+}
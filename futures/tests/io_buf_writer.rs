@@ -1,7 +1,7 @@
 use futures::executor::block_on;
 use futures::future::{Future, FutureExt};
 use futures::io::{
-    AsyncSeek, AsyncSeekExt, AsyncWrite, AsyncWriteExt, BufWriter, Cursor, SeekFrom,
+    AsyncSeek, AsyncSeekExt, AsyncWrite, AsyncWriteExt, BufWriter, Cursor, IoSlice, SeekFrom,
 };
 use futures::task::{Context, Poll};
 use futures_test::task::noop_context;
@@ -237,3 +237,178 @@ fn maybe_pending_buf_writer_seek() {
     run(w.flush()).unwrap();
     assert_eq!(&w.into_inner().inner.into_inner()[..], &[0, 1, 8, 9, 4, 5, 6, 7]);
 }
+
+#[test]
+fn buf_writer_flush_into_inner() {
+    let mut writer = BufWriter::with_capacity(10, MaybePending::new(Vec::new()));
+    run(writer.write_all(&[0, 1, 2, 3])).unwrap();
+    assert_eq!(writer.get_ref().inner, []);
+
+    let inner = run(writer.flush_into_inner()).unwrap();
+    assert_eq!(inner.inner, [0, 1, 2, 3]);
+}
+
+#[test]
+fn buf_writer_flush_into_inner_error() {
+    // Accepts `budget` bytes, one poll at a time, then fails.
+    struct Failing {
+        written: Vec<u8>,
+        budget: usize,
+    }
+
+    impl AsyncWrite for Failing {
+        fn poll_write(
+            mut self: Pin<&mut Self>,
+            _cx: &mut Context<'_>,
+            buf: &[u8],
+        ) -> Poll<io::Result<usize>> {
+            if self.budget == 0 {
+                return Poll::Ready(Err(io::Error::new(io::ErrorKind::Other, "broken")));
+            }
+            self.budget -= 1;
+            self.written.push(buf[0]);
+            Poll::Ready(Ok(1))
+        }
+
+        fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn poll_close(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    let mut writer = BufWriter::with_capacity(10, Failing { written: Vec::new(), budget: 2 });
+    block_on(writer.write_all(b"hello")).unwrap();
+
+    let err = block_on(writer.flush_into_inner()).err().unwrap();
+    assert_eq!(err.error().kind(), io::ErrorKind::Other);
+    let writer = err.into_inner();
+    assert_eq!(writer.get_ref().written, b"he");
+    assert_eq!(writer.buffer(), b"llo");
+
+    let (inner, unwritten) = writer.into_parts();
+    assert_eq!(inner.written, b"he");
+    assert_eq!(unwritten, b"llo");
+}
+
+/// Writer which records the lengths of the slices passed to each write.
+struct RecordingWriter {
+    data: Vec<u8>,
+    writes: Vec<Vec<usize>>,
+    vectored: bool,
+    max_write: usize,
+}
+
+impl RecordingWriter {
+    fn new(vectored: bool) -> Self {
+        Self { data: Vec::new(), writes: Vec::new(), vectored, max_write: usize::max_value() }
+    }
+}
+
+impl AsyncWrite for RecordingWriter {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        self.poll_write_vectored(cx, &[IoSlice::new(buf)])
+    }
+
+    fn poll_write_vectored(
+        mut self: Pin<&mut Self>,
+        _: &mut Context<'_>,
+        bufs: &[IoSlice<'_>],
+    ) -> Poll<io::Result<usize>> {
+        let this = &mut *self;
+        this.writes.push(bufs.iter().map(|buf| buf.len()).collect());
+        let mut n = 0;
+        for buf in bufs {
+            let len = buf.len().min(this.max_write - n);
+            this.data.extend_from_slice(&buf[..len]);
+            n += len;
+        }
+        Poll::Ready(Ok(n))
+    }
+
+    fn is_write_vectored(&self) -> bool {
+        self.vectored
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+#[test]
+fn buf_writer_not_vectored() {
+    let mut writer = BufWriter::with_capacity(4, RecordingWriter::new(false));
+
+    block_on(async {
+        assert_eq!(writer.write(&[0, 1]).await.unwrap(), 2);
+        assert_eq!(writer.write(&[2, 3, 4, 5, 6]).await.unwrap(), 5);
+        assert_eq!(
+            writer.write_vectored(&[IoSlice::new(&[7]), IoSlice::new(&[8])]).await.unwrap(),
+            2
+        );
+        assert_eq!(
+            writer
+                .write_vectored(&[IoSlice::new(&[9, 10]), IoSlice::new(&[11, 12])])
+                .await
+                .unwrap(),
+            4
+        );
+    });
+
+    // The buffer is flushed with a separate write before a large write is
+    // passed on.
+    assert_eq!(writer.buffer(), []);
+    assert_eq!(writer.get_ref().writes, [vec![2], vec![5], vec![2], vec![2, 2]]);
+    assert_eq!(writer.get_ref().data, (0..13).collect::<Vec<u8>>());
+}
+
+#[test]
+fn buf_writer_vectored() {
+    let mut writer = BufWriter::with_capacity(4, RecordingWriter::new(true));
+
+    block_on(async {
+        assert_eq!(writer.write(&[0, 1]).await.unwrap(), 2);
+        assert_eq!(writer.write(&[2, 3, 4, 5, 6]).await.unwrap(), 5);
+        assert_eq!(writer.write(&[7]).await.unwrap(), 1);
+        assert_eq!(
+            writer.write_vectored(&[IoSlice::new(&[8, 9]), IoSlice::new(&[10, 11])]).await.unwrap(),
+            4
+        );
+        // Small writes are still buffered.
+        assert_eq!(writer.write(&[12, 13]).await.unwrap(), 2);
+    });
+
+    // Large writes are passed on along with the buffered data.
+    assert_eq!(writer.buffer(), [12, 13]);
+    assert_eq!(writer.get_ref().writes, [vec![2, 5], vec![1, 2, 2]]);
+    assert_eq!(writer.get_ref().data, (0..12).collect::<Vec<u8>>());
+}
+
+#[test]
+fn buf_writer_vectored_partial_writes() {
+    let mut inner = RecordingWriter::new(true);
+    inner.max_write = 3;
+    let mut writer = BufWriter::with_capacity(8, inner);
+
+    block_on(async {
+        assert_eq!(writer.write(&[0, 1, 2, 3, 4]).await.unwrap(), 5);
+        // Only once all of the buffered data is written can the write report
+        // progress.
+        assert_eq!(writer.write(&[5; 8]).await.unwrap(), 1);
+        assert_eq!(writer.buffer(), []);
+        assert_eq!(writer.write(&[6; 8]).await.unwrap(), 3);
+    });
+
+    assert_eq!(writer.get_ref().writes, [vec![5, 8], vec![2, 8], vec![8]]);
+    assert_eq!(writer.get_ref().data, [0, 1, 2, 3, 4, 5, 6, 6, 6]);
+}
@@ -0,0 +1,83 @@
+use futures::executor::block_on;
+use futures::io::{self, AsyncRead, AsyncReadExt, Cursor};
+use futures::stream::{Stream, StreamExt, TryStreamExt};
+use futures::task::{Context, Poll};
+use futures_test::io::AsyncReadTestExt;
+use std::pin::Pin;
+
+#[test]
+fn bytes() {
+    let reader = Cursor::new(&b"hello"[..]).interleave_pending().limited(2);
+    let bytes: Vec<u8> = block_on(reader.bytes().try_collect()).unwrap();
+    assert_eq!(bytes, b"hello");
+
+    let mut s = Cursor::new(&b""[..]).bytes();
+    assert!(block_on(s.next()).is_none());
+}
+
+#[test]
+fn bytes_reads_chunks() {
+    struct Counted {
+        reads: usize,
+    }
+
+    impl AsyncRead for Counted {
+        fn poll_read(
+            mut self: Pin<&mut Self>,
+            _cx: &mut Context<'_>,
+            buf: &mut [u8],
+        ) -> Poll<io::Result<usize>> {
+            self.reads += 1;
+            match self.reads {
+                1 => {
+                    buf[..3].copy_from_slice(b"abc");
+                    Poll::Ready(Ok(3))
+                }
+                2 => Poll::Ready(Err(io::ErrorKind::Other.into())),
+                _ => Poll::Ready(Ok(0)),
+            }
+        }
+    }
+
+    let mut s = Counted { reads: 0 }.bytes();
+    assert_eq!(block_on(s.next()).unwrap().unwrap(), b'a');
+    assert_eq!(s.size_hint(), (2, None));
+    assert_eq!(block_on(s.next()).unwrap().unwrap(), b'b');
+    assert_eq!(block_on(s.next()).unwrap().unwrap(), b'c');
+    assert_eq!(block_on(s.next()).unwrap().unwrap_err().kind(), io::ErrorKind::Other);
+    assert!(block_on(s.next()).is_none());
+}
+
+#[test]
+fn bytes_retries_interrupted_reads() {
+    struct Interrupted {
+        reads: usize,
+    }
+
+    impl AsyncRead for Interrupted {
+        fn poll_read(
+            mut self: Pin<&mut Self>,
+            _cx: &mut Context<'_>,
+            buf: &mut [u8],
+        ) -> Poll<io::Result<usize>> {
+            self.reads += 1;
+            match self.reads {
+                1 => Poll::Ready(Err(io::ErrorKind::Interrupted.into())),
+                2 => {
+                    buf[..2].copy_from_slice(b"ab");
+                    Poll::Ready(Ok(2))
+                }
+                _ => Poll::Ready(Ok(0)),
+            }
+        }
+    }
+
+    let mut s = Interrupted { reads: 0 }.bytes();
+    assert_eq!(block_on(s.next()).unwrap().unwrap(), b'a');
+    assert_eq!(s.get_ref().reads, 2);
+    assert_eq!(s.buffer(), b"b");
+    assert_eq!(block_on(s.next()).unwrap().unwrap(), b'b');
+    assert!(s.buffer().is_empty());
+    assert!(block_on(s.next()).is_none());
+    assert_eq!(s.into_inner().reads, 3);
+}
@@ -0,0 +1,70 @@
+use futures::executor::block_on;
+use futures::future::{Future, FutureExt};
+use futures::io::{self, AsyncBufReadExt, AsyncReadExt, Cursor};
+use futures::task::Poll;
+use futures_test::io::AsyncReadTestExt;
+use futures_test::task::noop_context;
+
+fn run<F: Future + Unpin>(mut f: F) -> F::Output {
+    let mut cx = noop_context();
+    loop {
+        if let Poll::Ready(x) = f.poll_unpin(&mut cx) {
+            return x;
+        }
+    }
+}
+
+#[test]
+fn chain_all_short_reads() {
+    let readers = vec![&b"hello"[..], &b""[..], &b" "[..], &b"world"[..]]
+        .into_iter()
+        .map(|r| r.limited(2).interleave_pending());
+    let mut reader = io::chain_all(readers);
+
+    let mut buf = Vec::new();
+    assert_eq!(run(reader.read_to_end(&mut buf)).unwrap(), 11);
+    assert_eq!(buf, b"hello world");
+    assert!(reader.is_empty());
+}
+
+#[test]
+fn chain_all_eof_after_last_reader() {
+    let mut reader = io::chain_all(vec![Cursor::new(vec![1, 2]), Cursor::new(vec![])]);
+    assert_eq!(reader.len(), 2);
+
+    let mut buf = [0; 4];
+    assert_eq!(block_on(reader.read(&mut buf)).unwrap(), 2);
+    assert_eq!(block_on(reader.read(&mut buf)).unwrap(), 0);
+    assert!(reader.is_empty());
+
+    // Readers pushed after EOF are read from.
+    reader.push(Cursor::new(vec![3]));
+    reader.push(Cursor::new(vec![4, 5]));
+    assert_eq!(block_on(reader.read(&mut buf)).unwrap(), 1);
+    assert_eq!(buf[0], 3);
+    assert_eq!(block_on(reader.read(&mut buf)).unwrap(), 2);
+    assert_eq!(buf[..2], [4, 5]);
+    assert_eq!(block_on(reader.read(&mut buf)).unwrap(), 0);
+
+    // Reading into an empty buffer doesn't skip readers.
+    reader.push(Cursor::new(vec![6]));
+    assert_eq!(block_on(reader.read(&mut [])).unwrap(), 0);
+    assert_eq!(reader.len(), 1);
+}
+
+#[test]
+fn chain_all_buf_read() {
+    let readers =
+        vec![Cursor::new(&b"lorem\nip"[..]), Cursor::new(&b""[..]), Cursor::new(&b"sum\n"[..])];
+    let mut reader = io::chain_all(readers);
+
+    let mut line = String::new();
+    assert_eq!(block_on(reader.read_line(&mut line)).unwrap(), 6);
+    assert_eq!(line, "lorem\n");
+    line.clear();
+    assert_eq!(block_on(reader.read_line(&mut line)).unwrap(), 6);
+    assert_eq!(line, "ipsum\n");
+    line.clear();
+    assert_eq!(block_on(reader.read_line(&mut line)).unwrap(), 0);
+    assert!(reader.is_empty());
+}
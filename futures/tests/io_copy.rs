@@ -0,0 +1,157 @@
+use futures::executor::{block_on, LocalPool};
+use futures::future::{self, Future};
+use futures::io::{self, AsyncReadExt, BufReader, Cursor};
+use futures::task::{LocalSpawnExt, Poll};
+use futures_test::io::{AsyncReadTestExt, AsyncWriteTestExt};
+use std::cell::Cell;
+use std::rc::Rc;
+
+// Runs `fut` on a `LocalPool` alongside a task which keeps rescheduling itself
+// until `fut` is done, and returns how many times that task got to run.
+fn competing_polls<F: Future<Output = ()> + 'static>(fut: F) -> usize {
+    let mut pool = LocalPool::new();
+    let spawner = pool.spawner();
+    let done = Rc::new(Cell::new(false));
+    let polls = Rc::new(Cell::new(0));
+
+    let done2 = done.clone();
+    spawner
+        .spawn_local(async move {
+            fut.await;
+            done2.set(true);
+        })
+        .unwrap();
+    let polls2 = polls.clone();
+    spawner
+        .spawn_local(future::poll_fn(move |cx| {
+            if done.get() {
+                return Poll::Ready(());
+            }
+            polls2.set(polls2.get() + 1);
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }))
+        .unwrap();
+
+    pool.run();
+    polls.get()
+}
+
+#[test]
+fn copy_with_progress_reports_each_write() {
+    let reader = Cursor::new((0..10).collect::<Vec<u8>>()).limited(3);
+    let mut writer = Vec::new();
+    let mut reported = Vec::new();
+
+    let bytes =
+        block_on(io::copy_with_progress(reader, &mut writer, |n| reported.push(n))).unwrap();
+
+    assert_eq!(bytes, 10);
+    assert_eq!(reported, [3, 6, 9, 10]);
+    assert_eq!(writer, (0..10).collect::<Vec<u8>>());
+}
+
+#[test]
+fn copy_with_progress_short_writes() {
+    let reader = Cursor::new(vec![1, 2, 3, 4, 5]);
+    let mut writer = Vec::new().limited_write(2);
+    let mut reported = Vec::new();
+
+    let bytes =
+        block_on(io::copy_with_progress(reader, &mut writer, |n| reported.push(n))).unwrap();
+
+    assert_eq!(bytes, 5);
+    assert_eq!(reported, [2, 4, 5]);
+    assert_eq!(writer.get_ref(), &[1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn copy_until_limit_cuts_chunk() {
+    let mut reader = Cursor::new((0..10).collect::<Vec<u8>>()).limited(4);
+    let mut writer = Vec::new();
+
+    let res = block_on(io::copy_until_limit(&mut reader, &mut writer, 6)).unwrap();
+    assert_eq!(res, (6, true));
+    assert_eq!(writer, [0, 1, 2, 3, 4, 5]);
+
+    // The rest of the cut chunk is still available from the reader.
+    let mut rest = Vec::new();
+    block_on(reader.read_to_end(&mut rest)).unwrap();
+    assert_eq!(rest, [6, 7, 8, 9]);
+}
+
+#[test]
+fn copy_until_limit_eof() {
+    let reader = Cursor::new(vec![1, 2, 3]);
+    let mut writer = Vec::new();
+
+    let res = block_on(io::copy_until_limit(reader, &mut writer, 6)).unwrap();
+    assert_eq!(res, (3, false));
+    assert_eq!(writer, [1, 2, 3]);
+
+    let res = block_on(io::copy_until_limit(Cursor::new(vec![1]), &mut writer, 0)).unwrap();
+    assert_eq!(res, (0, true));
+    assert_eq!(writer, [1, 2, 3]);
+}
+
+#[test]
+fn copy_buf_yield_threshold() {
+    let data = vec![7u8; 64 * 1024];
+
+    let polls = competing_polls(async move {
+        let reader = BufReader::with_capacity(1024, Cursor::new(data));
+        let mut writer = Vec::new();
+        let bytes = io::copy_buf(reader, &mut writer).with_yield_threshold(4096).await.unwrap();
+        assert_eq!(bytes, 64 * 1024);
+        assert_eq!(writer, vec![7u8; 64 * 1024]);
+    });
+    assert!(polls >= 15, "competing task only ran {} times", polls);
+
+    let polls = competing_polls(async {
+        let reader = BufReader::with_capacity(1024, Cursor::new(vec![7u8; 64 * 1024]));
+        let bytes = io::copy_buf(reader, &mut Vec::new()).await.unwrap();
+        assert_eq!(bytes, 64 * 1024);
+    });
+    assert_eq!(polls, 0);
+}
+
+#[test]
+fn copy_yield_threshold() {
+    let polls = competing_polls(async {
+        let reader = Cursor::new(vec![7u8; 64 * 1024]).limited(1024);
+        let mut writer = Vec::new();
+        let bytes = io::copy(reader, &mut writer).with_yield_threshold(4096).await.unwrap();
+        assert_eq!(bytes, 64 * 1024);
+        assert_eq!(writer, vec![7u8; 64 * 1024]);
+    });
+    assert!(polls >= 15, "competing task only ran {} times", polls);
+}
+
+#[test]
+fn read_to_end_yield_threshold() {
+    let polls = competing_polls(async {
+        let mut reader = Cursor::new(vec![7u8; 64 * 1024]).limited(1024);
+        let mut output = Vec::new();
+        let bytes = reader.read_to_end(&mut output).with_yield_threshold(4096).await.unwrap();
+        assert_eq!(bytes, 64 * 1024);
+        assert_eq!(output, vec![7u8; 64 * 1024]);
+    });
+    assert!(polls >= 15, "competing task only ran {} times", polls);
+}
+
+#[test]
+fn copy_between_test_doubles() {
+    block_on(async {
+        let mut sink = io::sink();
+        assert_eq!(io::copy(io::empty(), &mut sink).await.unwrap(), 0);
+        assert_eq!(io::copy(io::repeat(7).take(10_000), &mut sink).await.unwrap(), 10_000);
+
+        let mut buf = Vec::new();
+        io::repeat(7).take(5).read_to_end(&mut buf).await.unwrap();
+        assert_eq!(buf, [7; 5]);
+
+        buf.clear();
+        io::empty().read_to_end(&mut buf).await.unwrap();
+        assert!(buf.is_empty());
+    });
+}
@@ -0,0 +1,164 @@
+use futures::executor::block_on;
+use futures::io::{
+    self, AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, CopyBidirectionalError,
+};
+use futures::join;
+use futures::task::{Context, Poll, Waker};
+use futures_test::io::AsyncWriteTestExt;
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+
+#[derive(Default)]
+struct Pipe {
+    buf: VecDeque<u8>,
+    closed: bool,
+    reader: Option<Waker>,
+}
+
+/// One end of an in-memory duplex connection.
+struct Duplex {
+    read: Arc<Mutex<Pipe>>,
+    write: Arc<Mutex<Pipe>>,
+}
+
+fn duplex() -> (Duplex, Duplex) {
+    let a = Arc::new(Mutex::new(Pipe::default()));
+    let b = Arc::new(Mutex::new(Pipe::default()));
+    (Duplex { read: a.clone(), write: b.clone() }, Duplex { read: b, write: a })
+}
+
+impl AsyncRead for Duplex {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<std::io::Result<usize>> {
+        let mut pipe = self.read.lock().unwrap();
+        if pipe.buf.is_empty() && !pipe.closed {
+            pipe.reader = Some(cx.waker().clone());
+            return Poll::Pending;
+        }
+        let n = buf.len().min(pipe.buf.len());
+        for (dst, src) in buf.iter_mut().zip(pipe.buf.drain(..n)) {
+            *dst = src;
+        }
+        Poll::Ready(Ok(n))
+    }
+}
+
+impl AsyncWrite for Duplex {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        _: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<std::io::Result<usize>> {
+        let mut pipe = self.write.lock().unwrap();
+        if pipe.closed {
+            return Poll::Ready(Err(std::io::ErrorKind::BrokenPipe.into()));
+        }
+        pipe.buf.extend(buf);
+        if let Some(waker) = pipe.reader.take() {
+            waker.wake();
+        }
+        Poll::Ready(Ok(buf.len()))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        let mut pipe = self.write.lock().unwrap();
+        pipe.closed = true;
+        if let Some(waker) = pipe.reader.take() {
+            waker.wake();
+        }
+        Poll::Ready(Ok(()))
+    }
+}
+
+#[test]
+fn copy_bidirectional_half_close() {
+    let (mut client, mut proxy_client) = duplex();
+    let (mut proxy_server, mut server) = duplex();
+
+    let client = async move {
+        client.write_all(b"request").await.unwrap();
+        client.close().await.unwrap();
+        let mut response = Vec::new();
+        client.read_to_end(&mut response).await.unwrap();
+        response
+    };
+    let server = async move {
+        // The server only sees EOF once the client closed its side.
+        let mut request = Vec::new();
+        server.read_to_end(&mut request).await.unwrap();
+        assert_eq!(request, b"request");
+        server.write_all(b"response to ").await.unwrap();
+        server.write_all(&request).await.unwrap();
+        server.close().await.unwrap();
+    };
+    let proxy = io::copy_bidirectional(&mut proxy_client, &mut proxy_server);
+
+    let (response, (), amounts) = block_on(async { join!(client, server, proxy) });
+    assert_eq!(response, b"response to request");
+    assert_eq!(amounts.unwrap(), (7, 19));
+}
+
+#[test]
+fn copy_bidirectional_asymmetric() {
+    let (mut client, proxy_client) = duplex();
+    let (proxy_server, mut server) = duplex();
+    let mut proxy_client = proxy_client.limited_write(3).interleave_pending_write();
+    let mut proxy_server = proxy_server.limited_write(1000).interleave_pending_write();
+
+    let data: Vec<u8> = (0..100_000).map(|i| i as u8).collect();
+    let expected = data.clone();
+    let client = async move {
+        client.write_all(b"get").await.unwrap();
+        let mut response = Vec::new();
+        client.read_to_end(&mut response).await.unwrap();
+        client.close().await.unwrap();
+        response
+    };
+    let server = async move {
+        let mut request = [0; 3];
+        server.read_exact(&mut request).await.unwrap();
+        assert_eq!(&request, b"get");
+        server.write_all(&data).await.unwrap();
+        server.close().await.unwrap();
+    };
+    let proxy = io::copy_bidirectional(&mut proxy_client, &mut proxy_server);
+
+    let (response, (), amounts) = block_on(async { join!(client, server, proxy) });
+    assert!(response == expected);
+    assert_eq!(amounts.unwrap(), (3, 100_000));
+}
+
+#[test]
+fn copy_bidirectional_error_reports_progress() {
+    let (mut client, mut proxy_client) = duplex();
+    let (mut proxy_server, mut server) = duplex();
+
+    let client = async move {
+        let mut greeting = [0; 7];
+        client.read_exact(&mut greeting).await.unwrap();
+        assert_eq!(&greeting, b"welcome");
+        client.write_all(b"hello").await.unwrap();
+    };
+    let server = async move {
+        server.write_all(b"welcome").await.unwrap();
+        // The server resets the connection, so the proxy can't write to it.
+        server.read.lock().unwrap().closed = true;
+    };
+    let proxy = io::copy_bidirectional(&mut proxy_client, &mut proxy_server);
+
+    let ((), (), result) = block_on(async { join!(client, server, proxy) });
+    let err = result.unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::BrokenPipe);
+    let err = err.into_inner().unwrap().downcast::<CopyBidirectionalError>().unwrap();
+    assert_eq!(err.error().kind(), std::io::ErrorKind::BrokenPipe);
+    assert_eq!(err.a_to_b(), 0);
+    assert_eq!(err.b_to_a(), 7);
+}
@@ -1,8 +1,13 @@
 use assert_matches::assert_matches;
-use futures::executor::block_on;
-use futures::future::lazy;
-use futures::io::{AsyncWrite, Cursor};
-use futures::task::Poll;
+use futures::executor::{block_on, LocalPool};
+use futures::future::{lazy, ready, FutureExt};
+use futures::io::{
+    AsyncRead, AsyncReadExt, AsyncSeek, AsyncSeekExt, AsyncWrite, AsyncWriteExt, Cursor, SeekFrom,
+};
+use futures::task::{Context, LocalSpawnExt, Poll};
+use futures_test::future::FutureTestExt;
+use futures_test::task::new_count_waker;
+use std::io;
 use std::pin::Pin;
 
 #[test]
@@ -28,3 +33,152 @@ fn cursor_asyncwrite_box() {
     }));
     assert_eq!(&*cursor.into_inner(), [1, 2, 3, 4, 5]);
 }
+
+#[test]
+fn cursor_split_rw_has_independent_positions() {
+    let mut cursor = Cursor::new(b"abc".to_vec());
+    cursor.set_position(1);
+    let (mut reader, mut writer) = cursor.split_rw();
+    assert_eq!(reader.position(), 1);
+    assert_eq!(writer.position(), 3);
+
+    block_on(async {
+        let mut buf = [0; 4];
+        assert_eq!(reader.read(&mut buf).await.unwrap(), 2);
+        assert_eq!(&buf[..2], b"bc");
+        // Caught up with the writer, which is still open.
+        assert!(reader.read(&mut buf).now_or_never().is_none());
+
+        writer.write_all(b"defg").await.unwrap();
+        assert_eq!(writer.position(), 7);
+        assert_eq!(reader.position(), 3);
+
+        assert_eq!(reader.read(&mut buf[..3]).await.unwrap(), 3);
+        assert_eq!(&buf[..3], b"def");
+        writer.write_all(b"h").await.unwrap();
+        writer.close().await.unwrap();
+        let mut rest = Vec::new();
+        reader.read_to_end(&mut rest).await.unwrap();
+        assert_eq!(rest, b"gh");
+    });
+}
+
+#[test]
+fn cursor_split_rw_read_waits_for_writer() {
+    let (mut reader, mut writer) = Cursor::new(Vec::new()).split_rw();
+    let (waker, count) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+    let mut buf = [0; 4];
+
+    assert_matches!(Pin::new(&mut reader).poll_read(&mut cx, &mut buf), Poll::Pending);
+    block_on(writer.write_all(b"hi")).unwrap();
+    assert_eq!(count, 1);
+    assert_matches!(Pin::new(&mut reader).poll_read(&mut cx, &mut buf), Poll::Ready(Ok(2)));
+    assert_eq!(&buf[..2], b"hi");
+
+    assert_matches!(Pin::new(&mut reader).poll_read(&mut cx, &mut buf), Poll::Pending);
+    drop(writer);
+    assert_eq!(count, 2);
+    assert_matches!(Pin::new(&mut reader).poll_read(&mut cx, &mut buf), Poll::Ready(Ok(0)));
+}
+
+#[test]
+fn cursor_split_rw_across_tasks() {
+    let (mut reader, mut writer) = Cursor::new(Vec::new()).split_rw();
+    let mut pool = LocalPool::new();
+    pool.spawner()
+        .spawn_local(async move {
+            for i in 0..100u8 {
+                writer.write_all(&[i]).await.unwrap();
+                ready(()).pending_once().await;
+            }
+        })
+        .unwrap();
+
+    let received = pool.run_until(async move {
+        let mut received = Vec::new();
+        while received.len() < 100 {
+            let mut buf = [0; 16];
+            let n = reader.read(&mut buf).await.unwrap();
+            received.extend_from_slice(&buf[..n]);
+            ready(()).pending_once().await;
+        }
+        received
+    });
+    assert_eq!(received, (0..100).collect::<Vec<u8>>());
+}
+
+#[test]
+fn cursor_stream_position_and_rewind() {
+    let mut cursor = Cursor::new(Vec::new());
+    block_on(async {
+        cursor.write_all(b"hello").await.unwrap();
+        assert_eq!(cursor.stream_position().await.unwrap(), 5);
+        cursor.rewind().await.unwrap();
+        assert_eq!(cursor.stream_position().await.unwrap(), 0);
+
+        let mut buf = String::new();
+        cursor.read_to_string(&mut buf).await.unwrap();
+        assert_eq!(buf, "hello");
+    });
+}
+
+#[test]
+fn cursor_stream_len_keeps_position() {
+    let mut cursor = Cursor::new(b"hello world");
+    block_on(async {
+        assert_eq!(cursor.seek(SeekFrom::Start(3)).await.unwrap(), 3);
+        assert_eq!(cursor.stream_len().await.unwrap(), 11);
+        assert_eq!(cursor.stream_position().await.unwrap(), 3);
+    });
+}
+
+// Seeker which counts its seeks, and fails seeking to `fail_at`.
+struct CountingSeek {
+    inner: Cursor<Vec<u8>>,
+    seeks: usize,
+    fail_at: Option<SeekFrom>,
+}
+
+impl AsyncSeek for CountingSeek {
+    fn poll_seek(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        pos: SeekFrom,
+    ) -> Poll<io::Result<u64>> {
+        self.seeks += 1;
+        if self.fail_at == Some(pos) {
+            return Poll::Ready(Err(io::ErrorKind::Other.into()));
+        }
+        Pin::new(&mut self.inner).poll_seek(cx, pos)
+    }
+}
+
+#[test]
+fn stream_len_skips_restore_at_end() {
+    let mut seek = CountingSeek { inner: Cursor::new(vec![0; 8]), seeks: 0, fail_at: None };
+    block_on(async {
+        assert_eq!(seek.stream_len().await.unwrap(), 8);
+        assert_eq!(seek.seeks, 3);
+        assert_eq!(seek.stream_position().await.unwrap(), 0);
+
+        seek.seek(SeekFrom::End(0)).await.unwrap();
+        seek.seeks = 0;
+        assert_eq!(seek.stream_len().await.unwrap(), 8);
+        assert_eq!(seek.seeks, 2);
+    });
+}
+
+#[test]
+fn stream_len_reports_restore_error() {
+    let mut seek = CountingSeek {
+        inner: Cursor::new(vec![0; 8]),
+        seeks: 0,
+        fail_at: Some(SeekFrom::Start(2)),
+    };
+    block_on(async {
+        seek.inner.set_position(2);
+        assert_eq!(seek.stream_len().await.unwrap_err().kind(), io::ErrorKind::Other);
+        assert_eq!(seek.seeks, 3);
+    });
+}
@@ -0,0 +1,52 @@
+use futures::executor::block_on;
+use futures::io::{self, AsyncReadExt, Cursor};
+use futures_test::io::{AsyncReadTestExt, AsyncWriteTestExt};
+
+#[test]
+fn frame_round_trip() {
+    let mut writer = Vec::new().limited_write(3).interleave_pending_write();
+    block_on(async {
+        io::write_frame(&mut writer, b"hello").await.unwrap();
+        io::write_frame(&mut writer, b"").await.unwrap();
+        io::write_frame(&mut writer, &[7; 300]).await.unwrap();
+    });
+    let data = writer.into_inner().into_inner();
+    assert_eq!(&data[..9], b"\x00\x00\x00\x05hello");
+
+    let mut reader = data.as_slice().limited(2).interleave_pending();
+    let mut buf = Vec::new();
+    block_on(async {
+        assert_eq!(io::read_frame(&mut reader, 300).await.unwrap().unwrap(), b"hello");
+        assert_eq!(io::read_frame_into(&mut reader, &mut buf, 300).await.unwrap(), Some(0));
+        assert!(buf.is_empty());
+        assert_eq!(io::read_frame_into(&mut reader, &mut buf, 300).await.unwrap(), Some(300));
+        assert_eq!(buf, [7; 300]);
+
+        // EOF between frames isn't an error.
+        assert_eq!(io::read_frame(&mut reader, 300).await.unwrap(), None);
+        assert_eq!(io::read_frame_into(&mut reader, &mut buf, 300).await.unwrap(), None);
+    });
+}
+
+#[test]
+fn read_frame_too_long() {
+    let mut reader = Cursor::new(b"\x00\x00\x01\x00rest".to_vec());
+    block_on(async {
+        let err = io::read_frame(&mut reader, 255).await.unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+        // Only the prefix has been consumed.
+        let mut rest = String::new();
+        reader.read_to_string(&mut rest).await.unwrap();
+        assert_eq!(rest, "rest");
+    });
+}
+
+#[test]
+fn read_frame_truncated() {
+    for data in [&b"\x00"[..], &b"\x00\x00"[..], &b"\x00\x00\x00\x04abc"[..]].iter() {
+        let mut reader = Cursor::new(data);
+        let err = block_on(io::read_frame(&mut reader, 16)).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+    }
+}
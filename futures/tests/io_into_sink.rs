@@ -0,0 +1,95 @@
+use futures::executor::block_on;
+use futures::io::{AsyncWrite, AsyncWriteExt};
+use futures::sink::SinkExt;
+use futures::stream::{self, StreamExt};
+use futures::task::{Context, Poll};
+use futures_test::io::AsyncWriteTestExt;
+use std::io;
+use std::pin::Pin;
+
+// Writer which counts its writes and flushes.
+#[derive(Default)]
+struct CountingWriter {
+    data: Vec<u8>,
+    writes: usize,
+    flushes: usize,
+}
+
+impl AsyncWrite for CountingWriter {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        _: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        self.writes += 1;
+        self.data.extend_from_slice(buf);
+        Poll::Ready(Ok(buf.len()))
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.flushes += 1;
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+fn items() -> Vec<Vec<u8>> {
+    (0..100u8).map(|i| vec![i; usize::from(i % 7)]).collect()
+}
+
+fn expected() -> Vec<u8> {
+    items().concat()
+}
+
+#[test]
+fn into_sink_buffered_coalesces_writes() {
+    let mut unbuffered = CountingWriter::default();
+    let mut buffered = CountingWriter::default();
+    block_on(async {
+        stream::iter(items()).forward((&mut unbuffered).into_sink::<Vec<u8>>()).await.unwrap();
+        stream::iter(items())
+            .forward((&mut buffered).into_sink_buffered::<Vec<u8>>(64))
+            .await
+            .unwrap();
+    });
+
+    assert_eq!(unbuffered.data, expected());
+    assert_eq!(buffered.data, expected());
+    // Every non-empty item is written on its own without a buffer.
+    assert_eq!(unbuffered.writes, 85);
+    assert_eq!(buffered.writes, 5);
+}
+
+#[test]
+fn into_sink_buffered_writes_on_flush() {
+    let mut writer = CountingWriter::default();
+    block_on(async {
+        let mut sink = (&mut writer).into_sink_buffered(16);
+        sink.feed(b"abc".to_vec()).await.unwrap();
+        sink.feed(b"def".to_vec()).await.unwrap();
+        sink.flush().await.unwrap();
+        // Larger than the buffer, so written directly after the buffer.
+        sink.feed(b"g".to_vec()).await.unwrap();
+        sink.feed(vec![b'h'; 20]).await.unwrap();
+        sink.close().await.unwrap();
+    });
+
+    let mut data = b"abcdefg".to_vec();
+    data.extend_from_slice(&[b'h'; 20]);
+    assert_eq!(writer.data, data);
+    assert_eq!(writer.writes, 3);
+    assert_eq!(writer.flushes, 1);
+}
+
+#[test]
+fn into_sink_buffered_partial_writes() {
+    let mut data = Vec::new();
+    block_on(async {
+        let writer = (&mut data).limited_write(3).interleave_pending_write();
+        stream::iter(items()).forward(writer.into_sink_buffered::<Vec<u8>>(10)).await.unwrap();
+    });
+    assert_eq!(data, expected());
+}
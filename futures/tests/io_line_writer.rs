@@ -1,5 +1,6 @@
 use futures::executor::block_on;
-use futures::io::{AsyncWriteExt, LineWriter};
+use futures::io::{AsyncWrite, AsyncWriteExt, LineWriter};
+use futures_test::io::AsyncWriteTestExt;
 use std::io;
 
 #[test]
@@ -25,6 +26,35 @@ fn line_writer() {
     assert_eq!(*writer.get_ref(), [0, 1, 0, b'\n', 1, b'\n', 2, 3, b'\n']);
 }
 
+#[test]
+fn line_writer_one_byte_per_poll() {
+    let inner = Vec::new().limited_write(1).interleave_pending_write();
+    let mut writer = LineWriter::new(inner);
+
+    block_on(writer.write_all(b"ab")).unwrap();
+    assert_eq!(writer.get_ref().get_ref().get_ref(), b"");
+
+    block_on(writer.write_all(b"c\nde\nf")).unwrap();
+    assert_eq!(writer.get_ref().get_ref().get_ref(), b"abc\nde\n");
+    assert_eq!(writer.buffer(), b"f");
+
+    block_on(writer.write_all(b"gh")).unwrap();
+    assert_eq!(writer.get_ref().get_ref().get_ref(), b"abc\nde\n");
+
+    // The inner writer only accepts the first newline, the second one is
+    // buffered and written out before any further data.
+    block_on(writer.write_all(b"\n\n")).unwrap();
+    assert_eq!(writer.get_ref().get_ref().get_ref(), b"abc\nde\nfgh\n");
+    assert_eq!(writer.buffer(), b"\n");
+
+    block_on(writer.write_all(b"i")).unwrap();
+    assert_eq!(writer.get_ref().get_ref().get_ref(), b"abc\nde\nfgh\n\n");
+    assert_eq!(writer.buffer(), b"i");
+
+    block_on(writer.close()).unwrap();
+    assert_eq!(writer.get_ref().get_ref().get_ref(), b"abc\nde\nfgh\n\ni");
+}
+
 #[test]
 fn line_vectored() {
     let mut line_writer = LineWriter::new(Vec::new());
@@ -71,3 +101,22 @@ fn line_vectored() {
     assert_eq!(block_on(line_writer.write_vectored(&[io::IoSlice::new(b"a\nb")])).unwrap(), 3);
     assert_eq!(line_writer.get_ref(), b"\nabaca\nb");
 }
+
+#[test]
+fn line_vectored_without_vectored_inner() {
+    let mut line_writer = LineWriter::new(Vec::new().limited_write(usize::max_value()));
+    assert!(!line_writer.is_write_vectored());
+
+    // Only the first non-empty buffer is written, like a single write.
+    assert_eq!(
+        block_on(line_writer.write_vectored(&[
+            io::IoSlice::new(&[]),
+            io::IoSlice::new(b"a\nb"),
+            io::IoSlice::new(b"c\n"),
+        ]))
+        .unwrap(),
+        3
+    );
+    assert_eq!(line_writer.get_ref().get_ref(), b"a\n");
+    assert_eq!(line_writer.buffer(), b"b");
+}
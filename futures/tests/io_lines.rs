@@ -1,6 +1,6 @@
 use futures::executor::block_on;
 use futures::future::{Future, FutureExt};
-use futures::io::{AsyncBufReadExt, Cursor};
+use futures::io::{self, AsyncBufReadExt, Cursor, LineTooLong, LineTooLongError};
 use futures::stream::{self, StreamExt, TryStreamExt};
 use futures::task::Poll;
 use futures_test::io::AsyncReadTestExt;
@@ -58,3 +58,51 @@ fn maybe_pending() {
     assert_eq!(run_next!(s), "".to_string());
     assert!(run(s.next()).is_none());
 }
+
+fn assert_too_long(res: Option<io::Result<String>>, limit: usize) {
+    let err = res.unwrap().unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    let inner = err.get_ref().unwrap().downcast_ref::<LineTooLongError>().unwrap();
+    assert_eq!(inner.limit(), limit);
+}
+
+#[test]
+fn lines_with_max_skip() {
+    let buf = Cursor::new(&b"12345\r\n123456\n1234567890\r\n12\r"[..]);
+    let mut s = buf.lines_with_max(5, LineTooLong::Skip);
+    assert_eq!(block_on_next!(s), "12345".to_string());
+    assert_too_long(block_on(s.next()), 5);
+    assert_too_long(block_on(s.next()), 5);
+    assert_eq!(block_on_next!(s), "12\r".to_string());
+    assert!(block_on(s.next()).is_none());
+    assert!(block_on(s.next()).is_none());
+}
+
+#[test]
+fn lines_with_max_terminate() {
+    let buf = Cursor::new(&b"1\n1234567890\n2\n"[..]);
+    let mut s = buf.lines_with_max(5, LineTooLong::Terminate);
+    assert_eq!(block_on_next!(s), "1".to_string());
+    assert_too_long(block_on(s.next()), 5);
+    assert!(block_on(s.next()).is_none());
+}
+
+#[test]
+fn lines_with_max_maybe_pending() {
+    let buf =
+        stream::iter(vec![&b"12"[..], &b"34\r"[..], &b"\n1234"[..], &b"56789"[..], &b"\n\n"[..]])
+            .map(Ok)
+            .into_async_read()
+            .interleave_pending();
+    let mut s = buf.lines_with_max(4, LineTooLong::Skip);
+    assert_eq!(run_next!(s), "1234".to_string());
+    assert_too_long(run(s.next()), 4);
+    assert_eq!(run_next!(s), "".to_string());
+    assert!(run(s.next()).is_none());
+
+    // The overlong line is detected without waiting for its end.
+    let buf = stream::iter(vec![&b"12345"[..]]).map(Ok).chain(stream::pending()).into_async_read();
+    let mut s = buf.lines_with_max(4, LineTooLong::Terminate);
+    assert_too_long(run(s.next()), 4);
+    assert!(run(s.next()).is_none());
+}
@@ -0,0 +1,149 @@
+use futures::executor::block_on;
+use futures::io::{AllowStdIo, AsyncRead, AsyncReadExt, BufReader, ReadBuf};
+use futures::task::{Context, Poll};
+use std::io;
+use std::mem::MaybeUninit;
+use std::pin::Pin;
+
+// Reader which fills buffers without initializing them first, and records
+// how much of each buffer was initialized when it was offered.
+struct UninitReader {
+    remaining: usize,
+    offered: Vec<(usize, usize)>,
+}
+
+impl AsyncRead for UninitReader {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let mut buf = ReadBuf::new(buf);
+        futures::ready!(self.as_mut().poll_read_buf(cx, &mut buf))?;
+        Poll::Ready(Ok(buf.filled().len()))
+    }
+
+    fn poll_read_buf(
+        mut self: Pin<&mut Self>,
+        _: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        self.offered.push((buf.initialized().len() - buf.filled().len(), buf.remaining()));
+        let n = self.remaining.min(buf.remaining());
+        unsafe {
+            for b in &mut buf.unfilled_mut()[..n] {
+                *b = MaybeUninit::new(7);
+            }
+            buf.assume_init(n);
+        }
+        buf.advance(n);
+        self.remaining -= n;
+        Poll::Ready(Ok(()))
+    }
+}
+
+// Reader which only implements `poll_read`, returning more than it was asked
+// for.
+struct OverreportingReader;
+
+impl AsyncRead for OverreportingReader {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        _: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        Poll::Ready(Ok(buf.len() + 1))
+    }
+}
+
+// Reader which replaces the buffer it is given with one of its own.
+struct SwappingReader;
+
+impl AsyncRead for SwappingReader {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        _: &mut Context<'_>,
+        _: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        unimplemented!()
+    }
+
+    fn poll_read_buf(
+        self: Pin<&mut Self>,
+        _: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let mut own = ReadBuf::new(Box::leak(vec![1; 1 << 16].into_boxed_slice()));
+        own.advance(1 << 16);
+        *buf = own;
+        Poll::Ready(Ok(()))
+    }
+}
+
+#[test]
+fn read_buf_tracks_initialized() {
+    let mut storage = [MaybeUninit::new(9); 8];
+    let mut buf = ReadBuf::uninit(&mut storage);
+    assert_eq!((buf.capacity(), buf.remaining(), buf.initialized().len()), (8, 8, 0));
+
+    buf.put_slice(b"abc");
+    assert_eq!(buf.filled(), b"abc");
+    assert_eq!(buf.initialized().len(), 3);
+
+    // Only the bytes which weren't initialized yet are zeroed.
+    assert_eq!(buf.initialize_unfilled(), [0; 5]);
+    buf.initialize_unfilled()[0] = b'd';
+    buf.advance(1);
+    buf.clear();
+    assert_eq!(buf.filled(), b"");
+    assert_eq!(buf.initialized(), b"abcd\0\0\0\0");
+}
+
+#[test]
+#[should_panic(expected = "filled must not become larger than initialized")]
+fn read_buf_advance_past_initialized() {
+    let mut storage = [MaybeUninit::uninit(); 8];
+    ReadBuf::uninit(&mut storage).advance(1);
+}
+
+#[test]
+fn read_to_end_skips_initializing() {
+    let mut reader = UninitReader { remaining: 100_000, offered: Vec::new() };
+    let mut data = Vec::new();
+    assert_eq!(block_on(reader.read_to_end(&mut data)).unwrap(), 100_000);
+    assert!(data.iter().all(|&b| b == 7));
+    // None of the spare capacity was initialized for the reader.
+    assert!(reader.offered.iter().all(|&(initialized, _)| initialized == 0));
+}
+
+#[test]
+fn read_to_end_with_poll_read() {
+    let mut reader = AllowStdIo::new(io::Read::take(io::repeat(3), 100_000));
+    let mut data = Vec::new();
+    assert_eq!(block_on(reader.read_to_end(&mut data)).unwrap(), 100_000);
+    assert!(data.iter().all(|&b| b == 3));
+}
+
+#[test]
+fn buf_reader_and_read_exact_use_read_buf() {
+    let inner = UninitReader { remaining: 10, offered: Vec::new() };
+    let mut reader = BufReader::with_capacity(4, inner);
+    let mut buf = [0; 6];
+    block_on(reader.read_exact(&mut buf)).unwrap();
+    assert_eq!(buf, [7; 6]);
+    // The buffer of the `BufReader` is initialized, so is the one passed
+    // directly to the inner reader by `read_exact`.
+    assert_eq!(reader.get_ref().offered, [(6, 6)]);
+}
+
+#[test]
+#[should_panic(expected = "assertion failed: n <= buf.len()")]
+fn read_to_end_overreporting_reader() {
+    let _ = block_on(OverreportingReader.read_to_end(&mut Vec::new()));
+}
+
+#[test]
+#[should_panic(expected = "`poll_read_buf` must not replace the buffer it is given")]
+fn read_to_end_swapping_reader() {
+    let _ = block_on(SwappingReader.read_to_end(&mut Vec::new()));
+}
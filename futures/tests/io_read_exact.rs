@@ -15,3 +15,46 @@ fn read_exact() {
     assert!(res.is_err());
     assert_eq!(reader.len(), 0);
 }
+
+#[test]
+fn read_exact_or_eof() {
+    use futures::io::{self, AsyncRead, ReadExactError};
+    use futures::task::{Context, Poll};
+    use std::pin::Pin;
+
+    struct Chunked {
+        data: Vec<u8>,
+        chunks: Vec<usize>,
+    }
+
+    impl AsyncRead for Chunked {
+        fn poll_read(
+            mut self: Pin<&mut Self>,
+            _cx: &mut Context<'_>,
+            buf: &mut [u8],
+        ) -> Poll<io::Result<usize>> {
+            let this = &mut *self;
+            let n = if this.chunks.is_empty() { 0 } else { this.chunks.remove(0) };
+            let n = n.min(buf.len()).min(this.data.len());
+            buf[..n].copy_from_slice(&this.data[..n]);
+            this.data.drain(..n);
+            Poll::Ready(Ok(n))
+        }
+    }
+
+    // A 4-byte header followed by a frame truncated after 5 of its 8 bytes.
+    let mut reader = Chunked { data: (1..=9).collect(), chunks: vec![1, 3, 2, 1, 2] };
+
+    let mut header = [0u8; 4];
+    block_on(reader.read_exact_or_eof(&mut header)).unwrap();
+    assert_eq!(header, [1, 2, 3, 4]);
+
+    let mut frame = [0u8; 8];
+    let err = block_on(reader.read_exact_or_eof(&mut frame)).unwrap_err();
+    assert!(matches!(err, ReadExactError::Eof { read: 5 }));
+    assert_eq!(frame[..5], [5, 6, 7, 8, 9]);
+
+    let err = block_on(reader.read_exact_or_eof(&mut frame)).unwrap_err();
+    assert!(matches!(err, ReadExactError::Eof { read: 0 }));
+    assert_eq!(io::Error::from(err).kind(), io::ErrorKind::UnexpectedEof);
+}
@@ -63,3 +63,34 @@ fn issue2310() {
         read.read_to_end(&mut vec.inner).await.unwrap();
     })
 }
+
+#[test]
+fn read_to_end_limited() {
+    use futures::io::{Cursor, ReadToEndError};
+
+    // limit equal to the payload
+    let mut reader = Cursor::new(&b"12345"[..]);
+    let mut buf = Vec::new();
+    assert_eq!(block_on(reader.read_to_end_limited(&mut buf, 5)).unwrap(), 5);
+    assert_eq!(buf, b"12345");
+
+    // limit one byte smaller than the payload
+    let mut reader = Cursor::new(&b"12345"[..]);
+    let mut buf = Vec::new();
+    let err = block_on(reader.read_to_end_limited(&mut buf, 4)).unwrap_err();
+    assert!(matches!(err, ReadToEndError::LimitExceeded { limit: 4 }));
+    assert_eq!(buf, b"12345");
+    assert_eq!(reader.position(), 5);
+
+    // zero limit
+    let mut reader = Cursor::new(&b"12345"[..]);
+    let mut buf = vec![b'0'];
+    let err = block_on(reader.read_to_end_limited(&mut buf, 0)).unwrap_err();
+    assert!(matches!(err, ReadToEndError::LimitExceeded { limit: 0 }));
+    assert_eq!(buf, b"01");
+    assert_eq!(reader.position(), 1);
+
+    let mut reader = Cursor::new(&b""[..]);
+    let mut buf = Vec::new();
+    assert_eq!(block_on(reader.read_to_end_limited(&mut buf, 0)).unwrap(), 0);
+}
@@ -42,3 +42,30 @@ fn interleave_pending() {
     assert_eq!(run(buf.read_to_string(&mut v)).unwrap(), 5);
     assert_eq!(v, "12333");
 }
+
+#[test]
+fn read_to_string_limited() {
+    use futures::io::ReadToEndError;
+
+    let mut c = Cursor::new(&b"1234"[..]);
+    let mut v = String::new();
+    assert_eq!(block_on(c.read_to_string_limited(&mut v, 4)).unwrap(), 4);
+    assert_eq!(v, "1234");
+
+    let mut c = Cursor::new(&b"1234"[..]);
+    let mut v = String::new();
+    let err = block_on(c.read_to_string_limited(&mut v, 3)).unwrap_err();
+    assert!(matches!(err, ReadToEndError::LimitExceeded { limit: 3 }));
+    assert_eq!(v, "1234");
+
+    // The limit splits the two-byte `é` in half.
+    let mut c = Cursor::new("aéb".as_bytes());
+    let mut v = String::new();
+    let err = block_on(c.read_to_string_limited(&mut v, 1)).unwrap_err();
+    assert!(matches!(err, ReadToEndError::LimitExceeded { limit: 1 }));
+    assert_eq!(v, "a");
+
+    let mut c = Cursor::new(&b"\xff"[..]);
+    let mut v = String::new();
+    assert!(matches!(block_on(c.read_to_string_limited(&mut v, 4)), Err(ReadToEndError::Io(_))));
+}
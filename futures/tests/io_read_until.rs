@@ -1,6 +1,6 @@
 use futures::executor::block_on;
 use futures::future::{Future, FutureExt};
-use futures::io::{AsyncBufReadExt, Cursor};
+use futures::io::{self, AsyncBufReadExt, Cursor, ReadUntilLimitExceeded};
 use futures::stream::{self, StreamExt, TryStreamExt};
 use futures::task::Poll;
 use futures_test::io::AsyncReadTestExt;
@@ -58,3 +58,88 @@ fn maybe_pending() {
     assert_eq!(run(buf.read_until(b'3', &mut v)).unwrap(), 0);
     assert_eq!(v, []);
 }
+
+fn assert_limit_exceeded(res: io::Result<usize>, limit: usize) {
+    let err = res.unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    let inner = err.get_ref().unwrap().downcast_ref::<ReadUntilLimitExceeded>().unwrap();
+    assert_eq!(inner.limit(), limit);
+}
+
+#[test]
+fn read_until_limited() {
+    // The delimiter arrives exactly at the limit.
+    let mut buf = Cursor::new(b"1233");
+    let mut v = Vec::new();
+    assert_eq!(block_on(buf.read_until_limited(b'3', &mut v, 3)).unwrap(), 3);
+    assert_eq!(v, b"123");
+
+    // The delimiter arrives just after the limit.
+    let mut buf = Cursor::new(b"1233");
+    let mut v = Vec::new();
+    assert_limit_exceeded(block_on(buf.read_until_limited(b'3', &mut v, 2)), 2);
+    assert_eq!(v, b"12");
+    v.clear();
+    assert_eq!(block_on(buf.read_until_limited(b'3', &mut v, 2)).unwrap(), 1);
+    assert_eq!(v, b"3");
+
+    // EOF before the limit.
+    let mut buf = Cursor::new(b"12");
+    let mut v = Vec::new();
+    assert_eq!(block_on(buf.read_until_limited(b'3', &mut v, 5)).unwrap(), 2);
+    assert_eq!(v, b"12");
+
+    let mut buf = Cursor::new(b"12");
+    let mut v = Vec::new();
+    assert_limit_exceeded(block_on(buf.read_until_limited(b'3', &mut v, 0)), 0);
+    assert_eq!(v, []);
+}
+
+#[test]
+fn read_until_limited_maybe_pending() {
+    // The limit is smaller than the buffered chunk, which must only be
+    // consumed up to the limit.
+    let mut buf = stream::iter(vec![&b"12"[..], &b"4567893"[..], &b"3"[..]])
+        .map(Ok)
+        .into_async_read()
+        .interleave_pending();
+    let mut v = Vec::new();
+    assert_limit_exceeded(run(buf.read_until_limited(b'3', &mut v, 4)), 4);
+    assert_eq!(v, b"1245");
+    v.clear();
+    assert_eq!(run(buf.read_until_limited(b'3', &mut v, 5)).unwrap(), 5);
+    assert_eq!(v, b"67893");
+    v.clear();
+    assert_eq!(run(buf.read_until_limited(b'3', &mut v, 5)).unwrap(), 1);
+    assert_eq!(v, b"3");
+    v.clear();
+    assert_eq!(run(buf.read_until_limited(b'3', &mut v, 5)).unwrap(), 0);
+    assert_eq!(v, []);
+}
+
+#[test]
+fn skip_until_across_buffers() {
+    let mut buf = stream::iter(vec![&b"ab"[..], &b"c\nd"[..], &b"ef"[..]])
+        .map(Ok)
+        .into_async_read()
+        .interleave_pending();
+    assert_eq!(run(buf.skip_until(b'\n')).unwrap(), 4);
+    // EOF before the delimiter.
+    assert_eq!(run(buf.skip_until(b'\n')).unwrap(), 3);
+    assert_eq!(run(buf.skip_until(b'\n')).unwrap(), 0);
+}
+
+#[test]
+fn skip_exact() {
+    let mut buf = stream::iter(vec![&b"12"[..], &b"345"[..], &b"6"[..]])
+        .map(Ok)
+        .into_async_read()
+        .interleave_pending();
+    run(buf.skip_exact(3)).unwrap();
+    let mut v = Vec::new();
+    assert_eq!(run(buf.read_until(b'5', &mut v)).unwrap(), 2);
+    assert_eq!(v, b"45");
+
+    let err = run(buf.skip_exact(2)).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+}
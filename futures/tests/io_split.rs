@@ -0,0 +1,86 @@
+use futures::executor::block_on;
+use futures::io::{
+    AsyncBufReadExt, AsyncReadExt, AsyncSeekExt, AsyncWrite, AsyncWriteExt, Cursor, SeekFrom,
+};
+
+#[test]
+fn reunite_matching_halves() {
+    let (read, write) = Cursor::new(vec![1, 2, 3]).split();
+    assert!(read.is_pair_of(&write));
+    assert!(write.is_pair_of(&read));
+
+    let cursor = read.reunite(write).unwrap();
+    assert_eq!(cursor.into_inner(), [1, 2, 3]);
+}
+
+#[test]
+fn reunite_mismatched_halves() {
+    let (read1, write1) = Cursor::new(vec![1, 2, 3]).split();
+    let (read2, write2) = Cursor::new(Vec::new()).split();
+    assert!(!read1.is_pair_of(&write2));
+    assert!(!write2.is_pair_of(&read1));
+
+    let err = read1.reunite(write2).unwrap_err();
+    let (mut read1, mut write2) = (err.0, err.1);
+
+    // Both halves can still be used after a failed attempt.
+    block_on(async {
+        let mut buf = Vec::new();
+        read1.read_to_end(&mut buf).await.unwrap();
+        assert_eq!(buf, [1, 2, 3]);
+        write2.write_all(&[4, 5]).await.unwrap();
+    });
+
+    let err = write1.reunite(read2).unwrap_err();
+    assert_eq!(read1.reunite(err.1).unwrap().into_inner(), [1, 2, 3]);
+    assert_eq!(err.0.reunite(write2).unwrap().into_inner(), [4, 5]);
+}
+
+#[test]
+fn read_half_buffered_reads() {
+    let (mut read, write) = Cursor::new(b"first\nsecond\nthird".to_vec()).split();
+
+    block_on(async {
+        let mut line = Vec::new();
+        assert_eq!(read.read_until(b'\n', &mut line).await.unwrap(), 6);
+        assert_eq!(line, b"first\n");
+
+        // Reads and buffered reads can be mixed.
+        let mut buf = [0; 3];
+        read.read_exact(&mut buf).await.unwrap();
+        assert_eq!(&buf, b"sec");
+        line.clear();
+        assert_eq!(read.read_until(b'\n', &mut line).await.unwrap(), 4);
+        assert_eq!(line, b"ond\n");
+    });
+
+    // Data consumed through the read half is consumed from the object.
+    let mut cursor = read.reunite(write).unwrap();
+    assert_eq!(cursor.position(), 13);
+    let mut rest = String::new();
+    block_on(cursor.read_to_string(&mut rest)).unwrap();
+    assert_eq!(rest, "third");
+}
+
+#[test]
+fn read_half_seek() {
+    let (mut read, mut write) = Cursor::new(b"hello world".to_vec()).split();
+
+    block_on(async {
+        let mut word = Vec::new();
+        read.read_until(b' ', &mut word).await.unwrap();
+        assert_eq!(read.seek(SeekFrom::Current(0)).await.unwrap(), 6);
+
+        assert_eq!(read.seek(SeekFrom::Start(0)).await.unwrap(), 0);
+        write.write_all(b"HELLO").await.unwrap();
+        let mut contents = String::new();
+        read.read_to_string(&mut contents).await.unwrap();
+        assert_eq!(contents, " world");
+    });
+}
+
+#[test]
+fn write_half_is_write_vectored() {
+    let (_, write) = Cursor::new(Vec::new()).split();
+    assert!(write.is_write_vectored());
+}
@@ -0,0 +1,49 @@
+use futures::executor::block_on;
+use futures::io::{AsyncRead, AsyncReadExt, Cursor};
+use futures::task::Poll;
+use futures_test::io::{AsyncReadTestExt, AsyncWriteTestExt};
+use futures_test::task::noop_context;
+use std::pin::Pin;
+
+#[test]
+fn tee_mirrors_with_slow_writer_and_short_reads() {
+    let data: Vec<u8> = (0..1000).map(|i| i as u8).collect();
+    let reader = Cursor::new(data.clone()).limited(7).interleave_pending();
+    let writer = Vec::new().limited_write(3).interleave_pending_write();
+    let mut tee = reader.tee(writer);
+
+    let mut read = Vec::new();
+    block_on(tee.read_to_end(&mut read)).unwrap();
+    assert!(read == data);
+
+    let (_, writer) = tee.into_parts();
+    assert!(writer.into_inner().into_inner() == data);
+}
+
+#[test]
+fn tee_returns_data_only_once_written() {
+    let reader = Cursor::new(b"abcdef".to_vec());
+    let mut tee = reader.tee(Vec::new().interleave_pending_write());
+    let mut cx = noop_context();
+
+    let mut buf = [0; 6];
+    assert!(Pin::new(&mut tee).poll_read(&mut cx, &mut buf).is_pending());
+    // The chunk which was read is returned once written, even in pieces.
+    let mut small = [0; 4];
+    assert_eq!(
+        Pin::new(&mut tee).poll_read(&mut cx, &mut small).map(|r| r.unwrap()),
+        Poll::Ready(4)
+    );
+    assert_eq!(&small, b"abcd");
+    assert_eq!(
+        Pin::new(&mut tee).poll_read(&mut cx, &mut small).map(|r| r.unwrap()),
+        Poll::Ready(2)
+    );
+    assert_eq!(&small[..2], b"ef");
+
+    assert_eq!(tee.get_ref().1.get_ref(), b"abcdef");
+    assert_eq!(
+        Pin::new(&mut tee).poll_read(&mut cx, &mut small).map(|r| r.unwrap()),
+        Poll::Ready(0)
+    );
+}
@@ -0,0 +1,57 @@
+use futures::future::FutureExt;
+use futures::lock::{BiLock, BiLockGuard, MappedBiLockGuard};
+use futures::task::{Context, Poll};
+use futures_test::task::{new_count_waker, panic_context};
+use std::pin::Pin;
+
+#[test]
+fn bilock_try_lock_contended_between_halves() {
+    let (a, b) = BiLock::new(0);
+    let (waker, counter) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+
+    let mut lock = a.try_lock().unwrap();
+    *lock += 1;
+    assert!(a.try_lock().is_none());
+    assert!(b.try_lock().is_none());
+
+    // `try_lock` fails while the other half is waiting, too, and doesn't
+    // disturb the waiting task.
+    let mut waiter = b.lock();
+    assert!(waiter.poll_unpin(&mut cx).is_pending());
+    assert!(a.try_lock().is_none());
+    drop(lock);
+    assert_eq!(counter, 1);
+
+    let mut lock = match waiter.poll_unpin(&mut panic_context()) {
+        Poll::Ready(lock) => lock,
+        Poll::Pending => panic!("waiter not granted the lock"),
+    };
+    *lock += 1;
+    assert!(a.try_lock().is_none());
+    drop(lock);
+
+    assert_eq!(*a.try_lock().unwrap(), 2);
+    assert_eq!(a.reunite(b).unwrap(), 2);
+}
+
+#[test]
+fn bilock_reunite_after_mapped_guard() {
+    let (a, b) = BiLock::new((vec![1], String::from("a")));
+
+    {
+        let items =
+            BiLockGuard::map(a.try_lock().unwrap(), |pair| Pin::new(&mut Pin::into_inner(pair).0));
+        let mut first = MappedBiLockGuard::map(items, |items| Pin::new(&mut items.get_mut()[0]));
+        *first += 1;
+        assert!(b.try_lock().is_none());
+    }
+
+    let mut label =
+        BiLockGuard::map(b.try_lock().unwrap(), |pair| Pin::new(&mut Pin::into_inner(pair).1));
+    label.push('b');
+    assert!(a.try_lock().is_none());
+    drop(label);
+
+    assert_eq!(a.reunite(b).unwrap(), (vec![2], String::from("ab")));
+}
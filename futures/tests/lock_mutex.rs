@@ -1,12 +1,13 @@
 use futures::channel::mpsc;
-use futures::executor::{block_on, ThreadPool};
+use futures::executor::{block_on, LocalPool, ThreadPool};
 use futures::future::{ready, FutureExt};
-use futures::lock::Mutex;
+use futures::lock::{Mutex, OwnedMutexGuard};
 use futures::stream::StreamExt;
-use futures::task::{Context, SpawnExt};
+use futures::task::{Context, LocalSpawnExt, Poll, SpawnExt};
 use futures_test::future::FutureTestExt;
 use futures_test::task::{new_count_waker, panic_context};
 use std::sync::Arc;
+use std::thread;
 
 #[test]
 fn mutex_acquire_uncontested() {
@@ -34,6 +35,56 @@ fn mutex_wakes_waiters() {
     assert!(waiter.poll_unpin(&mut panic_context()).is_ready());
 }
 
+#[test]
+fn mutex_passes_on_wake_of_dropped_waiter() {
+    let mutex = Mutex::new(());
+    let (waker1, counter1) = new_count_waker();
+    let (waker2, counter2) = new_count_waker();
+    let lock = mutex.try_lock().unwrap();
+
+    let mut waiter1 = mutex.lock();
+    let mut waiter2 = mutex.lock();
+    assert!(waiter1.poll_unpin(&mut Context::from_waker(&waker1)).is_pending());
+    assert!(waiter2.poll_unpin(&mut Context::from_waker(&waker2)).is_pending());
+
+    drop(lock);
+    assert_eq!(counter1, 1);
+    assert_eq!(counter2, 0);
+
+    // The woken waiter goes away without taking the lock, so the wake-up has
+    // to be passed on rather than lost.
+    drop(waiter1);
+    assert_eq!(counter2, 1);
+    assert!(waiter2.poll_unpin(&mut panic_context()).is_ready());
+}
+
+#[test]
+fn mutex_fair_passes_on_dropped_waiter() {
+    let mutex = Mutex::new_fair(());
+    let (waker1, counter1) = new_count_waker();
+    let (waker2, counter2) = new_count_waker();
+    let lock = mutex.try_lock().unwrap();
+
+    let mut waiter1 = mutex.lock();
+    let mut waiter2 = mutex.lock();
+    assert!(waiter1.poll_unpin(&mut Context::from_waker(&waker1)).is_pending());
+    assert!(waiter2.poll_unpin(&mut Context::from_waker(&waker2)).is_pending());
+
+    drop(lock);
+    assert_eq!(counter1, 1);
+    assert_eq!(counter2, 0);
+
+    // The lock was handed to the first waiter, which goes away without taking
+    // it, so it has to be handed on rather than kept locked.
+    drop(waiter1);
+    assert_eq!(counter2, 1);
+    assert!(mutex.try_lock().is_none());
+
+    // Once no waiter is left, it is unlocked instead.
+    drop(waiter2);
+    assert!(mutex.try_lock().is_some());
+}
+
 #[cfg_attr(miri, ignore)] // https://github.com/rust-lang/miri/issues/1038
 #[test]
 fn mutex_contested() {
@@ -65,3 +116,237 @@ fn mutex_contested() {
         assert_eq!(num_tasks, *lock);
     })
 }
+
+#[test]
+fn mutex_fair_serves_waiters_in_order() {
+    let mutex = Mutex::new_fair(Vec::new());
+    assert!(mutex.is_fair());
+    assert!(!Mutex::new(()).is_fair());
+
+    let (waker, counter) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+
+    let lock = mutex.try_lock().unwrap();
+    let mut waiters: Vec<_> = (0..3).map(|_| mutex.lock()).collect();
+    for waiter in &mut waiters {
+        assert!(waiter.poll_unpin(&mut cx).is_pending());
+    }
+
+    // The lock is handed over, so neither `try_lock` nor a new `lock` future
+    // can take it while tasks are waiting.
+    drop(lock);
+    assert_eq!(counter, 1);
+    assert!(mutex.try_lock().is_none());
+    let mut late = mutex.lock();
+    assert!(late.poll_unpin(&mut cx).is_pending());
+
+    // Dropping a waiter which was handed the lock passes it on.
+    drop(waiters.remove(0));
+    assert_eq!(counter, 2);
+
+    for (i, mut waiter) in waiters.into_iter().enumerate() {
+        let mut lock = match waiter.poll_unpin(&mut panic_context()) {
+            Poll::Ready(lock) => lock,
+            Poll::Pending => panic!("waiter {} not granted the lock", i),
+        };
+        lock.push(i);
+    }
+    assert_eq!(counter, 4);
+
+    let mut lock = match late.poll_unpin(&mut panic_context()) {
+        Poll::Ready(lock) => lock,
+        Poll::Pending => panic!("late waiter not granted the lock"),
+    };
+    lock.push(2);
+    assert_eq!(*lock, [0, 1, 2]);
+    drop(lock);
+    assert!(mutex.try_lock().is_some());
+}
+
+#[cfg_attr(miri, ignore)] // https://github.com/rust-lang/miri/issues/1038
+#[test]
+fn mutex_fair_contested() {
+    let (tx, mut rx) = mpsc::unbounded();
+    let pool = ThreadPool::builder().pool_size(16).create().unwrap();
+
+    let tx = Arc::new(tx);
+    let mutex = Arc::new(Mutex::new_fair(0));
+
+    let num_tasks = 1000;
+    for _ in 0..num_tasks {
+        let tx = tx.clone();
+        let mutex = mutex.clone();
+        pool.spawn(async move {
+            let mut lock = mutex.lock().await;
+            ready(()).pending_once().await;
+            *lock += 1;
+            tx.unbounded_send(()).unwrap();
+            drop(lock);
+        })
+        .unwrap();
+    }
+
+    block_on(async {
+        for _ in 0..num_tasks {
+            rx.next().await.unwrap();
+        }
+        let lock = mutex.lock().await;
+        assert_eq!(num_tasks, *lock);
+    })
+}
+
+#[test]
+fn mutex_fair_bounds_acquisition_disparity() {
+    let mut pool = LocalPool::new();
+    let spawner = pool.spawner();
+
+    let num_tasks = 50;
+    let total = 10_000;
+    let mutex = Arc::new(Mutex::new_fair(vec![0; num_tasks]));
+    let acquired = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+
+    for i in 0..num_tasks {
+        let mutex = mutex.clone();
+        let acquired = acquired.clone();
+        spawner
+            .spawn_local(async move {
+                while acquired.fetch_add(1, std::sync::atomic::Ordering::Relaxed) < total {
+                    let mut lock = mutex.lock().await;
+                    ready(()).pending_once().await;
+                    lock[i] += 1;
+                }
+            })
+            .unwrap();
+    }
+    pool.run();
+
+    let counts = mutex.try_lock().unwrap();
+    let max = counts.iter().max().unwrap();
+    let min = counts.iter().min().unwrap();
+    assert_eq!(counts.iter().sum::<usize>(), total);
+    assert!(max - min <= 1, "acquisitions ranged from {} to {}", min, max);
+}
+
+#[test]
+fn mutex_owned_guard_wakes_waiters() {
+    let mutex = Arc::new(Mutex::new(()));
+    let (waker, counter) = new_count_waker();
+    let lock = mutex.try_lock_owned().unwrap();
+    assert!(mutex.try_lock_owned().is_none());
+
+    let mut cx = Context::from_waker(&waker);
+    let mut waiter = mutex.lock_owned();
+    assert!(waiter.poll_unpin(&mut cx).is_pending());
+    assert_eq!(counter, 0);
+
+    let lock = OwnedMutexGuard::map(lock, |unit| unit);
+    drop(lock);
+
+    assert_eq!(counter, 1);
+    assert!(waiter.poll_unpin(&mut panic_context()).is_ready());
+}
+
+#[cfg_attr(miri, ignore)] // https://github.com/rust-lang/miri/issues/1038
+#[test]
+fn mutex_owned_guard_contested() {
+    let (tx, mut rx) = mpsc::unbounded();
+    let pool = ThreadPool::builder().pool_size(16).create().unwrap();
+
+    let tx = Arc::new(tx);
+    let mutex = Arc::new(Mutex::new(0));
+
+    // This guard is moved into the first task, holding back the others until
+    // that task is done with it.
+    let mut held = mutex.try_lock_owned().unwrap();
+    {
+        let tx = tx.clone();
+        pool.spawn(async move {
+            ready(()).pending_once().await;
+            *held += 1;
+            tx.unbounded_send(()).unwrap();
+            drop(held);
+        })
+        .unwrap();
+    }
+
+    let num_tasks = 1000;
+    for _ in 0..num_tasks {
+        let tx = tx.clone();
+        let mutex = mutex.clone();
+        pool.spawn(async move {
+            let mut lock = mutex.lock_owned().await;
+            ready(()).pending_once().await;
+            *lock += 1;
+            tx.unbounded_send(()).unwrap();
+            drop(lock);
+        })
+        .unwrap();
+    }
+
+    block_on(async {
+        for _ in 0..num_tasks + 1 {
+            rx.next().await.unwrap();
+        }
+        let lock = mutex.lock_owned().await;
+        assert_eq!(num_tasks + 1, *lock);
+    })
+}
+
+#[cfg_attr(miri, ignore)] // https://github.com/rust-lang/miri/issues/1038
+#[test]
+fn mutex_blocking_lock_contested() {
+    let (tx, mut rx) = mpsc::unbounded();
+    let pool = ThreadPool::builder().pool_size(16).create().unwrap();
+
+    let tx = Arc::new(tx);
+    let mutex = Arc::new(Mutex::new_fair(0));
+
+    let num_tasks = 1000;
+    for _ in 0..num_tasks {
+        let tx = tx.clone();
+        let mutex = mutex.clone();
+        pool.spawn(async move {
+            let mut lock = mutex.lock().await;
+            ready(()).pending_once().await;
+            *lock += 1;
+            tx.unbounded_send(()).unwrap();
+            drop(lock);
+        })
+        .unwrap();
+    }
+
+    let num_threads = 4;
+    let num_locks = 100;
+    let threads: Vec<_> = (0..num_threads)
+        .map(|i| {
+            let mutex = mutex.clone();
+            thread::spawn(move || {
+                for _ in 0..num_locks {
+                    if i % 2 == 0 {
+                        *mutex.blocking_lock() += 1;
+                    } else {
+                        *mutex.blocking_lock_owned() += 1;
+                    }
+                }
+            })
+        })
+        .collect();
+    for thread in threads {
+        thread.join().unwrap();
+    }
+
+    block_on(async {
+        for _ in 0..num_tasks {
+            rx.next().await.unwrap();
+        }
+        let lock = mutex.lock().await;
+        assert_eq!(num_tasks + num_threads * num_locks, *lock);
+    })
+}
+
+#[test]
+#[should_panic(expected = "cannot block on a `Mutex` from within an executor")]
+fn mutex_blocking_lock_panics_within_executor() {
+    let mutex = Mutex::new(());
+    block_on(async { drop(mutex.blocking_lock()) });
+}
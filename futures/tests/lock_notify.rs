@@ -0,0 +1,93 @@
+use futures::future::FutureExt;
+use futures::lock::Notify;
+use futures::task::Context;
+use futures_test::task::{new_count_waker, panic_context};
+
+#[test]
+fn notify_one_before_wait_stores_permit() {
+    let notify = Notify::new();
+    notify.notify_one();
+    // Only a single permit is stored.
+    notify.notify_one();
+
+    assert!(notify.notified().now_or_never().is_some());
+    assert!(notify.notified().now_or_never().is_none());
+}
+
+#[test]
+fn notify_one_wakes_waiters_in_order() {
+    let notify = Notify::new();
+    let (waker, counter) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+
+    let mut waiter1 = notify.notified();
+    let mut waiter2 = notify.notified();
+    assert!(waiter1.poll_unpin(&mut cx).is_pending());
+    assert!(waiter2.poll_unpin(&mut cx).is_pending());
+
+    notify.notify_one();
+    assert_eq!(counter, 1);
+    assert!(waiter2.poll_unpin(&mut cx).is_pending());
+    assert!(waiter1.poll_unpin(&mut panic_context()).is_ready());
+
+    notify.notify_one();
+    assert_eq!(counter, 2);
+    assert!(waiter2.poll_unpin(&mut panic_context()).is_ready());
+
+    // The waiters consumed the notifications, so no permit was stored.
+    assert!(notify.notified().now_or_never().is_none());
+}
+
+#[test]
+fn notify_waiters_does_not_store_permit() {
+    let notify = Notify::new();
+    let (waker, counter) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+
+    let mut waiter1 = notify.notified();
+    let mut waiter2 = notify.notified();
+    assert!(waiter1.poll_unpin(&mut cx).is_pending());
+    assert!(waiter2.poll_unpin(&mut cx).is_pending());
+    // A future created before the call is notified, even if it wasn't polled.
+    let mut unpolled = notify.notified();
+
+    notify.notify_waiters();
+    assert_eq!(counter, 2);
+    assert!(waiter1.poll_unpin(&mut panic_context()).is_ready());
+    assert!(waiter2.poll_unpin(&mut panic_context()).is_ready());
+    assert!(unpolled.poll_unpin(&mut panic_context()).is_ready());
+
+    assert!(notify.notified().now_or_never().is_none());
+}
+
+#[test]
+fn cancelled_waiter_passes_notification_on() {
+    let notify = Notify::new();
+    let (waker, counter) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+
+    let mut waiter1 = notify.notified();
+    let mut waiter2 = notify.notified();
+    let mut waiter3 = notify.notified();
+    assert!(waiter1.poll_unpin(&mut cx).is_pending());
+    assert!(waiter2.poll_unpin(&mut cx).is_pending());
+    assert!(waiter3.poll_unpin(&mut cx).is_pending());
+
+    // Dropping a waiter which wasn't notified just removes it from the queue.
+    drop(waiter2);
+    assert_eq!(counter, 0);
+
+    // A notified waiter that is dropped passes its notification on.
+    notify.notify_one();
+    assert_eq!(counter, 1);
+    drop(waiter1);
+    assert_eq!(counter, 2);
+    assert!(waiter3.poll_unpin(&mut panic_context()).is_ready());
+
+    // With no waiter left, the notification is stored as a permit.
+    let mut waiter = notify.notified();
+    assert!(waiter.poll_unpin(&mut cx).is_pending());
+    notify.notify_one();
+    drop(waiter);
+    assert!(notify.notified().now_or_never().is_some());
+}
@@ -0,0 +1,192 @@
+use futures::channel::mpsc;
+use futures::executor::{block_on, ThreadPool};
+use futures::future::{ready, FutureExt};
+use futures::lock::{
+    MappedRwLockReadGuard, MappedRwLockWriteGuard, RwLock, RwLockReadGuard, RwLockWriteGuard,
+};
+use futures::stream::StreamExt;
+use futures::task::{Context, SpawnExt};
+use futures_test::future::FutureTestExt;
+use futures_test::task::{new_count_waker, panic_context};
+use std::sync::Arc;
+
+#[test]
+fn rwlock_readers_share_access() {
+    let rwlock = RwLock::new(1);
+    let mut cx = panic_context();
+
+    let reader1 = rwlock.read().poll_unpin(&mut cx);
+    let reader2 = rwlock.read().poll_unpin(&mut cx);
+    assert!(reader1.is_ready());
+    assert!(reader2.is_ready());
+    assert!(rwlock.try_read().is_some());
+    assert!(rwlock.try_write().is_none());
+
+    drop((reader1, reader2));
+    assert!(rwlock.try_write().is_some());
+}
+
+#[test]
+fn rwlock_writer_is_exclusive() {
+    let rwlock = RwLock::new(1);
+    let (waker, counter) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+
+    let writer = rwlock.try_write().unwrap();
+    assert!(rwlock.try_read().is_none());
+    assert!(rwlock.try_write().is_none());
+
+    let mut reader = rwlock.read();
+    let mut other_writer = rwlock.write();
+    assert!(reader.poll_unpin(&mut cx).is_pending());
+    assert!(other_writer.poll_unpin(&mut cx).is_pending());
+    assert_eq!(counter, 0);
+
+    drop(writer);
+
+    // The lock is handed over to the reader, which was first in line.
+    assert_eq!(counter, 1);
+    let reader = reader.poll_unpin(&mut panic_context());
+    assert!(reader.is_ready());
+    assert!(other_writer.poll_unpin(&mut cx).is_pending());
+
+    drop(reader);
+
+    assert_eq!(counter, 2);
+    assert!(other_writer.poll_unpin(&mut panic_context()).is_ready());
+}
+
+#[test]
+fn rwlock_waiting_writer_blocks_new_readers() {
+    let rwlock = RwLock::new(1);
+    let (waker, counter) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+
+    let reader = rwlock.try_read().unwrap();
+
+    let mut writer = rwlock.write();
+    assert!(writer.poll_unpin(&mut cx).is_pending());
+
+    // Readers arriving after the writer have to wait for it.
+    assert!(rwlock.try_read().is_none());
+    let mut late_reader1 = rwlock.read();
+    let mut late_reader2 = rwlock.read();
+    assert!(late_reader1.poll_unpin(&mut cx).is_pending());
+    assert!(late_reader2.poll_unpin(&mut cx).is_pending());
+
+    drop(reader);
+    assert_eq!(counter, 1);
+    let writer = writer.poll_unpin(&mut panic_context());
+    assert!(writer.is_ready());
+    assert!(late_reader1.poll_unpin(&mut cx).is_pending());
+
+    drop(writer);
+
+    // Both readers are let in at once.
+    assert_eq!(counter, 3);
+    assert!(late_reader1.poll_unpin(&mut panic_context()).is_ready());
+    assert!(late_reader2.poll_unpin(&mut panic_context()).is_ready());
+}
+
+#[test]
+fn rwlock_dropped_waiters_pass_on_the_lock() {
+    let rwlock = RwLock::new(1);
+    let (waker, counter) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+
+    let reader = rwlock.try_read().unwrap();
+
+    // Cancelling a waiting writer lets the readers behind it in.
+    let mut writer = rwlock.write();
+    assert!(writer.poll_unpin(&mut cx).is_pending());
+    let mut late_reader = rwlock.read();
+    assert!(late_reader.poll_unpin(&mut cx).is_pending());
+    drop(writer);
+    assert_eq!(counter, 1);
+    assert!(late_reader.poll_unpin(&mut panic_context()).is_ready());
+    drop(reader);
+
+    // Dropping a writer which was granted the lock, but not polled since,
+    // hands the lock on to the next waiter.
+    let reader = rwlock.try_read().unwrap();
+    let mut writer1 = rwlock.write();
+    let mut writer2 = rwlock.write();
+    assert!(writer1.poll_unpin(&mut cx).is_pending());
+    assert!(writer2.poll_unpin(&mut cx).is_pending());
+    drop(reader);
+    assert_eq!(counter, 2);
+    drop(writer1);
+    assert_eq!(counter, 3);
+    assert!(writer2.poll_unpin(&mut panic_context()).is_ready());
+}
+
+#[test]
+fn rwlock_mapped_guards_hold_the_lock() {
+    let rwlock = RwLock::new((1, vec![2, 3]));
+    let (waker, counter) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+
+    let first = RwLockReadGuard::map(rwlock.try_read().unwrap(), |(first, _)| first);
+    let second = RwLockReadGuard::map(rwlock.try_read().unwrap(), |(_, rest)| rest);
+    let second = MappedRwLockReadGuard::map(second, |rest| &rest[0]);
+    assert_eq!((*first, *second), (1, 2));
+
+    let mut writer = rwlock.write();
+    assert!(writer.poll_unpin(&mut cx).is_pending());
+    drop(first);
+    assert_eq!(counter, 0);
+    drop(second);
+    assert_eq!(counter, 1);
+
+    let writer = match writer.poll_unpin(&mut panic_context()) {
+        std::task::Poll::Ready(writer) => writer,
+        std::task::Poll::Pending => panic!("writer should have been granted the lock"),
+    };
+    let rest = RwLockWriteGuard::map(writer, |(_, rest)| rest);
+    let mut last = MappedRwLockWriteGuard::map(rest, |rest| rest.last_mut().unwrap());
+    *last = 4;
+    assert!(rwlock.try_read().is_none());
+    let _ = format!("{:?}", last);
+    drop(last);
+
+    assert_eq!(*rwlock.try_read().unwrap(), (1, vec![2, 4]));
+}
+
+#[cfg_attr(miri, ignore)] // https://github.com/rust-lang/miri/issues/1038
+#[test]
+fn rwlock_contested() {
+    let (tx, mut rx) = mpsc::unbounded();
+    let pool = ThreadPool::builder().pool_size(16).create().unwrap();
+
+    let tx = Arc::new(tx);
+    let rwlock = Arc::new(RwLock::new(0));
+
+    let num_tasks = 1000;
+    for i in 0..num_tasks {
+        let tx = tx.clone();
+        let rwlock = rwlock.clone();
+        pool.spawn(async move {
+            if i % 2 == 0 {
+                let mut lock = rwlock.write().await;
+                ready(()).pending_once().await;
+                *lock += 1;
+                drop(lock);
+            } else {
+                let lock = rwlock.read().await;
+                ready(()).pending_once().await;
+                assert!(*lock <= num_tasks / 2);
+                drop(lock);
+            }
+            tx.unbounded_send(()).unwrap();
+        })
+        .unwrap();
+    }
+
+    block_on(async {
+        for _ in 0..num_tasks {
+            rx.next().await.unwrap();
+        }
+        let lock = rwlock.read().await;
+        assert_eq!(num_tasks / 2, *lock);
+    })
+}
@@ -0,0 +1,151 @@
+use futures::channel::mpsc;
+use futures::executor::{block_on, ThreadPool};
+use futures::future::{ready, FutureExt};
+use futures::lock::Semaphore;
+use futures::stream::StreamExt;
+use futures::task::{Context, Poll, SpawnExt};
+use futures_test::future::FutureTestExt;
+use futures_test::task::{new_count_waker, panic_context};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+#[test]
+fn semaphore_acquire_uncontested() {
+    let semaphore = Semaphore::new(2);
+    let permit1 = semaphore.try_acquire().unwrap();
+    let permit2 = semaphore.acquire().now_or_never().unwrap();
+    assert_eq!(semaphore.available_permits(), 0);
+    assert!(semaphore.try_acquire().is_none());
+
+    drop(permit1);
+    drop(permit2);
+    assert_eq!(semaphore.available_permits(), 2);
+}
+
+#[test]
+fn semaphore_wakes_waiters_in_order() {
+    let semaphore = Semaphore::new(1);
+    let (waker, counter) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+
+    let permit = semaphore.try_acquire().unwrap();
+    let mut waiter1 = semaphore.acquire();
+    let mut waiter2 = semaphore.acquire();
+    assert!(waiter1.poll_unpin(&mut cx).is_pending());
+    assert!(waiter2.poll_unpin(&mut cx).is_pending());
+
+    drop(permit);
+    assert_eq!(counter, 1);
+    // The permit was handed over to the first waiter.
+    assert!(semaphore.try_acquire().is_none());
+    assert!(waiter2.poll_unpin(&mut cx).is_pending());
+    let permit = match waiter1.poll_unpin(&mut panic_context()) {
+        Poll::Ready(permit) => permit,
+        Poll::Pending => panic!("first waiter not granted a permit"),
+    };
+
+    drop(permit);
+    assert_eq!(counter, 2);
+    assert!(waiter2.poll_unpin(&mut panic_context()).is_ready());
+    assert_eq!(semaphore.available_permits(), 1);
+}
+
+#[test]
+fn semaphore_cancelled_waiter_mid_queue() {
+    let semaphore = Semaphore::new(0);
+    let (waker, counter) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+
+    let mut waiters: Vec<_> = (0..3).map(|_| semaphore.acquire()).collect();
+    for waiter in &mut waiters {
+        assert!(waiter.poll_unpin(&mut cx).is_pending());
+    }
+
+    // Dropping a waiter in the middle of the queue removes it, without
+    // disturbing the others.
+    drop(waiters.remove(1));
+    assert_eq!(counter, 0);
+
+    semaphore.add_permits(2);
+    assert_eq!(counter, 2);
+    for mut waiter in waiters {
+        assert!(waiter.poll_unpin(&mut panic_context()).is_ready());
+    }
+    assert_eq!(semaphore.available_permits(), 2);
+
+    // A waiter that is dropped after having been granted its permit releases
+    // it to the next waiter.
+    let permit = semaphore.try_acquire_many(2).unwrap();
+    let mut granted = semaphore.acquire();
+    let mut next = semaphore.acquire();
+    assert!(granted.poll_unpin(&mut cx).is_pending());
+    assert!(next.poll_unpin(&mut cx).is_pending());
+    drop(permit);
+    assert_eq!(counter, 4);
+    drop(granted);
+    assert!(next.poll_unpin(&mut panic_context()).is_ready());
+    assert_eq!(semaphore.available_permits(), 2);
+}
+
+#[test]
+fn semaphore_acquire_many_waits_for_permits() {
+    let semaphore = Semaphore::new(1);
+    let (waker, counter) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+
+    let mut many = semaphore.acquire_many(3);
+    assert!(many.poll_unpin(&mut cx).is_pending());
+    // A waiter which needs fewer permits still queues up behind it.
+    let mut one = semaphore.acquire();
+    assert!(one.poll_unpin(&mut cx).is_pending());
+
+    semaphore.add_permits(1);
+    assert_eq!(counter, 0);
+    semaphore.add_permits(1);
+    assert_eq!(counter, 1);
+    assert_eq!(semaphore.available_permits(), 0);
+
+    let permits = match many.poll_unpin(&mut panic_context()) {
+        Poll::Ready(permits) => permits,
+        Poll::Pending => panic!("waiter not granted its permits"),
+    };
+    assert!(one.poll_unpin(&mut cx).is_pending());
+    drop(permits);
+    assert_eq!(counter, 2);
+    assert!(one.poll_unpin(&mut panic_context()).is_ready());
+    assert_eq!(semaphore.available_permits(), 3);
+}
+
+#[cfg_attr(miri, ignore)] // https://github.com/rust-lang/miri/issues/1038
+#[test]
+fn semaphore_owned_permit_contested() {
+    let (tx, mut rx) = mpsc::unbounded();
+    let pool = ThreadPool::builder().pool_size(16).create().unwrap();
+
+    let tx = Arc::new(tx);
+    let semaphore = Arc::new(Semaphore::new(3));
+    let in_flight = Arc::new(AtomicUsize::new(0));
+
+    let num_tasks = 1000;
+    for _ in 0..num_tasks {
+        let tx = tx.clone();
+        let semaphore = semaphore.clone();
+        let in_flight = in_flight.clone();
+        pool.spawn(async move {
+            let permit = semaphore.acquire_owned().await;
+            assert!(in_flight.fetch_add(1, Ordering::SeqCst) < 3);
+            ready(()).pending_once().await;
+            in_flight.fetch_sub(1, Ordering::SeqCst);
+            drop(permit);
+            tx.unbounded_send(()).unwrap();
+        })
+        .unwrap();
+    }
+
+    block_on(async {
+        for _ in 0..num_tasks {
+            rx.next().await.unwrap();
+        }
+        assert_eq!(semaphore.available_permits(), 3);
+    })
+}
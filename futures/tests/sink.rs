@@ -214,6 +214,24 @@ fn either_sink() {
     Pin::new(&mut s).start_send(0).unwrap();
 }
 
+#[test]
+fn either_sink_delegates_to_active_variant() {
+    let mut s: future::Either<Vec<i32>, _> = future::Either::Right(VecDeque::new());
+    block_on(s.send_all(&mut stream::iter(vec![1, 2]).map(Ok))).unwrap();
+    block_on(s.close()).unwrap();
+    match s {
+        future::Either::Left(_) => panic!("wrong variant"),
+        future::Either::Right(v) => assert_eq!(v, vec![1, 2]),
+    }
+
+    let mut s: future::Either<_, VecDeque<i32>> = future::Either::Left(Vec::new());
+    block_on(s.send(3)).unwrap();
+    match s {
+        future::Either::Left(v) => assert_eq!(v, vec![3]),
+        future::Either::Right(_) => panic!("wrong variant"),
+    }
+}
+
 #[test]
 fn vec_sink() {
     let mut v = Vec::new();
@@ -531,6 +549,47 @@ fn sink_unfold() {
     }))
 }
 
+#[test]
+fn sink_unfold_with_close() {
+    let (tx, rx) = mpsc::unbounded();
+    let unfold = sink::unfold_with_close(
+        Vec::new(),
+        |mut pending, i: i32| async move {
+            pending.push(i);
+            Ok::<_, String>(pending)
+        },
+        move |pending| async move {
+            tx.unbounded_send(pending).unwrap();
+            Ok(())
+        },
+    );
+    futures::pin_mut!(unfold);
+
+    block_on(async {
+        unfold.send(1).await.unwrap();
+        unfold.send(2).await.unwrap();
+        unfold.close().await.unwrap();
+        // Closing again doesn't call the close function again.
+        unfold.close().await.unwrap();
+    });
+    assert_eq!(block_on(rx.collect::<Vec<_>>()), [vec![1, 2]]);
+}
+
+#[test]
+fn sink_unfold_with_close_propagates_close_error() {
+    let unfold = sink::unfold_with_close(
+        0,
+        |sum, i: i32| async move { Ok::<_, String>(sum + i) },
+        |sum| async move { Err(format!("commit of {} failed", sum)) },
+    );
+    futures::pin_mut!(unfold);
+
+    block_on(async {
+        unfold.send(3).await.unwrap();
+        assert_eq!(unfold.close().await, Err("commit of 3 failed".to_string()));
+    });
+}
+
 #[test]
 fn err_into() {
     #[derive(Copy, Clone, Debug, PartialEq, Eq)]
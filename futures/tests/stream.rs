@@ -6,10 +6,12 @@ use futures::executor::block_on;
 use futures::future::{self, Future};
 use futures::lock::Mutex;
 use futures::sink::SinkExt;
-use futures::stream::{self, StreamExt};
-use futures::task::Poll;
+use futures::stream::{self, FusedStream, Stream, StreamExt};
+use futures::task::{Context, Poll};
 use futures::{ready, FutureExt};
-use futures_test::task::noop_context;
+use futures_test::future::FutureTestExt;
+use futures_test::stream::StreamTestExt;
+use futures_test::task::{new_count_waker, noop_context};
 
 #[test]
 fn select() {
@@ -25,6 +27,64 @@ fn select() {
     select_and_compare(vec![1, 2], vec![4, 5, 6], vec![1, 4, 2, 5, 6]);
 }
 
+#[test]
+fn adapters_on_pinned_stream() {
+    use futures::stream::Stream;
+    use futures::task::Context;
+    use std::marker::PhantomPinned;
+    use std::pin::Pin;
+
+    // Stream which can't be moved once it has been polled.
+    struct Countdown {
+        remaining: u32,
+        _pin: PhantomPinned,
+    }
+
+    impl Stream for Countdown {
+        type Item = u32;
+
+        fn poll_next(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Option<u32>> {
+            // Safety: `remaining` isn't structurally pinned.
+            let this = unsafe { self.get_unchecked_mut() };
+            if this.remaining == 0 {
+                return Poll::Ready(None);
+            }
+            this.remaining -= 1;
+            Poll::Ready(Some(this.remaining))
+        }
+    }
+
+    let stream = Countdown { remaining: 5, _pin: PhantomPinned }
+        .enumerate()
+        .map(|(i, x)| i as u32 * 10 + x)
+        .filter(|x| future::ready(x % 2 == 0));
+    futures::pin_mut!(stream);
+
+    let mut items = Vec::new();
+    block_on(async {
+        while let Some(x) = stream.next().await {
+            items.push(x);
+        }
+    });
+    assert_eq!(items, [4, 22, 40]);
+}
+
+#[test]
+fn by_ref() {
+    block_on(async {
+        let mut st = stream::iter(vec!["header", "---", "a", "b", "c"]);
+
+        let header: Vec<_> = st.by_ref().take(2).collect().await;
+        assert_eq!(header, vec!["header", "---"]);
+
+        let body: Vec<_> = st.by_ref().take_while(|&s| future::ready(s != "c")).collect().await;
+        assert_eq!(body, vec!["a", "b"]);
+
+        // `take_while` consumed the item that ended it.
+        assert_eq!(st.next().await, None);
+    });
+}
+
 #[test]
 fn flat_map() {
     block_on(async {
@@ -70,6 +130,30 @@ fn scan() {
     });
 }
 
+#[test]
+fn scan_state() {
+    block_on(async {
+        // A running checksum, read out once the stream has ended.
+        let mut stream = stream::iter(vec![1u32, 2, 3])
+            .scan(0u32, |sum, e| future::ready(Some((sum * 31 + e, e))));
+        assert_eq!(stream.state(), Some(&0));
+
+        assert_eq!(stream.next().await, Some(1));
+        assert_eq!(stream.state(), Some(&1));
+        assert_eq!(stream.by_ref().collect::<Vec<_>>().await, vec![2, 3]);
+        assert_eq!(stream.into_state(), Some(1026));
+    });
+
+    block_on(async {
+        // The state is consumed by a closure ending the stream.
+        let mut stream = stream::iter(vec![1u8, 2, 3])
+            .scan(0u8, |state, e| future::ready(if e < 2 { Some((state + e, e)) } else { None }));
+        assert_eq!(stream.by_ref().collect::<Vec<_>>().await, vec![1]);
+        assert_eq!(stream.state(), None);
+        assert_eq!(stream.into_state(), None);
+    });
+}
+
 #[test]
 fn flatten_unordered() {
     use futures::executor::block_on;
@@ -416,6 +500,76 @@ fn ready_chunks_panic_on_cap_zero() {
     let _ = rx1.ready_chunks(0);
 }
 
+#[test]
+fn try_chunks_cap() {
+    let (_, rx1) = mpsc::channel::<()>(1);
+    assert_eq!(rx1.try_chunks_cap(0).err(), Some(stream::InvalidChunkSize));
+
+    block_on(async {
+        let chunks = stream::iter(1..=5).try_chunks_cap(2).unwrap();
+        assert_eq!(chunks.collect::<Vec<_>>().await, vec![vec![1, 2], vec![3, 4], vec![5]]);
+    });
+}
+
+#[test]
+fn try_ready_chunks_cap() {
+    let (_, rx1) = mpsc::channel::<()>(1);
+    assert_eq!(rx1.try_ready_chunks_cap(0).err(), Some(stream::InvalidChunkSize));
+
+    block_on(async {
+        let chunks = stream::iter(1..=5).try_ready_chunks_cap(2).unwrap();
+        assert_eq!(chunks.collect::<Vec<_>>().await, vec![vec![1, 2], vec![3, 4], vec![5]]);
+    });
+}
+
+#[test]
+fn chunked_streams() {
+    block_on(async {
+        let chunks = stream::iter(1..=7).chunked_streams(3);
+        let chunks = chunks.then(|chunk| chunk.collect::<Vec<_>>()).collect::<Vec<_>>().await;
+        assert_eq!(chunks, vec![vec![1, 2, 3], vec![4, 5, 6], vec![7]]);
+
+        // Exactly filling the last chunk doesn't produce an empty one.
+        let chunks = stream::iter(1..=4).chunked_streams(2);
+        assert_eq!(chunks.count().await, 2);
+    });
+}
+
+#[test]
+fn chunked_streams_wait_for_current_chunk() {
+    let (waker, count) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+    let mut chunks = stream::iter(1..=6).chunked_streams(3);
+
+    let mut first = match chunks.poll_next_unpin(&mut cx) {
+        Poll::Ready(Some(chunk)) => chunk,
+        _ => panic!("expected a chunk"),
+    };
+    assert!(chunks.poll_next_unpin(&mut cx).is_pending());
+    assert_eq!(first.poll_next_unpin(&mut cx), Poll::Ready(Some(1)));
+    assert!(chunks.poll_next_unpin(&mut cx).is_pending());
+
+    // The rest of a dropped chunk is skipped.
+    drop(first);
+    assert_eq!(count, 1);
+    let mut second = match chunks.poll_next_unpin(&mut cx) {
+        Poll::Ready(Some(chunk)) => chunk,
+        _ => panic!("expected a chunk"),
+    };
+    assert_eq!(block_on(second.by_ref().collect::<Vec<_>>()), vec![4, 5, 6]);
+    assert!(second.is_terminated());
+    assert!(block_on(chunks.next()).is_none());
+    assert!(chunks.is_terminated());
+}
+
+#[test]
+#[should_panic]
+fn chunked_streams_panic_on_cap_zero() {
+    let (_, rx1) = mpsc::channel::<()>(1);
+
+    let _ = rx1.chunked_streams(0);
+}
+
 #[test]
 fn ready_chunks() {
     let (mut tx, rx1) = mpsc::channel::<i32>(16);
@@ -436,3 +590,326 @@ fn ready_chunks() {
         assert_eq!(s.next().await.unwrap(), vec![4]);
     });
 }
+
+#[test]
+fn retry() {
+    let mut created = 0;
+    let stream = stream::retry(
+        || {
+            created += 1;
+            stream::iter(vec![Ok(created), Err(created)])
+        },
+        2,
+    );
+    assert_eq!(block_on(stream.collect::<Vec<_>>()), [Ok(1), Ok(2), Ok(3), Err(3)]);
+    assert_eq!(created, 3);
+
+    // The stream ends as soon as the current stream does.
+    let stream = stream::retry(|| stream::iter(vec![Ok::<_, ()>(1)]), 2);
+    assert_eq!(block_on(stream.collect::<Vec<_>>()), [Ok(1)]);
+
+    // Without retries, the first error is yielded.
+    let mut stream = stream::retry(|| stream::iter(vec![Err::<(), _>(1), Err(2)]), 0);
+    assert_eq!(block_on(stream.next()), Some(Err(1)));
+    assert!(stream.is_terminated());
+    assert_eq!(block_on(stream.next()), None);
+}
+
+#[test]
+fn retry_with_backoff() {
+    let mut cx = noop_context();
+    let created = Arc::new(std::sync::Mutex::new(0));
+    let created2 = created.clone();
+    let mut retries = Vec::new();
+    let mut stream = stream::retry_with_backoff(
+        move || {
+            *created2.lock().unwrap() += 1;
+            stream::iter(vec![Err::<(), _>("error")])
+        },
+        2,
+        |retry| {
+            retries.push(retry);
+            future::ready(()).pending_once()
+        },
+    );
+
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Pending);
+    // The next stream is only created once the backoff is over.
+    assert_eq!(*created.lock().unwrap(), 1);
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Pending);
+    assert_eq!(*created.lock().unwrap(), 2);
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(Err("error"))));
+    assert_eq!(*created.lock().unwrap(), 3);
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(None));
+    drop(stream);
+    assert_eq!(retries, [1, 2]);
+}
+
+#[test]
+fn inspect_pending_and_ready() {
+    let mut pending = 0;
+    let mut ready = 0;
+    let mut stream = stream::iter(1..=2)
+        .then(|x| future::ready(x).pending_once())
+        .inspect_pending(|| pending += 1)
+        .inspect_ready(|| ready += 1);
+    let mut cx = noop_context();
+
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Pending);
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(1)));
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Pending);
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(2)));
+    // The end of the stream isn't an item.
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(None));
+    drop(stream);
+    assert_eq!((pending, ready), (2, 2));
+}
+
+#[test]
+fn inspect_poll() {
+    let mut events = Vec::new();
+    let mut stream = stream::iter(1..=2)
+        .then(|x| future::ready(x).pending_once())
+        .inspect_poll(|event| events.push((event.index(), event.is_ready())));
+    let mut cx = noop_context();
+
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Pending);
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(1)));
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Pending);
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(2)));
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(None));
+    drop(stream);
+    assert_eq!(events, [(0, false), (1, true), (2, false), (3, true), (4, true)]);
+}
+
+#[test]
+fn with_keepalive() {
+    use futures::channel::oneshot;
+    use std::cell::RefCell;
+    use std::time::Duration;
+
+    let timers = RefCell::new(Vec::new());
+    let (tx, rx) = mpsc::unbounded();
+    let mut stream = rx.with_keepalive(
+        Duration::from_secs(30),
+        |interval| {
+            let (fire, timer) = oneshot::channel();
+            timers.borrow_mut().push((interval, fire));
+            timer.map(drop)
+        },
+        || 0,
+    );
+    let mut cx = noop_context();
+
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Pending);
+    assert_eq!(timers.borrow().len(), 1);
+    assert_eq!(timers.borrow()[0].0, Duration::from_secs(30));
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Pending);
+    // The running timer is kept while the stream is inactive.
+    assert_eq!(timers.borrow().len(), 1);
+
+    timers.borrow_mut().pop().unwrap().1.send(()).unwrap();
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(0)));
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Pending);
+    assert_eq!(timers.borrow().len(), 1);
+
+    // An item restarts the timer.
+    tx.unbounded_send(1).unwrap();
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(1)));
+    timers.borrow_mut().pop().unwrap().1.send(()).unwrap_err();
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Pending);
+    assert_eq!(timers.borrow().len(), 1);
+
+    drop(tx);
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(None));
+}
+
+#[test]
+fn zip3() {
+    let a = stream::iter(vec![1, 2, 3]);
+    let b = stream::iter(vec!['a', 'b', 'c', 'd']).interleave_pending();
+    let c = stream::iter(vec!["x", "y", "z"]);
+    let zipped = stream::zip3(a, b, c);
+    assert_eq!(zipped.size_hint(), (3, Some(3)));
+    assert_eq!(
+        block_on(zipped.collect::<Vec<_>>()),
+        vec![(1, 'a', "x"), (2, 'b', "y"), (3, 'c', "z")]
+    );
+
+    let mut zipped =
+        stream::zip3(stream::iter(vec![1]), stream::iter(vec![2]), stream::empty::<i32>());
+    assert_eq!(block_on(zipped.next()), None);
+    assert!(zipped.is_terminated());
+}
+
+#[test]
+fn zip4() {
+    let a = stream::iter(vec![1, 2]);
+    let b = stream::iter(vec![3, 4]);
+    let c = stream::iter(vec![5, 6]).interleave_pending();
+    let d = stream::repeat(());
+    let zipped = stream::zip4(a, b, c, d);
+    assert_eq!(zipped.size_hint(), (2, Some(2)));
+    assert_eq!(block_on(zipped.collect::<Vec<_>>()), vec![(1, 3, 5, ()), (2, 4, 6, ())]);
+}
+
+#[test]
+fn flatten_size_hint() {
+    let inner = vec![stream::iter(vec![1, 2]), stream::iter(vec![3, 4, 5])];
+    let mut flattened = stream::iter(inner).flatten();
+    assert_eq!(flattened.size_hint(), (0, None));
+
+    assert_eq!(block_on(flattened.next()), Some(1));
+    assert_eq!(flattened.size_hint(), (1, None));
+    assert_eq!(block_on(flattened.next()), Some(2));
+    assert_eq!(block_on(flattened.next()), Some(3));
+    // The outer stream is exhausted, so only the active inner stream is left.
+    assert_eq!(flattened.size_hint(), (2, Some(2)));
+    assert_eq!(block_on(flattened.collect::<Vec<_>>()), vec![4, 5]);
+
+    let empty = stream::iter(Vec::<stream::Iter<std::vec::IntoIter<i32>>>::new()).flatten();
+    assert_eq!(empty.size_hint(), (0, Some(0)));
+}
+
+#[test]
+fn take_set_remaining() {
+    let mut take = stream::iter(1..=10).take(2);
+    assert_eq!(take.remaining(), 2);
+    assert_eq!(block_on(take.by_ref().collect::<Vec<_>>()), [1, 2]);
+    assert_eq!(take.remaining(), 0);
+
+    take.set_remaining(3);
+    assert_eq!(take.size_hint(), (3, Some(3)));
+    assert_eq!(block_on(take.by_ref().collect::<Vec<_>>()), [3, 4, 5]);
+
+    // Shortening the limit stops the stream early.
+    take.set_remaining(10);
+    assert_eq!(block_on(take.next()), Some(6));
+    take.set_remaining(1);
+    assert_eq!(block_on(take.by_ref().collect::<Vec<_>>()), [7]);
+
+    assert_eq!(block_on(take.into_inner().collect::<Vec<_>>()), [8, 9, 10]);
+}
+
+#[test]
+fn cloned_and_copied() {
+    let words = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+    let cloned = stream::iter(&words).cloned();
+    assert_eq!(cloned.size_hint(), (3, Some(3)));
+    assert_eq!(block_on(cloned.collect::<Vec<String>>()), words);
+
+    let numbers = [1, 2, 3];
+    let copied = stream::iter(&numbers).copied().map(|x: i32| x * 2);
+    assert_eq!(block_on(copied.collect::<Vec<_>>()), vec![2, 4, 6]);
+}
+
+#[test]
+fn chain_with_is_lazy() {
+    let (tx, rx) = mpsc::unbounded();
+    let calls = std::cell::Cell::new(0);
+    let mut stream = rx.chain_with(|| {
+        calls.set(calls.get() + 1);
+        stream::iter(vec![3, 4]).fuse()
+    });
+    let cx = &mut futures_test::task::noop_context();
+
+    assert_eq!(stream.poll_next_unpin(cx), Poll::Pending);
+    tx.unbounded_send(1).unwrap();
+    assert_eq!(stream.poll_next_unpin(cx), Poll::Ready(Some(1)));
+    assert_eq!(calls.get(), 0);
+    assert_eq!(stream.size_hint(), (0, None));
+
+    drop(tx);
+    assert_eq!(stream.poll_next_unpin(cx), Poll::Ready(Some(3)));
+    assert_eq!(calls.get(), 1);
+    assert_eq!(stream.size_hint(), (1, Some(1)));
+    assert_eq!(stream.poll_next_unpin(cx), Poll::Ready(Some(4)));
+    assert_eq!(stream.poll_next_unpin(cx), Poll::Ready(None));
+    assert!(stream.is_terminated());
+    assert_eq!(stream.poll_next_unpin(cx), Poll::Ready(None));
+    assert_eq!(calls.get(), 1);
+}
+
+#[test]
+fn map_spawned_limits_tasks_in_flight() {
+    use futures::executor::LocalPool;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    let mut pool = LocalPool::new();
+    let calls = Arc::new(AtomicUsize::new(0));
+    let mut stream = stream::iter(0..5).map_spawned(pool.spawner(), 2, {
+        let calls = calls.clone();
+        move |x| {
+            calls.fetch_add(1, Ordering::SeqCst);
+            x * 10
+        }
+    });
+    let cx = &mut futures_test::task::noop_context();
+
+    assert!(stream.poll_next_unpin(cx).is_pending());
+    pool.run_until_stalled();
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+    let mut results = Vec::new();
+    loop {
+        match stream.poll_next_unpin(cx) {
+            Poll::Ready(Some(output)) => results.push(output.unwrap()),
+            Poll::Ready(None) => break,
+            Poll::Pending => pool.run_until_stalled(),
+        }
+        assert!(calls.load(Ordering::SeqCst) <= results.len() + 2);
+    }
+    results.sort_unstable();
+    assert_eq!(results, [0, 10, 20, 30, 40]);
+}
+
+#[test]
+fn map_spawned_yields_spawn_errors() {
+    use futures::executor::LocalPool;
+
+    let spawner = LocalPool::new().spawner();
+    let results: Vec<_> = block_on(stream::iter(0..2).map_spawned(spawner, None, |x| x).collect());
+    assert_eq!(results.len(), 2);
+    assert!(results.iter().all(|r| r.as_ref().unwrap_err().is_shutdown()));
+}
+
+#[test]
+fn once_with_is_lazy() {
+    let calls = std::cell::Cell::new(0);
+    let make_stream = || {
+        stream::once_with(|| {
+            calls.set(calls.get() + 1);
+            future::ready(17)
+        })
+    };
+
+    drop(make_stream());
+    assert_eq!(calls.get(), 0);
+
+    let mut stream = make_stream();
+    assert_eq!(stream.size_hint(), (1, Some(1)));
+    assert!(!stream.is_terminated());
+    assert_eq!(block_on(stream.next()), Some(17));
+    assert_eq!(calls.get(), 1);
+    assert!(stream.is_terminated());
+    assert_eq!(block_on(stream.next()), None);
+    assert_eq!(calls.get(), 1);
+}
+
+#[test]
+fn buffered_flat_ordered_keeps_order() {
+    let (tx1, rx1) = mpsc::unbounded();
+    let (tx2, rx2) = mpsc::unbounded();
+    let mut stream = stream::iter(vec![rx1, rx2]).buffered_flat_ordered(2);
+    let mut cx = noop_context();
+
+    // The second inner stream finishes first, but is yielded second.
+    tx2.unbounded_send(3).unwrap();
+    drop(tx2);
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Pending);
+
+    tx1.unbounded_send(1).unwrap();
+    tx1.unbounded_send(2).unwrap();
+    drop(tx1);
+    assert_eq!(block_on(stream.collect::<Vec<_>>()), vec![vec![1, 2], vec![3]]);
+}
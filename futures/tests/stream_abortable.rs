@@ -1,6 +1,6 @@
 use futures::channel::mpsc;
 use futures::executor::block_on;
-use futures::stream::{abortable, Stream, StreamExt};
+use futures::stream::{abortable, AbortHandle, AbortableWith, Stream, StreamExt};
 use futures::task::{Context, Poll};
 use futures::SinkExt;
 use futures_test::task::new_count_waker;
@@ -44,3 +44,21 @@ fn abortable_resolves() {
     assert!(!abortable_rx.is_aborted());
     assert_eq!(Some(()), block_on(abortable_rx.next()));
 }
+
+#[test]
+fn abortable_with_reason() {
+    let (_tx, a_rx) = mpsc::channel::<()>(1);
+    let (handle, reg) = AbortHandle::new_pair_with();
+    let mut abortable_rx = AbortableWith::new(a_rx, reg);
+
+    let (waker, counter) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+    assert_eq!(Poll::Pending, Pin::new(&mut abortable_rx).poll_next(&mut cx));
+    assert_eq!(abortable_rx.reason(), None);
+
+    handle.clone().abort_with("superseded");
+    handle.abort_with("shutdown");
+    assert_eq!(counter, 1);
+    assert_eq!(Poll::Ready(None), Pin::new(&mut abortable_rx).poll_next(&mut cx));
+    assert_eq!(abortable_rx.reason(), Some("superseded"));
+}
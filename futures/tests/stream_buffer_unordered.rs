@@ -1,7 +1,9 @@
 use futures::channel::{mpsc, oneshot};
 use futures::executor::{block_on, block_on_stream};
 use futures::sink::SinkExt;
-use futures::stream::StreamExt;
+use futures::stream::{self, StreamExt};
+use futures::task::Poll;
+use futures_test::task::noop_context;
 use std::sync::mpsc as std_mpsc;
 use std::thread;
 
@@ -71,3 +73,61 @@ fn works() {
     t1.join().unwrap();
     t2.join().unwrap();
 }
+
+#[test]
+fn into_in_flight() {
+    let (txs, rxs): (Vec<_>, Vec<_>) = (0..4).map(|_| oneshot::channel::<i32>()).unzip();
+    let mut txs = txs.into_iter().map(Some).collect::<Vec<_>>();
+
+    let mut buffered = futures::stream::iter(rxs).buffer_unordered(3);
+    txs[1].take().unwrap().send(1).unwrap();
+    assert_eq!(block_on(buffered.next()), Some(Ok(1)));
+
+    // The first three receivers were pulled in, one of which has finished.
+    let in_flight = buffered.into_in_flight();
+    assert_eq!(in_flight.len(), 2);
+
+    txs[0].take().unwrap().send(0).unwrap();
+    txs[2].take().unwrap().send(2).unwrap();
+    let mut values = block_on(futures::future::join_all(in_flight))
+        .into_iter()
+        .map(Result::unwrap)
+        .collect::<Vec<_>>();
+    values.sort_unstable();
+    assert_eq!(values, [0, 2]);
+}
+
+#[test]
+fn buffer_unordered_from_iter_limits_concurrency() {
+    let (txs, rxs): (Vec<_>, Vec<_>) = (0..3).map(|_| oneshot::channel::<i32>()).unzip();
+    let mut txs = txs.into_iter().map(Some).collect::<Vec<_>>();
+    let mut stream = stream::buffer_unordered(rxs, 2);
+    let cx = &mut noop_context();
+
+    // The third receiver isn't polled until one of the first two finished.
+    txs[2].take().unwrap().send(2).unwrap();
+    assert_eq!(stream.poll_next_unpin(cx), Poll::Pending);
+    txs[1].take().unwrap().send(1).unwrap();
+    assert_eq!(stream.poll_next_unpin(cx), Poll::Ready(Some(Ok(1))));
+    assert_eq!(stream.poll_next_unpin(cx), Poll::Ready(Some(Ok(2))));
+    txs[0].take().unwrap().send(0).unwrap();
+    assert_eq!(stream.poll_next_unpin(cx), Poll::Ready(Some(Ok(0))));
+    assert_eq!(stream.poll_next_unpin(cx), Poll::Ready(None));
+}
+
+#[test]
+fn buffered_from_iter_keeps_order() {
+    let (txs, rxs): (Vec<_>, Vec<_>) = (0..3).map(|_| oneshot::channel::<i32>()).unzip();
+    let mut txs = txs.into_iter().map(Some).collect::<Vec<_>>();
+    let mut stream = stream::buffered(rxs, None);
+    let cx = &mut noop_context();
+
+    txs[2].take().unwrap().send(2).unwrap();
+    txs[1].take().unwrap().send(1).unwrap();
+    assert_eq!(stream.poll_next_unpin(cx), Poll::Pending);
+    txs[0].take().unwrap().send(0).unwrap();
+    assert_eq!(stream.poll_next_unpin(cx), Poll::Ready(Some(Ok(0))));
+    assert_eq!(stream.poll_next_unpin(cx), Poll::Ready(Some(Ok(1))));
+    assert_eq!(stream.poll_next_unpin(cx), Poll::Ready(Some(Ok(2))));
+    assert_eq!(stream.poll_next_unpin(cx), Poll::Ready(None));
+}
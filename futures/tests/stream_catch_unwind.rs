@@ -1,4 +1,5 @@
 use futures::executor::block_on_stream;
+use futures::future::{self, Panicked};
 use futures::stream::{self, StreamExt};
 
 #[test]
@@ -25,3 +26,25 @@ fn no_panic() {
     assert_eq!(12, iter.next().unwrap().ok().unwrap());
     assert!(iter.next().is_none());
 }
+
+#[test]
+fn buffer_unordered_catch_unwind() {
+    let futures =
+        stream::iter(vec![Some(10), None, Some(11)]).map(|o| future::lazy(move |_| o.unwrap()));
+    let results: Vec<_> = block_on_stream(futures.buffer_unordered_catch_unwind(2)).collect();
+
+    assert_eq!(results.len(), 3);
+    assert_eq!(10, *results[0].as_ref().ok().unwrap());
+    assert!(results[1].is_err());
+    assert_eq!(11, *results[2].as_ref().ok().unwrap());
+}
+
+#[test]
+fn panicked_message() {
+    let stream = stream::iter(vec![Some(10), None]).map(|o| o.expect("missing value"));
+    let mut iter = block_on_stream(stream.catch_unwind());
+
+    assert_eq!(10, iter.next().unwrap().ok().unwrap());
+    let panicked = Panicked::from(iter.next().unwrap().unwrap_err());
+    assert_eq!(panicked.message(), Some("missing value"));
+}
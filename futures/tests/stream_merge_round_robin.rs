@@ -0,0 +1,58 @@
+use futures::channel::mpsc;
+use futures::executor::block_on;
+use futures::stream::{self, FusedStream, Stream, StreamExt};
+use futures::task::Poll;
+use futures_test::task::noop_context;
+
+#[test]
+fn takes_turns_and_skips_pending() {
+    let mut cx = noop_context();
+    let (tx_a, rx_a) = mpsc::unbounded();
+    let (tx_b, rx_b) = mpsc::unbounded();
+    let (tx_c, rx_c) = mpsc::unbounded();
+    let mut merged = stream::merge_round_robin(vec![rx_a, rx_b, rx_c]);
+    assert_eq!(merged.cursor(), 0);
+
+    for i in 0..3 {
+        tx_a.unbounded_send(i).unwrap();
+        tx_c.unbounded_send(i + 20).unwrap();
+    }
+    // `b` is pending, so it is skipped without `a` yielding twice in a row.
+    assert_eq!(merged.poll_next_unpin(&mut cx), Poll::Ready(Some(0)));
+    assert_eq!(merged.cursor(), 1);
+    assert_eq!(merged.poll_next_unpin(&mut cx), Poll::Ready(Some(20)));
+    assert_eq!(merged.cursor(), 0);
+
+    tx_b.unbounded_send(10).unwrap();
+    assert_eq!(merged.poll_next_unpin(&mut cx), Poll::Ready(Some(1)));
+    assert_eq!(merged.poll_next_unpin(&mut cx), Poll::Ready(Some(10)));
+    assert_eq!(merged.poll_next_unpin(&mut cx), Poll::Ready(Some(21)));
+    assert_eq!(merged.cursor(), 0);
+
+    // Ended streams are removed from the rotation.
+    drop(tx_b);
+    assert_eq!(merged.poll_next_unpin(&mut cx), Poll::Ready(Some(2)));
+    assert_eq!(merged.poll_next_unpin(&mut cx), Poll::Ready(Some(22)));
+    assert_eq!(merged.len(), 2);
+    assert_eq!(merged.poll_next_unpin(&mut cx), Poll::Pending);
+
+    drop(tx_a);
+    drop(tx_c);
+    assert!(!merged.is_terminated());
+    assert_eq!(merged.poll_next_unpin(&mut cx), Poll::Ready(None));
+    assert!(merged.is_terminated());
+}
+
+#[test]
+fn uneven_lengths() {
+    let merged = stream::merge_round_robin(vec![
+        stream::iter(vec![1, 2, 3, 4]),
+        stream::iter(vec![]),
+        stream::iter(vec![10, 20]),
+    ]);
+    assert_eq!(merged.size_hint(), (6, Some(6)));
+    assert_eq!(block_on(merged.collect::<Vec<_>>()), [1, 10, 2, 20, 3, 4]);
+
+    let empty = stream::merge_round_robin(Vec::<stream::Iter<std::vec::IntoIter<i32>>>::new());
+    assert_eq!(block_on(empty.collect::<Vec<_>>()), []);
+}
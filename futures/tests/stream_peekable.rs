@@ -1,6 +1,6 @@
 use futures::executor::block_on;
 use futures::pin_mut;
-use futures::stream::{self, Peekable, StreamExt};
+use futures::stream::{self, FusedStream, Peekable, StreamExt};
 
 #[test]
 fn peekable() {
@@ -31,6 +31,24 @@ fn peekable_mut() {
     });
 }
 
+#[test]
+fn peekable_next_if() {
+    block_on(async {
+        let s = stream::iter(vec![1u8, 2, 3]).peekable();
+        pin_mut!(s);
+        assert_eq!(s.as_mut().next_if(|&x| x == 1).await, Some(1));
+        // A rejected item stays buffered.
+        assert_eq!(s.as_mut().next_if(|&x| x == 1).await, None);
+        assert_eq!(s.as_mut().peek().await, Some(&2));
+        while s.as_mut().next_if(|&x| x < 5).await.is_some() {}
+
+        // The stream isn't polled anymore once it has ended.
+        assert!(s.is_terminated());
+        assert_eq!(s.as_mut().next_if(|_| true).await, None);
+        assert_eq!(s.next().await, None);
+    });
+}
+
 #[test]
 fn peekable_next_if_eq() {
     block_on(async {
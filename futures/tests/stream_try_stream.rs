@@ -28,6 +28,14 @@ fn try_skip_while_after_err() {
     assert_eq!(Poll::Ready(None), s.poll_next_unpin(cx));
 }
 
+#[test]
+fn try_skip_while_passes_through_errors() {
+    let s = stream::iter(vec![Ok(1), Err("upstream"), Ok(2), Ok(3), Err("later"), Ok(1)])
+        .try_skip_while(|x| futures::future::ready(Ok(*x < 3)));
+    let items = futures::executor::block_on(s.collect::<Vec<_>>());
+    assert_eq!(items, [Err("upstream"), Ok(3), Err("later"), Ok(1)]);
+}
+
 #[test]
 fn try_take_while_after_err() {
     let cx = &mut noop_context();
@@ -38,3 +46,105 @@ fn try_take_while_after_err() {
         .boxed();
     assert_eq!(Poll::Ready(None), s.poll_next_unpin(cx));
 }
+
+#[test]
+fn try_filter_map_passes_through_both_kinds_of_errors() {
+    let cx = &mut noop_context();
+    let mut calls = 0;
+    let mut s = stream::iter(vec![Ok(1), Err("upstream"), Ok(2), Ok(3), Ok(4)])
+        .try_filter_map(|v| {
+            calls += 1;
+            async move {
+                match v {
+                    3 => Err("closure"),
+                    v if v % 2 == 0 => Ok(Some(v * 10)),
+                    _ => Ok(None),
+                }
+            }
+        })
+        .boxed();
+
+    assert_eq!(Poll::Ready(Some(Err("upstream"))), s.poll_next_unpin(cx));
+    assert_eq!(Poll::Ready(Some(Ok(20))), s.poll_next_unpin(cx));
+    assert_eq!(Poll::Ready(Some(Err("closure"))), s.poll_next_unpin(cx));
+    assert_eq!(Poll::Ready(Some(Ok(40))), s.poll_next_unpin(cx));
+    assert_eq!(Poll::Ready(None), s.poll_next_unpin(cx));
+    drop(s);
+    assert_eq!(calls, 4);
+}
+
+#[test]
+fn try_next_timeout_leaves_stream_intact() {
+    use futures::channel::{mpsc, oneshot};
+    use futures::executor::block_on;
+    use futures::future::FutureExt;
+
+    let (tx, mut rx) = mpsc::unbounded::<Result<i32, ()>>();
+    let (fire, timer) = oneshot::channel::<()>();
+    let cx = &mut noop_context();
+
+    {
+        let mut next = rx.try_next_timeout(timer);
+        assert!(next.poll_unpin(cx).is_pending());
+        fire.send(()).unwrap();
+        match next.poll_unpin(cx) {
+            Poll::Ready(Err(_)) => {}
+            other => panic!("expected the timer to elapse, got {:?}", other),
+        }
+    }
+
+    tx.unbounded_send(Ok(1)).unwrap();
+    tx.unbounded_send(Err(())).unwrap();
+    drop(tx);
+    block_on(async {
+        assert_eq!(rx.try_next_timeout(futures::future::pending::<()>()).await, Ok(Ok(Some(1))));
+        assert_eq!(rx.try_next_timeout(futures::future::pending::<()>()).await, Ok(Err(())));
+        assert_eq!(rx.try_next_timeout(futures::future::ready(())).await, Ok(Ok(None)));
+    });
+}
+
+#[test]
+fn log_and_skip_errors_continues_past_errors() {
+    let mut errors = Vec::new();
+    let values = futures::executor::block_on(
+        stream::iter(vec![Err(0), Ok(1), Err(2), Err(3), Ok(4), Err(5)])
+            .log_and_skip_errors(|e| errors.push(e))
+            .collect::<Vec<i32>>(),
+    );
+    assert_eq!(values, vec![1, 4]);
+    assert_eq!(errors, vec![0, 2, 3, 5]);
+}
+
+#[test]
+fn log_and_skip_errors_size_hint() {
+    use futures::stream::Stream;
+
+    let s = stream::iter(vec![Ok::<i32, ()>(1), Ok(2)]).log_and_skip_errors(drop);
+    assert_eq!(s.size_hint(), (0, Some(2)));
+}
+
+#[test]
+fn try_flatten_tagged_keeps_error_origin() {
+    use futures::stream::FlattenError;
+
+    let first = stream::iter(vec![Ok(1), Err("read"), Ok(2)]);
+    let second = stream::iter(vec![Ok(3)]);
+    let s = stream::iter(vec![Ok(first), Err(404), Ok(second)]).try_flatten_tagged();
+    let items = futures::executor::block_on(s.collect::<Vec<_>>());
+    assert_eq!(
+        items,
+        [Ok(1), Err(FlattenError::Inner("read")), Ok(2), Err(FlattenError::Outer(404)), Ok(3)]
+    );
+}
+
+#[test]
+fn flatten_error_reports_error_as_source() {
+    use futures::stream::FlattenError;
+    use std::error::Error;
+    use std::io;
+
+    let err = FlattenError::<io::Error, io::Error>::Inner(io::ErrorKind::Other.into());
+    assert_eq!(err.to_string(), "error in an inner stream");
+    let source = err.source().unwrap().downcast_ref::<io::Error>().unwrap();
+    assert_eq!(source.kind(), io::ErrorKind::Other);
+}
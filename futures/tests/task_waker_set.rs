@@ -0,0 +1,109 @@
+use futures::task::WakerSet;
+use futures_test::task::new_count_waker;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+
+#[test]
+fn wake_one_in_registration_order() {
+    let set = WakerSet::new();
+    let (waker1, count1) = new_count_waker();
+    let (waker2, count2) = new_count_waker();
+    let key1 = set.register(&waker1);
+    set.register(&waker2);
+
+    assert!(set.wake_one());
+    assert_eq!(count1, 1);
+    assert_eq!(count2, 0);
+    assert!(set.wake_one());
+    assert_eq!(count2, 1);
+    assert!(!set.wake_one());
+
+    // Woken registrations can't be deregistered anymore.
+    assert!(!set.deregister(key1));
+    assert!(set.is_empty());
+}
+
+#[test]
+fn wake_all_skips_deregistered() {
+    let set = WakerSet::new();
+    let (waker1, count1) = new_count_waker();
+    let (waker2, count2) = new_count_waker();
+    let key1 = set.register(&waker1);
+    set.register(&waker2);
+
+    assert!(set.deregister(key1));
+    assert_eq!(set.wake_all(), 1);
+    assert_eq!(count1, 0);
+    assert_eq!(count2, 1);
+    assert_eq!(set.wake_all(), 0);
+    assert_eq!(count2, 1);
+}
+
+#[test]
+fn deregister_after_wrapping_around() {
+    let set = WakerSet::new();
+    let (waker, count) = new_count_waker();
+
+    // Waking from the front while registering at the back makes the
+    // registrations wrap around the end of the underlying buffer.
+    let mut keys: Vec<_> = (0..3).map(|_| set.register(&waker)).collect();
+    for _ in 0..20 {
+        assert!(set.wake_one());
+        keys.remove(0);
+        keys.push(set.register(&waker));
+    }
+
+    for key in keys.into_iter().rev() {
+        assert!(set.deregister(key));
+        assert!(!set.deregister(key));
+    }
+    assert!(set.is_empty());
+    assert_eq!(count, 20);
+}
+
+#[cfg_attr(miri, ignore)] // Miri is too slow
+#[test]
+fn concurrent_register_deregister_wake_all() {
+    let set = Arc::new(WakerSet::new());
+    let done = Arc::new(AtomicBool::new(false));
+
+    let waking = {
+        let set = set.clone();
+        let done = done.clone();
+        thread::spawn(move || {
+            while !done.load(Ordering::SeqCst) {
+                set.wake_all();
+            }
+        })
+    };
+
+    let registering: Vec<_> = (0..8)
+        .map(|_| {
+            let set = set.clone();
+            thread::spawn(move || {
+                (0..1000)
+                    .map(|i| {
+                        let (waker, count) = new_count_waker();
+                        let key = set.register(&waker);
+                        // Every other registration is cancelled again.
+                        let deregistered = i % 2 == 0 && set.deregister(key);
+                        (count, deregistered)
+                    })
+                    .collect::<Vec<_>>()
+            })
+        })
+        .collect();
+    let registrations: Vec<_> =
+        registering.into_iter().flat_map(|thread| thread.join().unwrap()).collect();
+
+    done.store(true, Ordering::SeqCst);
+    waking.join().unwrap();
+    set.wake_all();
+
+    // Each registration is either woken exactly once, or removed unwoken.
+    for (count, deregistered) in registrations {
+        assert_eq!(count, if deregistered { 0 } else { 1 });
+    }
+    assert!(set.is_empty());
+}